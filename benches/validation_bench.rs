@@ -315,6 +315,56 @@ fn bench_error_formatting(c: &mut Criterion) {
     });
 }
 
+// -----------------------------------------------------------------------
+// Value path vs. Deserializer path
+// -----------------------------------------------------------------------
+
+fn bench_deserializer_path(c: &mut Criterion) {
+    vld::schema! {
+        #[derive(Debug)]
+        struct BenchRecord {
+            id: i64          => vld::number().int().min(0),
+            name: String     => vld::string().min(1).max(100),
+            email: String    => vld::string().email(),
+            tags: Vec<String> => vld::array(vld::string().min(1)).max_len(10),
+            score: Option<f64> => vld::number().min(0.0).optional(),
+        }
+    }
+
+    let records: Vec<String> = (0..1000)
+        .map(|i| {
+            format!(
+                r#"{{"id":{},"name":"user{}","email":"user{}@example.com","tags":["a","b","c"],"score":{}.5}}"#,
+                i, i, i, i
+            )
+        })
+        .collect();
+    let input = format!("[{}]", records.join(","));
+    let schema = vld::array(vld::nested!(BenchRecord));
+
+    let mut group = c.benchmark_group("records_1000");
+    group.bench_function("value_path", |b| {
+        b.iter(|| schema.parse(black_box(input.as_str())))
+    });
+    group.bench_function("deserializer_path", |b| {
+        b.iter(|| schema.parse_json_str(black_box(&input)))
+    });
+    group.finish();
+
+    let nums: Vec<String> = (0..1000).map(|i| i.to_string()).collect();
+    let ints_input = format!("[{}]", nums.join(","));
+    let ints = vld::array(vld::number().int().min(0));
+
+    let mut group = c.benchmark_group("ints_1000");
+    group.bench_function("value_path", |b| {
+        b.iter(|| ints.parse(black_box(ints_input.as_str())))
+    });
+    group.bench_function("deserializer_path", |b| {
+        b.iter(|| ints.parse_json_str(black_box(&ints_input)))
+    });
+    group.finish();
+}
+
 // -----------------------------------------------------------------------
 // Register all benchmark groups
 // -----------------------------------------------------------------------
@@ -334,5 +384,6 @@ criterion_group!(
     bench_coercion,
     bench_conditional,
     bench_error_formatting,
+    bench_deserializer_path,
 );
 criterion_main!(benches);
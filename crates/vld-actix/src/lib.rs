@@ -90,6 +90,22 @@ impl ResponseError for VldJsonError {
 /// Actix-web extractor that validates **JSON request bodies**.
 ///
 /// Drop-in replacement for `actix_web::web::Json<T>`.
+///
+/// With the `async` feature enabled the body is validated via
+/// [`VldParse::vld_parse_value_async_with_options`](vld::schema::VldParse::vld_parse_value_async_with_options),
+/// so `refine_async` / `super_refine_async` checks run as part of extraction.
+///
+/// Bodies are parsed with the [`ParseOptions`](vld::options::ParseOptions)
+/// registered as app data, falling back to
+/// [`ParseOptions::safe_defaults()`](vld::options::ParseOptions::safe_defaults):
+///
+/// ```ignore
+/// use vld::options::ParseOptions;
+///
+/// App::new()
+///     .app_data(ParseOptions::safe_defaults().abort_early(true))
+///     .route("/users", web::post().to(create_user));
+/// ```
 pub struct VldJson<T>(pub T);
 
 impl<T> std::ops::Deref for VldJson<T> {
@@ -110,6 +126,10 @@ impl<T: vld::schema::VldParse> FromRequest for VldJson<T> {
     type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
 
     fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let options = req
+            .app_data::<ParseOptions>()
+            .cloned()
+            .unwrap_or_else(ParseOptions::safe_defaults);
         let json_fut = actix_web::web::Json::<serde_json::Value>::from_request(req, payload);
 
         Box::pin(async move {
@@ -120,7 +140,11 @@ impl<T: vld::schema::VldParse> FromRequest for VldJson<T> {
                 ),
             })?;
 
-            let parsed = T::vld_parse_value(&json_value).map_err(|error| VldJsonError { error })?;
+            #[cfg(feature = "async")]
+            let parsed = T::vld_parse_value_async_with_options(&json_value, &options).await;
+            #[cfg(not(feature = "async"))]
+            let parsed = T::vld_parse_value_with_options(&json_value, &options);
+            let parsed = parsed.map_err(|error| VldJsonError { error })?;
 
             Ok(VldJson(parsed))
         })
@@ -416,6 +440,7 @@ impl<T: vld::schema::VldParse> FromRequest for VldCookie<T> {
 
 // ========================= Helper functions ==================================
 
+use vld::options::ParseOptions;
 use vld_http_common::{
     coerce_value, cookies_to_json, extract_path_param_names, query_string_to_json,
 };
@@ -439,5 +464,6 @@ fn headers_to_json(headers: &actix_web::http::header::HeaderMap) -> serde_json::
 /// Prelude — import everything you need.
 pub mod prelude {
     pub use crate::{VldCookie, VldForm, VldHeaders, VldJson, VldJsonError, VldPath, VldQuery};
+    pub use vld::options::ParseOptions;
     pub use vld::prelude::*;
 }
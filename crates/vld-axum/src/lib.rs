@@ -103,6 +103,23 @@ impl std::fmt::Debug for VldJsonRejection {
 /// Axum extractor that validates **JSON request bodies**.
 ///
 /// Drop-in replacement for `axum::Json<T>`.
+///
+/// With the `async` feature enabled the body is validated via
+/// [`VldParse::vld_parse_value_async_with_options`](vld::schema::VldParse::vld_parse_value_async_with_options),
+/// so `refine_async` / `super_refine_async` checks run as part of extraction.
+///
+/// Bodies are parsed with [`ParseOptions`](vld::options::ParseOptions) taken
+/// from the request extensions, falling back to
+/// [`ParseOptions::safe_defaults()`](vld::options::ParseOptions::safe_defaults).
+/// Configure them per router with a layer:
+///
+/// ```ignore
+/// use vld::options::ParseOptions;
+///
+/// let app = Router::new()
+///     .route("/users", post(create_user))
+///     .layer(axum::Extension(ParseOptions::safe_defaults().abort_early(true)));
+/// ```
 pub struct VldJson<T>(pub T);
 
 impl<S, T> FromRequest<S> for VldJson<T>
@@ -113,6 +130,12 @@ where
     type Rejection = VldJsonRejection;
 
     async fn from_request(req: Request, _state: &S) -> Result<Self, Self::Rejection> {
+        let options = req
+            .extensions()
+            .get::<ParseOptions>()
+            .cloned()
+            .unwrap_or_else(ParseOptions::safe_defaults);
+
         let body = axum::body::to_bytes(req.into_body(), usize::MAX)
             .await
             .map_err(|_| VldJsonRejection {
@@ -130,7 +153,11 @@ where
                 ),
             })?;
 
-        let parsed = T::vld_parse_value(&value).map_err(|error| VldJsonRejection { error })?;
+        #[cfg(feature = "async")]
+        let parsed = T::vld_parse_value_async_with_options(&value, &options).await;
+        #[cfg(not(feature = "async"))]
+        let parsed = T::vld_parse_value_with_options(&value, &options);
+        let parsed = parsed.map_err(|error| VldJsonRejection { error })?;
 
         Ok(VldJson(parsed))
     }
@@ -368,6 +395,7 @@ where
 
 // ========================= Helper functions ==================================
 
+use vld::options::ParseOptions;
 use vld_http_common::{coerce_value, cookies_to_json, query_string_to_json};
 
 /// Build a JSON object from HTTP headers.
@@ -389,5 +417,6 @@ fn headers_to_json(headers: &http::HeaderMap) -> serde_json::Value {
 /// Prelude — import everything you need.
 pub mod prelude {
     pub use crate::{VldCookie, VldForm, VldHeaders, VldJson, VldJsonRejection, VldPath, VldQuery};
+    pub use vld::options::ParseOptions;
     pub use vld::prelude::*;
 }
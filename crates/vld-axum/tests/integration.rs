@@ -135,6 +135,46 @@ async fn rejection_display_and_debug() {
     assert!(json.get("issues").is_some());
 }
 
+async fn post_json(app: Router, body: serde_json::Value) -> axum::response::Response {
+    app.oneshot(
+        Request::builder()
+            .method("POST")
+            .uri("/test")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap(),
+    )
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn parse_options_from_extension() {
+    let app = json_app().layer(axum::Extension(
+        vld::options::ParseOptions::new().abort_early(true),
+    ));
+    let resp = post_json(app, serde_json::json!({"name": "A", "age": -1})).await;
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let json = body_json(resp).await;
+    assert_eq!(json["issues"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn parse_options_reject_oversized_input() {
+    let app = json_app().layer(axum::Extension(
+        vld::options::ParseOptions::new().max_string_len(10),
+    ));
+    let resp = post_json(
+        app,
+        serde_json::json!({"name": "Alice", "age": 1, "bio": "x".repeat(11)}),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let json = body_json(resp).await;
+    assert_eq!(json["issues"].as_array().unwrap().len(), 1);
+    assert_eq!(json["issues"][0]["path"], ".bio");
+}
+
 // ===========================================================================
 // VldQuery tests
 // ===========================================================================
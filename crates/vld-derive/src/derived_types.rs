@@ -0,0 +1,289 @@
+//! Struct types derived from a named struct with struct-level
+//! `#[vld(partial = "...")]`, `#[vld(pick(...))]` and `#[vld(omit(...))]`.
+//!
+//! Each declaration emits a new struct from a selection of the annotated
+//! struct's fields, with the same `#[vld(...)]` rules and serde attributes,
+//! and expands `Validate` for it. `partial` wraps every required field in
+//! `Option` and its schema in `.optional()`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{DeriveInput, Expr, Ident, Meta};
+
+use crate::fields::str_value;
+use crate::{expand, field_schema, find_serde_attr, has_serde_flag, shorthand};
+
+/// Keys of a struct-level `#[vld(...)]` that declares a derived type.
+const KEYS: &[&str] = &["partial", "pick", "omit"];
+
+enum Selection {
+    All,
+    Pick(Vec<Ident>),
+    Omit(Vec<Ident>),
+}
+
+pub(crate) struct DerivedType {
+    /// The declaring attribute, for error spans.
+    attr: syn::Attribute,
+    name: Ident,
+    selection: Selection,
+    partial: bool,
+    derives: Vec<syn::Path>,
+}
+
+/// Whether a struct-level `#[vld(...)]` declares derived types rather than
+/// a `refine` rule.
+pub(crate) fn is_derived_type_attr(attr: &syn::Attribute) -> bool {
+    attr.path().is_ident("vld")
+        && attr
+            .parse_args_with(Punctuated::<Meta, syn::Token![,]>::parse_terminated)
+            .is_ok_and(|nested| {
+                nested
+                    .first()
+                    .is_some_and(|meta| KEYS.iter().any(|key| meta.path().is_ident(key)))
+            })
+}
+
+/// All derived types declared on a type.
+pub(crate) fn parse(attrs: &[syn::Attribute]) -> syn::Result<Vec<DerivedType>> {
+    let mut derived = Vec::new();
+    for attr in attrs.iter().filter(|attr| is_derived_type_attr(attr)) {
+        let nested = attr.parse_args_with(Punctuated::<Meta, syn::Token![,]>::parse_terminated)?;
+        for meta in nested {
+            derived.push(DerivedType::parse(attr, &meta)?);
+        }
+    }
+    Ok(derived)
+}
+
+impl DerivedType {
+    /// `partial = "Name"`, or `partial(...)` / `pick(...)` / `omit(...)` with
+    /// `name = "..."`, `fields = [...]`, `partial` and `derive(...)` items.
+    fn parse(attr: &syn::Attribute, meta: &Meta) -> syn::Result<Self> {
+        let key = meta.path().get_ident().map(Ident::to_string);
+        let mut derived = DerivedType {
+            attr: attr.clone(),
+            name: Ident::new("__vld_unnamed", proc_macro2::Span::call_site()),
+            selection: Selection::All,
+            partial: key.as_deref() == Some("partial"),
+            derives: Vec::new(),
+        };
+
+        let list = match (key.as_deref(), meta) {
+            (Some("partial"), Meta::NameValue(nv)) => {
+                derived.name = str_value(&nv.value)?.parse()?;
+                return Ok(derived);
+            }
+            (Some("partial" | "pick" | "omit"), Meta::List(list)) => list,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    meta,
+                    "expected `partial = \"Name\"`, `partial(...)`, `pick(...)` or `omit(...)`",
+                ))
+            }
+        };
+
+        let mut name = None;
+        let mut fields = None;
+        for item in list.parse_args_with(Punctuated::<Meta, syn::Token![,]>::parse_terminated)? {
+            match &item {
+                Meta::NameValue(nv) if nv.path.is_ident("name") => {
+                    name = Some(str_value(&nv.value)?.parse::<Ident>()?)
+                }
+                Meta::NameValue(nv)
+                    if nv.path.is_ident("fields") && key.as_deref() != Some("partial") =>
+                {
+                    fields = Some(field_list(&nv.value)?)
+                }
+                Meta::Path(path) if path.is_ident("partial") => derived.partial = true,
+                Meta::List(list) if list.path.is_ident("derive") => {
+                    derived.derives.extend(list.parse_args_with(
+                        Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+                    )?)
+                }
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        "unknown key, expected `name`, `fields`, `partial` or `derive(...)`",
+                    ))
+                }
+            }
+        }
+
+        derived.name =
+            name.ok_or_else(|| syn::Error::new_spanned(meta, "missing `name = \"...\"`"))?;
+        derived.selection = match (key.as_deref(), fields) {
+            (Some("pick"), Some(fields)) => Selection::Pick(fields),
+            (Some("omit"), Some(fields)) => Selection::Omit(fields),
+            (Some("partial"), _) => Selection::All,
+            _ => return Err(syn::Error::new_spanned(meta, "missing `fields = [...]`")),
+        };
+        Ok(derived)
+    }
+
+    /// The derived struct and its `Validate` expansion.
+    pub(crate) fn expand(
+        &self,
+        input: &DeriveInput,
+        fields: &Punctuated<syn::Field, syn::Token![,]>,
+    ) -> syn::Result<TokenStream> {
+        let listed = match &self.selection {
+            Selection::All => &[][..],
+            Selection::Pick(names) | Selection::Omit(names) => &names[..],
+        };
+        for ident in listed {
+            if !fields.iter().any(|f| f.ident.as_ref() == Some(ident)) {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    format!("no field `{}` in `{}`", ident, input.ident),
+                ));
+            }
+        }
+
+        let mut selected = Punctuated::<syn::Field, syn::Token![,]>::new();
+        for field in fields {
+            let ident = field.ident.as_ref().unwrap();
+            let keep = match &self.selection {
+                Selection::All => true,
+                Selection::Pick(names) => names.contains(ident),
+                Selection::Omit(names) => !names.contains(ident),
+            };
+            if keep {
+                selected.push(self.derive_field(field, &input.generics)?);
+            }
+        }
+
+        // The new type's impls come from the same expansion as the original's.
+        let mut derived_input = input.clone();
+        derived_input.ident = self.name.clone();
+        derived_input.attrs = input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("serde") || attr.path().is_ident("into_params"))
+            .cloned()
+            .collect();
+        derived_input.data = syn::Data::Struct(syn::DataStruct {
+            struct_token: Default::default(),
+            fields: syn::Fields::Named(syn::FieldsNamed {
+                brace_token: Default::default(),
+                named: selected.clone(),
+            }),
+            semi_token: None,
+        });
+        let impls = expand(&derived_input)?;
+
+        // Serde attributes are only allowed on the emitted struct when one of
+        // its derives is serde's.
+        let keep_serde = self.derives.iter().any(|path| {
+            path.segments
+                .last()
+                .is_some_and(|seg| seg.ident == "Serialize" || seg.ident == "Deserialize")
+        });
+        let kept = |attr: &&syn::Attribute| {
+            attr.path().is_ident("doc") || (keep_serde && attr.path().is_ident("serde"))
+        };
+        let container_attrs = derived_input.attrs.iter().filter(kept);
+        let field_defs = selected.iter().map(|field| {
+            let attrs = field.attrs.iter().filter(kept);
+            let (vis, ident, ty) = (&field.vis, &field.ident, &field.ty);
+            quote! { #(#attrs)* #vis #ident: #ty }
+        });
+
+        let doc = self.doc(&input.ident);
+        let derives = if self.derives.is_empty() {
+            quote! {}
+        } else {
+            let derives = &self.derives;
+            quote! { #[derive(#(#derives),*)] }
+        };
+        let vis = &input.vis;
+        let name = &self.name;
+        let generics = &input.generics;
+        let where_clause = &input.generics.where_clause;
+
+        Ok(quote! {
+            #[doc = #doc]
+            #derives
+            #(#container_attrs)*
+            #vis struct #name #generics #where_clause {
+                #(#field_defs,)*
+            }
+
+            #impls
+        })
+    }
+
+    /// A field of the derived struct. Under `partial`, a field that must be
+    /// present becomes `Option<T>` with an `.optional()` schema.
+    fn derive_field(
+        &self,
+        field: &syn::Field,
+        generics: &syn::Generics,
+    ) -> syn::Result<syn::Field> {
+        let attrs = &field.attrs;
+        let already_optional = shorthand::generic_arg(&field.ty, "Option").is_some()
+            || has_serde_flag(attrs, "skip")
+            || has_serde_flag(attrs, "skip_deserializing")
+            || has_serde_flag(attrs, "flatten")
+            || find_serde_attr(attrs, "default").is_some();
+        if !self.partial || already_optional {
+            return Ok(field.clone());
+        }
+
+        let ident = field.ident.as_ref().unwrap();
+        let schema = field_schema(field, &ident.to_string(), generics)?;
+        let ty = &field.ty;
+        let mut field = field.clone();
+        field.ty = syn::parse_quote!(::std::option::Option<#ty>);
+        field.attrs.retain(|attr| !attr.path().is_ident("vld"));
+        field
+            .attrs
+            .push(syn::parse_quote!(#[vld((#schema).optional())]));
+        Ok(field)
+    }
+
+    fn doc(&self, source: &Ident) -> String {
+        let names = |idents: &[Ident]| {
+            idents
+                .iter()
+                .map(|i| format!("`{}`", i))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut doc = match &self.selection {
+            Selection::All => format!("[`{}`] with every field optional.", source),
+            Selection::Pick(fields) => format!("[`{}`] with only {}.", source, names(fields)),
+            Selection::Omit(fields) => format!("[`{}`] without {}.", source, names(fields)),
+        };
+        if self.partial && !matches!(self.selection, Selection::All) {
+            doc.push_str(" Every field is optional.");
+        }
+        doc
+    }
+
+    /// The declaring attribute, for errors about where it is used.
+    pub(crate) fn attr(&self) -> &syn::Attribute {
+        &self.attr
+    }
+}
+
+/// The identifiers of `fields = [a, b, ...]`.
+fn field_list(expr: &Expr) -> syn::Result<Vec<Ident>> {
+    let Expr::Array(array) = expr else {
+        return Err(syn::Error::new_spanned(
+            expr,
+            "expected a list of fields: `[a, b]`",
+        ));
+    };
+    array
+        .elems
+        .iter()
+        .map(|elem| match elem {
+            Expr::Path(path) if path.path.get_ident().is_some() => {
+                Ok(path.path.get_ident().unwrap().clone())
+            }
+            other => Err(syn::Error::new_spanned(other, "expected a field name")),
+        })
+        .collect()
+}
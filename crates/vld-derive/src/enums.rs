@@ -0,0 +1,537 @@
+//! `#[derive(Validate)]` for enums.
+//!
+//! Unit-only enums validate like `vld::enumeration()`. Enums with data
+//! follow serde's representations (external, `tag`, `tag` + `content`,
+//! `untagged`) and validate each variant's fields with their `#[vld(...)]`
+//! schemas.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{DataEnum, DeriveInput, Fields, Ident, Type};
+
+use crate::{
+    bounded_generics, field_schema, find_serde_attr, get_serde_rename, get_serde_rename_all,
+    get_serde_str, has_serde_flag, input_methods, json_schema_impl, parse_async, rename_field,
+    rename_variant,
+};
+
+/// Largest tuple variant supported (tuples of schemas implement `VldSchema` up to 6).
+const MAX_TUPLE_FIELDS: usize = 6;
+
+enum Repr {
+    External,
+    Internal(String),
+    Adjacent(String, String),
+    Untagged,
+}
+
+enum Kind {
+    Unit,
+    Newtype(TokenStream),
+    Tuple(Vec<TokenStream>),
+    Struct(Vec<StructField>),
+}
+
+struct StructField {
+    ident: Ident,
+    ty: Type,
+    schema: TokenStream,
+    json_key: String,
+}
+
+struct Variant {
+    ident: Ident,
+    name: String,
+    kind: Kind,
+}
+
+fn parse_variants(input: &DeriveInput, data: &DataEnum, repr: &Repr) -> syn::Result<Vec<Variant>> {
+    let rename_all = get_serde_rename_all(&input.attrs)?;
+
+    data.variants
+        .iter()
+        .map(|variant| {
+            let ident = variant.ident.clone();
+            let name = get_serde_rename(&variant.attrs).unwrap_or_else(|| match &rename_all {
+                Some(convention) => rename_variant(&ident.to_string(), convention),
+                None => ident.to_string(),
+            });
+
+            let kind = match &variant.fields {
+                Fields::Unit => Kind::Unit,
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Kind::Newtype(
+                    field_schema(&fields.unnamed[0], &format!("{}.0", ident), &input.generics)?,
+                ),
+                Fields::Unnamed(fields) => {
+                    if let Repr::Internal(_) = repr {
+                        return Err(syn::Error::new_spanned(
+                            fields,
+                            format!(
+                                "internally tagged enum `{}` cannot have tuple variant `{}`",
+                                input.ident, ident
+                            ),
+                        ));
+                    }
+                    if fields.unnamed.len() > MAX_TUPLE_FIELDS {
+                        return Err(syn::Error::new_spanned(
+                            fields,
+                            format!(
+                                "tuple variant `{}` has more than {} fields",
+                                ident, MAX_TUPLE_FIELDS
+                            ),
+                        ));
+                    }
+                    Kind::Tuple(
+                        fields
+                            .unnamed
+                            .iter()
+                            .enumerate()
+                            .map(|(i, f)| {
+                                field_schema(f, &format!("{}.{}", ident, i), &input.generics)
+                            })
+                            .collect::<syn::Result<_>>()?,
+                    )
+                }
+                Fields::Named(fields) => {
+                    let variant_rename_all = get_serde_rename_all(&variant.attrs)?;
+                    Kind::Struct(
+                        fields
+                            .named
+                            .iter()
+                            .map(|f| {
+                                let fident = f.ident.clone().unwrap();
+                                let json_key = get_serde_rename(&f.attrs).unwrap_or_else(|| {
+                                    match &variant_rename_all {
+                                        Some(convention) => {
+                                            rename_field(&fident.to_string(), convention)
+                                        }
+                                        None => fident.to_string(),
+                                    }
+                                });
+                                Ok(StructField {
+                                    schema: field_schema(
+                                        f,
+                                        &format!("{}::{}", ident, fident),
+                                        &input.generics,
+                                    )?,
+                                    ident: fident,
+                                    ty: f.ty.clone(),
+                                    json_key,
+                                })
+                            })
+                            .collect::<syn::Result<_>>()?,
+                    )
+                }
+            };
+
+            Ok(Variant { ident, name, kind })
+        })
+        .collect()
+}
+
+/// Expression parsing `value` with the field schema `schema`.
+fn parse_field(schema: &TokenStream, value: TokenStream, asynchronous: bool) -> TokenStream {
+    if asynchronous {
+        parse_async(schema, &value)
+    } else {
+        quote! {{
+            let __vld_field_schema = { #schema };
+            __vld_field_schema.parse_value(#value)
+        }}
+    }
+}
+
+/// Expression of type `Result<Enum, VldError>` validating `__vld_payload`
+/// as the given variant.
+fn variant_parse(
+    name: &Ident,
+    variant: &Variant,
+    check_unit: bool,
+    asynchronous: bool,
+) -> TokenStream {
+    let ident = &variant.ident;
+    match &variant.kind {
+        Kind::Unit if check_unit => quote! {
+            if __vld_payload.is_null() {
+                ::std::result::Result::Ok(#name::#ident)
+            } else {
+                ::std::result::Result::Err(::vld::error::VldError::single(
+                    ::vld::error::IssueCode::InvalidType {
+                        expected: ::std::string::String::from("null"),
+                        received: ::vld::error::value_type_name(__vld_payload),
+                    },
+                    ::std::format!(
+                        "Expected null, received {}",
+                        ::vld::error::value_type_name(__vld_payload)
+                    ),
+                ))
+            }
+        },
+        Kind::Unit => quote! { ::std::result::Result::Ok(#name::#ident) },
+        Kind::Newtype(schema) => {
+            let parse = parse_field(schema, quote! { __vld_payload }, asynchronous);
+            quote! {
+                ::std::result::Result::map(#parse, #name::#ident)
+            }
+        }
+        Kind::Tuple(schemas) => {
+            let parse = parse_field(
+                &quote! { ( #( { #schemas }, )* ) },
+                quote! { __vld_payload },
+                asynchronous,
+            );
+            let binds: Vec<Ident> = (0..schemas.len())
+                .map(|i| format_ident!("__vld_{}", i))
+                .collect();
+            quote! {
+                ::std::result::Result::map(
+                    #parse,
+                    |( #( #binds, )* )| #name::#ident( #( #binds ),* ),
+                )
+            }
+        }
+        Kind::Struct(fields) => {
+            let field_names: Vec<&Ident> = fields.iter().map(|f| &f.ident).collect();
+            let field_types: Vec<&Type> = fields.iter().map(|f| &f.ty).collect();
+            let field_json_keys: Vec<&String> = fields.iter().map(|f| &f.json_key).collect();
+            let parses: Vec<TokenStream> = fields
+                .iter()
+                .map(|f| parse_field(&f.schema, quote! { __vld_field_value }, asynchronous))
+                .collect();
+            quote! {
+                match __vld_payload.as_object() {
+                    ::std::option::Option::None => ::std::result::Result::Err(
+                        ::vld::error::VldError::single(
+                            ::vld::error::IssueCode::InvalidType {
+                                expected: ::std::string::String::from("object"),
+                                received: ::vld::error::value_type_name(__vld_payload),
+                            },
+                            ::std::format!(
+                                "Expected object, received {}",
+                                ::vld::error::value_type_name(__vld_payload)
+                            ),
+                        ),
+                    ),
+                    ::std::option::Option::Some(__vld_obj) => {
+                        let mut __vld_errors = ::vld::error::VldError::new();
+
+                        #(
+                            #[allow(non_snake_case)]
+                            let #field_names: ::std::option::Option<#field_types> = {
+                                let __vld_field_value = __vld_obj
+                                    .get(#field_json_keys)
+                                    .unwrap_or(::vld::object::__absent());
+                                match #parses {
+                                    ::std::result::Result::Ok(v) => ::std::option::Option::Some(v),
+                                    ::std::result::Result::Err(e) => {
+                                        __vld_errors = ::vld::error::VldError::merge(
+                                            __vld_errors,
+                                            ::vld::error::VldError::with_prefix(
+                                                e,
+                                                ::vld::error::PathSegment::Field(
+                                                    ::std::string::String::from(#field_json_keys),
+                                                ),
+                                            ),
+                                        );
+                                        ::std::option::Option::None
+                                    }
+                                }
+                            };
+                        )*
+
+                        if ::vld::error::VldError::is_empty(&__vld_errors) {
+                            ::std::result::Result::Ok(#name::#ident {
+                                #( #field_names: #field_names.unwrap(), )*
+                            })
+                        } else {
+                            ::std::result::Result::Err(__vld_errors)
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Body of `parse_value` / `parse_value_async`, validating `__vld_json`.
+fn parse_body(name: &Ident, variants: &[Variant], repr: &Repr, asynchronous: bool) -> TokenStream {
+    let names: Vec<&String> = variants.iter().map(|v| &v.name).collect();
+    let all_unit = variants.iter().all(|v| matches!(v.kind, Kind::Unit));
+
+    match repr {
+        Repr::External if all_unit => {
+            let idents: Vec<&Ident> = variants.iter().map(|v| &v.ident).collect();
+            quote! {
+                let __vld_value = ::vld::enumeration(&[ #( #names ),* ]).parse_value(__vld_json)?;
+                match __vld_value.as_str() {
+                    #( #names => ::std::result::Result::Ok(#name::#idents), )*
+                    _ => ::std::unreachable!(),
+                }
+            }
+        }
+        Repr::Untagged => {
+            let parses: Vec<TokenStream> = variants
+                .iter()
+                .map(|v| variant_parse(name, v, true, asynchronous))
+                .collect();
+            let message = format!("Input did not match any variant of {}", name);
+            quote! {
+                let __vld_payload = __vld_json;
+                #(
+                    let __vld_attempt: ::std::result::Result<Self, ::vld::error::VldError> =
+                        #parses;
+                    if let ::std::result::Result::Ok(v) = __vld_attempt {
+                        return ::std::result::Result::Ok(v);
+                    }
+                )*
+                ::std::result::Result::Err(::vld::error::VldError::single(
+                    ::vld::error::IssueCode::Custom {
+                        code: ::std::string::String::from("invalid_union"),
+                    },
+                    #message,
+                ))
+            }
+        }
+        _ => {
+            let (tag, content) = match repr {
+                Repr::Internal(tag) => (quote! { Some(#tag) }, quote! { None }),
+                Repr::Adjacent(tag, content) => (quote! { Some(#tag) }, quote! { Some(#content) }),
+                _ => (quote! { None }, quote! { None }),
+            };
+            let check_unit = !matches!(repr, Repr::Internal(_));
+            let indexes = 0..variants.len();
+            let parses: Vec<TokenStream> = variants
+                .iter()
+                .map(|v| variant_parse(name, v, check_unit, asynchronous))
+                .collect();
+            quote! {
+                let (__vld_index, __vld_payload, __vld_prefix) =
+                    ::vld::combinators::__select_enum_variant(
+                        __vld_json,
+                        #tag,
+                        #content,
+                        &[ #( #names ),* ],
+                    )?;
+                let __vld_result: ::std::result::Result<Self, ::vld::error::VldError> =
+                    match __vld_index {
+                        #( #indexes => #parses, )*
+                        _ => ::std::unreachable!(),
+                    };
+                __vld_result.map_err(|e| match __vld_prefix {
+                    ::std::option::Option::Some(p) => ::vld::error::VldError::with_prefix(e, p),
+                    ::std::option::Option::None => e,
+                })
+            }
+        }
+    }
+}
+
+/// Expression of type `Option<serde_json::Value>`: the variant's payload schema.
+fn payload_schema(variant: &Variant) -> TokenStream {
+    match &variant.kind {
+        Kind::Unit => quote! { ::std::option::Option::None },
+        Kind::Newtype(schema) => quote! {
+            ::std::option::Option::Some({ #schema }.json_schema())
+        },
+        Kind::Tuple(schemas) => {
+            let len = schemas.len();
+            quote! {{
+                let __vld_items: ::std::vec::Vec<::vld::serde_json::Value> =
+                    ::std::vec![ #( { #schemas }.json_schema() ),* ];
+                ::std::option::Option::Some(::vld::serde_json::json!({
+                    "type": "array",
+                    "prefixItems": __vld_items,
+                    "minItems": #len,
+                    "maxItems": #len,
+                }))
+            }}
+        }
+        Kind::Struct(fields) => {
+            let field_schemas: Vec<&TokenStream> = fields.iter().map(|f| &f.schema).collect();
+            let field_json_keys: Vec<&String> = fields.iter().map(|f| &f.json_key).collect();
+            quote! {{
+                let mut __vld_properties = ::vld::serde_json::Map::new();
+                #(
+                    __vld_properties.insert(
+                        ::std::string::String::from(#field_json_keys),
+                        { #field_schemas }.json_schema(),
+                    );
+                )*
+                ::std::option::Option::Some(::vld::serde_json::json!({
+                    "type": "object",
+                    "required": [ #( #field_json_keys ),* ],
+                    "properties": ::vld::serde_json::Value::Object(__vld_properties),
+                }))
+            }}
+        }
+    }
+}
+
+pub(crate) fn derive_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+
+    let tag = get_serde_str(&input.attrs, "tag");
+    let content = get_serde_str(&input.attrs, "content");
+    let untagged = has_serde_flag(&input.attrs, "untagged");
+    let repr = match (tag, content, untagged) {
+        (None, None, true) => Repr::Untagged,
+        (_, _, true) => {
+            return Err(serde_attr_error(
+                input,
+                "untagged",
+                "`untagged` cannot be combined with `tag` or `content`",
+            ))
+        }
+        (Some(tag), Some(content), false) => Repr::Adjacent(tag, content),
+        (Some(tag), None, false) => Repr::Internal(tag),
+        (None, Some(_), false) => {
+            return Err(serde_attr_error(
+                input,
+                "content",
+                "`content` requires `tag`",
+            ))
+        }
+        (None, None, false) => Repr::External,
+    };
+
+    let variants = parse_variants(input, data, &repr)?;
+    let sync_body = parse_body(name, &variants, &repr, false);
+    let async_body = parse_body(name, &variants, &repr, true);
+
+    let names: Vec<&String> = variants.iter().map(|v| &v.name).collect();
+    let payloads: Vec<TokenStream> = variants.iter().map(payload_schema).collect();
+    let (tag_expr, content_expr, untagged_expr) = match &repr {
+        Repr::External => (quote! { None }, quote! { None }, quote! { false }),
+        Repr::Internal(tag) => (quote! { Some(#tag) }, quote! { None }, quote! { false }),
+        Repr::Adjacent(tag, content) => (
+            quote! { Some(#tag) },
+            quote! { Some(#content) },
+            quote! { false },
+        ),
+        Repr::Untagged => (quote! { None }, quote! { None }, quote! { true }),
+    };
+
+    let input_methods = input_methods();
+    let json_schema_impl = json_schema_impl(input);
+
+    let generics = bounded_generics(&input.generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #input_methods
+
+            /// Parse and validate directly from a `serde_json::Value`.
+            pub fn parse_value(
+                __vld_json: &::vld::serde_json::Value,
+            ) -> ::std::result::Result<Self, ::vld::error::VldError> {
+                #[allow(unused_imports)]
+                use ::vld::schema::VldSchema as _;
+
+                #sync_body
+            }
+        }
+
+        impl #impl_generics ::vld::schema::VldParse for #name #ty_generics #where_clause {
+            fn vld_parse_value(
+                value: &::vld::serde_json::Value,
+            ) -> ::std::result::Result<Self, ::vld::error::VldError> {
+                Self::parse_value(value)
+            }
+
+            ::vld::__vld_if_async! {
+                fn vld_parse_value_async(
+                    value: &::vld::serde_json::Value,
+                ) -> ::vld::async_schema::BoxFuture<
+                    '_,
+                    ::std::result::Result<Self, ::vld::error::VldError>,
+                > {
+                    Self::parse_value_async(value)
+                }
+            }
+        }
+
+        ::vld::__vld_if_async! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Parse and validate input data, running async refinements.
+                ///
+                /// Requires the `async` feature on `vld`.
+                pub fn vld_parse_async<__VldInputT: ::vld::input::VldInput + ?Sized>(
+                    input: &__VldInputT,
+                ) -> ::vld::async_schema::BoxFuture<
+                    'static,
+                    ::std::result::Result<Self, ::vld::error::VldError>,
+                > {
+                    let __vld_json = <__VldInputT as ::vld::input::VldInput>::to_json_value(input);
+                    ::std::boxed::Box::pin(async move {
+                        let __vld_json = __vld_json?;
+                        Self::parse_value_async(&__vld_json).await
+                    })
+                }
+
+                /// Async counterpart of `parse_value()`.
+                ///
+                /// Requires the `async` feature on `vld`.
+                pub fn parse_value_async(
+                    __vld_json: &::vld::serde_json::Value,
+                ) -> ::vld::async_schema::BoxFuture<
+                    '_,
+                    ::std::result::Result<Self, ::vld::error::VldError>,
+                > {
+                    ::std::boxed::Box::pin(async move {
+                        #[allow(unused_imports)]
+                        use ::vld::async_schema::{__VldViaAsync as _, __VldViaSync as _};
+                        #[allow(unused_imports)]
+                        use ::vld::schema::VldSchema as _;
+
+                        #async_body
+                    })
+                }
+            }
+        }
+
+        ::vld::__vld_if_openapi! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Generate a JSON Schema / OpenAPI 3.1 representation of this enum.
+                ///
+                /// Requires the `openapi` feature on `vld`.
+                pub fn json_schema() -> ::vld::serde_json::Value {
+                    #[allow(unused_imports)]
+                    use ::vld::json_schema::JsonSchema as _;
+                    ::vld::json_schema::__enum_json_schema(
+                        #tag_expr,
+                        #content_expr,
+                        #untagged_expr,
+                        ::std::vec![ #( (#names, #payloads) ),* ],
+                    )
+                }
+
+                /// Wrap `json_schema()` in a minimal OpenAPI 3.1 document.
+                ///
+                /// Requires the `openapi` feature on `vld`.
+                pub fn to_openapi_document() -> ::vld::serde_json::Value {
+                    ::vld::json_schema::to_openapi_document(
+                        &<Self as ::vld::json_schema::VldJsonSchema>::vld_schema_name(),
+                        &Self::json_schema(),
+                    )
+                }
+            }
+
+            impl #impl_generics ::vld::json_schema::OpenApiParameterIn for #name #ty_generics #where_clause {
+                fn parameter_in() -> Option<&'static str> {
+                    None
+                }
+            }
+
+            #json_schema_impl
+        }
+    })
+}
+
+/// An error on the `#[serde(...)]` attribute that sets `key`.
+fn serde_attr_error(input: &DeriveInput, key: &str, message: &str) -> syn::Error {
+    match find_serde_attr(&input.attrs, key) {
+        Some(attr) => syn::Error::new_spanned(attr, message),
+        None => syn::Error::new_spanned(&input.ident, message),
+    }
+}
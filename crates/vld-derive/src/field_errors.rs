@@ -0,0 +1,137 @@
+//! Typed field enums from a struct-level `#[vld(field_errors)]`.
+//!
+//! For `struct User` this emits `UserField`, with one variant per field read
+//! under its own JSON key, and `UserFieldError`, pairing each variant with a
+//! `ValidationIssue`. Skipped and flattened fields get no variant.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::punctuated::Punctuated;
+use syn::{DeriveInput, Meta};
+
+use crate::fields::NamedField;
+use crate::to_pascal_case;
+
+/// Whether a struct-level `#[vld(...)]` is `#[vld(field_errors)]`.
+pub(crate) fn is_field_errors_attr(attr: &syn::Attribute) -> bool {
+    attr.path().is_ident("vld")
+        && attr
+            .parse_args_with(Punctuated::<Meta, syn::Token![,]>::parse_terminated)
+            .is_ok_and(|nested| {
+                nested.len() == 1
+                    && matches!(nested.first(), Some(Meta::Path(path)) if path.is_ident("field_errors"))
+            })
+}
+
+/// The `#[vld(field_errors)]` attribute of a type, if any.
+pub(crate) fn find(attrs: &[syn::Attribute]) -> Option<&syn::Attribute> {
+    attrs.iter().find(|attr| is_field_errors_attr(attr))
+}
+
+/// The field enum and field-error enum of a named struct.
+pub(crate) fn expand(input: &DeriveInput, fields: &[NamedField]) -> TokenStream {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let field_enum = format_ident!("{}Field", name);
+    let error_enum = format_ident!("{}FieldError", name);
+
+    let keyed: Vec<_> = fields
+        .iter()
+        .filter_map(|field| {
+            let (key, aliases) = field.key()?;
+            let variant = format_ident!("{}", to_pascal_case(&field.ident().unraw().to_string()));
+            Some((variant, key, aliases))
+        })
+        .collect();
+    let variants: Vec<_> = keyed.iter().map(|(variant, ..)| variant).collect();
+    let variant_docs = keyed.iter().map(|(_, key, _)| format!("`{}`", key));
+    let keys = keyed.iter().map(|(_, key, _)| key);
+    let accepted = keyed.iter().map(|(_, key, aliases)| {
+        let aliases = aliases.iter();
+        quote! { #key #( | #aliases )* }
+    });
+    let aliases = keyed.iter().map(|(_, _, aliases)| {
+        let aliases = aliases.iter();
+        quote! { &[ #( #aliases ),* ] }
+    });
+
+    let field_doc = format!("The fields of [`{}`], one variant per JSON key.", name);
+    let error_doc = format!("A validation issue of one of the fields of [`{}`].", name);
+
+    quote! {
+        #[doc = #field_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis enum #field_enum {
+            #( #[doc = #variant_docs] #variants, )*
+        }
+
+        impl #field_enum {
+            /// Every field, in declaration order.
+            pub const ALL: &'static [Self] = &[ #( Self::#variants ),* ];
+
+            /// The field read from this JSON key or alias.
+            pub fn from_key(key: &str) -> ::std::option::Option<Self> {
+                match key {
+                    #( #accepted => ::std::option::Option::Some(Self::#variants), )*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+
+        impl ::vld::error::FieldKey for #field_enum {
+            fn key(self) -> &'static str {
+                match self {
+                    #( Self::#variants => #keys, )*
+                }
+            }
+
+            fn aliases(self) -> &'static [&'static str] {
+                match self {
+                    #( Self::#variants => #aliases, )*
+                }
+            }
+        }
+
+        #[doc = #error_doc]
+        #[derive(Debug, Clone)]
+        #vis enum #error_enum {
+            #( #variants(::vld::error::ValidationIssue), )*
+        }
+
+        impl #error_enum {
+            /// The field the issue is about.
+            pub fn field(&self) -> #field_enum {
+                match self {
+                    #( Self::#variants(_) => #field_enum::#variants, )*
+                }
+            }
+
+            /// The issue itself.
+            pub fn issue(&self) -> &::vld::error::ValidationIssue {
+                match self {
+                    #( Self::#variants(issue) => issue, )*
+                }
+            }
+
+            /// The issue as a field error, if its path starts at one of the fields.
+            pub fn from_issue(
+                issue: &::vld::error::ValidationIssue,
+            ) -> ::std::option::Option<Self> {
+                let key = match issue.path.first() {
+                    ::std::option::Option::Some(::vld::error::PathSegment::Field(key)) => key,
+                    _ => return ::std::option::Option::None,
+                };
+                match #field_enum::from_key(key)? {
+                    #( #field_enum::#variants => ::std::option::Option::Some(Self::#variants(issue.clone())), )*
+                }
+            }
+
+            /// Every issue of `err` that belongs to a field. Struct-level
+            /// issues and unknown keys are left out.
+            pub fn from_error(err: &::vld::error::VldError) -> ::std::vec::Vec<Self> {
+                err.issues.iter().filter_map(Self::from_issue).collect()
+            }
+        }
+    }
+}
@@ -0,0 +1,447 @@
+//! Per-field code of `#[derive(Validate)]` for named structs.
+//!
+//! Besides its `#[vld(...)]` schema, a field honors the serde attributes that
+//! change how it is read: `rename`, `alias`, `default`, `skip` /
+//! `skip_deserializing` and `flatten`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Expr, Ident, Lit, Meta, Type};
+
+use crate::{field_schema, get_serde_rename, parse_async, rename_field};
+
+pub(crate) struct NamedField<'a> {
+    ident: &'a Ident,
+    ty: &'a Type,
+    kind: Kind,
+}
+
+enum Kind {
+    /// `#[serde(skip)]`: not read from the input, always `Default::default()`.
+    Skip,
+    /// `#[serde(flatten)]`: a nested validated struct read from the same object.
+    Flatten,
+    /// A field under its own JSON key.
+    Keyed {
+        key: String,
+        aliases: Vec<String>,
+        /// Value used when the key is absent (`#[serde(default)]`).
+        default: Option<TokenStream>,
+        schema: TokenStream,
+    },
+}
+
+impl<'a> NamedField<'a> {
+    /// Read a field's attributes. `container_default` is the struct-level
+    /// `#[serde(default)]` value, whose fields fill in missing keys.
+    pub(crate) fn parse(
+        field: &'a syn::Field,
+        rename_all: Option<&str>,
+        container_default: Option<&TokenStream>,
+        generics: &syn::Generics,
+    ) -> syn::Result<Self> {
+        let ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let mut skip = false;
+        let mut flatten = false;
+        let mut default = None;
+        let mut aliases = Vec::new();
+
+        for meta in serde_metas(&field.attrs)? {
+            match &meta {
+                Meta::Path(path)
+                    if path.is_ident("skip") || path.is_ident("skip_deserializing") =>
+                {
+                    skip = true
+                }
+                Meta::Path(path) if path.is_ident("flatten") => flatten = true,
+                Meta::NameValue(nv) if nv.path.is_ident("alias") => {
+                    aliases.push(str_value(&nv.value)?.value())
+                }
+                _ => {
+                    if let Some(expr) = serde_default(&meta, quote! { #ty })? {
+                        default = Some(expr);
+                    }
+                }
+            }
+        }
+
+        let kind = if skip {
+            Kind::Skip
+        } else if flatten {
+            Kind::Flatten
+        } else {
+            // JSON key: #[serde(rename = "...")] > rename_all > field name
+            let key = get_serde_rename(&field.attrs).unwrap_or_else(|| match rename_all {
+                Some(convention) => rename_field(&ident.to_string(), convention),
+                None => ident.to_string(),
+            });
+            let default = default.or_else(|| container_default.map(|d| quote! { #d.#ident }));
+            Kind::Keyed {
+                key,
+                aliases,
+                default,
+                schema: field_schema(field, &ident.to_string(), generics)?,
+            }
+        };
+
+        Ok(Self { ident, ty, kind })
+    }
+
+    pub(crate) fn ident(&self) -> &Ident {
+        self.ident
+    }
+
+    pub(crate) fn ty(&self) -> &Type {
+        self.ty
+    }
+
+    pub(crate) fn is_flatten(&self) -> bool {
+        matches!(self.kind, Kind::Flatten)
+    }
+
+    /// The JSON key and aliases of a field read under its own key.
+    pub(crate) fn key(&self) -> Option<(&str, &[String])> {
+        match &self.kind {
+            Kind::Keyed { key, aliases, .. } => Some((key, aliases)),
+            Kind::Skip | Kind::Flatten => None,
+        }
+    }
+
+    /// The keys this field accepts in the input object.
+    pub(crate) fn known_keys(&self) -> Vec<&str> {
+        match &self.kind {
+            Kind::Keyed { key, aliases, .. } => std::iter::once(key.as_str())
+                .chain(aliases.iter().map(String::as_str))
+                .collect(),
+            Kind::Skip | Kind::Flatten => Vec::new(),
+        }
+    }
+
+    /// `let <field>: Option<T>` for `parse_value()` / `parse_value_async()`,
+    /// merging the field's issues into `__vld_errors`.
+    pub(crate) fn parse_value(&self, is_async: bool) -> TokenStream {
+        let Self { ident, ty, .. } = self;
+        let value = match &self.kind {
+            Kind::Skip => quote! {
+                ::std::option::Option::Some(::std::default::Default::default())
+            },
+            Kind::Flatten => {
+                let call = if is_async {
+                    quote! { <#ty as ::vld::schema::VldParse>::vld_parse_value_async(__vld_json).await }
+                } else {
+                    quote! { <#ty as ::vld::schema::VldParse>::vld_parse_value(__vld_json) }
+                };
+                quote! {
+                    match #call {
+                        ::std::result::Result::Ok(v) => ::std::option::Option::Some(v),
+                        ::std::result::Result::Err(e) => {
+                            __vld_errors = ::vld::error::VldError::merge(__vld_errors, e);
+                            ::std::option::Option::None
+                        }
+                    }
+                }
+            }
+            Kind::Keyed {
+                default, schema, ..
+            } => {
+                let call = if is_async {
+                    parse_async(schema, &quote! { __vld_field_value })
+                } else {
+                    quote! {{
+                        let __vld_field_schema = { #schema };
+                        __vld_field_schema.parse_value(__vld_field_value)
+                    }}
+                };
+                let present = quote! {
+                    match #call {
+                        ::std::result::Result::Ok(v) => ::std::option::Option::Some(v),
+                        ::std::result::Result::Err(e) => {
+                            __vld_errors = ::vld::error::VldError::merge(
+                                __vld_errors,
+                                ::vld::error::VldError::with_prefix(
+                                    e,
+                                    ::vld::error::PathSegment::Field(
+                                        ::std::string::String::from(__vld_key),
+                                    ),
+                                ),
+                            );
+                            ::std::option::Option::None
+                        }
+                    }
+                };
+                let missing = default
+                    .as_ref()
+                    .map(|d| quote! { ::std::option::Option::Some(#d) });
+                let lookup = self.lookup();
+                let read = read_entry(present, missing);
+                quote! {{
+                    #lookup
+                    #read
+                }}
+            }
+        };
+        quote! {
+            #[allow(non_snake_case)]
+            let #ident: ::std::option::Option<#ty> = #value;
+        }
+    }
+
+    /// Push this field's `FieldResult`s onto `__vld_results` for
+    /// `validate_fields_value()`.
+    pub(crate) fn validate_fields(&self) -> TokenStream {
+        let ty = self.ty;
+        match &self.kind {
+            Kind::Skip => quote! {},
+            Kind::Flatten => quote! {
+                __vld_results.extend(<#ty>::validate_fields_value(__vld_json)?);
+            },
+            Kind::Keyed {
+                default, schema, ..
+            } => {
+                let present = quote! {{
+                    let (__vld_result, __vld_children) =
+                        ::vld::object::DynSchema::dyn_parse_lenient(
+                            &__vld_field_schema,
+                            __vld_field_value,
+                        );
+
+                    __vld_results.push(::vld::error::FieldResult::new(
+                        ::std::string::String::from(__vld_key),
+                        __vld_field_value.clone(),
+                        __vld_result,
+                    )
+                    .with_children(__vld_children));
+                }};
+                let missing = default.as_ref().map(|d| {
+                    let result = default_result(quote! { &__vld_default });
+                    quote! {{
+                        let __vld_default: #ty = #d;
+                        __vld_results.push(#result);
+                    }}
+                });
+                let lookup = self.lookup();
+                let read = read_entry(present, missing);
+                quote! {{
+                    let __vld_field_schema = { #schema };
+                    #lookup
+                    #read
+                }}
+            }
+        }
+    }
+
+    /// `let <field>: T` for `parse_lenient_value()`, pushing its
+    /// `FieldResult`s onto `__vld_results`.
+    pub(crate) fn parse_lenient(&self) -> TokenStream {
+        let Self { ident, ty, .. } = self;
+        let value = match &self.kind {
+            Kind::Skip => quote! { ::std::default::Default::default() },
+            Kind::Flatten => quote! {{
+                let (__vld_value, __vld_fields) =
+                    <#ty as ::vld::schema::VldParseLenient>::vld_parse_lenient_value(__vld_json)?
+                        .into_parts();
+                __vld_results.extend(__vld_fields);
+                __vld_value
+            }},
+            Kind::Keyed {
+                default, schema, ..
+            } => {
+                let present = quote! {{
+                    let __vld_lenient = __vld_field_schema.parse_value_lenient(__vld_field_value);
+                    let mut __vld_children = __vld_lenient.children;
+                    let __json_repr = __vld_lenient
+                        .value
+                        .as_ref()
+                        .and_then(|v| ::vld::serde_json::to_value(v).ok());
+                    if let ::std::option::Option::Some(j) = &__json_repr {
+                        ::vld::error::__fill_child_outputs(&mut __vld_children, j);
+                    }
+                    __vld_results.push(::vld::error::FieldResult::new(
+                        ::std::string::String::from(__vld_key),
+                        __vld_field_value.clone(),
+                        match __vld_lenient.error {
+                            ::std::option::Option::Some(e) => ::std::result::Result::Err(e),
+                            ::std::option::Option::None => ::std::result::Result::Ok(
+                                __json_repr.unwrap_or_else(|| __vld_field_value.clone()),
+                            ),
+                        },
+                    )
+                    .with_children(__vld_children));
+                    // Partially valid nested values are kept; only
+                    // unrecoverable fields fall back to `Default`.
+                    __vld_lenient.value.unwrap_or_default()
+                }};
+                let missing = default.as_ref().map(|d| {
+                    let result = default_result(quote! { &__vld_default });
+                    quote! {{
+                        let __vld_default: #ty = #d;
+                        __vld_results.push(#result);
+                        __vld_default
+                    }}
+                });
+                let lookup = self.lookup();
+                let read = read_entry(present, missing);
+                quote! {{
+                    let __vld_field_schema = { #schema };
+                    #lookup
+                    #read
+                }}
+            }
+        };
+        quote! {
+            #[allow(non_snake_case)]
+            let #ident: #ty = #value;
+        }
+    }
+
+    /// Add this field to `__vld_properties` / `__vld_required` in
+    /// `json_schema()`.
+    pub(crate) fn json_schema(&self) -> TokenStream {
+        let ty = self.ty;
+        match &self.kind {
+            Kind::Skip => quote! {},
+            Kind::Flatten => quote! {
+                ::vld::json_schema::__flatten_into(
+                    &<#ty as ::vld::json_schema::VldJsonSchema>::vld_json_schema(),
+                    &mut __vld_properties,
+                    &mut __vld_required,
+                );
+            },
+            Kind::Keyed {
+                key,
+                default,
+                schema,
+                ..
+            } => {
+                let required = if default.is_none() {
+                    quote! {
+                        if !::vld::schema::VldSchema::__vld_optional_key(&__vld_field_schema) {
+                            __vld_required.push(::std::string::String::from(#key));
+                        }
+                    }
+                } else {
+                    quote! {}
+                };
+                quote! {{
+                    let __vld_field_schema = { #schema };
+                    __vld_properties.insert(
+                        ::std::string::String::from(#key),
+                        __vld_field_schema.json_schema(),
+                    );
+                    #required
+                }}
+            }
+        }
+    }
+
+    /// Add this field to the `ZObject` of `vld_deep_partial()`.
+    pub(crate) fn deep_partial(&self) -> TokenStream {
+        let ty = self.ty;
+        match &self.kind {
+            Kind::Skip => quote! {},
+            Kind::Flatten => quote! {
+                .extend(<#ty as ::vld::schema::VldDeepPartial>::vld_deep_partial())
+            },
+            Kind::Keyed { key, schema, .. } => quote! {
+                .__field_boxed(
+                    #key,
+                    (&&::vld::object::__VldPartialField(::std::cell::Cell::new(
+                        ::std::option::Option::Some({ #schema }),
+                    )))
+                    .__vld_boxed(),
+                )
+            },
+        }
+    }
+
+    /// Bind `(__vld_key, __vld_entry)`: the key that matched and its value.
+    fn lookup(&self) -> TokenStream {
+        let Kind::Keyed { key, aliases, .. } = &self.kind else {
+            unreachable!("only keyed fields are looked up");
+        };
+        quote! {
+            let (__vld_key, __vld_entry) =
+                ::vld::object::__field_entry(__vld_obj, #key, &[ #( #aliases ),* ]);
+        }
+    }
+}
+
+/// Run `present` with `__vld_field_value` bound to the field's input. Without
+/// a `missing` expression an absent key reads as `null` (`vld::patch()` tells
+/// the two apart), so the schema decides whether the field is required.
+fn read_entry(present: TokenStream, missing: Option<TokenStream>) -> TokenStream {
+    match missing {
+        Some(missing) => quote! {
+            match __vld_entry {
+                ::std::option::Option::None => #missing,
+                ::std::option::Option::Some(__vld_field_value) => #present,
+            }
+        },
+        None => quote! {{
+            let __vld_field_value =
+                __vld_entry.unwrap_or(::vld::object::__absent());
+            #present
+        }},
+    }
+}
+
+/// The `FieldResult` of an absent field that took its default `value`.
+fn default_result(value: TokenStream) -> TokenStream {
+    quote! {
+        ::vld::error::FieldResult::new(
+            ::std::string::String::from(__vld_key),
+            ::vld::serde_json::Value::Null,
+            ::std::result::Result::Ok(
+                ::vld::serde_json::to_value(#value)
+                    .unwrap_or(::vld::serde_json::Value::Null),
+            ),
+        )
+    }
+}
+
+/// Every item of every `#[serde(...)]` attribute.
+fn serde_metas(attrs: &[syn::Attribute]) -> syn::Result<Vec<Meta>> {
+    let mut metas = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        metas.extend(attr.parse_args_with(
+            syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated,
+        )?);
+    }
+    Ok(metas)
+}
+
+/// The expression of a `default` / `default = "path"` serde item, with
+/// `default_ty` as the type of a bare `default`.
+fn serde_default(meta: &Meta, default_ty: TokenStream) -> syn::Result<Option<TokenStream>> {
+    match meta {
+        Meta::Path(path) if path.is_ident("default") => Ok(Some(
+            quote! { <#default_ty as ::std::default::Default>::default() },
+        )),
+        Meta::NameValue(nv) if nv.path.is_ident("default") => {
+            let path: syn::ExprPath = str_value(&nv.value)?.parse()?;
+            Ok(Some(quote! { #path() }))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// The struct-level `#[serde(default)]` value, if any.
+pub(crate) fn container_default(attrs: &[syn::Attribute]) -> syn::Result<Option<TokenStream>> {
+    for meta in serde_metas(attrs)? {
+        if let Some(expr) = serde_default(&meta, quote! { Self })? {
+            return Ok(Some(expr));
+        }
+    }
+    Ok(None)
+}
+
+/// The string literal a serde item is set to.
+pub(crate) fn str_value(expr: &Expr) -> syn::Result<&syn::LitStr> {
+    match expr {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(s), ..
+        }) => Ok(s),
+        other => Err(syn::Error::new_spanned(other, "expected a string literal")),
+    }
+}
@@ -1,9 +1,18 @@
+use fields::NamedField;
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{parse_macro_input, Data, DeriveInput, Expr, Fields, Lit, Meta};
 
+mod derived_types;
+mod enums;
+mod field_errors;
+mod fields;
+mod shorthand;
+mod tuple_struct;
+
 /// Derive macro that generates `vld_parse()`, `parse_value()`, `validate_fields()`,
 /// and `parse_lenient()` methods for a struct, plus implements the `VldParse` trait.
+/// Structs with named fields also implement `VldDeepPartial`, for deep PATCH bodies.
 ///
 /// # Usage
 ///
@@ -43,15 +52,206 @@ use syn::{parse_macro_input, Data, DeriveInput, Expr, Fields, Lit, Meta};
 /// Supported rename_all conventions: `camelCase`, `PascalCase`, `snake_case`,
 /// `SCREAMING_SNAKE_CASE`, `kebab-case`, `SCREAMING-KEBAB-CASE`.
 ///
+/// # Other serde attributes
+///
+/// Named structs also read their input the way serde does:
+///
+/// - `#[serde(alias = "...")]` accepts alternate keys; issues are reported
+///   under the key that was used.
+/// - `#[serde(default)]` / `#[serde(default = "path")]`, on a field or on the
+///   struct, fills in missing keys. The default itself is not validated.
+/// - `#[serde(skip)]` / `#[serde(skip_deserializing)]` ignores the input and
+///   uses `Default::default()`; such fields need no `#[vld(...)]`.
+/// - `#[serde(flatten)]` parses a field's type (itself a validated struct)
+///   from the same object; its issues keep their own paths.
+/// - `#[serde(deny_unknown_fields)]` rejects keys no field reads, like
+///   `ZObject::strict()`, and sets `additionalProperties: false` in
+///   `json_schema()`. As in serde, it cannot be combined with `flatten`.
+///
+/// # Partial, pick and omit types
+///
+/// Struct-level `partial`, `pick` and `omit` declare new struct types built
+/// from this one's fields, with the same `#[vld(...)]` rules and serde
+/// attributes, for PATCH or summary endpoints:
+///
+/// ```ignore
+/// #[derive(Debug, Validate)]
+/// #[vld(partial = "UserPatch")]
+/// #[vld(pick(name = "UserSummary", fields = [id, name], derive(Debug, Clone)))]
+/// #[vld(omit(name = "UserUpdate", fields = [id], partial))]
+/// struct User {
+///     #[vld(range(min = 1))]
+///     id: u64,
+///     #[vld(length(min = 2))]
+///     name: String,
+/// }
+/// ```
+///
+/// `partial` (alone, or as a flag of `pick` / `omit`) turns each field that
+/// must be present into `Option<T>` with an `.optional()` schema. The new
+/// types get the full `Validate` API, including `VldParse` and
+/// `json_schema()`, but not the struct-level `refine` rules, and only the
+/// derives listed in `derive(...)`. Serde attributes are copied onto them
+/// when `derive(...)` includes `Serialize` or `Deserialize`.
+///
+/// # Typed field errors
+///
+/// Struct-level `#[vld(field_errors)]` generates `UserField`, a `Copy` enum
+/// with a PascalCase variant per field read under its own JSON key, and
+/// `UserFieldError`, pairing each variant with a `ValidationIssue`:
+///
+/// ```ignore
+/// #[derive(Debug, Validate)]
+/// #[vld(field_errors)]
+/// struct User {
+///     #[vld(email)]
+///     email_address: String,
+/// }
+///
+/// let err = User::vld_parse(json).unwrap_err();
+/// let issues = err.for_field(UserField::EmailAddress);
+/// for e in UserFieldError::from_error(&err) {
+///     match e {
+///         UserFieldError::EmailAddress(issue) => { /* ... */ }
+///     }
+/// }
+/// ```
+///
+/// Keys follow serde renames, and `for_field()` also matches aliases.
+///
+/// # Shorthand validators
+///
+/// Instead of a schema expression, a field can list `validator`-style rules.
+/// The base schema comes from the field type (`String`, integers, floats,
+/// `bool`, `Option<T>`, `Vec<T>`) and each rule becomes the matching builder
+/// call:
+///
+/// ```ignore
+/// #[derive(Debug, Validate)]
+/// struct User {
+///     #[vld(length(min = 2, max = 50))]
+///     name: String,                       // vld::string().min(2).max(50)
+///     #[vld(email(message = "Bad email"))]
+///     email: String,                      // vld::string().email_msg("Bad email")
+///     #[vld(range(min = 18))]
+///     age: Option<u8>,                    // vld::number().int().min(18) ... .optional()
+///     #[vld(nested, length(max = 5))]
+///     addresses: Vec<Address>,            // vld::array(vld::nested!(Address)).max_len(5)
+/// }
+/// ```
+///
+/// | Rule | Applies to | Expands to |
+/// |------|-----------|------------|
+/// | `email`, `url` | strings | `.email()`, `.url()` |
+/// | `length(min, max, equal)` | strings, `Vec` | `.min()` / `.max()` / `.len()`, `.min_len()` / `.max_len()` / `.len()` |
+/// | `range(min, max, exclusive_min, exclusive_max)` | numbers | `.min()` / `.max()` / `.gt()` / `.lt()` |
+/// | `regex = "..."`, `pattern = "..."` | strings | `.regex(...)` (needs the `regex` feature) |
+/// | `contains = "..."` | strings, `Vec` | `.contains(...)` |
+/// | `custom = path` | any | `.refine(path, "Invalid value")` |
+/// | `nested` | structs, `Vec` of structs | `vld::nested!(T)` |
+/// | `required` | `Option<T>` | rejects a missing / `null` value |
+///
+/// Rules that take a single value also accept the `name(key = ...)` form,
+/// and every string and number rule plus `custom` take an optional
+/// `message = "..."`. Integer types other than `i64` are parsed as `i64` and
+/// range-checked before the cast; `f32` is parsed as `f64`.
+///
+/// # Struct-level rules
+///
+/// Cross-field checks go in struct-level `#[vld(refine = ..., message = "...")]`
+/// attributes, one per rule. `refine` is any `Fn(&Self) -> bool` (a function
+/// path or a closure) and the optional `path = "..."` is the JSON key the issue
+/// is reported under. Rules run in `vld_parse()` / `parse_value()` once every
+/// field is valid; `validate_fields()` and `parse_lenient()` skip them:
+///
+/// ```ignore
+/// #[derive(Debug, Validate)]
+/// #[vld(refine = |s: &Signup| s.password == s.confirm, message = "Passwords do not match", path = "confirm")]
+/// struct Signup {
+///     #[vld(vld::string().min(8))]
+///     password: String,
+///     #[vld(vld::string())]
+///     confirm: String,
+/// }
+/// ```
+///
+/// # Generic types
+///
+/// Every type parameter gets a `vld::schema::VldTypeParam` bound, so
+/// `Envelope<User>` implements `VldParse` for any validated `User`. A
+/// `#[vld(nested)]` field of a parameter type uses `vld::nested_param::<T>()`,
+/// and `validate_fields()` / `parse_lenient()` additionally need every field
+/// type to be `Serialize + Default`:
+///
+/// ```ignore
+/// #[derive(Debug, Validate)]
+/// struct Envelope<T> {
+///     #[vld(nested)]
+///     data: T,
+///     #[vld(vld::string().min(1))]
+///     request_id: String,
+/// }
+///
+/// let env = Envelope::<User>::vld_parse(json)?;
+/// ```
+///
+/// # Tuple structs and newtypes
+///
+/// A newtype's single `#[vld(...)]` field parses transparently from the inner
+/// JSON value; tuple structs with more fields parse from a JSON array,
+/// position by position:
+///
+/// ```ignore
+/// #[derive(Debug, Validate)]
+/// struct Email(#[vld(vld::string().email())] String);
+///
+/// #[derive(Debug, Validate)]
+/// struct Range(#[vld(vld::number().int())] i64, #[vld(vld::number().int())] i64);
+///
+/// let email = Email::vld_parse(r#""a@b.com""#).unwrap();
+/// let range = Range::vld_parse("[1, 10]").unwrap();
+/// ```
+///
+/// # Enums
+///
+/// Unit-only enums validate like `vld::enumeration()` (honoring
+/// `#[serde(rename_all)]` / `#[serde(rename)]`). Enums with data follow
+/// serde's representations — externally tagged by default, or
+/// `#[serde(tag = "...")]`, `#[serde(tag = "...", content = "...")]` and
+/// `#[serde(untagged)]` — and validate each variant's fields with their own
+/// `#[vld(...)]` attributes:
+///
+/// ```ignore
+/// #[derive(Debug, Validate)]
+/// #[serde(tag = "type", rename_all = "snake_case")]
+/// enum Shape {
+///     Circle {
+///         #[vld(vld::number().positive())]
+///         radius: f64,
+///     },
+///     Square(#[vld(vld::nested!(Square))] Square),
+/// }
+/// ```
+///
+/// Enums get `vld_parse()`, `parse_value()` and `json_schema()` (a `oneOf`
+/// with a `discriminator` for tagged representations), but not the
+/// per-field `validate_fields()` / `parse_lenient()` methods.
+///
 /// The expression inside `#[vld(...)]` is used as-is in the generated code.
 /// Make sure the types are in scope (e.g., use `vld::string()` or import via prelude).
 #[proc_macro_derive(Validate, attributes(vld, into_params))]
 pub fn derive_validate(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = &input.ident;
 
     // Check for #[serde(rename_all = "...")]
-    let rename_all = get_serde_rename_all(&input.attrs);
+    let rename_all = get_serde_rename_all(&input.attrs)?;
     let parameter_in_expr = match get_into_params_parameter_in(&input.attrs).as_deref() {
         Some("query") => quote! { Some("query") },
         Some("path") => quote! { Some("path") },
@@ -60,62 +260,132 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
         _ => quote! { None },
     };
 
+    // Struct-level #[vld(partial = ...)] / pick(...) / omit(...)
+    let derived = derived_types::parse(&input.attrs)?;
+    if let (Some(first), false) = (
+        derived.first(),
+        matches!(&input.data, Data::Struct(data) if matches!(data.fields, Fields::Named(_))),
+    ) {
+        return Err(syn::Error::new_spanned(
+            first.attr(),
+            "partial / pick / omit types can only be derived from structs with named fields",
+        ));
+    }
+    let field_errors_attr = field_errors::find(&input.attrs);
+    if let (Some(attr), false) = (
+        field_errors_attr,
+        matches!(&input.data, Data::Struct(data) if matches!(data.fields, Fields::Named(_))),
+    ) {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "field_errors can only be used on structs with named fields",
+        ));
+    }
+
     let fields = match &input.data {
         Data::Struct(data) => match &data.fields {
             Fields::Named(fields) => &fields.named,
-            _ => panic!("Validate can only be derived for structs with named fields"),
+            Fields::Unnamed(fields) => return tuple_struct::derive_tuple_struct(input, fields),
+            Fields::Unit => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "Validate cannot be derived for unit structs",
+                ))
+            }
         },
-        _ => panic!("Validate can only be derived for structs"),
+        Data::Enum(data) => return enums::derive_enum(input, data),
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "Validate can only be derived for structs and enums",
+            ))
+        }
     };
 
-    let mut field_names = Vec::new();
-    let mut field_types = Vec::new();
-    let mut field_schemas = Vec::new();
-    let mut field_json_keys = Vec::new();
-
-    for field in fields {
-        let fname = field.ident.as_ref().unwrap();
-        let ftype = &field.ty;
-        field_names.push(fname.clone());
-        field_types.push(ftype.clone());
-
-        // Determine JSON key: #[serde(rename = "...")] > rename_all > field name
-        let json_key = get_serde_rename(&field.attrs).unwrap_or_else(|| {
-            if let Some(ref convention) = rename_all {
-                rename_field(&fname.to_string(), convention)
-            } else {
-                fname.to_string()
+    let derived_types = derived
+        .iter()
+        .map(|d| d.expand(input, fields))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let container_default = fields::container_default(&input.attrs)?;
+    let fields = fields
+        .iter()
+        .map(|field| {
+            NamedField::parse(
+                field,
+                rename_all.as_deref(),
+                container_default.as_ref(),
+                &input.generics,
+            )
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    let field_names: Vec<&syn::Ident> = fields.iter().map(NamedField::ident).collect();
+    let field_types: Vec<&syn::Type> = fields.iter().map(NamedField::ty).collect();
+    let parse_fields = fields.iter().map(|f| f.parse_value(false));
+    let parse_fields_async = fields.iter().map(|f| f.parse_value(true));
+    let validate_fields = fields.iter().map(NamedField::validate_fields);
+    let lenient_fields = fields.iter().map(NamedField::parse_lenient);
+    let schema_fields = fields.iter().map(NamedField::json_schema);
+    let deep_partial_fields = fields.iter().map(NamedField::deep_partial);
+
+    // #[serde(deny_unknown_fields)]: reject keys no field reads, like
+    // `ZObject::strict()`.
+    let deny_unknown_fields = has_serde_flag(&input.attrs, "deny_unknown_fields");
+    let (deny_unknown, additional_properties) = if deny_unknown_fields {
+        if let Some(field) = fields.iter().find(|f| f.is_flatten()) {
+            let attr = find_serde_attr(&input.attrs, "deny_unknown_fields");
+            let mut err = syn::Error::new_spanned(
+                field.ident(),
+                "#[serde(flatten)] cannot be combined with #[serde(deny_unknown_fields)]",
+            );
+            if let Some(attr) = attr {
+                err.combine(syn::Error::new_spanned(
+                    attr,
+                    "deny_unknown_fields is set here",
+                ));
             }
-        });
-        field_json_keys.push(json_key);
+            return Err(err);
+        }
+        let known = fields.iter().flat_map(NamedField::known_keys);
+        (
+            quote! {
+                ::vld::object::__deny_unknown_fields(
+                    __vld_obj,
+                    &[ #( #known ),* ],
+                    &mut __vld_errors,
+                );
+            },
+            quote! { "additionalProperties": false, },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
 
-        let schema_tokens = field
-            .attrs
-            .iter()
-            .find(|attr| attr.path().is_ident("vld"))
-            .map(|attr| attr.parse_args::<proc_macro2::TokenStream>().unwrap())
-            .unwrap_or_else(|| panic!("Field `{}` is missing #[vld(...)] attribute", fname));
+    // Struct-level #[vld(field_errors)]
+    let field_errors = match field_errors_attr {
+        Some(_) => field_errors::expand(input, &fields),
+        None => quote! {},
+    };
 
-        field_schemas.push(schema_tokens);
-    }
+    let input_methods = input_methods();
+    let struct_checks = struct_checks(&input.attrs)?;
+    let json_schema_impl = json_schema_impl(input);
+
+    let generics = bounded_generics(&input.generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let lenient_generics = lenient_generics(&generics, &field_types);
+    let lenient_where = lenient_generics.where_clause.as_ref();
+    let deep_partial_generics = deep_partial_generics(&generics, &fields);
+    let deep_partial_where = deep_partial_generics.where_clause.as_ref();
 
     let expanded = quote! {
-        impl #name {
-            /// Parse and validate input data into this struct.
-            ///
-            /// Named `vld_parse` to avoid conflicts with other derive macros
-            /// (e.g. `clap::Parser::parse()`).
-            pub fn vld_parse<__VldInputT: ::vld::input::VldInput + ?Sized>(
-                input: &__VldInputT,
-            ) -> ::std::result::Result<#name, ::vld::error::VldError> {
-                let __vld_json = <__VldInputT as ::vld::input::VldInput>::to_json_value(input)?;
-                Self::parse_value(&__vld_json)
-            }
+        impl #impl_generics #name #ty_generics #where_clause {
+            #input_methods
 
             /// Parse and validate directly from a `serde_json::Value`.
             pub fn parse_value(
                 __vld_json: &::vld::serde_json::Value,
-            ) -> ::std::result::Result<#name, ::vld::error::VldError> {
+            ) -> ::std::result::Result<Self, ::vld::error::VldError> {
                 use ::vld::schema::VldSchema as _;
 
                 let __vld_obj = __vld_json.as_object().ok_or_else(|| {
@@ -133,40 +403,21 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
 
                 let mut __vld_errors = ::vld::error::VldError::new();
 
-                #(
-                    #[allow(non_snake_case)]
-                    let #field_names: ::std::option::Option<#field_types> = {
-                        let __vld_field_schema = { #field_schemas };
-                        let __vld_field_value = __vld_obj
-                            .get(#field_json_keys)
-                            .unwrap_or(&::vld::serde_json::Value::Null);
-                        match __vld_field_schema.parse_value(__vld_field_value) {
-                            ::std::result::Result::Ok(v) => ::std::option::Option::Some(v),
-                            ::std::result::Result::Err(e) => {
-                                __vld_errors = ::vld::error::VldError::merge(
-                                    __vld_errors,
-                                    ::vld::error::VldError::with_prefix(
-                                        e,
-                                        ::vld::error::PathSegment::Field(
-                                            ::std::string::String::from(#field_json_keys),
-                                        ),
-                                    ),
-                                );
-                                ::std::option::Option::None
-                            }
-                        }
-                    };
-                )*
+                #( #parse_fields )*
+                #deny_unknown
 
                 if !::vld::error::VldError::is_empty(&__vld_errors) {
                     return ::std::result::Result::Err(__vld_errors);
                 }
 
-                ::std::result::Result::Ok(#name {
+                let __vld_value = Self {
                     #( #field_names: #field_names.unwrap(), )*
-                })
+                };
+                #struct_checks
             }
+        }
 
+        impl #impl_generics #name #ty_generics #lenient_where {
             /// Validate each field individually and return per-field results.
             pub fn validate_fields<__VldInputT: ::vld::input::VldInput + ?Sized>(
                 input: &__VldInputT,
@@ -201,25 +452,7 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
                 let mut __vld_results: ::std::vec::Vec<::vld::error::FieldResult> =
                     ::std::vec::Vec::new();
 
-                #(
-                    {
-                        let __vld_field_schema = { #field_schemas };
-                        let __vld_field_value = __vld_obj
-                            .get(#field_json_keys)
-                            .unwrap_or(&::vld::serde_json::Value::Null);
-
-                        let __vld_result = ::vld::object::DynSchema::dyn_parse(
-                            &__vld_field_schema,
-                            __vld_field_value,
-                        );
-
-                        __vld_results.push(::vld::error::FieldResult {
-                            name: ::std::string::String::from(#field_json_keys),
-                            input: __vld_field_value.clone(),
-                            result: __vld_result,
-                        });
-                    }
-                )*
+                #( #validate_fields )*
 
                 ::std::result::Result::Ok(__vld_results)
             }
@@ -228,7 +461,7 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
             pub fn parse_lenient<__VldInputT: ::vld::input::VldInput + ?Sized>(
                 input: &__VldInputT,
             ) -> ::std::result::Result<
-                ::vld::error::ParseResult<#name>,
+                ::vld::error::ParseResult<Self>,
                 ::vld::error::VldError,
             > {
                 let __vld_json = <__VldInputT as ::vld::input::VldInput>::to_json_value(input)?;
@@ -239,7 +472,7 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
             pub fn parse_lenient_value(
                 __vld_json: &::vld::serde_json::Value,
             ) -> ::std::result::Result<
-                ::vld::error::ParseResult<#name>,
+                ::vld::error::ParseResult<Self>,
                 ::vld::error::VldError,
             > {
                 use ::vld::schema::VldSchema as _;
@@ -260,38 +493,9 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
                 let mut __vld_results: ::std::vec::Vec<::vld::error::FieldResult> =
                     ::std::vec::Vec::new();
 
-                #(
-                    #[allow(non_snake_case)]
-                    let #field_names: #field_types = {
-                        let __vld_field_schema = { #field_schemas };
-                        let __vld_field_value = __vld_obj
-                            .get(#field_json_keys)
-                            .unwrap_or(&::vld::serde_json::Value::Null);
-
-                        match __vld_field_schema.parse_value(__vld_field_value) {
-                            ::std::result::Result::Ok(v) => {
-                                let __json_repr = ::vld::serde_json::to_value(&v)
-                                    .unwrap_or_else(|_| __vld_field_value.clone());
-                                __vld_results.push(::vld::error::FieldResult {
-                                    name: ::std::string::String::from(#field_json_keys),
-                                    input: __vld_field_value.clone(),
-                                    result: ::std::result::Result::Ok(__json_repr),
-                                });
-                                v
-                            }
-                            ::std::result::Result::Err(e) => {
-                                __vld_results.push(::vld::error::FieldResult {
-                                    name: ::std::string::String::from(#field_json_keys),
-                                    input: __vld_field_value.clone(),
-                                    result: ::std::result::Result::Err(e),
-                                });
-                                <#field_types as ::std::default::Default>::default()
-                            }
-                        }
-                    };
-                )*
+                #( #lenient_fields )*
 
-                let __vld_struct = #name {
+                let __vld_struct = Self {
                     #( #field_names, )*
                 };
 
@@ -301,16 +505,107 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
             }
         }
 
-        impl ::vld::schema::VldParse for #name {
+        impl #impl_generics ::vld::schema::VldParseLenient for #name #ty_generics #lenient_where {
+            fn vld_parse_lenient_value(
+                value: &::vld::serde_json::Value,
+            ) -> ::std::result::Result<::vld::error::ParseResult<Self>, ::vld::error::VldError> {
+                Self::parse_lenient_value(value)
+            }
+        }
+
+        impl #impl_generics ::vld::schema::VldDeepPartial for #name #ty_generics #deep_partial_where {
+            fn vld_deep_partial() -> ::vld::object::ZObject {
+                #[allow(unused_imports)]
+                use ::vld::object::{__VldPartialViaInput as _, __VldPartialViaSerialize as _};
+                ::vld::object::ZObject::new()
+                    #( #deep_partial_fields )*
+                    .deep_partial()
+            }
+        }
+
+        impl #impl_generics ::vld::schema::VldParse for #name #ty_generics #where_clause {
             fn vld_parse_value(
                 value: &::vld::serde_json::Value,
             ) -> ::std::result::Result<Self, ::vld::error::VldError> {
                 Self::parse_value(value)
             }
+
+            ::vld::__vld_if_async! {
+                fn vld_parse_value_async(
+                    value: &::vld::serde_json::Value,
+                ) -> ::vld::async_schema::BoxFuture<
+                    '_,
+                    ::std::result::Result<Self, ::vld::error::VldError>,
+                > {
+                    Self::parse_value_async(value)
+                }
+            }
+        }
+
+        ::vld::__vld_if_async! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Parse and validate input data, running async refinements.
+                ///
+                /// Requires the `async` feature on `vld`.
+                pub fn vld_parse_async<__VldInputT: ::vld::input::VldInput + ?Sized>(
+                    input: &__VldInputT,
+                ) -> ::vld::async_schema::BoxFuture<
+                    'static,
+                    ::std::result::Result<Self, ::vld::error::VldError>,
+                > {
+                    let __vld_json = <__VldInputT as ::vld::input::VldInput>::to_json_value(input);
+                    ::std::boxed::Box::pin(async move {
+                        let __vld_json = __vld_json?;
+                        Self::parse_value_async(&__vld_json).await
+                    })
+                }
+
+                /// Async counterpart of `parse_value()`.
+                ///
+                /// Requires the `async` feature on `vld`.
+                pub fn parse_value_async(
+                    __vld_json: &::vld::serde_json::Value,
+                ) -> ::vld::async_schema::BoxFuture<
+                    '_,
+                    ::std::result::Result<Self, ::vld::error::VldError>,
+                > {
+                    ::std::boxed::Box::pin(async move {
+                        #[allow(unused_imports)]
+                        use ::vld::async_schema::{__VldViaAsync as _, __VldViaSync as _};
+
+                        let __vld_obj = __vld_json.as_object().ok_or_else(|| {
+                            ::vld::error::VldError::single(
+                                ::vld::error::IssueCode::InvalidType {
+                                    expected: ::std::string::String::from("object"),
+                                    received: ::vld::error::value_type_name(__vld_json),
+                                },
+                                ::std::format!(
+                                    "Expected object, received {}",
+                                    ::vld::error::value_type_name(__vld_json)
+                                ),
+                            )
+                        })?;
+
+                        let mut __vld_errors = ::vld::error::VldError::new();
+
+                        #( #parse_fields_async )*
+                        #deny_unknown
+
+                        if !::vld::error::VldError::is_empty(&__vld_errors) {
+                            return ::std::result::Result::Err(__vld_errors);
+                        }
+
+                        let __vld_value = Self {
+                            #( #field_names: #field_names.unwrap(), )*
+                        };
+                        #struct_checks
+                    })
+                }
+            }
         }
 
         ::vld::__vld_if_openapi! {
-            impl #name {
+            impl #impl_generics #name #ty_generics #where_clause {
                 /// Generate a JSON Schema / OpenAPI 3.1 representation of this struct.
                 ///
                 /// Requires the `openapi` feature on `vld`.
@@ -320,23 +615,13 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
                     let mut __vld_required: ::std::vec::Vec<::std::string::String> =
                         ::std::vec::Vec::new();
 
-                    #(
-                        {
-                            let __vld_field_schema = { #field_schemas };
-                            __vld_properties.insert(
-                                ::std::string::String::from(#field_json_keys),
-                                __vld_field_schema.json_schema(),
-                            );
-                            __vld_required.push(
-                                ::std::string::String::from(#field_json_keys),
-                            );
-                        }
-                    )*
+                    #( #schema_fields )*
 
                     ::vld::serde_json::json!({
                         "type": "object",
                         "required": __vld_required,
                         "properties": ::vld::serde_json::Value::Object(__vld_properties),
+                        #additional_properties
                     })
                 }
 
@@ -345,29 +630,310 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
                 /// Requires the `openapi` feature on `vld`.
                 pub fn to_openapi_document() -> ::vld::serde_json::Value {
                     ::vld::json_schema::to_openapi_document(
-                        stringify!(#name),
+                        &<Self as ::vld::json_schema::VldJsonSchema>::vld_schema_name(),
                         &Self::json_schema(),
                     )
                 }
             }
 
-            impl ::vld::json_schema::OpenApiParameterIn for #name {
+            impl #impl_generics ::vld::json_schema::OpenApiParameterIn for #name #ty_generics #where_clause {
                 fn parameter_in() -> Option<&'static str> {
                     #parameter_in_expr
                 }
             }
+
+            #json_schema_impl
         }
+
+        #field_errors
+        #( #derived_types )*
     };
 
-    TokenStream::from(expanded)
+    Ok(expanded)
+}
+
+/// `vld_parse*()` entry points shared by every derived type. They convert the
+/// input to JSON and delegate to the type's `parse_value()`.
+fn input_methods() -> proc_macro2::TokenStream {
+    quote! {
+        /// Parse and validate input data into this type.
+        ///
+        /// Named `vld_parse` to avoid conflicts with other derive macros
+        /// (e.g. `clap::Parser::parse()`).
+        pub fn vld_parse<__VldInputT: ::vld::input::VldInput + ?Sized>(
+            input: &__VldInputT,
+        ) -> ::std::result::Result<Self, ::vld::error::VldError> {
+            let __vld_json = <__VldInputT as ::vld::input::VldInput>::to_json_value(input)?;
+            Self::parse_value(&__vld_json)
+        }
+
+        /// Parse and validate input data with a request-scoped validation context.
+        ///
+        /// The context is visible to `refine_ctx` / `super_refine_ctx` /
+        /// `transform_ctx` on every field, including nested structs.
+        pub fn vld_parse_with_context<
+            __VldInputT: ::vld::input::VldInput + ?Sized,
+            __VldCtxT: ::std::clone::Clone + 'static,
+        >(
+            input: &__VldInputT,
+            ctx: &__VldCtxT,
+        ) -> ::std::result::Result<Self, ::vld::error::VldError> {
+            let __vld_json = <__VldInputT as ::vld::input::VldInput>::to_json_value(input)?;
+            ::vld::context::with_context(ctx, || Self::parse_value(&__vld_json))
+        }
+
+        /// Parse and validate input data with `vld::options::ParseOptions` limits.
+        pub fn vld_parse_with_options<__VldInputT: ::vld::input::VldInput + ?Sized>(
+            input: &__VldInputT,
+            options: &::vld::options::ParseOptions,
+        ) -> ::std::result::Result<Self, ::vld::error::VldError> {
+            let __vld_json = <__VldInputT as ::vld::input::VldInput>::to_json_value(input)?;
+            <Self as ::vld::schema::VldParse>::vld_parse_value_with_options(&__vld_json, options)
+        }
+
+        /// Parse and validate JSON text, attaching a source span
+        /// (line/column) to every issue. See `vld::source`.
+        pub fn vld_parse_with_spans(
+            text: &str,
+        ) -> ::std::result::Result<Self, ::vld::error::VldError> {
+            ::vld::source::parse_with_spans(text)
+        }
+    }
+}
+
+/// The schema expression of a field's `#[vld(...)]` attribute, expanding the
+/// shorthand validator syntax when it is used.
+fn field_schema(
+    field: &syn::Field,
+    label: &str,
+    generics: &syn::Generics,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let Some(attr) = field.attrs.iter().find(|attr| attr.path().is_ident("vld")) else {
+        let message = format!("field `{}` is missing a #[vld(...)] attribute", label);
+        return Err(match &field.ident {
+            Some(ident) => syn::Error::new_spanned(ident, message),
+            None => syn::Error::new_spanned(&field.ty, message),
+        });
+    };
+    let tokens = attr.parse_args::<proc_macro2::TokenStream>()?;
+    if tokens.is_empty() {
+        return Err(syn::Error::new_spanned(
+            attr,
+            format!("#[vld(...)] on field `{}` is empty", label),
+        ));
+    }
+    let params: Vec<&syn::Ident> = generics.type_params().map(|p| &p.ident).collect();
+    Ok(shorthand::expand(&field.ty, tokens.clone(), label, &params)?.unwrap_or(tokens))
+}
+
+/// Expression awaiting the parse of `value` with the field schema `schema`,
+/// through its async implementation when it has one. The schema is moved
+/// into the future, or dropped before the `.await` when it parses
+/// synchronously, so a schema that is not `Send` keeps the generated future
+/// `Send`.
+fn parse_async(
+    schema: &proc_macro2::TokenStream,
+    value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    quote! {{
+        let __vld_field_future = {
+            let __vld_field_schema = { #schema };
+            (&&::vld::async_schema::__VldAsyncField::new(__vld_field_schema))
+                .__vld_parse_async(#value)
+        };
+        __vld_field_future.await
+    }}
+}
+
+/// The derived type's generics with a `vld::schema::VldTypeParam` bound on
+/// every type parameter, so `Paginated<T>` gets its impls for every
+/// validated `T`.
+fn bounded_generics(generics: &syn::Generics) -> syn::Generics {
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        param
+            .bounds
+            .push(syn::parse_quote!(::vld::schema::VldTypeParam));
+    }
+    generics
+}
+
+/// `generics` plus, for generic types, a `Serialize + Default` bound on every
+/// field type: `validate_fields()` / `parse_lenient()` serialize each field
+/// and fall back to `Default`, which a field like `Vec<T>` only supports for
+/// some `T`.
+fn lenient_generics(generics: &syn::Generics, field_types: &[&syn::Type]) -> syn::Generics {
+    let mut generics = generics.clone();
+    if generics.type_params().next().is_some() {
+        let where_clause = generics.make_where_clause();
+        for ty in field_types {
+            where_clause.predicates.push(syn::parse_quote!(
+                #ty: ::vld::serde::Serialize + ::std::default::Default
+            ));
+        }
+    }
+    generics
+}
+
+/// Generics of `impl VldDeepPartial`: flattened fields must implement it too.
+fn deep_partial_generics(generics: &syn::Generics, fields: &[NamedField]) -> syn::Generics {
+    let mut generics = generics.clone();
+    let flattened: Vec<&syn::Type> = fields
+        .iter()
+        .filter(|f| f.is_flatten())
+        .map(NamedField::ty)
+        .collect();
+    if !flattened.is_empty() {
+        let where_clause = generics.make_where_clause();
+        for ty in flattened {
+            where_clause
+                .predicates
+                .push(syn::parse_quote!(#ty: ::vld::schema::VldDeepPartial));
+        }
+    }
+    generics
+}
+
+/// `impl VldJsonSchema`, naming a generic instantiation after its type
+/// arguments (`Paginated_User`). Expects the type's `json_schema()`.
+fn json_schema_impl(input: &DeriveInput) -> proc_macro2::TokenStream {
+    let name = &input.ident;
+    let generics = bounded_generics(&input.generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let params = generics.type_params().map(|p| &p.ident);
+    quote! {
+        impl #impl_generics ::vld::json_schema::VldJsonSchema for #name #ty_generics #where_clause {
+            fn vld_schema_name() -> ::std::string::String {
+                ::vld::json_schema::__generic_schema_name(
+                    stringify!(#name),
+                    &[ #( <#params as ::vld::json_schema::VldJsonSchema>::vld_schema_name() ),* ],
+                )
+            }
+
+            fn vld_json_schema() -> ::vld::serde_json::Value {
+                Self::json_schema()
+            }
+        }
+    }
+}
+
+/// Return `__vld_value`, or the issues from its struct-level rules. Expands
+/// to a plain `Ok(__vld_value)` when the type declares none.
+fn struct_checks(attrs: &[syn::Attribute]) -> syn::Result<proc_macro2::TokenStream> {
+    let rules = struct_rules(attrs)?;
+    if rules.is_empty() {
+        return Ok(quote! { ::std::result::Result::Ok(__vld_value) });
+    }
+    Ok(quote! {
+        #( #rules )*
+        if !::vld::error::VldError::is_empty(&__vld_errors) {
+            return ::std::result::Result::Err(__vld_errors);
+        }
+        ::std::result::Result::Ok(__vld_value)
+    })
+}
+
+/// Struct-level `#[vld(refine = ..., message = "...", path = "...")]` rules,
+/// expanded into checks against the fully built `__vld_value`.
+fn struct_rules(attrs: &[syn::Attribute]) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    attrs
+        .iter()
+        .filter(|attr| {
+            attr.path().is_ident("vld")
+                && !derived_types::is_derived_type_attr(attr)
+                && !field_errors::is_field_errors_attr(attr)
+        })
+        .map(|attr| {
+            let nested = attr.parse_args_with(
+                syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated,
+            )?;
+
+            let mut check = None;
+            let mut message = None;
+            let mut path = None;
+            for meta in nested {
+                match meta {
+                    Meta::NameValue(nv) if nv.path.is_ident("refine") => check = Some(nv.value),
+                    Meta::NameValue(nv) if nv.path.is_ident("message") => {
+                        message = Some(nv.value)
+                    }
+                    Meta::NameValue(nv) if nv.path.is_ident("path") => path = Some(nv.value),
+                    other => {
+                        return Err(syn::Error::new_spanned(
+                            other,
+                            "unknown struct-level #[vld(...)] key, expected `refine`, `message` or `path`",
+                        ))
+                    }
+                }
+            }
+
+            let check = check.ok_or_else(|| {
+                syn::Error::new_spanned(attr, "struct-level #[vld(...)] requires `refine = ...`")
+            })?;
+            let message = message.ok_or_else(|| {
+                syn::Error::new_spanned(attr, "#[vld(refine = ...)] requires `message = \"...\"`")
+            })?;
+            let path = match path {
+                Some(path) => quote! { ::std::option::Option::Some(#path) },
+                None => quote! { ::std::option::Option::None },
+            };
+
+            Ok(quote! {
+                ::vld::schema::__refine_struct(
+                    &__vld_value,
+                    &mut __vld_errors,
+                    #path,
+                    #message,
+                    #check,
+                );
+            })
+        })
+        .collect()
 }
 
 // ---------------------------------------------------------------------------
 // Serde attribute parsing helpers
 // ---------------------------------------------------------------------------
 
-/// Extract `#[serde(rename_all = "...")]` from struct-level attributes.
-fn get_serde_rename_all(attrs: &[syn::Attribute]) -> Option<String> {
+/// `rename_all` conventions understood by serde.
+const RENAME_RULES: &[&str] = &[
+    "lowercase",
+    "UPPERCASE",
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+/// Extract `#[serde(rename_all = "...")]` from struct-level attributes,
+/// rejecting conventions serde does not know.
+fn get_serde_rename_all(attrs: &[syn::Attribute]) -> syn::Result<Option<String>> {
+    let Some(lit) = get_serde_lit(attrs, "rename_all") else {
+        return Ok(None);
+    };
+    let convention = lit.value();
+    if !RENAME_RULES.contains(&convention.as_str()) {
+        return Err(syn::Error::new_spanned(
+            lit,
+            format!(
+                "unknown rename_all convention `{}`, expected one of: {}",
+                convention,
+                RENAME_RULES.join(", ")
+            ),
+        ));
+    }
+    Ok(Some(convention))
+}
+
+/// Extract a string-valued `#[serde(key = "...")]` attribute.
+fn get_serde_str(attrs: &[syn::Attribute], key: &str) -> Option<String> {
+    get_serde_lit(attrs, key).map(|lit| lit.value())
+}
+
+/// The string literal of a `#[serde(key = "...")]` attribute.
+fn get_serde_lit(attrs: &[syn::Attribute], key: &str) -> Option<syn::LitStr> {
     for attr in attrs {
         if !attr.path().is_ident("serde") {
             continue;
@@ -377,10 +943,10 @@ fn get_serde_rename_all(attrs: &[syn::Attribute]) -> Option<String> {
         {
             for meta in &nested {
                 if let Meta::NameValue(nv) = meta {
-                    if nv.path.is_ident("rename_all") {
+                    if nv.path.is_ident(key) {
                         if let Expr::Lit(lit) = &nv.value {
                             if let Lit::Str(s) = &lit.lit {
-                                return Some(s.value());
+                                return Some(s.clone());
                             }
                         }
                     }
@@ -391,6 +957,35 @@ fn get_serde_rename_all(attrs: &[syn::Attribute]) -> Option<String> {
     None
 }
 
+/// Check for a bare `#[serde(flag)]` attribute, e.g. `untagged`.
+fn has_serde_flag(attrs: &[syn::Attribute], flag: &str) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("serde")
+            && attr
+                .parse_args_with(
+                    syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated,
+                )
+                .map(|nested| {
+                    nested
+                        .iter()
+                        .any(|meta| matches!(meta, Meta::Path(path) if path.is_ident(flag)))
+                })
+                .unwrap_or(false)
+    })
+}
+
+/// The `#[serde(...)]` attribute that sets `key`, for error spans.
+fn find_serde_attr<'a>(attrs: &'a [syn::Attribute], key: &str) -> Option<&'a syn::Attribute> {
+    attrs.iter().find(|attr| {
+        attr.path().is_ident("serde")
+            && attr
+                .parse_args_with(
+                    syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated,
+                )
+                .is_ok_and(|nested| nested.iter().any(|meta| meta.path().is_ident(key)))
+    })
+}
+
 /// Extract `#[into_params(parameter_in = ...)]` from struct-level attributes.
 fn get_into_params_parameter_in(attrs: &[syn::Attribute]) -> Option<String> {
     for attr in attrs {
@@ -417,27 +1012,7 @@ fn get_into_params_parameter_in(attrs: &[syn::Attribute]) -> Option<String> {
 
 /// Extract `#[serde(rename = "...")]` from field-level attributes.
 fn get_serde_rename(attrs: &[syn::Attribute]) -> Option<String> {
-    for attr in attrs {
-        if !attr.path().is_ident("serde") {
-            continue;
-        }
-        if let Ok(nested) = attr
-            .parse_args_with(syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated)
-        {
-            for meta in &nested {
-                if let Meta::NameValue(nv) = meta {
-                    if nv.path.is_ident("rename") {
-                        if let Expr::Lit(lit) = &nv.value {
-                            if let Lit::Str(s) = &lit.lit {
-                                return Some(s.value());
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
-    None
+    get_serde_str(attrs, "rename")
 }
 
 /// Convert a snake_case field name to the given naming convention.
@@ -446,13 +1021,44 @@ fn rename_field(name: &str, convention: &str) -> String {
         "camelCase" => to_camel_case(name),
         "PascalCase" => to_pascal_case(name),
         "snake_case" => name.to_string(),
-        "SCREAMING_SNAKE_CASE" => name.to_uppercase(),
+        "lowercase" => name.to_lowercase(),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => name.to_uppercase(),
         "kebab-case" => name.replace('_', "-"),
         "SCREAMING-KEBAB-CASE" => name.replace('_', "-").to_uppercase(),
         _ => name.to_string(),
     }
 }
 
+/// Convert a PascalCase variant name to the given naming convention.
+fn rename_variant(name: &str, convention: &str) -> String {
+    let snake = || {
+        let mut out = String::new();
+        for (i, ch) in name.chars().enumerate() {
+            if ch.is_uppercase() && i > 0 {
+                out.push('_');
+            }
+            out.extend(ch.to_lowercase());
+        }
+        out
+    };
+    match convention {
+        "lowercase" => name.to_lowercase(),
+        "UPPERCASE" => name.to_uppercase(),
+        "camelCase" => {
+            let mut chars = name.chars();
+            match chars.next() {
+                Some(first) => first.to_lowercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        }
+        "snake_case" => snake(),
+        "SCREAMING_SNAKE_CASE" => snake().to_uppercase(),
+        "kebab-case" => snake().replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => snake().replace('_', "-").to_uppercase(),
+        _ => name.to_string(),
+    }
+}
+
 fn to_camel_case(s: &str) -> String {
     let mut result = String::new();
     let mut capitalize_next = false;
@@ -0,0 +1,468 @@
+//! Shorthand field attributes in the style of the `validator` crate, e.g.
+//! `#[vld(email, length(min = 2, max = 50))]`.
+//!
+//! The base schema is inferred from the field type and every validator
+//! becomes the builder call one would write by hand, so the expansion is an
+//! ordinary schema expression. Attributes that are not a list of known
+//! validators are left alone and used as a full schema expression.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{
+    Expr, ExprLit, GenericArgument, Ident, Lit, LitFloat, Meta, MetaNameValue, PathArguments, Type,
+};
+
+const VALIDATORS: &[&str] = &[
+    "email", "url", "length", "range", "regex", "pattern", "contains", "custom", "nested",
+    "required",
+];
+
+/// Expand a shorthand attribute body, or return `None` when `tokens` is a
+/// schema expression. `params` are the type parameters of the derived type.
+pub(crate) fn expand(
+    ty: &Type,
+    tokens: TokenStream,
+    label: &str,
+    params: &[&Ident],
+) -> syn::Result<Option<TokenStream>> {
+    let Ok(metas) = Punctuated::<Meta, syn::Token![,]>::parse_terminated.parse2(tokens) else {
+        return Ok(None);
+    };
+    let known = |meta: &Meta| {
+        meta.path()
+            .get_ident()
+            .is_some_and(|ident| VALIDATORS.contains(&ident.to_string().as_str()))
+    };
+    if metas.is_empty() || !metas.iter().all(known) {
+        return Ok(None);
+    }
+
+    let rules = metas
+        .iter()
+        .map(|meta| Rule::parse(meta, label))
+        .collect::<syn::Result<Vec<Rule>>>()?;
+    build(ty, &rules, label, params).map(Some)
+}
+
+/// One validator: `name`, `name = value` or `name(key = value, ...)`.
+struct Rule {
+    /// The validator's name, spanned for diagnostics.
+    ident: Ident,
+    name: String,
+    value: Option<Expr>,
+    args: Vec<(Ident, Expr)>,
+}
+
+impl Rule {
+    fn parse(meta: &Meta, label: &str) -> syn::Result<Rule> {
+        let ident = meta.path().require_ident()?.clone();
+        let name = ident.to_string();
+        let (value, args) = match meta {
+            Meta::Path(_) => (None, Vec::new()),
+            Meta::NameValue(nv) => (Some(nv.value.clone()), Vec::new()),
+            Meta::List(list) => {
+                let nested = list
+                    .parse_args_with(Punctuated::<MetaNameValue, syn::Token![,]>::parse_terminated)
+                    .map_err(|e| {
+                        syn::Error::new(
+                            e.span(),
+                            format!("invalid `{}(...)` on field `{}`: {}", name, label, e),
+                        )
+                    })?;
+                let args = nested
+                    .into_iter()
+                    .map(|nv| Ok((nv.path.require_ident()?.clone(), nv.value)))
+                    .collect::<syn::Result<_>>()?;
+                (None, args)
+            }
+        };
+        Ok(Rule {
+            ident,
+            name,
+            value,
+            args,
+        })
+    }
+
+    /// Reject arguments the validator does not take.
+    fn allow(&self, keys: &[&str], label: &str) -> syn::Result<()> {
+        for (key, _) in &self.args {
+            if !keys.iter().any(|k| key == k) {
+                return Err(syn::Error::new_spanned(
+                    key,
+                    format!(
+                        "unknown argument `{}` for `{}` on field `{}`, expected one of: {}",
+                        key,
+                        self.name,
+                        label,
+                        keys.join(", ")
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn arg(&self, key: &str) -> Option<&Expr> {
+        self.args.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// The validator's single operand, given as `name = v` or `name(key = v)`.
+    fn operand(&self, key: &str, label: &str) -> syn::Result<&Expr> {
+        self.value
+            .as_ref()
+            .or_else(|| self.arg(key))
+            .ok_or_else(|| {
+                self.error(format!(
+                    "`{}` on field `{}` needs a value: `{0} = ...` or `{0}({} = ...)`",
+                    self.name, label, key
+                ))
+            })
+    }
+
+    fn error(&self, message: String) -> syn::Error {
+        syn::Error::new_spanned(&self.ident, message)
+    }
+}
+
+/// The schema a field type starts from before any validators apply.
+enum Base {
+    Str,
+    /// Integers parse as `i64`; other widths are range-checked and cast.
+    Int(Option<Ident>),
+    /// Floats parse as `f64`; `f32` is cast.
+    Float(Option<Ident>),
+    Bool,
+    Other,
+}
+
+fn base_of(ty: &Type) -> Base {
+    let ident = match ty {
+        Type::Path(p) if p.qself.is_none() => match p.path.segments.last() {
+            Some(seg) if seg.arguments.is_empty() => seg.ident.clone(),
+            _ => return Base::Other,
+        },
+        _ => return Base::Other,
+    };
+    match ident.to_string().as_str() {
+        "String" => Base::Str,
+        "i64" => Base::Int(None),
+        "i8" | "i16" | "i32" | "isize" | "u8" | "u16" | "u32" | "u64" | "usize" => {
+            Base::Int(Some(ident))
+        }
+        "f64" => Base::Float(None),
+        "f32" => Base::Float(Some(ident)),
+        "bool" => Base::Bool,
+        _ => Base::Other,
+    }
+}
+
+/// `T` for a `wrapper<T>` type such as `Option<T>` or `Vec<T>`.
+pub(crate) fn generic_arg<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(p) = ty else { return None };
+    let seg = p.path.segments.last()?;
+    if seg.ident != wrapper {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &seg.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+/// Float bounds accept integer literals: `range(min = 0)` on an `f64`.
+fn float_operand(expr: &Expr) -> TokenStream {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(int) => {
+                let float = LitFloat::new(&format!("{}.0", int.base10_digits()), int.span());
+                quote! { #float }
+            }
+            _ => quote! { #expr },
+        },
+        Expr::Unary(unary) if matches!(unary.op, syn::UnOp::Neg(_)) => {
+            let inner = float_operand(&unary.expr);
+            quote! { -#inner }
+        }
+        _ => quote! { #expr },
+    }
+}
+
+/// `vld::nested!(T)`, or `vld::nested_param::<T>()` when `T` is one of the
+/// derived type's own type parameters.
+fn nested_schema(ty: &Type, params: &[&Ident]) -> TokenStream {
+    let is_param = match ty {
+        Type::Path(p) if p.qself.is_none() => p
+            .path
+            .get_ident()
+            .is_some_and(|ident| params.contains(&ident)),
+        _ => false,
+    };
+    if is_param {
+        quote! { ::vld::nested_param::<#ty>() }
+    } else {
+        quote! { ::vld::nested!(#ty) }
+    }
+}
+
+fn build(ty: &Type, rules: &[Rule], label: &str, params: &[&Ident]) -> syn::Result<TokenStream> {
+    let has = |name: &str| rules.iter().any(|r| r.name == name);
+    let nested = has("nested");
+
+    let (optional, ty) = match generic_arg(ty, "Option") {
+        Some(inner) => (true, inner),
+        None => (false, ty),
+    };
+
+    let mut schema = match generic_arg(ty, "Vec") {
+        Some(elem) => {
+            let elem_schema = if nested {
+                nested_schema(elem, params)
+            } else {
+                scalar(elem, &[], label)?
+            };
+            let mut schema = quote! { ::vld::array(#elem_schema) };
+            for rule in rules {
+                match rule.name.as_str() {
+                    "length" => {
+                        rule.allow(&["min", "max", "equal"], label)?;
+                        if let Some(v) = rule.arg("min") {
+                            schema = quote! { #schema.min_len(#v) };
+                        }
+                        if let Some(v) = rule.arg("max") {
+                            schema = quote! { #schema.max_len(#v) };
+                        }
+                        if let Some(v) = rule.arg("equal") {
+                            schema = quote! { #schema.len(#v) };
+                        }
+                    }
+                    "contains" => {
+                        rule.allow(&["pattern"], label)?;
+                        let v = rule.operand("pattern", label)?;
+                        schema = quote! { #schema.contains(#v) };
+                    }
+                    "custom" | "nested" | "required" => {}
+                    other => {
+                        let message = format!(
+                            "`{}` does not apply to the `Vec` field `{}`; use a full schema expression",
+                            other, label
+                        );
+                        return Err(rule.error(message));
+                    }
+                }
+            }
+            schema
+        }
+        None if nested => {
+            for rule in rules {
+                if !matches!(rule.name.as_str(), "custom" | "nested" | "required") {
+                    return Err(rule.error(format!(
+                        "`{}` cannot be combined with `nested` on field `{}`",
+                        rule.name, label
+                    )));
+                }
+            }
+            nested_schema(ty, params)
+        }
+        None => scalar(ty, rules, label)?,
+    };
+
+    for rule in rules.iter().filter(|r| r.name == "custom") {
+        rule.allow(&["function", "message"], label)?;
+        let function = match rule.operand("function", label)? {
+            // `validator`-style `custom(function = "path::to::fn")`.
+            Expr::Lit(lit) => match &lit.lit {
+                Lit::Str(s) => {
+                    let path: syn::ExprPath = s.parse().map_err(|e| {
+                        syn::Error::new_spanned(
+                            s,
+                            format!("invalid `custom` function on field `{}`: {}", label, e),
+                        )
+                    })?;
+                    quote! { #path }
+                }
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        format!(
+                            "`custom` on field `{}` expects a function path or a string",
+                            label
+                        ),
+                    ))
+                }
+            },
+            expr => quote! { #expr },
+        };
+        let message = match rule.arg("message") {
+            Some(msg) => quote! { #msg },
+            None => quote! { "Invalid value" },
+        };
+        schema = quote! { #schema.refine(#function, #message) };
+    }
+
+    if optional {
+        schema = if has("required") {
+            quote! { #schema.transform(::std::option::Option::Some) }
+        } else {
+            quote! { #schema.optional() }
+        };
+    }
+    Ok(schema)
+}
+
+/// A non-collection field: its base schema plus string / number validators.
+fn scalar(ty: &Type, rules: &[Rule], label: &str) -> syn::Result<TokenStream> {
+    let base = base_of(ty);
+    let mut schema = match &base {
+        Base::Str => quote! { ::vld::string() },
+        Base::Int(_) => quote! { ::vld::number().int() },
+        Base::Float(_) => quote! { ::vld::number() },
+        Base::Bool => quote! { ::vld::boolean() },
+        Base::Other => {
+            return Err(syn::Error::new_spanned(
+                ty,
+                format!(
+                    "cannot infer a schema for field `{}` of type `{}`; add `nested` or use a full schema expression",
+                    label,
+                    quote! { #ty }
+                ),
+            ))
+        }
+    };
+
+    for rule in rules {
+        let message = rule.arg("message");
+        match (rule.name.as_str(), &base) {
+            ("email" | "url", Base::Str) => {
+                rule.allow(&["message"], label)?;
+                let method = Ident::new(&rule.name, proc_macro2::Span::call_site());
+                let method_msg = Ident::new(
+                    &format!("{}_msg", rule.name),
+                    proc_macro2::Span::call_site(),
+                );
+                schema = match message {
+                    Some(msg) => quote! { #schema.#method_msg(#msg) },
+                    None => quote! { #schema.#method() },
+                };
+            }
+            ("length", Base::Str) => {
+                rule.allow(&["min", "max", "equal", "message"], label)?;
+                for (key, method) in [("min", "min"), ("max", "max"), ("equal", "len")] {
+                    if let Some(v) = rule.arg(key) {
+                        let method = Ident::new(method, proc_macro2::Span::call_site());
+                        let method_msg =
+                            Ident::new(&format!("{}_msg", method), proc_macro2::Span::call_site());
+                        schema = match message {
+                            Some(msg) => quote! { #schema.#method_msg(#v, #msg) },
+                            None => quote! { #schema.#method(#v) },
+                        };
+                    }
+                }
+            }
+            ("regex" | "pattern", Base::Str) => {
+                rule.allow(&["pattern", "message"], label)?;
+                let pattern = rule.operand("pattern", label)?;
+                if let Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }) = pattern
+                {
+                    if let Err(err) = regex_lite::Regex::new(&lit.value()) {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            format!("invalid regex on field `{}`: {}", label, err),
+                        ));
+                    }
+                }
+                let re = quote! {
+                    {
+                        static __VLD_RE: ::std::sync::OnceLock<::vld::regex_lite::Regex> =
+                            ::std::sync::OnceLock::new();
+                        __VLD_RE
+                            .get_or_init(|| {
+                                ::vld::regex_lite::Regex::new(#pattern)
+                                    .expect("invalid regex in #[vld(regex = ...)]")
+                            })
+                            .clone()
+                    }
+                };
+                schema = match message {
+                    Some(msg) => quote! { #schema.regex_msg(#re, #msg) },
+                    None => quote! { #schema.regex(#re) },
+                };
+            }
+            ("contains", Base::Str) => {
+                rule.allow(&["pattern", "message"], label)?;
+                let v = rule.operand("pattern", label)?;
+                schema = match message {
+                    Some(msg) => quote! { #schema.contains_msg(#v, #msg) },
+                    None => quote! { #schema.contains(#v) },
+                };
+            }
+            ("range", Base::Int(_) | Base::Float(_)) => {
+                rule.allow(
+                    &["min", "max", "exclusive_min", "exclusive_max", "message"],
+                    label,
+                )?;
+                let float = matches!(base, Base::Float(_));
+                for (key, method) in [
+                    ("min", "min"),
+                    ("max", "max"),
+                    ("exclusive_min", "gt"),
+                    ("exclusive_max", "lt"),
+                ] {
+                    if let Some(v) = rule.arg(key) {
+                        let method = Ident::new(method, proc_macro2::Span::call_site());
+                        let v = if float {
+                            float_operand(v)
+                        } else {
+                            quote! { #v }
+                        };
+                        schema = quote! { #schema.#method(#v) };
+                    }
+                }
+                if let Some(msg) = message {
+                    schema = quote! {
+                        #schema.with_messages(|__vld_key| match __vld_key {
+                            "too_small" | "too_big" => {
+                                ::std::option::Option::Some(::std::string::String::from(#msg))
+                            }
+                            _ => ::std::option::Option::None,
+                        })
+                    };
+                }
+            }
+            ("custom" | "nested" | "required", _) => {}
+            (other, _) => {
+                return Err(rule.error(format!(
+                    "`{}` does not apply to field `{}` of type `{}`",
+                    other,
+                    label,
+                    quote! { #ty }
+                )))
+            }
+        }
+    }
+
+    Ok(match &base {
+        Base::Int(Some(target)) => {
+            let message = format!("Number is out of range for {}", target);
+            quote! {
+                #schema
+                    .refine(
+                        |__vld_n: &i64| <#target as ::std::convert::TryFrom<i64>>::try_from(*__vld_n).is_ok(),
+                        #message,
+                    )
+                    .transform(|__vld_n: i64| __vld_n as #target)
+            }
+        }
+        Base::Float(Some(target)) => {
+            quote! { #schema.transform(|__vld_n: f64| __vld_n as #target) }
+        }
+        _ => schema,
+    })
+}
@@ -0,0 +1,376 @@
+//! `#[derive(Validate)]` for tuple structs.
+//!
+//! A newtype (one field) is transparent: it parses from the inner JSON value
+//! with its field's schema. Tuple structs with more fields parse from a JSON
+//! array of exactly that length, position by position.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{DeriveInput, FieldsUnnamed, Ident, Index};
+
+use crate::{
+    bounded_generics, field_schema, input_methods, json_schema_impl, lenient_generics, parse_async,
+    struct_checks,
+};
+
+pub(crate) fn derive_tuple_struct(
+    input: &DeriveInput,
+    fields: &FieldsUnnamed,
+) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let newtype = fields.unnamed.len() == 1;
+    let len = fields.unnamed.len();
+
+    let field_types: Vec<&syn::Type> = fields.unnamed.iter().map(|f| &f.ty).collect();
+    let field_schemas: Vec<TokenStream> = fields
+        .unnamed
+        .iter()
+        .enumerate()
+        .map(|(i, f)| field_schema(f, &format!("{}.{}", name, i), &input.generics))
+        .collect::<syn::Result<_>>()?;
+    let field_binds: Vec<Ident> = (0..len).map(|i| format_ident!("__vld_{}", i)).collect();
+    let field_labels: Vec<String> = (0..len).map(|i| i.to_string()).collect();
+
+    // Where each field's input comes from, and the path its issues go under.
+    let (array_check, field_values, field_errors) = if newtype {
+        (quote! {}, vec![quote! { __vld_json }], vec![quote! { e }])
+    } else {
+        let indexes: Vec<Index> = (0..len).map(Index::from).collect();
+        (
+            quote! {
+                let __vld_arr = __vld_json.as_array().ok_or_else(|| {
+                    ::vld::error::VldError::single(
+                        ::vld::error::IssueCode::InvalidType {
+                            expected: ::std::string::String::from("array"),
+                            received: ::vld::error::value_type_name(__vld_json),
+                        },
+                        ::std::format!(
+                            "Expected array (tuple), received {}",
+                            ::vld::error::value_type_name(__vld_json)
+                        ),
+                    )
+                })?;
+                if __vld_arr.len() != #len {
+                    return ::std::result::Result::Err(::vld::error::VldError::single(
+                        ::vld::error::IssueCode::Custom {
+                            code: ::std::string::String::from("invalid_tuple_length"),
+                        },
+                        ::std::format!(
+                            "Expected tuple of {} elements, received {}",
+                            #len,
+                            __vld_arr.len()
+                        ),
+                    ));
+                }
+            },
+            indexes.iter().map(|i| quote! { &__vld_arr[#i] }).collect(),
+            indexes
+                .iter()
+                .map(|i| {
+                    quote! {
+                        ::vld::error::VldError::with_prefix(
+                            e,
+                            ::vld::error::PathSegment::Index(#i),
+                        )
+                    }
+                })
+                .collect(),
+        )
+    };
+    let async_parses: Vec<TokenStream> = field_schemas
+        .iter()
+        .zip(&field_values)
+        .map(|(schema, value)| parse_async(schema, value))
+        .collect();
+
+    let json_schema_body = if newtype {
+        let schema = &field_schemas[0];
+        quote! { { #schema }.json_schema() }
+    } else {
+        quote! {
+            let __vld_items: ::std::vec::Vec<::vld::serde_json::Value> =
+                ::std::vec![ #( { #field_schemas }.json_schema() ),* ];
+            ::vld::serde_json::json!({
+                "type": "array",
+                "prefixItems": __vld_items,
+                "minItems": #len,
+                "maxItems": #len,
+            })
+        }
+    };
+
+    let input_methods = input_methods();
+    let struct_checks = struct_checks(&input.attrs)?;
+    let json_schema_impl = json_schema_impl(input);
+
+    let generics = bounded_generics(&input.generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let lenient_generics = lenient_generics(&generics, &field_types);
+    let lenient_where = lenient_generics.where_clause.as_ref();
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #input_methods
+
+            /// Parse and validate directly from a `serde_json::Value`.
+            pub fn parse_value(
+                __vld_json: &::vld::serde_json::Value,
+            ) -> ::std::result::Result<Self, ::vld::error::VldError> {
+                use ::vld::schema::VldSchema as _;
+
+                #array_check
+
+                let mut __vld_errors = ::vld::error::VldError::new();
+
+                #(
+                    let #field_binds: ::std::option::Option<#field_types> = {
+                        let __vld_field_schema = { #field_schemas };
+                        match __vld_field_schema.parse_value(#field_values) {
+                            ::std::result::Result::Ok(v) => ::std::option::Option::Some(v),
+                            ::std::result::Result::Err(e) => {
+                                __vld_errors =
+                                    ::vld::error::VldError::merge(__vld_errors, #field_errors);
+                                ::std::option::Option::None
+                            }
+                        }
+                    };
+                )*
+
+                if !::vld::error::VldError::is_empty(&__vld_errors) {
+                    return ::std::result::Result::Err(__vld_errors);
+                }
+
+                let __vld_value = Self( #( #field_binds.unwrap() ),* );
+                #struct_checks
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #lenient_where {
+            /// Validate each field individually and return per-field results.
+            ///
+            /// Fields are named by position (`"0"`, `"1"`, ...).
+            pub fn validate_fields<__VldInputT: ::vld::input::VldInput + ?Sized>(
+                input: &__VldInputT,
+            ) -> ::std::result::Result<
+                ::std::vec::Vec<::vld::error::FieldResult>,
+                ::vld::error::VldError,
+            > {
+                let __vld_json = <__VldInputT as ::vld::input::VldInput>::to_json_value(input)?;
+                Self::validate_fields_value(&__vld_json)
+            }
+
+            /// Validate each field individually from a `serde_json::Value`.
+            pub fn validate_fields_value(
+                __vld_json: &::vld::serde_json::Value,
+            ) -> ::std::result::Result<
+                ::std::vec::Vec<::vld::error::FieldResult>,
+                ::vld::error::VldError,
+            > {
+                #array_check
+
+                let mut __vld_results: ::std::vec::Vec<::vld::error::FieldResult> =
+                    ::std::vec::Vec::new();
+
+                #(
+                    {
+                        let __vld_field_schema = { #field_schemas };
+                        let __vld_field_value: &::vld::serde_json::Value = #field_values;
+
+                        let (__vld_result, __vld_children) =
+                            ::vld::object::DynSchema::dyn_parse_lenient(
+                                &__vld_field_schema,
+                                __vld_field_value,
+                            );
+
+                        __vld_results.push(::vld::error::FieldResult::new(
+                            ::std::string::String::from(#field_labels),
+                            __vld_field_value.clone(),
+                            __vld_result,
+                        )
+                        .with_children(__vld_children));
+                    }
+                )*
+
+                ::std::result::Result::Ok(__vld_results)
+            }
+
+            /// Parse leniently: build the value even when some fields fail.
+            pub fn parse_lenient<__VldInputT: ::vld::input::VldInput + ?Sized>(
+                input: &__VldInputT,
+            ) -> ::std::result::Result<
+                ::vld::error::ParseResult<Self>,
+                ::vld::error::VldError,
+            > {
+                let __vld_json = <__VldInputT as ::vld::input::VldInput>::to_json_value(input)?;
+                Self::parse_lenient_value(&__vld_json)
+            }
+
+            /// Parse leniently from a `serde_json::Value`.
+            pub fn parse_lenient_value(
+                __vld_json: &::vld::serde_json::Value,
+            ) -> ::std::result::Result<
+                ::vld::error::ParseResult<Self>,
+                ::vld::error::VldError,
+            > {
+                use ::vld::schema::VldSchema as _;
+
+                #array_check
+
+                let mut __vld_results: ::std::vec::Vec<::vld::error::FieldResult> =
+                    ::std::vec::Vec::new();
+
+                #(
+                    let #field_binds: #field_types = {
+                        let __vld_field_schema = { #field_schemas };
+                        let __vld_field_value: &::vld::serde_json::Value = #field_values;
+
+                        let __vld_lenient = __vld_field_schema.parse_value_lenient(__vld_field_value);
+                        let mut __vld_children = __vld_lenient.children;
+                        let __json_repr = __vld_lenient
+                            .value
+                            .as_ref()
+                            .and_then(|v| ::vld::serde_json::to_value(v).ok());
+                        if let ::std::option::Option::Some(j) = &__json_repr {
+                            ::vld::error::__fill_child_outputs(&mut __vld_children, j);
+                        }
+                        __vld_results.push(::vld::error::FieldResult::new(
+                            ::std::string::String::from(#field_labels),
+                            __vld_field_value.clone(),
+                            match __vld_lenient.error {
+                                ::std::option::Option::Some(e) => ::std::result::Result::Err(e),
+                                ::std::option::Option::None => ::std::result::Result::Ok(
+                                    __json_repr.unwrap_or_else(|| __vld_field_value.clone()),
+                                ),
+                            },
+                        )
+                        .with_children(__vld_children));
+                        __vld_lenient.value.unwrap_or_default()
+                    };
+                )*
+
+                ::std::result::Result::Ok(::vld::error::ParseResult::new(
+                    Self( #( #field_binds ),* ),
+                    __vld_results,
+                ))
+            }
+        }
+
+        impl #impl_generics ::vld::schema::VldParseLenient for #name #ty_generics #lenient_where {
+            fn vld_parse_lenient_value(
+                value: &::vld::serde_json::Value,
+            ) -> ::std::result::Result<::vld::error::ParseResult<Self>, ::vld::error::VldError> {
+                Self::parse_lenient_value(value)
+            }
+        }
+
+        impl #impl_generics ::vld::schema::VldParse for #name #ty_generics #where_clause {
+            fn vld_parse_value(
+                value: &::vld::serde_json::Value,
+            ) -> ::std::result::Result<Self, ::vld::error::VldError> {
+                Self::parse_value(value)
+            }
+
+            ::vld::__vld_if_async! {
+                fn vld_parse_value_async(
+                    value: &::vld::serde_json::Value,
+                ) -> ::vld::async_schema::BoxFuture<
+                    '_,
+                    ::std::result::Result<Self, ::vld::error::VldError>,
+                > {
+                    Self::parse_value_async(value)
+                }
+            }
+        }
+
+        ::vld::__vld_if_async! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Parse and validate input data, running async refinements.
+                ///
+                /// Requires the `async` feature on `vld`.
+                pub fn vld_parse_async<__VldInputT: ::vld::input::VldInput + ?Sized>(
+                    input: &__VldInputT,
+                ) -> ::vld::async_schema::BoxFuture<
+                    'static,
+                    ::std::result::Result<Self, ::vld::error::VldError>,
+                > {
+                    let __vld_json = <__VldInputT as ::vld::input::VldInput>::to_json_value(input);
+                    ::std::boxed::Box::pin(async move {
+                        let __vld_json = __vld_json?;
+                        Self::parse_value_async(&__vld_json).await
+                    })
+                }
+
+                /// Async counterpart of `parse_value()`.
+                ///
+                /// Requires the `async` feature on `vld`.
+                pub fn parse_value_async(
+                    __vld_json: &::vld::serde_json::Value,
+                ) -> ::vld::async_schema::BoxFuture<
+                    '_,
+                    ::std::result::Result<Self, ::vld::error::VldError>,
+                > {
+                    ::std::boxed::Box::pin(async move {
+                        #[allow(unused_imports)]
+                        use ::vld::async_schema::{__VldViaAsync as _, __VldViaSync as _};
+
+                        #array_check
+
+                        let mut __vld_errors = ::vld::error::VldError::new();
+
+                        #(
+                            let #field_binds: ::std::option::Option<#field_types> = {
+                                match #async_parses {
+                                    ::std::result::Result::Ok(v) => ::std::option::Option::Some(v),
+                                    ::std::result::Result::Err(e) => {
+                                        __vld_errors = ::vld::error::VldError::merge(
+                                            __vld_errors,
+                                            #field_errors,
+                                        );
+                                        ::std::option::Option::None
+                                    }
+                                }
+                            };
+                        )*
+
+                        if !::vld::error::VldError::is_empty(&__vld_errors) {
+                            return ::std::result::Result::Err(__vld_errors);
+                        }
+
+                        let __vld_value = Self( #( #field_binds.unwrap() ),* );
+                #struct_checks
+                    })
+                }
+            }
+        }
+
+        ::vld::__vld_if_openapi! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Generate a JSON Schema / OpenAPI 3.1 representation of this type.
+                ///
+                /// Requires the `openapi` feature on `vld`.
+                pub fn json_schema() -> ::vld::serde_json::Value {
+                    use ::vld::json_schema::JsonSchema as _;
+                    #json_schema_body
+                }
+
+                /// Wrap `json_schema()` in a minimal OpenAPI 3.1 document.
+                ///
+                /// Requires the `openapi` feature on `vld`.
+                pub fn to_openapi_document() -> ::vld::serde_json::Value {
+                    ::vld::json_schema::to_openapi_document(
+                        &<Self as ::vld::json_schema::VldJsonSchema>::vld_schema_name(),
+                        &Self::json_schema(),
+                    )
+                }
+            }
+
+            impl #impl_generics ::vld::json_schema::OpenApiParameterIn for #name #ty_generics #where_clause {
+                fn parameter_in() -> Option<&'static str> {
+                    None
+                }
+            }
+
+            #json_schema_impl
+        }
+    })
+}
@@ -46,6 +46,7 @@ use ntex::http::StatusCode;
 use ntex::web::error::WebResponseError;
 use ntex::web::{ErrorRenderer, FromRequest, HttpRequest, HttpResponse};
 use std::fmt;
+use vld::options::ParseOptions;
 
 // ============================= Error / Rejection =============================
 
@@ -82,6 +83,18 @@ impl<Err: ErrorRenderer> WebResponseError<Err> for VldNtexError {
 /// ntex extractor that validates **JSON request bodies**.
 ///
 /// Drop-in replacement for `ntex::web::types::Json<T>`.
+///
+/// Bodies are parsed with the [`ParseOptions`](vld::options::ParseOptions)
+/// registered as app state, falling back to
+/// [`ParseOptions::safe_defaults()`](vld::options::ParseOptions::safe_defaults):
+///
+/// ```ignore
+/// use vld::options::ParseOptions;
+///
+/// App::new()
+///     .state(ParseOptions::safe_defaults().abort_early(true))
+///     .route("/users", web::post().to(create_user));
+/// ```
 pub struct VldJson<T>(pub T);
 
 impl<T> std::ops::Deref for VldJson<T> {
@@ -104,6 +117,10 @@ impl<T: vld::schema::VldParse, Err: ErrorRenderer> FromRequest<Err> for VldJson<
         req: &HttpRequest,
         payload: &mut ntex::http::Payload,
     ) -> Result<Self, Self::Error> {
+        let options = req
+            .app_state::<ParseOptions>()
+            .cloned()
+            .unwrap_or_else(ParseOptions::safe_defaults);
         let json_value =
             <ntex::web::types::Json<serde_json::Value> as FromRequest<Err>>::from_request(
                 req, payload,
@@ -116,7 +133,8 @@ impl<T: vld::schema::VldParse, Err: ErrorRenderer> FromRequest<Err> for VldJson<
                 ),
             })?;
 
-        let parsed = T::vld_parse_value(&json_value).map_err(|error| VldNtexError { error })?;
+        let parsed = T::vld_parse_value_with_options(&json_value, &options)
+            .map_err(|error| VldNtexError { error })?;
 
         Ok(VldJson(parsed))
     }
@@ -347,5 +365,6 @@ fn headers_to_json(headers: &ntex::http::HeaderMap) -> serde_json::Value {
 /// Prelude — import everything you need.
 pub mod prelude {
     pub use crate::{VldCookie, VldForm, VldHeaders, VldJson, VldNtexError, VldPath, VldQuery};
+    pub use vld::options::ParseOptions;
     pub use vld::prelude::*;
 }
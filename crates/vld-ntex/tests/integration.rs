@@ -47,6 +47,47 @@ async fn json_validation_error_returns_422() {
     assert!(json["issues"].as_array().unwrap().len() >= 2);
 }
 
+#[ntex::test]
+async fn json_parse_options_from_state() {
+    let app = test::init_service(
+        App::new()
+            .state(vld::options::ParseOptions::new().abort_early(true))
+            .route("/test", web::post().to(json_handler)),
+    )
+    .await;
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(&serde_json::json!({"name": "A", "age": -1}))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 422);
+    let body = test::read_body(resp).await;
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["issues"].as_array().unwrap().len(), 1);
+}
+
+#[ntex::test]
+async fn json_parse_options_reject_deep_input() {
+    let app = test::init_service(
+        App::new()
+            .state(vld::options::ParseOptions::new().max_depth(1))
+            .route("/test", web::post().to(json_handler)),
+    )
+    .await;
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(&serde_json::json!({"name": "Alice", "age": 1, "extra": {"a": [1]}}))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 422);
+    let body = test::read_body(resp).await;
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(json["issues"][0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("nested deeper"));
+}
+
 #[ntex::test]
 async fn json_missing_fields_returns_422() {
     let app = test::init_service(App::new().route("/test", web::post().to(json_handler))).await;
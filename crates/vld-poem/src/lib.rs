@@ -20,6 +20,7 @@ use poem::http::StatusCode;
 use poem::{FromRequest, Request, RequestBody, Result};
 use std::fmt;
 use std::ops::{Deref, DerefMut};
+use vld::options::ParseOptions;
 use vld::schema::VldParse;
 
 // ---------------------------------------------------------------------------
@@ -56,6 +57,19 @@ impl ResponseError for VldPoemError {
 // ---------------------------------------------------------------------------
 
 /// Validated JSON body extractor for Poem.
+///
+/// Bodies are parsed with the [`ParseOptions`](vld::options::ParseOptions)
+/// attached as request data, falling back to
+/// [`ParseOptions::safe_defaults()`](vld::options::ParseOptions::safe_defaults):
+///
+/// ```ignore
+/// use poem::EndpointExt;
+/// use vld::options::ParseOptions;
+///
+/// let app = Route::new()
+///     .at("/users", post(create_user))
+///     .data(ParseOptions::safe_defaults().abort_early(true));
+/// ```
 #[derive(Debug, Clone)]
 pub struct VldJson<T>(pub T);
 
@@ -73,12 +87,16 @@ impl<T> DerefMut for VldJson<T> {
 }
 
 impl<'a, T: VldParse + Send + Sync + 'static> FromRequest<'a> for VldJson<T> {
-    async fn from_request(_req: &'a Request, body: &mut RequestBody) -> Result<Self> {
+    async fn from_request(req: &'a Request, body: &mut RequestBody) -> Result<Self> {
+        let options = req
+            .data::<ParseOptions>()
+            .cloned()
+            .unwrap_or_else(ParseOptions::safe_defaults);
         let bytes = body.take()?.into_bytes().await?;
         let value: serde_json::Value = serde_json::from_slice(&bytes)
             .map_err(|e| VldPoemError(format_json_parse_error(&e.to_string())))?;
 
-        T::vld_parse_value(&value)
+        T::vld_parse_value_with_options(&value, &options)
             .map(VldJson)
             .map_err(|e| VldPoemError(format_vld_error(&e)).into())
     }
@@ -284,5 +302,6 @@ impl<'a, T: VldParse + Send + Sync + 'static> FromRequest<'a> for VldCookie<T> {
 /// Prelude — import everything you need.
 pub mod prelude {
     pub use crate::{VldCookie, VldForm, VldHeaders, VldJson, VldPath, VldQuery};
+    pub use vld::options::ParseOptions;
     pub use vld::prelude::*;
 }
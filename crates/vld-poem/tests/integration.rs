@@ -94,6 +94,44 @@ async fn json_malformed() {
     );
 }
 
+#[tokio::test]
+async fn json_parse_options_from_data() {
+    use poem::EndpointExt;
+
+    let app = app().data(ParseOptions::new().abort_early(true));
+    let cli = TestClient::new(app);
+    let resp = cli
+        .post("/users")
+        .content_type("application/json")
+        .body(r#"{"name": "A", "email": "bad"}"#)
+        .send()
+        .await;
+    resp.assert_status(poem::http::StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = resp.0.into_body().into_json().await.unwrap();
+    assert_eq!(body["issues"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn json_safe_defaults_reject_deep_input() {
+    let nested = format!("{}1{}", "[".repeat(100), "]".repeat(100));
+    let cli = TestClient::new(app());
+    let resp = cli
+        .post("/users")
+        .content_type("application/json")
+        .body(format!(
+            r#"{{"name": "Alice", "email": "alice@example.com", "extra": {}}}"#,
+            nested
+        ))
+        .send()
+        .await;
+    resp.assert_status(poem::http::StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = resp.0.into_body().into_json().await.unwrap();
+    assert!(body["issues"][0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("nested deeper"));
+}
+
 // ---------------------------------------------------------------------------
 // Tests — Query
 // ---------------------------------------------------------------------------
@@ -31,6 +31,7 @@ use rocket::http::Status;
 use rocket::request::{FromRequest, Outcome, Request};
 use rocket::serde::json::Json;
 use std::ops::{Deref, DerefMut};
+use vld::options::ParseOptions;
 use vld::schema::VldParse;
 use vld_http_common::{
     coerce_value, cookies_to_json, format_vld_error, parse_query_string as parse_query_to_json,
@@ -55,8 +56,21 @@ fn store_error(req: &Request<'_>, err: serde_json::Value) {
 
 /// Validated JSON body extractor.
 ///
-/// Reads the request body as JSON, validates via `T::vld_parse_value()`,
-/// and returns `422` with error details on failure.
+/// Reads the request body as JSON, validates via
+/// `T::vld_parse_value_with_options()`, and returns `422` with error details
+/// on failure.
+///
+/// Bodies are parsed with the [`ParseOptions`](vld::options::ParseOptions)
+/// in managed state, falling back to
+/// [`ParseOptions::safe_defaults()`](vld::options::ParseOptions::safe_defaults):
+///
+/// ```rust,ignore
+/// use vld::options::ParseOptions;
+///
+/// rocket::build()
+///     .manage(ParseOptions::safe_defaults().abort_early(true))
+///     .mount("/", routes![create_user])
+/// ```
 #[derive(Debug, Clone)]
 pub struct VldJson<T>(pub T);
 
@@ -78,6 +92,11 @@ impl<'r, T: VldParse + Send + 'static> FromData<'r> for VldJson<T> {
     type Error = serde_json::Value;
 
     async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let options = req
+            .rocket()
+            .state::<ParseOptions>()
+            .cloned()
+            .unwrap_or_else(ParseOptions::safe_defaults);
         let json_outcome = <Json<serde_json::Value> as FromData<'r>>::from_data(req, data).await;
         let value = match json_outcome {
             DataOutcome::Success(json) => json.into_inner(),
@@ -89,7 +108,7 @@ impl<'r, T: VldParse + Send + 'static> FromData<'r> for VldJson<T> {
             DataOutcome::Forward(f) => return DataOutcome::Forward(f),
         };
 
-        match T::vld_parse_value(&value) {
+        match T::vld_parse_value_with_options(&value, &options) {
             Ok(parsed) => DataOutcome::Success(VldJson(parsed)),
             Err(vld_err) => {
                 let body = format_vld_error(&vld_err);
@@ -423,5 +442,6 @@ pub mod prelude {
         vld_400_catcher, vld_422_catcher, VldCookie, VldForm, VldHeaders, VldJson, VldPath,
         VldQuery,
     };
+    pub use vld::options::ParseOptions;
     pub use vld::prelude::*;
 }
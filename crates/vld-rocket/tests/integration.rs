@@ -91,6 +91,40 @@ fn json_malformed() {
     assert!(resp.status() == Status::BadRequest || resp.status() == Status::UnprocessableEntity);
 }
 
+#[test]
+fn json_parse_options_from_managed_state() {
+    let client = Client::tracked(rocket().manage(ParseOptions::new().abort_early(true)))
+        .expect("valid rocket");
+    let resp = client
+        .post("/users")
+        .header(ContentType::JSON)
+        .body(r#"{"name": "A", "email": "not-email"}"#)
+        .dispatch();
+    assert_eq!(resp.status(), Status::UnprocessableEntity);
+    let body: serde_json::Value = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+    assert_eq!(body["issues"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn json_safe_defaults_reject_deep_input() {
+    let nested = format!("{}1{}", "[".repeat(100), "]".repeat(100));
+    let client = Client::tracked(rocket()).expect("valid rocket");
+    let resp = client
+        .post("/users")
+        .header(ContentType::JSON)
+        .body(format!(
+            r#"{{"name": "Alice", "email": "alice@example.com", "extra": {}}}"#,
+            nested
+        ))
+        .dispatch();
+    assert_eq!(resp.status(), Status::UnprocessableEntity);
+    let body: serde_json::Value = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+    assert!(body["issues"][0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("nested deeper"));
+}
+
 // ---------------------------------------------------------------------------
 // Tests — Query
 // ---------------------------------------------------------------------------
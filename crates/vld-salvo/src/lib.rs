@@ -42,6 +42,7 @@ use salvo::extract::Extractible;
 use salvo::http::StatusCode;
 use salvo::prelude::*;
 use std::sync::OnceLock;
+use vld::options::ParseOptions;
 use vld::schema::VldParse;
 use vld_http_common::coerce_value;
 
@@ -115,6 +116,21 @@ fn parse_error(msg: impl std::fmt::Display) -> VldSalvoError {
 ///     res.render(Json(body.0));
 /// }
 /// ```
+///
+/// Bodies are parsed with the [`ParseOptions`](vld::options::ParseOptions)
+/// injected into the [`Depot`], falling back to
+/// [`ParseOptions::safe_defaults()`](vld::options::ParseOptions::safe_defaults):
+///
+/// ```rust,ignore
+/// use vld::options::ParseOptions;
+///
+/// #[handler]
+/// async fn parse_options(depot: &mut Depot) {
+///     depot.inject(ParseOptions::safe_defaults().abort_early(true));
+/// }
+///
+/// let router = Router::new().hoop(parse_options).push(users);
+/// ```
 pub struct VldJson<T>(pub T);
 
 impl<T> std::ops::Deref for VldJson<T> {
@@ -141,13 +157,18 @@ impl<'ex, T: VldParse + Send> Extractible<'ex> for VldJson<T> {
 
     async fn extract(
         req: &'ex mut Request,
-        _depot: &'ex mut Depot,
+        depot: &'ex mut Depot,
     ) -> Result<Self, impl Writer + Send + std::fmt::Debug + 'static> {
+        let options = depot
+            .obtain::<ParseOptions>()
+            .ok()
+            .cloned()
+            .unwrap_or_else(ParseOptions::safe_defaults);
         let value: serde_json::Value = req
             .parse_json()
             .await
             .map_err(|e| parse_error(format_args!("Invalid JSON: {e}")))?;
-        T::vld_parse_value(&value)
+        T::vld_parse_value_with_options(&value, &options)
             .map(VldJson)
             .map_err(VldSalvoError::from)
     }
@@ -423,5 +444,6 @@ impl<'ex, T: VldParse + Send> Extractible<'ex> for VldCookie<T> {
 /// Prelude — import everything you need.
 pub mod prelude {
     pub use crate::{VldCookie, VldForm, VldHeaders, VldJson, VldPath, VldQuery, VldSalvoError};
+    pub use vld::options::ParseOptions;
     pub use vld::prelude::*;
 }
@@ -102,6 +102,44 @@ async fn json_invalid() {
     assert!(!body["issues"].as_array().unwrap().is_empty());
 }
 
+#[handler]
+async fn abort_early_options(depot: &mut Depot) {
+    depot.inject(ParseOptions::new().abort_early(true));
+}
+
+#[tokio::test]
+async fn json_parse_options_from_depot() {
+    let router = Router::new().hoop(abort_early_options).push(build_router());
+    let service = Service::new(router);
+    let mut resp = TestClient::post("http://localhost/users")
+        .json(&serde_json::json!({"name": "A", "email": "bad"}))
+        .send(&service)
+        .await;
+    assert_eq!(resp.status_code.unwrap(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = resp.take_json().await.unwrap();
+    assert_eq!(body["issues"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn json_safe_defaults_reject_deep_input() {
+    let nested = format!("{}1{}", "[".repeat(100), "]".repeat(100));
+    let service = Service::new(build_router());
+    let mut resp = TestClient::post("http://localhost/users")
+        .body(format!(
+            r#"{{"name": "Alice", "email": "alice@example.com", "extra": {}}}"#,
+            nested
+        ))
+        .add_header("content-type", "application/json", true)
+        .send(&service)
+        .await;
+    assert_eq!(resp.status_code.unwrap(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = resp.take_json().await.unwrap();
+    assert!(body["issues"][0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("nested deeper"));
+}
+
 #[tokio::test]
 async fn json_malformed() {
     let service = Service::new(build_router());
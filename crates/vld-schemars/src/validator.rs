@@ -546,11 +546,10 @@ fn make_issue(
     message: impl Into<String>,
     received: Option<&Value>,
 ) -> ValidationIssue {
-    ValidationIssue {
-        code,
-        message: message.into(),
-        path: path.to_vec(),
-        received: received.cloned(),
+    let issue = ValidationIssue::new(code, message).with_path(path.to_vec());
+    match received {
+        Some(value) => issue.with_received(value.clone()),
+        None => issue,
     }
 }
 
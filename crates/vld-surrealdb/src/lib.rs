@@ -775,16 +775,17 @@ macro_rules! validate_fields {
         } else {
             let mut __vld_err = $crate::vld::error::VldError::new();
             for fe in &__vld_errors {
-                __vld_err.issues.push($crate::vld::error::ValidationIssue {
-                    code: $crate::vld::error::IssueCode::Custom {
-                        code: fe.field.clone(),
-                    },
-                    message: fe.message.clone(),
-                    path: vec![
+                __vld_err.issues.push(
+                    $crate::vld::error::ValidationIssue::new(
+                        $crate::vld::error::IssueCode::Custom {
+                            code: fe.field.clone(),
+                        },
+                        fe.message.clone(),
+                    )
+                    .with_path(vec![
                         $crate::vld::error::PathSegment::Field(fe.field.clone()),
-                    ],
-                    received: None,
-                });
+                    ]),
+                );
             }
             ::std::result::Result::Err($crate::VldSurrealError::Validation(__vld_err))
         }
@@ -39,6 +39,7 @@ use std::future::Future;
 use std::marker::PhantomData;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use vld::options::ParseOptions;
 use vld::schema::VldParse;
 
 // ---------------------------------------------------------------------------
@@ -53,7 +54,9 @@ use vld::schema::VldParse;
 /// # Behaviour
 ///
 /// 1. Reads the full request body.
-/// 2. Parses as JSON and validates via `T::vld_parse_value()`.
+/// 2. Parses as JSON and validates via `T::vld_parse_value_with_options()`,
+///    with [`ParseOptions::safe_defaults()`] unless configured with
+///    [`with_options()`](Self::with_options).
 /// 3. **Valid** — inserts `T` into request extensions, re-attaches the
 ///    body bytes, and calls the inner service.
 /// 4. **Invalid** — returns `422 Unprocessable Entity` with a JSON body
@@ -63,6 +66,7 @@ use vld::schema::VldParse;
 /// type) are **passed through** without validation.
 #[derive(Clone)]
 pub struct ValidateJsonLayer<T> {
+    options: ParseOptions,
     _marker: PhantomData<fn() -> T>,
 }
 
@@ -70,9 +74,24 @@ impl<T> ValidateJsonLayer<T> {
     /// Create a new validation layer.
     pub fn new() -> Self {
         Self {
+            options: ParseOptions::safe_defaults(),
             _marker: PhantomData,
         }
     }
+
+    /// Parse bodies with these [`ParseOptions`] instead of
+    /// [`ParseOptions::safe_defaults()`].
+    ///
+    /// ```rust,ignore
+    /// use vld::options::ParseOptions;
+    ///
+    /// let layer = ValidateJsonLayer::<CreateUser>::new()
+    ///     .with_options(ParseOptions::safe_defaults().abort_early(true));
+    /// ```
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
 }
 
 impl<T> Default for ValidateJsonLayer<T> {
@@ -87,6 +106,7 @@ impl<S, T> tower_layer::Layer<S> for ValidateJsonLayer<T> {
     fn layer(&self, inner: S) -> Self::Service {
         ValidateJsonService {
             inner,
+            options: self.options.clone(),
             _marker: PhantomData,
         }
     }
@@ -101,6 +121,7 @@ impl<S, T> tower_layer::Layer<S> for ValidateJsonLayer<T> {
 #[derive(Clone)]
 pub struct ValidateJsonService<S, T> {
     inner: S,
+    options: ParseOptions,
     _marker: PhantomData<fn() -> T>,
 }
 
@@ -130,6 +151,7 @@ where
         let mut inner = self.inner.clone();
         // Swap so `self` is ready for next call (standard Tower pattern)
         std::mem::swap(&mut self.inner, &mut inner);
+        let options = self.options.clone();
 
         Box::pin(async move {
             let is_json = req
@@ -176,7 +198,7 @@ where
             };
 
             // Validate with vld
-            match T::vld_parse_value(&json_value) {
+            match T::vld_parse_value_with_options(&json_value, &options) {
                 Ok(validated) => {
                     let mut new_req = Request::from_parts(parts, http_body_util::Full::new(bytes));
                     // Store validated struct in extensions
@@ -235,4 +257,5 @@ pub fn try_validated<T: Clone + Send + Sync + 'static>(req: &Request<impl Body>)
 /// Prelude — import everything you need.
 pub mod prelude {
     pub use crate::{try_validated, validated, ValidateJsonLayer, ValidateJsonService};
+    pub use vld::options::ParseOptions;
 }
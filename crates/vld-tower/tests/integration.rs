@@ -96,6 +96,46 @@ async fn invalid_json_returns_422() {
     assert!(json["issues"].as_array().unwrap().len() >= 2);
 }
 
+#[tokio::test]
+async fn layer_with_options() {
+    let svc = ServiceBuilder::new()
+        .layer(
+            ValidateJsonLayer::<CreateUser>::new()
+                .with_options(vld::options::ParseOptions::new().abort_early(true)),
+        )
+        .service_fn(echo_service);
+
+    let req = make_json_request(r#"{"name": "A", "email": "bad"}"#);
+    let resp = svc.oneshot(req).await.unwrap();
+
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = resp.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["issues"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn safe_defaults_reject_deep_input() {
+    let svc = ServiceBuilder::new()
+        .layer(ValidateJsonLayer::<CreateUser>::new())
+        .service_fn(echo_service);
+
+    let nested = format!("{}1{}", "[".repeat(100), "]".repeat(100));
+    let req = make_json_request(&format!(
+        r#"{{"name": "Alice", "email": "alice@example.com", "extra": {}}}"#,
+        nested
+    ));
+    let resp = svc.oneshot(req).await.unwrap();
+
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = resp.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(json["issues"][0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("nested deeper"));
+}
+
 #[tokio::test]
 async fn malformed_json_returns_400() {
     let svc = ServiceBuilder::new()
@@ -320,8 +320,9 @@ fn convert_schema(schema: &Value) -> String {
         return format!("z.union([{}])", variants.join(", "));
     }
 
-    if let Some(all_of) = schema.get("allOf").and_then(|v| v.as_array()) {
-        let parts: Vec<String> = all_of.iter().map(convert_schema).collect();
+    let all_of = schema_parts(schema, "allOf");
+    if !all_of.is_empty() {
+        let parts: Vec<String> = all_of.into_iter().map(convert_schema).collect();
         if parts.len() == 1 {
             return parts[0].clone();
         }
@@ -444,13 +445,36 @@ fn convert_array(schema: &Value) -> String {
         .map(convert_schema)
         .unwrap_or_else(|| "z.unknown()".to_string());
 
-    let mut s = format!("z.array({})", items);
+    let prefix = prefix_items(schema);
+    let is_tuple = !prefix.is_empty();
+    let mut s = if is_tuple {
+        let parts: Vec<String> = prefix.into_iter().map(convert_schema).collect();
+        format!("z.tuple([{}]).rest({})", parts.join(", "), items)
+    } else {
+        format!("z.array({})", items)
+    };
 
+    // `z.tuple()` has no `.min()` / `.max()`, so lengths become refinements.
     if let Some(min) = schema.get("minItems").and_then(|v| v.as_u64()) {
-        s.push_str(&format!(".min({})", min));
+        if is_tuple {
+            s.push_str(&format!(".refine((a) => a.length >= {})", min));
+        } else {
+            s.push_str(&format!(".min({})", min));
+        }
     }
     if let Some(max) = schema.get("maxItems").and_then(|v| v.as_u64()) {
-        s.push_str(&format!(".max({})", max));
+        if is_tuple {
+            s.push_str(&format!(".refine((a) => a.length <= {})", max));
+        } else {
+            s.push_str(&format!(".max({})", max));
+        }
+    }
+    if let Some(contains) = schema.get("contains") {
+        let matches = format!("(x) => {}.safeParse(x).success", convert_schema(contains));
+        s.push_str(&format!(
+            ".refine({})",
+            contains_predicate(schema, &matches)
+        ));
     }
     if schema.get("uniqueItems").and_then(|v| v.as_bool()) == Some(true) {
         s.push_str(" /* uniqueItems */");
@@ -505,6 +529,53 @@ fn convert_object(schema: &Value) -> String {
     }
 }
 
+/// The subschemas of a combinator keyword, without `if` / `then` / `else`
+/// entries (conditional rules of `ZObject`), which have no TypeScript
+/// counterpart.
+fn schema_parts<'a>(schema: &'a Value, keyword: &str) -> Vec<&'a Value> {
+    schema
+        .get(keyword)
+        .and_then(|v| v.as_array())
+        .map(|parts| parts.iter().filter(|p| !is_conditional(p)).collect())
+        .unwrap_or_default()
+}
+
+fn is_conditional(schema: &Value) -> bool {
+    schema.as_object().is_some_and(|map| {
+        map.contains_key("if")
+            && map
+                .keys()
+                .all(|k| matches!(k.as_str(), "if" | "then" | "else"))
+    })
+}
+
+/// The `prefixItems` subschemas of an array schema.
+fn prefix_items(schema: &Value) -> Vec<&Value> {
+    schema
+        .get("prefixItems")
+        .and_then(|v| v.as_array())
+        .map(|items| items.iter().collect())
+        .unwrap_or_default()
+}
+
+/// `contains` with `minContains` / `maxContains` as a predicate over the
+/// array, given a function that tests one element.
+fn contains_predicate(schema: &Value, matches: &str) -> String {
+    let min = schema
+        .get("minContains")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(1);
+    let mut bounds = vec![format!("n >= {}", min)];
+    if let Some(max) = schema.get("maxContains").and_then(|v| v.as_u64()) {
+        bounds.push(format!("n <= {}", max));
+    }
+    format!(
+        "(a) => {{ const n = a.filter({}).length; return {}; }}",
+        matches,
+        bounds.join(" && ")
+    )
+}
+
 fn add_description(s: &mut String, schema: &Value) {
     if let Some(desc) = schema.get("description").and_then(|v| v.as_str()) {
         s.push_str(&format!(".describe(\"{}\")", desc.replace('"', "\\\"")));
@@ -573,8 +644,9 @@ fn convert_schema_valibot(schema: &Value) -> String {
         return format!("v.union([{}])", variants.join(", "));
     }
 
-    if let Some(all_of) = schema.get("allOf").and_then(|v| v.as_array()) {
-        let parts: Vec<String> = all_of.iter().map(convert_schema_valibot).collect();
+    let all_of = schema_parts(schema, "allOf");
+    if !all_of.is_empty() {
+        let parts: Vec<String> = all_of.into_iter().map(convert_schema_valibot).collect();
         if parts.len() == 1 {
             return parts[0].clone();
         }
@@ -697,7 +769,13 @@ fn convert_array_valibot(schema: &Value) -> String {
         .get("items")
         .map(convert_schema_valibot)
         .unwrap_or_else(|| "v.unknown()".to_string());
-    let base = format!("v.array({})", items);
+    let prefix = prefix_items(schema);
+    let base = if prefix.is_empty() {
+        format!("v.array({})", items)
+    } else {
+        let parts: Vec<String> = prefix.into_iter().map(convert_schema_valibot).collect();
+        format!("v.tupleWithRest([{}], {})", parts.join(", "), items)
+    };
     let mut actions = Vec::new();
     if let Some(min) = schema.get("minItems").and_then(|v| v.as_u64()) {
         actions.push(format!("v.minSize({})", min));
@@ -705,6 +783,10 @@ fn convert_array_valibot(schema: &Value) -> String {
     if let Some(max) = schema.get("maxItems").and_then(|v| v.as_u64()) {
         actions.push(format!("v.maxSize({})", max));
     }
+    if let Some(contains) = schema.get("contains") {
+        let matches = format!("(x) => v.is({}, x)", convert_schema_valibot(contains));
+        actions.push(format!("v.check({})", contains_predicate(schema, &matches)));
+    }
     if let Some(desc) = schema.get("description").and_then(|v| v.as_str()) {
         actions.push(format!("v.description(\"{}\")", escape_js_string(desc)));
     }
@@ -158,3 +158,49 @@ fn type_to_openapi_custom_name() {
     assert_eq!(doc["type"], "object");
     assert!(doc["properties"]["name"].is_object());
 }
+
+#[test]
+fn object_conditional_rules_are_left_out() {
+    let schema = vld::object()
+        .field_schema("country", vld::string())
+        .if_then(
+            vld::object::Condition::field("country").eq("US"),
+            vld::object().field_schema("zip", vld::string()),
+        );
+    let zod = vld_ts::to_zod(&schema);
+    assert_eq!(zod, "z.object({\n  country: z.string()\n})");
+    let valibot = vld_ts::to_valibot(&schema);
+    assert!(!valibot.contains("intersect"), "{}", valibot);
+    assert!(vld_ts::to_openapi(&schema)["allOf"][0]["if"].is_object());
+}
+
+#[test]
+fn array_prefix_items_and_contains() {
+    let schema = vld::array(vld::json_value())
+        .prefix_items((vld::string(), vld::number().int()))
+        .min_len(2)
+        .contains_schema(vld::string().min(3))
+        .max_contains(2);
+
+    let zod = vld_ts::to_zod(&schema);
+    assert_eq!(
+        zod,
+        "z.tuple([z.string(), z.number().int()]).rest(z.unknown())\
+         .refine((a) => a.length >= 2)\
+         .refine((a) => { const n = a.filter((x) => z.string().min(3).safeParse(x).success).length; return n >= 1 && n <= 2; })"
+    );
+
+    let valibot = vld_ts::to_valibot(&schema);
+    assert!(
+        valibot.starts_with(
+            "v.pipe(v.tupleWithRest([v.string(), v.pipe(v.number(), v.integer())], v.unknown())"
+        ),
+        "{}",
+        valibot
+    );
+    assert!(
+        valibot.contains("v.check((a) => { const n = a.filter((x) => v.is(v.pipe(v.string(), v.minLength(3)), x)).length; return n >= 1 && n <= 2; })"),
+        "{}",
+        valibot
+    );
+}
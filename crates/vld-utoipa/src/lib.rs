@@ -92,12 +92,19 @@ pub fn json_schema_to_schema(value: &Value) -> RefOr<Schema> {
                 return RefOr::T(Schema::OneOf(one_of));
             }
 
-            // Handle allOf
+            // Handle allOf, leaving out `if` / `then` / `else` entries
+            // (conditional rules of `ZObject`), which OpenAPI 3.0 lacks
             if let Some(Value::Array(items)) = map.get("allOf") {
-                let schemas: Vec<RefOr<Schema>> = items.iter().map(json_schema_to_schema).collect();
-                let mut all_of = AllOf::new();
-                all_of.items = schemas;
-                return RefOr::T(Schema::AllOf(all_of));
+                let schemas: Vec<RefOr<Schema>> = items
+                    .iter()
+                    .filter(|item| !is_conditional(item))
+                    .map(json_schema_to_schema)
+                    .collect();
+                if !schemas.is_empty() {
+                    let mut all_of = AllOf::new();
+                    all_of.items = schemas;
+                    return RefOr::T(Schema::AllOf(all_of));
+                }
             }
 
             // Determine type
@@ -131,6 +138,15 @@ pub fn json_schema_to_schema(value: &Value) -> RefOr<Schema> {
     }
 }
 
+fn is_conditional(schema: &Value) -> bool {
+    schema.as_object().is_some_and(|map| {
+        map.contains_key("if")
+            && map
+                .keys()
+                .all(|k| matches!(k.as_str(), "if" | "then" | "else"))
+    })
+}
+
 fn convert_object(map: &serde_json::Map<String, Value>) -> RefOr<Schema> {
     let mut obj = Object::with_type(SchemaType::Type(Type::Object));
 
@@ -359,13 +375,22 @@ pub fn resolve_parameter_in<T: vld::json_schema::OpenApiParameterIn>(
     resolve_parameter_in_with_override::<T>(parameter_in_provider, None)
 }
 
+/// Component name of a vld type: its own name, with the type arguments of a
+/// generic instantiation appended (`Paginated<User>` is `Paginated_User`).
+#[doc(hidden)]
+pub fn __schema_name<T: vld::json_schema::VldJsonSchema>() -> std::borrow::Cow<'static, str> {
+    std::borrow::Cow::Owned(T::vld_schema_name())
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __impl_to_schema {
     ($ty:ty, name: None, param_in: None) => {
         $crate::__impl_to_schema_body! {
             $ty,
-            name: { },
+            name: { fn name() -> ::std::borrow::Cow<'static, str> {
+                $crate::__schema_name::<$ty>()
+            } },
             param_in: { None::<$crate::utoipa::openapi::path::ParameterIn> },
         }
     };
@@ -381,7 +406,9 @@ macro_rules! __impl_to_schema {
     ($ty:ty, name: None, param_in: Some($param_in:expr)) => {
         $crate::__impl_to_schema_body! {
             $ty,
-            name: { },
+            name: { fn name() -> ::std::borrow::Cow<'static, str> {
+                $crate::__schema_name::<$ty>()
+            } },
             param_in: { Some($param_in) },
         }
     };
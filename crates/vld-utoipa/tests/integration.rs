@@ -128,6 +128,20 @@ fn all_of() {
     assert_eq!(items.len(), 2);
 }
 
+#[test]
+fn object_conditional_rules_are_left_out() {
+    let schema = vld::object()
+        .field_schema("country", vld::string())
+        .if_then(
+            Condition::field("country").eq("US"),
+            vld::object().field_schema("zip", vld::string()),
+        )
+        .to_json_schema();
+    let json = serde_json::to_value(json_schema_to_schema(&schema)).unwrap();
+    assert!(json.get("allOf").is_none());
+    assert_eq!(json["properties"]["country"]["type"], "string");
+}
+
 #[test]
 fn ref_schema() {
     let s = json_schema_to_schema(&json!({"$ref": "#/components/schemas/User"}));
@@ -506,6 +520,49 @@ fn flat_schema_has_empty_nested_schemas() {
     );
 }
 
+// ---- Generic structs ----
+
+vld::schema! {
+    #[derive(Debug)]
+    pub struct Page<T> {
+        pub items: Vec<T> => vld::array(vld::nested_param::<T>()),
+        pub total: i64 => vld::number().int().non_negative(),
+    }
+}
+
+impl_to_schema!(Page<Address>);
+
+#[test]
+fn generic_instantiation_named_after_type_arguments() {
+    assert_eq!(<Page<Address> as ToSchema>::name(), "Page_Address");
+
+    let mut schemas = Vec::new();
+    <Page<Address> as ToSchema>::schemas(&mut schemas);
+    let names: Vec<&str> = schemas.iter().map(|(n, _)| n.as_str()).collect();
+    assert_eq!(names, vec!["Address"]);
+}
+
+#[test]
+fn generic_instantiation_referenced_by_component_name() {
+    vld::schema! {
+        #[derive(Debug)]
+        pub struct Directory {
+            pub page: Page<Address> => vld::nested!(Page<Address>),
+        }
+    }
+
+    impl_to_schema!(Directory);
+
+    assert_eq!(
+        Directory::json_schema()["properties"]["page"]["$ref"],
+        "#/components/schemas/Page_Address"
+    );
+    let mut schemas = Vec::new();
+    <Directory as ToSchema>::schemas(&mut schemas);
+    let names: Vec<&str> = schemas.iter().map(|(n, _)| n.as_str()).collect();
+    assert_eq!(names, vec!["Page_Address"]);
+}
+
 // ---- IntoParams tests (issue #3) ----
 
 vld::schema! {
@@ -9,6 +9,7 @@
 //! | Filter / Function | Source |
 //! |-------------------|--------|
 //! | [`vld_json::<T>()`] | JSON body |
+//! | [`vld_json_with_options::<T>(options)`] | JSON body, custom limits |
 //! | [`vld_query::<T>()`] | Query string |
 //! | [`vld_form::<T>()`] | URL-encoded form body |
 //! | [`vld_param::<T>(name)`] | Single path segment |
@@ -18,6 +19,7 @@
 //! | [`vld_cookie::<T>()`] | Cookie values |
 
 use std::convert::Infallible;
+use vld::options::ParseOptions;
 use vld::schema::VldParse;
 use warp::http::StatusCode;
 use warp::reject::Reject;
@@ -49,6 +51,10 @@ impl Reject for ValidationFailed {}
 ///
 /// Returns the validated `T` or rejects with [`ValidationFailed`].
 ///
+/// Bodies are parsed with
+/// [`ParseOptions::safe_defaults()`](vld::options::ParseOptions::safe_defaults);
+/// use [`vld_json_with_options()`] to configure the limits.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -71,14 +77,36 @@ impl Reject for ValidationFailed {}
 /// ```
 pub fn vld_json<T: VldParse + Send + 'static>(
 ) -> impl Filter<Extract = (T,), Error = Rejection> + Clone {
-    warp::body::bytes().and_then(|bytes: bytes::Bytes| async move {
-        let value: serde_json::Value = serde_json::from_slice(&bytes).map_err(|e| {
-            warp::reject::custom(InvalidJson {
-                message: e.to_string(),
-            })
-        })?;
+    vld_json_with_options(ParseOptions::safe_defaults())
+}
 
-        T::vld_parse_value(&value).map_err(|e| warp::reject::custom(ValidationFailed { error: e }))
+/// Like [`vld_json()`], but parses with the given
+/// [`ParseOptions`](vld::options::ParseOptions).
+///
+/// ```rust,ignore
+/// use vld::options::ParseOptions;
+///
+/// let route = warp::post()
+///     .and(warp::path("users"))
+///     .and(vld_json_with_options::<CreateUser>(
+///         ParseOptions::safe_defaults().abort_early(true),
+///     ));
+/// ```
+pub fn vld_json_with_options<T: VldParse + Send + 'static>(
+    options: ParseOptions,
+) -> impl Filter<Extract = (T,), Error = Rejection> + Clone {
+    warp::body::bytes().and_then(move |bytes: bytes::Bytes| {
+        let options = options.clone();
+        async move {
+            let value: serde_json::Value = serde_json::from_slice(&bytes).map_err(|e| {
+                warp::reject::custom(InvalidJson {
+                    message: e.to_string(),
+                })
+            })?;
+
+            T::vld_parse_value_with_options(&value, &options)
+                .map_err(|e| warp::reject::custom(ValidationFailed { error: e }))
+        }
     })
 }
 
@@ -383,7 +411,8 @@ pub fn vld_cookie<T: VldParse + Send + 'static>(
 pub mod prelude {
     pub use crate::{
         handle_rejection, validate_path_params, vld_cookie, vld_form, vld_headers, vld_json,
-        vld_param, vld_path, vld_query, InvalidJson, ValidationFailed,
+        vld_json_with_options, vld_param, vld_path, vld_query, InvalidJson, ValidationFailed,
     };
+    pub use vld::options::ParseOptions;
     pub use vld::prelude::*;
 }
@@ -156,6 +156,46 @@ async fn json_invalid() {
     assert!(body["issues"].as_array().unwrap().len() >= 2);
 }
 
+#[tokio::test]
+async fn json_with_options() {
+    let route = warp::post()
+        .and(vld_json_with_options::<CreateUser>(
+            ParseOptions::new().abort_early(true),
+        ))
+        .map(|u: CreateUser| warp::reply::json(&serde_json::json!({"name": u.name})))
+        .recover(handle_rejection);
+    let resp = warp::test::request()
+        .method("POST")
+        .header("content-type", "application/json")
+        .body(r#"{"name":"A","email":"bad"}"#)
+        .reply(&route)
+        .await;
+    assert_eq!(resp.status(), 422);
+    let body: serde_json::Value = serde_json::from_slice(resp.body()).unwrap();
+    assert_eq!(body["issues"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn json_safe_defaults_reject_deep_input() {
+    let nested = format!("{}1{}", "[".repeat(100), "]".repeat(100));
+    let resp = warp::test::request()
+        .method("POST")
+        .path("/users")
+        .header("content-type", "application/json")
+        .body(format!(
+            r#"{{"name":"Alice","email":"alice@example.com","extra":{}}}"#,
+            nested
+        ))
+        .reply(&routes_with_recovery())
+        .await;
+    assert_eq!(resp.status(), 422);
+    let body: serde_json::Value = serde_json::from_slice(resp.body()).unwrap();
+    assert!(body["issues"][0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("nested deeper"));
+}
+
 #[tokio::test]
 async fn json_malformed() {
     let resp = warp::test::request()
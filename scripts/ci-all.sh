@@ -8,7 +8,7 @@ set -euo pipefail
 # - workspace-wide `--all-features` covers optional integrations without manual crate lists
 # - explicit feature-matrix checks remain only for core `vld`
 
-VLD_EXTENDED_FEATURES="chrono,derive,serialize,openapi,diff,decimal,net,file,string-advanced,file-advanced"
+VLD_EXTENDED_FEATURES="chrono,derive,serialize,openapi,diff,decimal,net,file,string-advanced,file-advanced,async,yaml,toml,msgpack,cbor,urlencoded"
 JIFF_FEATURES="jiff,derive,serialize,openapi,diff,decimal,net,file,string-advanced,file-advanced"
 TIME_FEATURES="time,derive,serialize,openapi,diff,decimal,net,file,string-advanced,file-advanced"
 
@@ -28,6 +28,8 @@ cargo check -p vld-redis --no-default-features --features "redis-0"
 cargo check -p vld-redis --no-default-features --features "redis-1"
 cargo check -p vld-tonic
 cargo check -p vld-warp
+cargo check -p vld-axum --features async
+cargo check -p vld-actix --features async
 cargo check -p vld-lapin --no-default-features --features "lapin-2"
 cargo check -p vld-lapin --no-default-features --features "lapin-3"
 cargo check -p vld-lapin --no-default-features --features "lapin-4"
@@ -42,6 +44,7 @@ cargo test -p vld --no-default-features --features serialize
 cargo test -p vld --no-default-features --features openapi
 cargo test -p vld --no-default-features --features diff
 cargo test -p vld --no-default-features --features "serialize,openapi,diff"
+cargo test -p vld --features async
 cargo test -p vld --features "${VLD_EXTENDED_FEATURES}"
 
 echo "==> Test vld jiff feature matrix"
@@ -0,0 +1,988 @@
+//! Asynchronous validation.
+//!
+//! [`VldSchemaAsync`] is the async counterpart of [`VldSchema`]. It lets
+//! checks that need I/O — "email is not already taken", "referenced order
+//! exists" — run in the same pass as the synchronous rules, with all issues
+//! merged into one [`VldError`].
+//!
+//! Async checks are attached with [`VldSchema::refine_async()`] and
+//! [`VldSchema::super_refine_async()`]. They compose through collections,
+//! modifiers, [`vld::object_async()`](crate::object_async),
+//! [`vld::nested!`](crate::nested!) and the [`schema!`](crate::schema!) macro.
+//! Objects built with [`vld::object()`](crate::object) only parse
+//! synchronously, so their field schemas need not be `Send`.
+//!
+//! Requires the `async` feature.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use vld::prelude::*;
+//!
+//! vld::schema! {
+//!     #[derive(Debug)]
+//!     pub struct Signup {
+//!         pub name: String => vld::string().min(2),
+//!         pub email: String => vld::string().email().refine_async(
+//!             |email: &String| {
+//!                 let email = email.clone();
+//!                 async move { !db::email_taken(&email).await }
+//!             },
+//!             "Email is already taken",
+//!         ),
+//!     }
+//! }
+//!
+//! let signup = Signup::parse_async(r#"{"name": "Alex", "email": "a@b.com"}"#).await?;
+//! ```
+//!
+//! Schemas without async-aware children (unions of primitives, tuples, maps,
+//! sets, pipes, custom schemas) fall back to their synchronous parse.
+
+use serde_json::Value;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+use crate::error::{value_type_name, IssueCode, PathSegment, VldError};
+use crate::input::VldInput;
+use crate::schema::{VldParse, VldSchema};
+
+/// A boxed, `Send` future returned by async parsing.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Signature of an async parse function, e.g.
+/// [`VldParse::vld_parse_value_async`].
+pub type ParseAsyncFn<T> = for<'a> fn(&'a Value) -> BoxFuture<'a, Result<T, VldError>>;
+
+/// Async validation schema trait.
+///
+/// Implemented for all core `vld` schemas. Schemas without async-aware
+/// children simply resolve to their synchronous result.
+///
+/// # Example
+/// ```ignore
+/// use vld::prelude::*;
+///
+/// let schema = vld::array(vld::string().refine_async(
+///     |s: &String| {
+///         let s = s.clone();
+///         async move { s != "taken" }
+///     },
+///     "Already taken",
+/// ));
+/// let err = schema.parse_async(r#"["free", "taken"]"#).await.unwrap_err();
+/// assert_eq!(err.issues.len(), 1);
+/// ```
+pub trait VldSchemaAsync: VldSchema + Send + Sync {
+    /// Parse and validate a `serde_json::Value`, running async refinements.
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>>;
+
+    /// Parse from any supported input, running async refinements.
+    fn parse_async<'a, I: VldInput + ?Sized>(
+        &'a self,
+        input: &I,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        let json = input.to_json_value();
+        Box::pin(async move {
+            let json = json?;
+            self.parse_value_async(&json).await
+        })
+    }
+
+    /// Async counterpart of
+    /// [`parse_with_options()`](VldSchema::parse_with_options).
+    ///
+    /// The structural limits are checked before any schema runs, and
+    /// `abort_early` / `max_issues` stop the same container loops as the
+    /// synchronous parse, so a large invalid payload is not fully visited.
+    fn parse_async_with_options<'a, I: VldInput + ?Sized>(
+        &'a self,
+        input: &I,
+        options: &'a crate::options::ParseOptions,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        let json = input.to_json_value();
+        Box::pin(async move {
+            let json = json?;
+            self.parse_value_async_with_options(&json, options).await
+        })
+    }
+
+    /// Async counterpart of
+    /// [`parse_value_with_options()`](VldSchema::parse_value_with_options).
+    fn parse_value_async_with_options<'a>(
+        &'a self,
+        value: &'a Value,
+        options: &'a crate::options::ParseOptions,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            options.check_input(value)?;
+            crate::context::with_context_async(options, self.parse_value_async(value))
+                .await
+                .map_err(|e| options.truncate(e))
+        })
+    }
+
+    /// Async counterpart of
+    /// [`parse_with_context()`](VldSchema::parse_with_context): the context
+    /// stays installed across `.await` points.
+    fn parse_with_context_async<'a, I, C>(
+        &'a self,
+        input: &I,
+        ctx: &C,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>>
+    where
+        I: VldInput + ?Sized,
+        C: Clone + Send + 'static,
+    {
+        Box::pin(crate::context::with_context_async(
+            ctx,
+            self.parse_async(input),
+        ))
+    }
+
+    /// Async counterpart of
+    /// [`parse_value_with_context()`](VldSchema::parse_value_with_context).
+    fn parse_value_with_context_async<'a, C>(
+        &'a self,
+        value: &'a Value,
+        ctx: &C,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>>
+    where
+        C: Clone + Send + 'static,
+    {
+        Box::pin(crate::context::with_context_async(
+            ctx,
+            self.parse_value_async(value),
+        ))
+    }
+
+    /// Async counterpart of [`VldSchema::__vld_deep_partial()`]: the partial
+    /// schema as a field of a
+    /// [`ZObjectAsync`](crate::object::ZObjectAsync), for
+    /// [`deep_partial()`](crate::object::ZObject::deep_partial).
+    #[doc(hidden)]
+    fn __vld_deep_partial_async(
+        &mut self,
+    ) -> Option<Box<dyn crate::object::DynSchema + Send + Sync>> {
+        None
+    }
+}
+
+/// Implement [`VldSchemaAsync`] by delegating to the synchronous parse.
+macro_rules! impl_async_via_sync {
+    ($($(#[$meta:meta])* $ty:ty),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            impl VldSchemaAsync for $ty {
+                fn parse_value_async<'a>(
+                    &'a self,
+                    value: &'a Value,
+                ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+                    Box::pin(async move { self.parse_value(value) })
+                }
+            }
+        )*
+    };
+}
+
+// ---------------------------------------------------------------------------
+// Primitives
+// ---------------------------------------------------------------------------
+
+impl_async_via_sync!(
+    crate::primitives::ZString,
+    crate::primitives::ZNumber,
+    crate::primitives::ZInt,
+    crate::primitives::ZBoolean,
+    crate::primitives::ZBytes,
+    #[cfg(feature = "decimal")]
+    crate::primitives::ZDecimal,
+    #[cfg(feature = "net")]
+    crate::primitives::ZIpNetwork,
+    crate::primitives::ZSocketAddr,
+    crate::primitives::ZJsonValue,
+    #[cfg(feature = "file")]
+    crate::primitives::ZFile,
+    #[cfg(feature = "std")]
+    crate::primitives::ZDuration,
+    #[cfg(feature = "std")]
+    crate::primitives::ZPath,
+    crate::primitives::ZEnum,
+    crate::primitives::ZAny,
+    #[cfg(any(feature = "chrono", feature = "jiff", feature = "time"))]
+    crate::primitives::ZDate,
+    #[cfg(any(feature = "chrono", feature = "jiff", feature = "time"))]
+    crate::primitives::ZDateTime,
+);
+
+impl<T: crate::primitives::IntoLiteral + Send + Sync> VldSchemaAsync
+    for crate::primitives::ZLiteral<T>
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move { self.parse_value(value) })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Collections
+// ---------------------------------------------------------------------------
+
+impl<T> VldSchemaAsync for crate::collections::ZArray<T>
+where
+    T: VldSchemaAsync,
+    T::Output: Send,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            let arr = value.as_array().ok_or_else(|| {
+                VldError::single(
+                    IssueCode::InvalidType {
+                        expected: "array".to_string(),
+                        received: value_type_name(value),
+                    },
+                    format!("Expected array, received {}", value_type_name(value)),
+                )
+            })?;
+
+            let mut errors = self.check_array(arr);
+            let limit = crate::options::issue_limit();
+            let mut results = Vec::with_capacity(arr.len());
+
+            for (i, item) in arr.iter().enumerate() {
+                if crate::options::limit_reached(&errors, limit) {
+                    break;
+                }
+                let parsed = match self.prefix_schema(i) {
+                    Some(schema) => match schema.dyn_parse_async(item).await {
+                        Ok(v) => self.prefix_output(v),
+                        Err(e) => Err(e),
+                    },
+                    None => self.element_schema().parse_value_async(item).await,
+                };
+                match parsed {
+                    Ok(v) => results.push(v),
+                    Err(e) => {
+                        errors = errors.merge(e.with_prefix(PathSegment::Index(i)));
+                    }
+                }
+            }
+
+            if errors.is_empty() {
+                Ok(results)
+            } else {
+                Err(errors)
+            }
+        })
+    }
+
+    fn __vld_deep_partial_async(
+        &mut self,
+    ) -> Option<Box<dyn crate::object::DynSchema + Send + Sync>> {
+        self.deep_partial_with(T::__vld_deep_partial_async)
+            .map(crate::object::erase_async)
+    }
+}
+
+impl<V> VldSchemaAsync for crate::collections::ZRecord<V>
+where
+    V: VldSchemaAsync,
+    V::Output: Send,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            let obj = value.as_object().ok_or_else(|| {
+                VldError::single(
+                    IssueCode::InvalidType {
+                        expected: "object".to_string(),
+                        received: value_type_name(value),
+                    },
+                    format!("Expected object, received {}", value_type_name(value)),
+                )
+            })?;
+
+            let mut errors = self.check_keys(obj).merge(self.check_key_names(obj));
+            let limit = crate::options::issue_limit();
+            let mut result = std::collections::HashMap::new();
+
+            for (key, val) in obj {
+                if crate::options::limit_reached(&errors, limit) {
+                    break;
+                }
+                match self.value_schema_ref().parse_value_async(val).await {
+                    Ok(v) => {
+                        result.insert(key.clone(), v);
+                    }
+                    Err(e) => {
+                        errors = errors.merge(e.with_prefix(PathSegment::Field(key.clone())));
+                    }
+                }
+            }
+
+            if errors.is_empty() {
+                Ok(result)
+            } else {
+                Err(errors)
+            }
+        })
+    }
+
+    fn __vld_deep_partial_async(
+        &mut self,
+    ) -> Option<Box<dyn crate::object::DynSchema + Send + Sync>> {
+        self.deep_partial_with(V::__vld_deep_partial_async)
+            .map(crate::object::erase_async)
+    }
+}
+
+impl<T> VldSchemaAsync for crate::collections::ZSet<T>
+where
+    T: VldSchema + Send + Sync,
+    T::Output: Eq + std::hash::Hash,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move { self.parse_value(value) })
+    }
+}
+
+impl<K, V> VldSchemaAsync for crate::collections::ZMap<K, V>
+where
+    K: VldSchema + Send + Sync,
+    V: VldSchema + Send + Sync,
+    K::Output: Eq + std::hash::Hash,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move { self.parse_value(value) })
+    }
+}
+
+macro_rules! impl_tuple_async {
+    ($($T:ident),+) => {
+        impl<$($T: VldSchema + Send + Sync),+> VldSchemaAsync for ($($T,)+) {
+            fn parse_value_async<'a>(
+                &'a self,
+                value: &'a Value,
+            ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+                Box::pin(async move { self.parse_value(value) })
+            }
+        }
+    };
+}
+
+impl_tuple_async!(A);
+impl_tuple_async!(A, B);
+impl_tuple_async!(A, B, C);
+impl_tuple_async!(A, B, C, D);
+impl_tuple_async!(A, B, C, D, E);
+impl_tuple_async!(A, B, C, D, E, F);
+
+// ---------------------------------------------------------------------------
+// Modifiers
+// ---------------------------------------------------------------------------
+
+impl<T: VldSchemaAsync> VldSchemaAsync for crate::modifiers::ZOptional<T> {
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            if value.is_null() {
+                return Ok(None);
+            }
+            self.inner_schema().parse_value_async(value).await.map(Some)
+        })
+    }
+
+    fn __vld_deep_partial_async(
+        &mut self,
+    ) -> Option<Box<dyn crate::object::DynSchema + Send + Sync>> {
+        self.deep_partial_with(T::__vld_deep_partial_async)
+            .map(crate::object::erase_async)
+    }
+}
+
+impl<T: VldSchemaAsync> VldSchemaAsync for crate::modifiers::ZNullable<T> {
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            if value.is_null() {
+                return Ok(None);
+            }
+            self.inner_schema().parse_value_async(value).await.map(Some)
+        })
+    }
+
+    fn __vld_deep_partial_async(
+        &mut self,
+    ) -> Option<Box<dyn crate::object::DynSchema + Send + Sync>> {
+        self.deep_partial_with(T::__vld_deep_partial_async)
+            .map(crate::object::erase_async)
+    }
+}
+
+impl<T: VldSchemaAsync> VldSchemaAsync for crate::modifiers::ZNullish<T> {
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            if value.is_null() {
+                return Ok(None);
+            }
+            self.inner_schema().parse_value_async(value).await.map(Some)
+        })
+    }
+
+    fn __vld_deep_partial_async(
+        &mut self,
+    ) -> Option<Box<dyn crate::object::DynSchema + Send + Sync>> {
+        self.deep_partial_with(T::__vld_deep_partial_async)
+            .map(crate::object::erase_async)
+    }
+}
+
+impl<T: VldSchemaAsync> VldSchemaAsync for crate::modifiers::ZPatch<T> {
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            if crate::object::is_absent(value) {
+                return Ok(crate::modifiers::Patch::Absent);
+            }
+            if value.is_null() {
+                return Ok(crate::modifiers::Patch::Null);
+            }
+            self.inner_schema()
+                .parse_value_async(value)
+                .await
+                .map(crate::modifiers::Patch::Value)
+        })
+    }
+}
+
+impl<T> VldSchemaAsync for crate::modifiers::ZDefault<T>
+where
+    T: VldSchemaAsync,
+    T::Output: Clone + Send + Sync,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            if value.is_null() {
+                return Ok(self.default_value.clone());
+            }
+            self.inner_schema().parse_value_async(value).await
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Combinators
+// ---------------------------------------------------------------------------
+
+impl<T, F, Fut> VldSchemaAsync for crate::combinators::ZRefineAsync<T, F>
+where
+    T: VldSchemaAsync,
+    T::Output: Send,
+    F: Fn(&T::Output) -> Fut + Send + Sync,
+    Fut: Future<Output = bool> + Send,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            let result = self.inner.parse_value_async(value).await?;
+            if (self.check)(&result).await {
+                Ok(result)
+            } else {
+                Err(VldError::single(
+                    IssueCode::Custom {
+                        code: "custom".to_string(),
+                    },
+                    self.message.clone(),
+                ))
+            }
+        })
+    }
+}
+
+impl<T, F, Fut> VldSchemaAsync for crate::combinators::ZSuperRefineAsync<T, F>
+where
+    T: VldSchemaAsync,
+    T::Output: Send,
+    F: Fn(&T::Output) -> Fut + Send + Sync,
+    Fut: Future<Output = VldError> + Send,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            let result = self.inner.parse_value_async(value).await?;
+            let errors = (self.check)(&result).await;
+            if errors.is_empty() {
+                Ok(result)
+            } else {
+                Err(errors)
+            }
+        })
+    }
+}
+
+impl<T, F> VldSchemaAsync for crate::combinators::ZRefine<T, F>
+where
+    T: VldSchemaAsync,
+    T::Output: Send,
+    F: Fn(&T::Output) -> bool + Send + Sync,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            let result = self.inner.parse_value_async(value).await?;
+            if (self.check)(&result) {
+                Ok(result)
+            } else {
+                Err(VldError::single(
+                    IssueCode::Custom {
+                        code: "custom".to_string(),
+                    },
+                    self.message.clone(),
+                ))
+            }
+        })
+    }
+}
+
+impl<T, F> VldSchemaAsync for crate::combinators::ZSuperRefine<T, F>
+where
+    T: VldSchemaAsync,
+    T::Output: Send,
+    F: Fn(&T::Output, &mut VldError) + Send + Sync,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            let result = self.inner.parse_value_async(value).await?;
+            let mut errors = VldError::new();
+            (self.check)(&result, &mut errors);
+            if errors.is_empty() {
+                Ok(result)
+            } else {
+                Err(errors)
+            }
+        })
+    }
+}
+
+impl<T, F, U> VldSchemaAsync for crate::combinators::ZTransform<T, F, U>
+where
+    T: VldSchemaAsync,
+    F: Fn(T::Output) -> U + Send + Sync,
+    U: Send + Sync,
+{
+    fn parse_value_async<'a>(&'a self, value: &'a Value) -> BoxFuture<'a, Result<U, VldError>> {
+        Box::pin(async move {
+            let result = self.inner_schema().parse_value_async(value).await?;
+            Ok((self.transform_fn)(result))
+        })
+    }
+}
+
+impl<T, C, F> VldSchemaAsync for crate::combinators::ZRefineCtx<T, C, F>
+where
+    T: VldSchemaAsync,
+    T::Output: Send,
+    C: 'static,
+    F: Fn(&T::Output, &C) -> bool + Send + Sync,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            let result = self.inner.parse_value_async(value).await?;
+            self.check_output(result)
+        })
+    }
+}
+
+impl<T, C, F> VldSchemaAsync for crate::combinators::ZSuperRefineCtx<T, C, F>
+where
+    T: VldSchemaAsync,
+    T::Output: Send,
+    C: 'static,
+    F: Fn(&T::Output, &C, &mut VldError) + Send + Sync,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            let result = self.inner.parse_value_async(value).await?;
+            self.check_output(result)
+        })
+    }
+}
+
+impl<T, C, F, U> VldSchemaAsync for crate::combinators::ZTransformCtx<T, C, F, U>
+where
+    T: VldSchemaAsync,
+    C: 'static,
+    F: Fn(T::Output, &C) -> U + Send + Sync,
+    U: Send + Sync,
+{
+    fn parse_value_async<'a>(&'a self, value: &'a Value) -> BoxFuture<'a, Result<U, VldError>> {
+        Box::pin(async move {
+            let result = self.inner_schema().parse_value_async(value).await?;
+            self.apply(result)
+        })
+    }
+}
+
+impl<T: VldSchemaAsync> VldSchemaAsync for crate::combinators::ZDescribe<T> {
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        self.inner_schema().parse_value_async(value)
+    }
+}
+
+impl<T: VldSchemaAsync> VldSchemaAsync for crate::combinators::ZMessage<T> {
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            self.inner
+                .parse_value_async(value)
+                .await
+                .map_err(|mut err| {
+                    for issue in &mut err.issues {
+                        issue.message = self.msg.clone();
+                    }
+                    err
+                })
+        })
+    }
+}
+
+impl<T> VldSchemaAsync for crate::combinators::ZCatch<T>
+where
+    T: VldSchemaAsync,
+    T::Output: Clone + Send + Sync,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            match self.inner.parse_value_async(value).await {
+                Ok(v) => Ok(v),
+                Err(_) => Ok(self.fallback.clone()),
+            }
+        })
+    }
+}
+
+impl<F, S> VldSchemaAsync for crate::combinators::ZPreprocess<F, S>
+where
+    F: Fn(&Value) -> Value + Send + Sync,
+    S: VldSchemaAsync,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            let preprocessed = (self.preprocessor)(value);
+            self.schema.parse_value_async(&preprocessed).await
+        })
+    }
+}
+
+impl<T, F> VldSchemaAsync for crate::combinators::ZLazy<T, F>
+where
+    F: Fn() -> T + Send + Sync,
+    T: VldSchemaAsync,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            let schema = (self.factory)();
+            schema.parse_value_async(value).await
+        })
+    }
+}
+
+impl<A, B> VldSchemaAsync for crate::combinators::ZUnion2<A, B>
+where
+    A: VldSchemaAsync,
+    B: VldSchemaAsync,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        use crate::combinators::Either;
+        Box::pin(async move {
+            if let Ok(v) = self.schema_a().parse_value_async(value).await {
+                return Ok(Either::Left(v));
+            }
+            if let Ok(v) = self.schema_b().parse_value_async(value).await {
+                return Ok(Either::Right(v));
+            }
+            Err(VldError::single(
+                IssueCode::Custom {
+                    code: "invalid_union".to_string(),
+                },
+                "Input did not match any variant of the union",
+            ))
+        })
+    }
+}
+
+impl<A, B, C> VldSchemaAsync for crate::combinators::ZUnion3<A, B, C>
+where
+    A: VldSchemaAsync,
+    B: VldSchemaAsync,
+    C: VldSchemaAsync,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        use crate::combinators::Either3;
+        Box::pin(async move {
+            if let Ok(v) = self.schema_a().parse_value_async(value).await {
+                return Ok(Either3::First(v));
+            }
+            if let Ok(v) = self.schema_b().parse_value_async(value).await {
+                return Ok(Either3::Second(v));
+            }
+            if let Ok(v) = self.schema_c().parse_value_async(value).await {
+                return Ok(Either3::Third(v));
+            }
+            Err(VldError::single(
+                IssueCode::Custom {
+                    code: "invalid_union".to_string(),
+                },
+                "Input did not match any variant of the union",
+            ))
+        })
+    }
+}
+
+impl<A, B> VldSchemaAsync for crate::combinators::ZIntersection<A, B>
+where
+    A: VldSchemaAsync,
+    A::Output: Send,
+    B: VldSchemaAsync,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move {
+            let mut errors = VldError::new();
+
+            let first_result = match self.schema_a().parse_value_async(value).await {
+                Ok(v) => Some(v),
+                Err(e) => {
+                    errors = errors.merge(e);
+                    None
+                }
+            };
+
+            if let Err(e) = self.schema_b().parse_value_async(value).await {
+                errors = errors.merge(e);
+            }
+
+            match first_result {
+                Some(v) if errors.is_empty() => Ok(v),
+                _ => Err(errors),
+            }
+        })
+    }
+}
+
+impl<A, B> VldSchemaAsync for crate::combinators::ZPipe<A, B>
+where
+    A: VldSchema + Send + Sync,
+    A::Output: serde::Serialize,
+    B: VldSchema + Send + Sync,
+{
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(async move { self.parse_value(value) })
+    }
+}
+
+impl<F, T> VldSchemaAsync for crate::combinators::ZCustom<F, T>
+where
+    F: Fn(&Value) -> Result<T, String> + Send + Sync,
+    T: Send + Sync,
+{
+    fn parse_value_async<'a>(&'a self, value: &'a Value) -> BoxFuture<'a, Result<T, VldError>> {
+        Box::pin(async move { self.parse_value(value) })
+    }
+}
+
+impl VldSchemaAsync for crate::combinators::ZDiscriminatedUnionAsync {
+    fn parse_value_async<'a>(&'a self, value: &'a Value) -> BoxFuture<'a, Result<Value, VldError>> {
+        Box::pin(self.parse_value_async_impl(value))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Object and nested structs
+// ---------------------------------------------------------------------------
+
+impl VldSchemaAsync for crate::object::ZObjectAsync {
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> BoxFuture<'a, Result<Self::Output, VldError>> {
+        Box::pin(self.parse_value_async_impl(value))
+    }
+
+    fn __vld_deep_partial_async(
+        &mut self,
+    ) -> Option<Box<dyn crate::object::DynSchema + Send + Sync>> {
+        Some(crate::object::erase_async(
+            std::mem::take(self).deep_partial(),
+        ))
+    }
+}
+
+impl<T, F> VldSchemaAsync for crate::schema::NestedSchema<T, F>
+where
+    T: Send + Sync,
+    F: crate::schema::NestedParseFn<T> + Send + Sync,
+{
+    fn parse_value_async<'a>(&'a self, value: &'a Value) -> BoxFuture<'a, Result<T, VldError>> {
+        match self.parse_async_fn {
+            Some(f) => f(value),
+            None => Box::pin(async move { self.parse_value(value) }),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Macro support
+// ---------------------------------------------------------------------------
+
+/// Field wrapper used by `schema!` / `#[derive(Validate)]` to pick the async
+/// parse of a field schema when it implements [`VldSchemaAsync`], and the
+/// synchronous parse otherwise.
+///
+/// The wrapper owns the schema: the async parse moves it into the returned
+/// future, and the synchronous parse runs before the future is returned, so
+/// a schema that is not `Send` does not have to live across an `.await`.
+#[doc(hidden)]
+pub struct __VldAsyncField<S>(std::cell::Cell<Option<S>>);
+
+impl<S> __VldAsyncField<S> {
+    pub fn new(schema: S) -> Self {
+        Self(std::cell::Cell::new(Some(schema)))
+    }
+
+    fn take(&self) -> S {
+        self.0.take().expect("field schema is parsed once")
+    }
+}
+
+#[doc(hidden)]
+pub trait __VldViaAsync<S: VldSchema> {
+    fn __vld_parse_async<'a>(&self, value: &'a Value) -> BoxFuture<'a, Result<S::Output, VldError>>
+    where
+        S: 'a;
+}
+
+impl<S: VldSchemaAsync> __VldViaAsync<S> for &__VldAsyncField<S> {
+    fn __vld_parse_async<'a>(&self, value: &'a Value) -> BoxFuture<'a, Result<S::Output, VldError>>
+    where
+        S: 'a,
+    {
+        let schema = self.take();
+        Box::pin(async move { schema.parse_value_async(value).await })
+    }
+}
+
+#[doc(hidden)]
+pub trait __VldViaSync<S: VldSchema> {
+    fn __vld_parse_async<'a>(&self, value: &'a Value) -> BoxFuture<'a, Result<S::Output, VldError>>
+    where
+        S: 'a;
+}
+
+impl<S: VldSchema> __VldViaSync<S> for __VldAsyncField<S>
+where
+    S::Output: Send,
+{
+    fn __vld_parse_async<'a>(&self, value: &'a Value) -> BoxFuture<'a, Result<S::Output, VldError>>
+    where
+        S: 'a,
+    {
+        Box::pin(std::future::ready(self.take().parse_value(value)))
+    }
+}
+
+/// Type probe used by [`vld::nested!`](crate::nested!) to pick up
+/// [`VldParse::vld_parse_value_async`] when the nested type implements [`VldParse`].
+#[doc(hidden)]
+pub struct __VldNestedAsync<T>(pub PhantomData<T>);
+
+#[doc(hidden)]
+pub trait __VldNestedViaParse<T> {
+    fn __vld_async_fn(&self) -> Option<ParseAsyncFn<T>>;
+}
+
+impl<T: VldParse> __VldNestedViaParse<T> for &__VldNestedAsync<T> {
+    fn __vld_async_fn(&self) -> Option<ParseAsyncFn<T>> {
+        Some(T::vld_parse_value_async)
+    }
+}
+
+#[doc(hidden)]
+pub trait __VldNestedFallback<T> {
+    fn __vld_async_fn(&self) -> Option<ParseAsyncFn<T>>;
+}
+
+impl<T> __VldNestedFallback<T> for __VldNestedAsync<T> {
+    fn __vld_async_fn(&self) -> Option<ParseAsyncFn<T>> {
+        None
+    }
+}
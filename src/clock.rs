@@ -0,0 +1,192 @@
+//! Time sources for date/datetime checks.
+//!
+//! [`ZDate`](crate::primitives::ZDate) and [`ZDateTime`](crate::primitives::ZDateTime)
+//! consult a [`Clock`] for `past()`, `future()`, `within_next()` and
+//! `not_older_than()`. The clock is resolved in this order:
+//!
+//! 1. a clock set on the schema via `.clock(...)`,
+//! 2. a [`SharedClock`] passed as the validation context
+//!    ([`parse_with_context()`](crate::schema::VldSchema::parse_with_context)),
+//! 3. the [`SystemClock`].
+//!
+//! # Example
+//! ```
+//! use std::time::Duration;
+//! use vld::clock::{FixedClock, SharedClock};
+//! use vld::prelude::*;
+//!
+//! let clock = FixedClock::from_unix_secs(1_718_409_600); // 2024-06-15T00:00:00Z
+//!
+//! // Per schema
+//! let expires = vld::datetime().future().clock(clock.clone());
+//! assert!(expires.parse(r#""2024-06-16T00:00:00Z""#).is_ok());
+//!
+//! // Per parse
+//! let expires = vld::datetime().within_next(Duration::from_secs(3600));
+//! let ctx = SharedClock::new(clock);
+//! assert!(expires.parse_with_context(r#""2024-06-15T00:30:00Z""#, &ctx).is_ok());
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A source of the current time.
+pub trait Clock: Send + Sync {
+    /// The current instant.
+    fn now(&self) -> SystemTime;
+}
+
+/// The real system clock. Used when no other clock is configured.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock frozen at a fixed instant. Useful for deterministic tests.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(SystemTime);
+
+impl FixedClock {
+    pub fn new(at: SystemTime) -> Self {
+        Self(at)
+    }
+
+    /// A clock frozen at the given number of seconds since the Unix epoch.
+    pub fn from_unix_secs(secs: i64) -> Self {
+        let at = if secs >= 0 {
+            UNIX_EPOCH + Duration::from_secs(secs as u64)
+        } else {
+            UNIX_EPOCH - Duration::from_secs(secs.unsigned_abs())
+        };
+        Self(at)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        self.0
+    }
+}
+
+/// A clock shifted from another clock by a fixed offset.
+///
+/// Offsets that leave the platform's `SystemTime` range saturate instead of
+/// panicking: a clock shifted too far back reads as the Unix epoch (or the
+/// base time, if that is earlier), and one shifted too far ahead reads as the
+/// base time.
+#[derive(Clone)]
+pub struct OffsetClock {
+    base: SharedClock,
+    offset: Duration,
+    ahead: bool,
+}
+
+impl OffsetClock {
+    /// The system clock shifted forward by `offset`.
+    pub fn ahead(offset: Duration) -> Self {
+        Self::ahead_of(SystemClock, offset)
+    }
+
+    /// The system clock shifted backward by `offset`.
+    pub fn behind(offset: Duration) -> Self {
+        Self::behind_of(SystemClock, offset)
+    }
+
+    /// `base` shifted forward by `offset`.
+    pub fn ahead_of(base: impl Clock + 'static, offset: Duration) -> Self {
+        Self {
+            base: SharedClock::new(base),
+            offset,
+            ahead: true,
+        }
+    }
+
+    /// `base` shifted backward by `offset`.
+    pub fn behind_of(base: impl Clock + 'static, offset: Duration) -> Self {
+        Self {
+            base: SharedClock::new(base),
+            offset,
+            ahead: false,
+        }
+    }
+}
+
+impl Clock for OffsetClock {
+    fn now(&self) -> SystemTime {
+        let base = self.base.now();
+        if self.ahead {
+            base.checked_add(self.offset).unwrap_or(base)
+        } else {
+            base.checked_sub(self.offset)
+                .unwrap_or_else(|| base.min(UNIX_EPOCH))
+        }
+    }
+}
+
+impl fmt::Debug for OffsetClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OffsetClock")
+            .field("offset", &self.offset)
+            .field("ahead", &self.ahead)
+            .finish()
+    }
+}
+
+/// A cheaply clonable, type-erased [`Clock`].
+///
+/// Stored by date schemas and used as the validation-context type for
+/// per-parse clocks.
+#[derive(Clone)]
+pub struct SharedClock(Arc<dyn Clock>);
+
+impl SharedClock {
+    pub fn new(clock: impl Clock + 'static) -> Self {
+        Self(Arc::new(clock))
+    }
+}
+
+impl Clock for SharedClock {
+    fn now(&self) -> SystemTime {
+        self.0.now()
+    }
+}
+
+impl fmt::Debug for SharedClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SharedClock").field(&self.0.now()).finish()
+    }
+}
+
+/// Resolve the current time for a schema: schema clock, then context clock, then system.
+pub(crate) fn now(schema_clock: Option<&SharedClock>) -> SystemTime {
+    if let Some(clock) = schema_clock {
+        return clock.now();
+    }
+    crate::context::with_current::<SharedClock, _>(|ctx| {
+        ctx.map(|clock| clock.now()).unwrap_or_else(SystemTime::now)
+    })
+}
+
+/// Human-readable duration for error messages, using the largest exact unit.
+pub(crate) fn describe_duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    let (n, unit) = if secs != 0 && secs % 86_400 == 0 {
+        (secs / 86_400, "day")
+    } else if secs != 0 && secs % 3_600 == 0 {
+        (secs / 3_600, "hour")
+    } else if secs != 0 && secs % 60 == 0 {
+        (secs / 60, "minute")
+    } else {
+        (secs, "second")
+    };
+    if n == 1 {
+        format!("1 {}", unit)
+    } else {
+        format!("{} {}s", n, unit)
+    }
+}
@@ -1,7 +1,14 @@
+use serde::de::{IgnoredAny, SeqAccess};
+use serde::Deserialize;
 use serde_json::Value;
 
-use crate::error::{value_type_name, IssueCode, PathSegment, VldError};
-use crate::schema::VldSchema;
+use crate::de::{DeResult, DirectVisitor, SchemaSeed};
+use crate::error::{value_type_name, FieldResult, IssueCode, PathSegment, VldError};
+use crate::object::{DynSchema, MaybeJsonSchema};
+use crate::schema::{Lenient, VldSchema};
+
+/// Converts the output of a `prefix_items()` schema into the element output.
+type PrefixOutput<O> = fn(Value) -> Result<O, serde_json::Error>;
 
 /// Schema for array validation. Created via [`vld::array()`](crate::array).
 ///
@@ -15,10 +22,13 @@ use crate::schema::VldSchema;
 /// ```
 pub struct ZArray<T: VldSchema> {
     element: T,
+    prefix_items: Vec<Box<dyn DynSchema + Send + Sync>>,
+    prefix_output: Option<PrefixOutput<T::Output>>,
     min_len: Option<usize>,
     max_len: Option<usize>,
     exact_len: Option<usize>,
     contains: Option<serde_json::Value>,
+    contains_schema: Option<Box<dyn DynSchema + Send + Sync>>,
     min_contains: Option<usize>,
     max_contains: Option<usize>,
     unique: bool,
@@ -28,10 +38,13 @@ impl<T: VldSchema> ZArray<T> {
     pub fn new(element: T) -> Self {
         Self {
             element,
+            prefix_items: Vec::new(),
+            prefix_output: None,
             min_len: None,
             max_len: None,
             exact_len: None,
             contains: None,
+            contains_schema: None,
             min_contains: None,
             max_contains: None,
             unique: false,
@@ -61,19 +74,79 @@ impl<T: VldSchema> ZArray<T> {
         self.min_len(1)
     }
 
+    /// Validate the leading elements with their own schemas, one per
+    /// position; the element schema then applies to the rest. Maps to
+    /// `prefixItems` in JSON Schema.
+    ///
+    /// Takes a tuple of up to 6 schemas. Their outputs are converted into the
+    /// element output type, so use a rest schema that can hold them, such as
+    /// [`vld::json_value()`](crate::json_value). Shorter arrays are accepted;
+    /// combine with `min_len()` to require the whole prefix.
+    ///
+    /// # Example
+    /// ```
+    /// use vld::prelude::*;
+    ///
+    /// // [command, priority, ...args]
+    /// let schema = vld::array(vld::json_value())
+    ///     .prefix_items((vld::string().min(1), vld::number().int().min(0)));
+    /// let parsed = schema.parse(r#"["deploy", 1, "--force"]"#).unwrap();
+    /// assert_eq!(parsed[1], 1);
+    ///
+    /// let err = schema.parse(r#"["deploy", "high"]"#).unwrap_err();
+    /// assert_eq!(err.issues[0].path[0].to_string(), "[1]");
+    /// ```
+    pub fn prefix_items<P: PrefixItems>(mut self, items: P) -> Self
+    where
+        T::Output: serde::de::DeserializeOwned,
+    {
+        self.prefix_items = items.into_schemas();
+        self.prefix_output = Some(serde_json::from_value);
+        self
+    }
+
     /// Require the array to contain this JSON value.
+    ///
+    /// Replaces a previous `contains_schema(...)`.
     pub fn contains(mut self, value: impl Into<serde_json::Value>) -> Self {
         self.contains = Some(value.into());
+        self.contains_schema = None;
+        self
+    }
+
+    /// Require the array to contain an element that passes `schema`.
+    /// `min_contains()` / `max_contains()` then count the matching elements.
+    ///
+    /// Replaces a previous `contains(...)`.
+    ///
+    /// # Example
+    /// ```
+    /// use vld::prelude::*;
+    ///
+    /// let scores = vld::array(vld::number()).contains_schema(vld::number().min(90.0)).max_contains(2);
+    /// assert!(scores.parse("[40, 95]").is_ok());
+    /// assert!(scores.parse("[40, 50]").is_err());
+    /// assert!(scores.parse("[91, 95, 99]").is_err());
+    /// ```
+    pub fn contains_schema<S>(mut self, schema: S) -> Self
+    where
+        S: VldSchema + MaybeJsonSchema + Send + Sync + 'static,
+        S::Output: serde::Serialize,
+    {
+        self.contains_schema = Some(Box::new(crate::object::json_field(schema)));
+        self.contains = None;
         self
     }
 
-    /// Require at least this many matches of the `contains(...)` value.
+    /// Require at least this many matches of the `contains(...)` value or
+    /// `contains_schema(...)`.
     pub fn min_contains(mut self, n: usize) -> Self {
         self.min_contains = Some(n);
         self
     }
 
-    /// Require at most this many matches of the `contains(...)` value.
+    /// Require at most this many matches of the `contains(...)` value or
+    /// `contains_schema(...)`.
     pub fn max_contains(mut self, n: usize) -> Self {
         self.max_contains = Some(n);
         self
@@ -90,63 +163,69 @@ impl<T: VldSchema> ZArray<T> {
         &self.element
     }
 
-    /// Generate a JSON Schema (called by [`JsonSchema`](crate::json_schema::JsonSchema) trait impl).
-    ///
-    /// Requires the `openapi` feature.
-    #[cfg(feature = "openapi")]
-    pub fn to_json_schema_inner(&self) -> serde_json::Value
+    /// This array with the element schema that `partial` rebuilds from the
+    /// current one, or `None` when it has no partial form. Used by
+    /// [`ZObject::deep_partial()`](crate::object::ZObject::deep_partial).
+    pub(crate) fn deep_partial_with<D>(
+        &mut self,
+        partial: impl FnOnce(&mut T) -> Option<Box<D>>,
+    ) -> Option<ZArray<crate::object::DynValue<D>>>
     where
-        T: crate::json_schema::JsonSchema,
+        D: DynSchema + ?Sized,
     {
-        let mut schema = serde_json::json!({
-            "type": "array",
-            "items": self.element.json_schema(),
-        });
-        if let Some(min) = self.min_len {
-            schema["minItems"] = serde_json::json!(min);
-        }
-        if let Some(max) = self.max_len {
-            schema["maxItems"] = serde_json::json!(max);
-        }
-        if let Some(exact) = self.exact_len {
-            schema["minItems"] = serde_json::json!(exact);
-            schema["maxItems"] = serde_json::json!(exact);
-        }
-        if let Some(ref contains) = self.contains {
-            schema["contains"] = contains.clone();
-        }
-        if let Some(min_contains) = self.min_contains {
-            schema["minContains"] = serde_json::json!(min_contains);
-        }
-        if let Some(max_contains) = self.max_contains {
-            schema["maxContains"] = serde_json::json!(max_contains);
-        }
-        if self.unique {
-            schema["uniqueItems"] = serde_json::json!(true);
-        }
-        schema
+        let element = partial(&mut self.element)?;
+        Some(ZArray {
+            element: crate::object::DynValue(element),
+            prefix_items: std::mem::take(&mut self.prefix_items),
+            prefix_output: self
+                .prefix_output
+                .map(|_| serde_json::from_value as PrefixOutput<Value>),
+            min_len: self.min_len,
+            max_len: self.max_len,
+            exact_len: self.exact_len,
+            contains: self.contains.take(),
+            contains_schema: self.contains_schema.take(),
+            min_contains: self.min_contains,
+            max_contains: self.max_contains,
+            unique: self.unique,
+        })
     }
-}
 
-impl<T: VldSchema> VldSchema for ZArray<T> {
-    type Output = Vec<T::Output>;
+    /// The `prefix_items(...)` schema for the element at `index`, if any.
+    #[allow(dead_code)]
+    pub(crate) fn prefix_schema(&self, index: usize) -> Option<&(dyn DynSchema + Send + Sync)> {
+        self.prefix_items.get(index).map(|s| s.as_ref())
+    }
 
-    fn parse_value(&self, value: &Value) -> Result<Vec<T::Output>, VldError> {
-        let arr = value.as_array().ok_or_else(|| {
+    /// Convert the output of a prefix schema into the element output type.
+    pub(crate) fn prefix_output(&self, value: Value) -> Result<T::Output, VldError> {
+        let convert = self
+            .prefix_output
+            .expect("prefix_items() sets the output conversion");
+        convert(value).map_err(|e| {
             VldError::single(
-                IssueCode::InvalidType {
-                    expected: "array".to_string(),
-                    received: value_type_name(value),
+                IssueCode::Custom {
+                    code: "invalid_prefix_item".to_string(),
                 },
-                format!("Expected array, received {}", value_type_name(value)),
+                format!("Prefix item does not fit the element type: {}", e),
             )
-        })?;
+        })
+    }
+
+    /// Parse one element, with its prefix schema or the element schema.
+    fn parse_element(&self, index: usize, item: &Value) -> Result<T::Output, VldError> {
+        match self.prefix_schema(index) {
+            Some(schema) => schema.dyn_parse(item).and_then(|v| self.prefix_output(v)),
+            None => self.element.parse_value(item),
+        }
+    }
 
+    /// Length checks (`min_len`, `max_len`, `len`).
+    fn check_len(&self, len: usize) -> VldError {
         let mut errors = VldError::new();
 
-        // Length checks
         if let Some(min) = self.min_len {
-            if arr.len() < min {
+            if len < min {
                 errors.push(
                     IssueCode::TooSmall {
                         minimum: min as f64,
@@ -158,7 +237,7 @@ impl<T: VldSchema> VldSchema for ZArray<T> {
         }
 
         if let Some(max) = self.max_len {
-            if arr.len() > max {
+            if len > max {
                 errors.push(
                     IssueCode::TooBig {
                         maximum: max as f64,
@@ -170,7 +249,7 @@ impl<T: VldSchema> VldSchema for ZArray<T> {
         }
 
         if let Some(exact) = self.exact_len {
-            if arr.len() != exact {
+            if len != exact {
                 errors.push(
                     IssueCode::Custom {
                         code: "invalid_length".to_string(),
@@ -180,6 +259,14 @@ impl<T: VldSchema> VldSchema for ZArray<T> {
             }
         }
 
+        errors
+    }
+
+    /// Run the array-level checks (length, uniqueness, `contains`) that do not
+    /// depend on the element outputs.
+    pub(crate) fn check_array(&self, arr: &[Value]) -> VldError {
+        let mut errors = self.check_len(arr.len());
+
         if self.unique {
             for i in 0..arr.len() {
                 for j in (i + 1)..arr.len() {
@@ -196,51 +283,130 @@ impl<T: VldSchema> VldSchema for ZArray<T> {
             }
         }
 
-        if let Some(ref contains) = self.contains {
+        let (count, subject) = if let Some(schema) = &self.contains_schema {
+            let count = arr.iter().filter(|v| schema.dyn_parse(v).is_ok()).count();
+            (count, "a matching element")
+        } else if let Some(contains) = &self.contains {
             let count = arr.iter().filter(|v| *v == contains).count();
-            if count == 0 {
+            (count, "required value")
+        } else {
+            return errors;
+        };
+
+        if count == 0 {
+            errors.push(
+                IssueCode::Custom {
+                    code: "missing_contains".to_string(),
+                },
+                format!("Array must contain {}", subject),
+            );
+        }
+        if let Some(min_contains) = self.min_contains {
+            if count < min_contains {
                 errors.push(
-                    IssueCode::Custom {
-                        code: "missing_contains".to_string(),
+                    IssueCode::TooSmall {
+                        minimum: min_contains as f64,
+                        inclusive: true,
                     },
-                    "Array must contain required value",
+                    format!(
+                        "Array must contain {} at least {} time(s)",
+                        subject, min_contains
+                    ),
                 );
             }
-            if let Some(min_contains) = self.min_contains {
-                if count < min_contains {
-                    errors.push(
-                        IssueCode::TooSmall {
-                            minimum: min_contains as f64,
-                            inclusive: true,
-                        },
-                        format!(
-                            "Array must contain required value at least {} time(s)",
-                            min_contains
-                        ),
-                    );
-                }
-            }
-            if let Some(max_contains) = self.max_contains {
-                if count > max_contains {
-                    errors.push(
-                        IssueCode::TooBig {
-                            maximum: max_contains as f64,
-                            inclusive: true,
-                        },
-                        format!(
-                            "Array must contain required value at most {} time(s)",
-                            max_contains
-                        ),
-                    );
-                }
+        }
+        if let Some(max_contains) = self.max_contains {
+            if count > max_contains {
+                errors.push(
+                    IssueCode::TooBig {
+                        maximum: max_contains as f64,
+                        inclusive: true,
+                    },
+                    format!(
+                        "Array must contain {} at most {} time(s)",
+                        subject, max_contains
+                    ),
+                );
             }
         }
 
+        errors
+    }
+
+    /// Generate a JSON Schema (called by [`JsonSchema`](crate::json_schema::JsonSchema) trait impl).
+    ///
+    /// Requires the `openapi` feature.
+    #[cfg(feature = "openapi")]
+    pub fn to_json_schema_inner(&self) -> serde_json::Value
+    where
+        T: crate::json_schema::JsonSchema,
+    {
+        let mut schema = serde_json::json!({
+            "type": "array",
+            "items": self.element.json_schema(),
+        });
+        if !self.prefix_items.is_empty() {
+            let prefix: Vec<Value> = self
+                .prefix_items
+                .iter()
+                .map(|s| s.dyn_json_schema())
+                .collect();
+            schema["prefixItems"] = Value::Array(prefix);
+        }
+        if let Some(min) = self.min_len {
+            schema["minItems"] = serde_json::json!(min);
+        }
+        if let Some(max) = self.max_len {
+            schema["maxItems"] = serde_json::json!(max);
+        }
+        if let Some(exact) = self.exact_len {
+            schema["minItems"] = serde_json::json!(exact);
+            schema["maxItems"] = serde_json::json!(exact);
+        }
+        if let Some(ref contains) = self.contains {
+            schema["contains"] = serde_json::json!({ "const": contains });
+        }
+        if let Some(contains) = &self.contains_schema {
+            schema["contains"] = contains.dyn_json_schema();
+        }
+        if let Some(min_contains) = self.min_contains {
+            schema["minContains"] = serde_json::json!(min_contains);
+        }
+        if let Some(max_contains) = self.max_contains {
+            schema["maxContains"] = serde_json::json!(max_contains);
+        }
+        if self.unique {
+            schema["uniqueItems"] = serde_json::json!(true);
+        }
+        schema
+    }
+}
+
+impl<T: VldSchema> VldSchema for ZArray<T> {
+    type Output = Vec<T::Output>;
+
+    fn parse_value(&self, value: &Value) -> Result<Vec<T::Output>, VldError> {
+        let arr = value.as_array().ok_or_else(|| {
+            VldError::single(
+                IssueCode::InvalidType {
+                    expected: "array".to_string(),
+                    received: value_type_name(value),
+                },
+                format!("Expected array, received {}", value_type_name(value)),
+            )
+        })?;
+
+        let mut errors = self.check_array(arr);
+        let limit = crate::options::issue_limit();
+
         // Validate each element
         let mut results = Vec::with_capacity(arr.len());
 
         for (i, item) in arr.iter().enumerate() {
-            match self.element.parse_value(item) {
+            if crate::options::limit_reached(&errors, limit) {
+                break;
+            }
+            match self.parse_element(i, item) {
                 Ok(v) => results.push(v),
                 Err(e) => {
                     errors = errors.merge(e.with_prefix(PathSegment::Index(i)));
@@ -254,4 +420,139 @@ impl<T: VldSchema> VldSchema for ZArray<T> {
             Err(errors)
         }
     }
+
+    fn deserialize_with<'de, D: serde::Deserializer<'de>>(
+        &self,
+        de: D,
+    ) -> DeResult<Vec<T::Output>, D::Error> {
+        // `unique` and `contains` look at raw JSON values, and prefix items
+        // are parsed by position, so they need the buffered array.
+        if self.unique
+            || self.contains.is_some()
+            || self.contains_schema.is_some()
+            || !self.prefix_items.is_empty()
+        {
+            let value = Value::deserialize(de)?;
+            return Ok(self.parse_value(&value));
+        }
+        crate::de::deserialize_any(de, ArrayVisitor(self))
+    }
+
+    fn parse_value_lenient(&self, value: &Value) -> Lenient<Vec<T::Output>> {
+        let Some(arr) = value.as_array() else {
+            return Lenient::from_result(self.parse_value(value));
+        };
+
+        let mut errors = self.check_array(arr);
+        let mut results = Some(Vec::with_capacity(arr.len()));
+        let mut children = Vec::with_capacity(arr.len());
+
+        for (i, item) in arr.iter().enumerate() {
+            let element = match self.prefix_schema(i) {
+                Some(schema) => {
+                    let (result, children) = schema.dyn_parse_lenient(item);
+                    let result = result.and_then(|v| self.prefix_output(v));
+                    Lenient {
+                        children,
+                        ..Lenient::from_result(result)
+                    }
+                }
+                None => self.element.parse_value_lenient(item),
+            };
+            match (element.value, results.as_mut()) {
+                (Some(v), Some(results)) => results.push(v),
+                _ => results = None,
+            }
+            let result = match element.error {
+                Some(e) => {
+                    errors = errors.merge(e.clone().with_prefix(PathSegment::Index(i)));
+                    Err(e)
+                }
+                // Replaced with the serialized output by `parse_lenient()`.
+                None => Ok(item.clone()),
+            };
+            children.push(
+                FieldResult::new(i.to_string(), item.clone(), result)
+                    .with_children(element.children),
+            );
+        }
+
+        Lenient {
+            value: results,
+            error: (!errors.is_empty()).then_some(errors),
+            children,
+        }
+    }
+
+    fn __vld_deep_partial(&mut self) -> Option<Box<dyn crate::object::DynSchema>> {
+        self.deep_partial_with(T::__vld_deep_partial)
+            .map(crate::object::erase)
+    }
+}
+
+/// Schemas for [`ZArray::prefix_items()`]: a tuple of up to 6 schemas, one
+/// per leading position.
+pub trait PrefixItems {
+    fn into_schemas(self) -> Vec<Box<dyn DynSchema + Send + Sync>>;
+}
+
+macro_rules! impl_prefix_items {
+    ($($S:ident $s:ident),+) => {
+        impl<$($S),+> PrefixItems for ($($S,)+)
+        where
+            $($S: VldSchema + MaybeJsonSchema + Send + Sync + 'static,
+            $S::Output: serde::Serialize,)+
+        {
+            fn into_schemas(self) -> Vec<Box<dyn DynSchema + Send + Sync>> {
+                let ($($s,)+) = self;
+                vec![$(Box::new(crate::object::json_field($s))),+]
+            }
+        }
+    };
+}
+
+impl_prefix_items!(A a);
+impl_prefix_items!(A a, B b);
+impl_prefix_items!(A a, B b, C c);
+impl_prefix_items!(A a, B b, C c, D d);
+impl_prefix_items!(A a, B b, C c, D d, E e);
+impl_prefix_items!(A a, B b, C c, D d, E e, F f);
+
+struct ArrayVisitor<'a, T: VldSchema>(&'a ZArray<T>);
+
+impl<'de, T: VldSchema> DirectVisitor<'de> for ArrayVisitor<'_, T> {
+    type Output = Vec<T::Output>;
+
+    fn visit_value(self, value: Value) -> Result<Vec<T::Output>, VldError> {
+        self.0.parse_value(&value)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> DeResult<Vec<T::Output>, A::Error> {
+        let limit = crate::options::issue_limit();
+        let mut errors = VldError::new();
+        let mut results = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(1024));
+        let mut len = 0;
+
+        loop {
+            if crate::options::limit_reached(&errors, limit) {
+                while seq.next_element::<IgnoredAny>()?.is_some() {
+                    len += 1;
+                }
+                break;
+            }
+            match seq.next_element_seed(SchemaSeed(&self.0.element))? {
+                Some(Ok(v)) => results.push(v),
+                Some(Err(e)) => errors = errors.merge(e.with_prefix(PathSegment::Index(len))),
+                None => break,
+            }
+            len += 1;
+        }
+
+        let errors = self.0.check_len(len).merge(errors);
+        if errors.is_empty() {
+            Ok(Ok(results))
+        } else {
+            Ok(Err(errors))
+        }
+    }
 }
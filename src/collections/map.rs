@@ -52,8 +52,12 @@ where
 
         let mut result = HashMap::new();
         let mut errors = VldError::new();
+        let limit = crate::options::issue_limit();
 
         for (i, item) in arr.iter().enumerate() {
+            if crate::options::limit_reached(&errors, limit) {
+                break;
+            }
             let pair = item.as_array().filter(|a| a.len() == 2);
             match pair {
                 Some(pair) => {
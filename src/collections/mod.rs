@@ -4,7 +4,7 @@ mod record;
 mod set;
 mod tuple;
 
-pub use array::ZArray;
+pub use array::{PrefixItems, ZArray};
 pub use map::ZMap;
 pub use record::ZRecord;
 pub use set::ZSet;
@@ -2,6 +2,7 @@ use serde_json::Value;
 use std::collections::HashMap;
 
 use crate::error::{value_type_name, IssueCode, PathSegment, VldError};
+use crate::object::{DynSchema, MaybeJsonSchema};
 use crate::schema::VldSchema;
 
 /// Schema for validating JSON objects as key-value records.
@@ -19,6 +20,7 @@ use crate::schema::VldSchema;
 /// ```
 pub struct ZRecord<V: VldSchema> {
     value_schema: V,
+    key_schema: Option<Box<dyn DynSchema + Send + Sync>>,
     min_keys: Option<usize>,
     max_keys: Option<usize>,
 }
@@ -27,11 +29,35 @@ impl<V: VldSchema> ZRecord<V> {
     pub fn new(value_schema: V) -> Self {
         Self {
             value_schema,
+            key_schema: None,
             min_keys: None,
             max_keys: None,
         }
     }
 
+    /// Validate every key, as a JSON string, with `schema`. A key that fails
+    /// is reported with the key in the path. Maps to `propertyNames` in
+    /// JSON Schema.
+    ///
+    /// # Example
+    /// ```
+    /// use vld::prelude::*;
+    ///
+    /// let headers = vld::record(vld::string()).key_schema(vld::string().starts_with("x-"));
+    /// assert!(headers.parse(r#"{"x-trace": "abc"}"#).is_ok());
+    ///
+    /// let err = headers.parse(r#"{"trace": "abc"}"#).unwrap_err();
+    /// assert_eq!(err.issues[0].path[0].to_string(), ".trace");
+    /// ```
+    pub fn key_schema<S>(mut self, schema: S) -> Self
+    where
+        S: VldSchema + MaybeJsonSchema + Send + Sync + 'static,
+        S::Output: serde::Serialize,
+    {
+        self.key_schema = Some(Box::new(crate::object::json_field(schema)));
+        self
+    }
+
     /// Minimum number of keys.
     pub fn min_keys(mut self, n: usize) -> Self {
         self.min_keys = Some(n);
@@ -49,35 +75,27 @@ impl<V: VldSchema> ZRecord<V> {
         &self.value_schema
     }
 
-    /// Generate a JSON Schema (called by [`JsonSchema`](crate::json_schema::JsonSchema) trait impl).
-    ///
-    /// Requires the `openapi` feature.
-    #[cfg(feature = "openapi")]
-    pub fn to_json_schema_inner(&self) -> serde_json::Value
+    /// This record with the value schema that `partial` rebuilds from the
+    /// current one, or `None` when it has no partial form. Used by
+    /// [`ZObject::deep_partial()`](crate::object::ZObject::deep_partial).
+    pub(crate) fn deep_partial_with<D>(
+        &mut self,
+        partial: impl FnOnce(&mut V) -> Option<Box<D>>,
+    ) -> Option<ZRecord<crate::object::DynValue<D>>>
     where
-        V: crate::json_schema::JsonSchema,
+        D: DynSchema + ?Sized,
     {
-        serde_json::json!({
-            "type": "object",
-            "additionalProperties": self.value_schema.json_schema(),
+        let value_schema = partial(&mut self.value_schema)?;
+        Some(ZRecord {
+            value_schema: crate::object::DynValue(value_schema),
+            key_schema: self.key_schema.take(),
+            min_keys: self.min_keys,
+            max_keys: self.max_keys,
         })
     }
-}
-
-impl<V: VldSchema> VldSchema for ZRecord<V> {
-    type Output = HashMap<String, V::Output>;
-
-    fn parse_value(&self, value: &Value) -> Result<HashMap<String, V::Output>, VldError> {
-        let obj = value.as_object().ok_or_else(|| {
-            VldError::single(
-                IssueCode::InvalidType {
-                    expected: "object".to_string(),
-                    received: value_type_name(value),
-                },
-                format!("Expected object, received {}", value_type_name(value)),
-            )
-        })?;
 
+    /// Run the key-count checks that do not depend on value parsing.
+    pub(crate) fn check_keys(&self, obj: &serde_json::Map<String, Value>) -> VldError {
         let mut errors = VldError::new();
 
         if let Some(min) = self.min_keys {
@@ -104,9 +122,64 @@ impl<V: VldSchema> VldSchema for ZRecord<V> {
             }
         }
 
+        errors
+    }
+
+    /// Run the key schema on every key of `obj`.
+    pub(crate) fn check_key_names(&self, obj: &serde_json::Map<String, Value>) -> VldError {
+        let mut errors = VldError::new();
+        if let Some(key_schema) = &self.key_schema {
+            for key in obj.keys() {
+                if let Err(e) = key_schema.dyn_parse(&Value::String(key.clone())) {
+                    errors = errors.merge(e.with_prefix(PathSegment::Field(key.clone())));
+                }
+            }
+        }
+        errors
+    }
+
+    /// Generate a JSON Schema (called by [`JsonSchema`](crate::json_schema::JsonSchema) trait impl).
+    ///
+    /// Requires the `openapi` feature.
+    #[cfg(feature = "openapi")]
+    pub fn to_json_schema_inner(&self) -> serde_json::Value
+    where
+        V: crate::json_schema::JsonSchema,
+    {
+        let mut schema = serde_json::json!({
+            "type": "object",
+            "additionalProperties": self.value_schema.json_schema(),
+        });
+        if let Some(key_schema) = &self.key_schema {
+            schema["propertyNames"] = key_schema.dyn_json_schema();
+        }
+        schema
+    }
+}
+
+impl<V: VldSchema> VldSchema for ZRecord<V> {
+    type Output = HashMap<String, V::Output>;
+
+    fn parse_value(&self, value: &Value) -> Result<HashMap<String, V::Output>, VldError> {
+        let obj = value.as_object().ok_or_else(|| {
+            VldError::single(
+                IssueCode::InvalidType {
+                    expected: "object".to_string(),
+                    received: value_type_name(value),
+                },
+                format!("Expected object, received {}", value_type_name(value)),
+            )
+        })?;
+
+        let mut errors = self.check_keys(obj).merge(self.check_key_names(obj));
+        let limit = crate::options::issue_limit();
+
         let mut result = HashMap::new();
 
         for (key, val) in obj {
+            if crate::options::limit_reached(&errors, limit) {
+                break;
+            }
             match self.value_schema.parse_value(val) {
                 Ok(v) => {
                     result.insert(key.clone(), v);
@@ -123,4 +196,9 @@ impl<V: VldSchema> VldSchema for ZRecord<V> {
             Err(errors)
         }
     }
+
+    fn __vld_deep_partial(&mut self) -> Option<Box<dyn crate::object::DynSchema>> {
+        self.deep_partial_with(V::__vld_deep_partial)
+            .map(crate::object::erase)
+    }
 }
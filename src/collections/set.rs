@@ -91,8 +91,12 @@ where
 
         let mut result = HashSet::new();
         let mut errors = VldError::new();
+        let limit = crate::options::issue_limit();
 
         for (i, item) in arr.iter().enumerate() {
+            if crate::options::limit_reached(&errors, limit) {
+                break;
+            }
             match self.element.parse_value(item) {
                 Ok(v) => {
                     result.insert(v);
@@ -13,8 +13,8 @@ pub struct ZCatch<T: VldSchema>
 where
     T::Output: Clone,
 {
-    inner: T,
-    fallback: T::Output,
+    pub(crate) inner: T,
+    pub(crate) fallback: T::Output,
 }
 
 impl<T: VldSchema> ZCatch<T>
@@ -43,4 +43,8 @@ where
             Err(_) => Ok(self.fallback.clone()),
         }
     }
+
+    fn __vld_optional_key(&self) -> bool {
+        self.inner.__vld_optional_key()
+    }
 }
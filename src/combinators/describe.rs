@@ -38,4 +38,19 @@ impl<T: VldSchema> VldSchema for ZDescribe<T> {
     fn parse_value(&self, value: &Value) -> Result<T::Output, VldError> {
         self.inner.parse_value(value)
     }
+
+    fn deserialize_with<'de, D: serde::Deserializer<'de>>(
+        &self,
+        de: D,
+    ) -> crate::de::DeResult<T::Output, D::Error> {
+        self.inner.deserialize_with(de)
+    }
+
+    fn parse_value_lenient(&self, value: &Value) -> crate::schema::Lenient<T::Output> {
+        self.inner.parse_value_lenient(value)
+    }
+
+    fn __vld_optional_key(&self) -> bool {
+        self.inner.__vld_optional_key()
+    }
 }
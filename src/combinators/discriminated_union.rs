@@ -1,13 +1,13 @@
-use serde_json::Value;
+use serde_json::{Map, Value};
 
-use crate::error::{value_type_name, IssueCode, VldError};
+use crate::error::{value_type_name, IssueCode, PathSegment, VldError};
 use crate::object::DynSchema;
 use crate::schema::VldSchema;
 
 /// Entry in a discriminated union: a discriminator value and its associated schema.
-struct Variant {
+struct Variant<D: ?Sized> {
     discriminator_value: Value,
-    schema: Box<dyn DynSchema>,
+    schema: Box<D>,
 }
 
 /// Discriminated union: chooses a schema based on a discriminator field value.
@@ -23,18 +23,33 @@ struct Variant {
 ///     .variant("dog", vld::object().field("type", vld::literal("dog")).field("bark", vld::boolean()))
 ///     .variant("cat", vld::object().field("type", vld::literal("cat")).field("lives", vld::number().int()));
 /// ```
-pub struct ZDiscriminatedUnion {
+pub struct ZDiscriminatedUnion<D: ?Sized = dyn DynSchema> {
     discriminator: String,
-    variants: Vec<Variant>,
+    variants: Vec<Variant<D>>,
 }
 
-impl ZDiscriminatedUnion {
-    pub fn new(discriminator: impl Into<String>) -> Self {
+/// A discriminated union whose variant schemas are `Send + Sync` and run
+/// through their [`VldSchemaAsync`](crate::async_schema::VldSchemaAsync)
+/// implementation on the async path. Created via
+/// [`vld::discriminated_union_async()`](crate::discriminated_union_async).
+///
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub type ZDiscriminatedUnionAsync = ZDiscriminatedUnion<dyn DynSchema + Send + Sync>;
+
+impl<D: ?Sized> ZDiscriminatedUnion<D> {
+    pub(crate) fn with_discriminator(discriminator: impl Into<String>) -> Self {
         Self {
             discriminator: discriminator.into(),
             variants: vec![],
         }
     }
+}
+
+impl ZDiscriminatedUnion {
+    pub fn new(discriminator: impl Into<String>) -> Self {
+        Self::with_discriminator(discriminator)
+    }
 
     /// Add a variant: when the discriminator field equals `value`, use `schema`.
     pub fn variant<S: DynSchema + 'static>(mut self, value: impl Into<Value>, schema: S) -> Self {
@@ -51,48 +66,164 @@ impl ZDiscriminatedUnion {
     }
 }
 
-impl VldSchema for ZDiscriminatedUnion {
-    type Output = Value;
+#[cfg(feature = "async")]
+impl ZDiscriminatedUnionAsync {
+    /// Add a variant: when the discriminator field equals `value`, use
+    /// `schema`, parsed through its
+    /// [`VldSchemaAsync`](crate::async_schema::VldSchemaAsync) implementation
+    /// on the async path.
+    pub fn variant<S>(mut self, value: impl Into<Value>, schema: S) -> Self
+    where
+        S: crate::async_schema::VldSchemaAsync + crate::object::MaybeJsonSchema + 'static,
+        S::Output: serde::Serialize,
+    {
+        self.variants.push(Variant {
+            discriminator_value: value.into(),
+            schema: crate::object::erase_async(schema),
+        });
+        self
+    }
 
-    fn parse_value(&self, value: &Value) -> Result<Value, VldError> {
-        let obj = value.as_object().ok_or_else(|| {
-            VldError::single(
-                IssueCode::InvalidType {
-                    expected: "object".to_string(),
-                    received: value_type_name(value),
-                },
-                format!("Expected object, received {}", value_type_name(value)),
-            )
-        })?;
+    /// Add a string variant (convenience).
+    pub fn variant_str<S>(self, value: &str, schema: S) -> Self
+    where
+        S: crate::async_schema::VldSchemaAsync + crate::object::MaybeJsonSchema + 'static,
+        S::Output: serde::Serialize,
+    {
+        self.variant(Value::String(value.to_string()), schema)
+    }
 
-        let disc_value = obj.get(&self.discriminator).ok_or_else(|| {
-            VldError::single(
-                IssueCode::MissingField,
-                format!("Missing discriminator field \"{}\"", self.discriminator),
-            )
-        })?;
+    /// Async counterpart of [`parse_value`](VldSchema::parse_value).
+    pub(crate) async fn parse_value_async_impl(&self, value: &Value) -> Result<Value, VldError> {
+        self.select_variant(value)?.dyn_parse_async(value).await
+    }
+}
+
+impl<D: ?Sized + DynSchema> ZDiscriminatedUnion<D> {
+    /// Find the variant schema matching the discriminator of `value`.
+    fn select_variant(&self, value: &Value) -> Result<&D, VldError> {
+        let obj = expect_object(value)?;
+
+        let disc_value = obj
+            .get(&self.discriminator)
+            .ok_or_else(|| missing_discriminator(&self.discriminator))?;
 
         for variant in &self.variants {
             if *disc_value == variant.discriminator_value {
-                return variant.schema.dyn_parse(value);
+                return Ok(variant.schema.as_ref());
             }
         }
 
-        let known: Vec<String> = self
-            .variants
-            .iter()
-            .map(|v| format!("{}", v.discriminator_value))
-            .collect();
-
-        Err(VldError::single(
-            IssueCode::Custom {
-                code: "invalid_discriminator".to_string(),
-            },
-            format!(
-                "Invalid discriminator value {}. Expected one of: {}",
-                disc_value,
-                known.join(", ")
-            ),
+        Err(invalid_discriminator(
+            disc_value,
+            self.variants.iter().map(|v| &v.discriminator_value),
         ))
     }
 }
+
+impl<D: ?Sized + DynSchema> VldSchema for ZDiscriminatedUnion<D> {
+    type Output = Value;
+
+    fn parse_value(&self, value: &Value) -> Result<Value, VldError> {
+        self.select_variant(value)?.dyn_parse(value)
+    }
+}
+
+fn expect_object(value: &Value) -> Result<&Map<String, Value>, VldError> {
+    value.as_object().ok_or_else(|| {
+        VldError::single(
+            IssueCode::InvalidType {
+                expected: "object".to_string(),
+                received: value_type_name(value),
+            },
+            format!("Expected object, received {}", value_type_name(value)),
+        )
+    })
+}
+
+fn missing_discriminator(discriminator: &str) -> VldError {
+    VldError::single(
+        IssueCode::MissingField,
+        format!("Missing discriminator field \"{}\"", discriminator),
+    )
+}
+
+fn invalid_discriminator<'a>(value: &Value, known: impl Iterator<Item = &'a Value>) -> VldError {
+    let known: Vec<String> = known.map(|v| format!("{}", v)).collect();
+    VldError::single(
+        IssueCode::Custom {
+            code: "invalid_discriminator".to_string(),
+        },
+        format!(
+            "Invalid discriminator value {}. Expected one of: {}",
+            value,
+            known.join(", ")
+        ),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Support for `#[derive(Validate)]` on enums
+// ---------------------------------------------------------------------------
+
+static NULL: Value = Value::Null;
+
+/// Select the variant of a derived enum, following serde's representations:
+/// no `tag` is externally tagged, `tag` alone is internally tagged and `tag`
+/// with `content` is adjacently tagged.
+///
+/// Returns the index into `names`, the payload to validate and the path
+/// segment the payload's issues belong under.
+#[doc(hidden)]
+pub fn __select_enum_variant<'v>(
+    value: &'v Value,
+    tag: Option<&str>,
+    content: Option<&str>,
+    names: &[&str],
+) -> Result<(usize, &'v Value, Option<PathSegment>), VldError> {
+    let find = |name: &str| {
+        names.iter().position(|n| *n == name).ok_or_else(|| {
+            let known: Vec<Value> = names.iter().map(|n| Value::from(*n)).collect();
+            invalid_discriminator(&Value::from(name), known.iter())
+        })
+    };
+
+    let Some(tag) = tag else {
+        // Externally tagged: `"Unit"` or `{"Variant": payload}`.
+        if let Value::String(name) = value {
+            return Ok((find(name)?, &NULL, None));
+        }
+        let obj = expect_object(value)?;
+        let mut entries = obj.iter();
+        return match (entries.next(), entries.next()) {
+            (Some((name, payload)), None) => {
+                Ok((find(name)?, payload, Some(PathSegment::Field(name.clone()))))
+            }
+            _ => Err(VldError::single(
+                IssueCode::Custom {
+                    code: "invalid_discriminator".to_string(),
+                },
+                format!("Expected exactly one variant key, received {}", obj.len()),
+            )),
+        };
+    };
+
+    let obj = expect_object(value)?;
+    let name = obj.get(tag).ok_or_else(|| missing_discriminator(tag))?;
+    let index = match name {
+        Value::String(name) => find(name)?,
+        other => {
+            let known: Vec<Value> = names.iter().map(|n| Value::from(*n)).collect();
+            return Err(invalid_discriminator(other, known.iter()));
+        }
+    };
+
+    Ok(match content {
+        Some(content) => (
+            index,
+            obj.get(content).unwrap_or(&NULL),
+            Some(PathSegment::Field(content.to_string())),
+        ),
+        None => (index, value, None),
+    })
+}
@@ -25,7 +25,7 @@ where
     F: Fn() -> T,
     T: VldSchema,
 {
-    factory: F,
+    pub(crate) factory: F,
     _phantom: PhantomData<T>,
 }
 
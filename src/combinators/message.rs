@@ -18,8 +18,8 @@ use crate::schema::VldSchema;
 /// assert_eq!(err.issues[0].message, "Too short");
 /// ```
 pub struct ZMessage<T: VldSchema> {
-    inner: T,
-    msg: String,
+    pub(crate) inner: T,
+    pub(crate) msg: String,
 }
 
 impl<T: VldSchema> ZMessage<T> {
@@ -42,4 +42,8 @@ impl<T: VldSchema> VldSchema for ZMessage<T> {
             err
         })
     }
+
+    fn __vld_optional_key(&self) -> bool {
+        self.inner.__vld_optional_key()
+    }
 }
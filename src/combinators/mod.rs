@@ -8,20 +8,38 @@ mod message;
 mod pipe;
 mod preprocess;
 mod refine;
+#[cfg(feature = "async")]
+mod refine_async;
+mod refine_ctx;
 mod super_refine;
+#[cfg(feature = "async")]
+mod super_refine_async;
+mod super_refine_ctx;
 mod transform;
+mod transform_ctx;
 mod union;
 
 pub use catch_val::ZCatch;
 pub use custom::ZCustom;
 pub use describe::ZDescribe;
+#[doc(hidden)]
+pub use discriminated_union::__select_enum_variant;
 pub use discriminated_union::ZDiscriminatedUnion;
+#[cfg(feature = "async")]
+pub use discriminated_union::ZDiscriminatedUnionAsync;
 pub use intersection::ZIntersection;
 pub use lazy_schema::ZLazy;
 pub use message::ZMessage;
 pub use pipe::ZPipe;
 pub use preprocess::ZPreprocess;
 pub use refine::ZRefine;
+#[cfg(feature = "async")]
+pub use refine_async::ZRefineAsync;
+pub use refine_ctx::ZRefineCtx;
 pub use super_refine::ZSuperRefine;
+#[cfg(feature = "async")]
+pub use super_refine_async::ZSuperRefineAsync;
+pub use super_refine_ctx::ZSuperRefineCtx;
 pub use transform::ZTransform;
+pub use transform_ctx::ZTransformCtx;
 pub use union::{Either, Either3, ZUnion2, ZUnion3};
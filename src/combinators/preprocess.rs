@@ -7,6 +7,12 @@ use crate::schema::VldSchema;
 ///
 /// Created via [`vld::preprocess()`](crate::preprocess).
 ///
+/// A missing object key reaches the preprocessor as `null`, and the value it
+/// returns is a new one, so a [`vld::patch()`](crate::patch) schema inside
+/// can no longer tell the key was missing: the field parses to
+/// [`Patch::Null`](crate::modifiers::Patch::Null), not `Patch::Absent`.
+/// Wrap the other way round, `vld::patch(vld::preprocess(..))`, to keep it.
+///
 /// # Example
 /// ```
 /// use vld::prelude::*;
@@ -25,8 +31,8 @@ where
     F: Fn(&Value) -> Value,
     S: VldSchema,
 {
-    preprocessor: F,
-    schema: S,
+    pub(crate) preprocessor: F,
+    pub(crate) schema: S,
 }
 
 impl<F, S> ZPreprocess<F, S>
@@ -53,4 +59,8 @@ where
         let preprocessed = (self.preprocessor)(value);
         self.schema.parse_value(&preprocessed)
     }
+
+    fn __vld_optional_key(&self) -> bool {
+        self.schema.__vld_optional_key()
+    }
 }
@@ -18,9 +18,9 @@ where
     T: VldSchema,
     F: Fn(&T::Output) -> bool,
 {
-    inner: T,
-    check: F,
-    message: String,
+    pub(crate) inner: T,
+    pub(crate) check: F,
+    pub(crate) message: String,
 }
 
 impl<T, F> ZRefine<T, F>
@@ -40,6 +40,19 @@ where
     pub fn inner_schema(&self) -> &T {
         &self.inner
     }
+
+    fn check_output(&self, result: T::Output) -> Result<T::Output, VldError> {
+        if (self.check)(&result) {
+            Ok(result)
+        } else {
+            Err(VldError::single(
+                IssueCode::Custom {
+                    code: "custom".to_string(),
+                },
+                self.message.clone(),
+            ))
+        }
+    }
 }
 
 impl<T, F> VldSchema for ZRefine<T, F>
@@ -51,16 +64,20 @@ where
 
     fn parse_value(&self, value: &Value) -> Result<T::Output, VldError> {
         let result = self.inner.parse_value(value)?;
+        self.check_output(result)
+    }
 
-        if (self.check)(&result) {
-            Ok(result)
-        } else {
-            Err(VldError::single(
-                IssueCode::Custom {
-                    code: "custom".to_string(),
-                },
-                self.message.clone(),
-            ))
-        }
+    fn deserialize_with<'de, D: serde::Deserializer<'de>>(
+        &self,
+        de: D,
+    ) -> crate::de::DeResult<T::Output, D::Error> {
+        Ok(self
+            .inner
+            .deserialize_with(de)?
+            .and_then(|r| self.check_output(r)))
+    }
+
+    fn __vld_optional_key(&self) -> bool {
+        self.inner.__vld_optional_key()
     }
 }
@@ -0,0 +1,73 @@
+use serde_json::Value;
+use std::future::Future;
+
+use crate::error::{IssueCode, VldError};
+use crate::schema::VldSchema;
+
+/// Adds an asynchronous refinement check to a schema without changing its output type.
+///
+/// Created via [`VldSchema::refine_async()`]. The check only runs on the async
+/// path ([`VldSchemaAsync::parse_async()`](crate::async_schema::VldSchemaAsync::parse_async));
+/// a synchronous `parse()` fails with an `async_refinement` issue instead of
+/// silently skipping it.
+///
+/// # Example
+/// ```ignore
+/// use vld::prelude::*;
+///
+/// let email = vld::string().email().refine_async(
+///     |email: &String| {
+///         let email = email.clone();
+///         async move { !db::email_taken(&email).await }
+///     },
+///     "Email is already taken",
+/// );
+/// let parsed = email.parse_async(r#""new@example.com""#).await?;
+/// ```
+pub struct ZRefineAsync<T, F> {
+    pub(crate) inner: T,
+    pub(crate) check: F,
+    pub(crate) message: String,
+}
+
+impl<T, F, Fut> ZRefineAsync<T, F>
+where
+    T: VldSchema,
+    F: Fn(&T::Output) -> Fut,
+    Fut: Future<Output = bool>,
+{
+    pub fn new(inner: T, check: F, message: &str) -> Self {
+        Self {
+            inner,
+            check,
+            message: message.to_string(),
+        }
+    }
+
+    /// Access the inner schema.
+    pub fn inner_schema(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T, F> VldSchema for ZRefineAsync<T, F>
+where
+    T: VldSchema,
+{
+    type Output = T::Output;
+
+    fn parse_value(&self, value: &Value) -> Result<T::Output, VldError> {
+        self.inner.parse_value(value)?;
+        Err(async_required_error())
+    }
+}
+
+/// Issue reported when a schema with async refinements is parsed synchronously.
+pub(crate) fn async_required_error() -> VldError {
+    VldError::single(
+        IssueCode::Custom {
+            code: "async_refinement".to_string(),
+        },
+        "Schema contains async refinements, use parse_async() instead",
+    )
+}
@@ -0,0 +1,89 @@
+use serde_json::Value;
+use std::marker::PhantomData;
+
+use crate::context::{missing_context_error, with_current};
+use crate::error::{IssueCode, VldError};
+use crate::schema::VldSchema;
+
+/// Adds a refinement check that can read the validation context.
+///
+/// Created via [`VldSchema::refine_ctx()`]. The context is supplied by
+/// [`VldSchema::parse_with_context()`]; without it the check fails with a
+/// `missing_context` issue.
+///
+/// # Example
+/// ```
+/// use vld::prelude::*;
+///
+/// #[derive(Clone)]
+/// struct Limits {
+///     max_len: usize,
+/// }
+///
+/// let bio = vld::string().refine_ctx(|s, l: &Limits| s.len() <= l.max_len, "Bio too long");
+/// assert!(bio.parse_with_context(r#""hello""#, &Limits { max_len: 3 }).is_err());
+/// assert!(bio.parse_with_context(r#""hello""#, &Limits { max_len: 10 }).is_ok());
+/// ```
+pub struct ZRefineCtx<T, C, F>
+where
+    T: VldSchema,
+    C: 'static,
+    F: Fn(&T::Output, &C) -> bool,
+{
+    pub(crate) inner: T,
+    pub(crate) check: F,
+    pub(crate) message: String,
+    _ctx: PhantomData<fn() -> C>,
+}
+
+impl<T, C, F> ZRefineCtx<T, C, F>
+where
+    T: VldSchema,
+    C: 'static,
+    F: Fn(&T::Output, &C) -> bool,
+{
+    pub fn new(inner: T, check: F, message: &str) -> Self {
+        Self {
+            inner,
+            check,
+            message: message.to_string(),
+            _ctx: PhantomData,
+        }
+    }
+
+    /// Access the inner schema.
+    pub fn inner_schema(&self) -> &T {
+        &self.inner
+    }
+
+    pub(crate) fn check_output(&self, result: T::Output) -> Result<T::Output, VldError> {
+        with_current::<C, _>(|ctx| match ctx {
+            Some(ctx) if (self.check)(&result, ctx) => Ok(result),
+            Some(_) => Err(VldError::single(
+                IssueCode::Custom {
+                    code: "custom".to_string(),
+                },
+                self.message.clone(),
+            )),
+            None => Err(missing_context_error::<C>()),
+        })
+    }
+}
+
+impl<T, C, F> VldSchema for ZRefineCtx<T, C, F>
+where
+    T: VldSchema,
+    C: 'static,
+    F: Fn(&T::Output, &C) -> bool,
+{
+    type Output = T::Output;
+
+    fn parse_value(&self, value: &Value) -> Result<T::Output, VldError> {
+        let result = self.inner.parse_value(value)?;
+        self.check_output(result)
+    }
+
+    fn __vld_optional_key(&self) -> bool {
+        self.inner.__vld_optional_key()
+    }
+}
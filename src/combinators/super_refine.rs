@@ -25,8 +25,8 @@ where
     T: VldSchema,
     F: Fn(&T::Output, &mut VldError),
 {
-    inner: T,
-    check: F,
+    pub(crate) inner: T,
+    pub(crate) check: F,
 }
 
 impl<T, F> ZSuperRefine<T, F>
@@ -37,6 +37,16 @@ where
     pub fn new(inner: T, check: F) -> Self {
         Self { inner, check }
     }
+
+    fn check_output(&self, result: T::Output) -> Result<T::Output, VldError> {
+        let mut errors = VldError::new();
+        (self.check)(&result, &mut errors);
+        if errors.is_empty() {
+            Ok(result)
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 impl<T, F> VldSchema for ZSuperRefine<T, F>
@@ -48,12 +58,20 @@ where
 
     fn parse_value(&self, value: &Value) -> Result<T::Output, VldError> {
         let result = self.inner.parse_value(value)?;
-        let mut errors = VldError::new();
-        (self.check)(&result, &mut errors);
-        if errors.is_empty() {
-            Ok(result)
-        } else {
-            Err(errors)
-        }
+        self.check_output(result)
+    }
+
+    fn deserialize_with<'de, D: serde::Deserializer<'de>>(
+        &self,
+        de: D,
+    ) -> crate::de::DeResult<T::Output, D::Error> {
+        Ok(self
+            .inner
+            .deserialize_with(de)?
+            .and_then(|r| self.check_output(r)))
+    }
+
+    fn __vld_optional_key(&self) -> bool {
+        self.inner.__vld_optional_key()
     }
 }
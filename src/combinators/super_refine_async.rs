@@ -0,0 +1,62 @@
+use serde_json::Value;
+use std::future::Future;
+
+use crate::error::VldError;
+use crate::schema::VldSchema;
+
+use super::refine_async::async_required_error;
+
+/// Asynchronous refinement that can produce multiple errors at once.
+///
+/// Created via [`VldSchema::super_refine_async()`]. The closure returns a future
+/// resolving to the collected issues; an empty `VldError` means the value is valid.
+///
+/// Like [`ZRefineAsync`](super::ZRefineAsync), the check only runs on the async path.
+///
+/// # Example
+/// ```ignore
+/// use vld::prelude::*;
+///
+/// let order_id = vld::number().int().super_refine_async(|id: &i64| {
+///     let id = *id;
+///     async move {
+///         let mut errors = VldError::new();
+///         if !db::order_exists(id).await {
+///             errors.push(IssueCode::Custom { code: "not_found".into() }, "Unknown order");
+///         }
+///         errors
+///     }
+/// });
+/// ```
+pub struct ZSuperRefineAsync<T, F> {
+    pub(crate) inner: T,
+    pub(crate) check: F,
+}
+
+impl<T, F, Fut> ZSuperRefineAsync<T, F>
+where
+    T: VldSchema,
+    F: Fn(&T::Output) -> Fut,
+    Fut: Future<Output = VldError>,
+{
+    pub fn new(inner: T, check: F) -> Self {
+        Self { inner, check }
+    }
+
+    /// Access the inner schema.
+    pub fn inner_schema(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T, F> VldSchema for ZSuperRefineAsync<T, F>
+where
+    T: VldSchema,
+{
+    type Output = T::Output;
+
+    fn parse_value(&self, value: &Value) -> Result<T::Output, VldError> {
+        self.inner.parse_value(value)?;
+        Err(async_required_error())
+    }
+}
@@ -0,0 +1,89 @@
+use serde_json::Value;
+use std::marker::PhantomData;
+
+use crate::context::{missing_context_error, with_current};
+use crate::error::VldError;
+use crate::schema::VldSchema;
+
+/// Context-aware refinement that can produce multiple errors at once.
+///
+/// Created via [`VldSchema::super_refine_ctx()`].
+///
+/// # Example
+/// ```
+/// use vld::prelude::*;
+///
+/// #[derive(Clone)]
+/// struct Flags {
+///     allow_admin: bool,
+/// }
+///
+/// let role = vld::string().super_refine_ctx(|role, flags: &Flags, errors| {
+///     if role == "admin" && !flags.allow_admin {
+///         errors.push(IssueCode::Custom { code: "forbidden".into() }, "Role not allowed");
+///     }
+/// });
+/// let flags = Flags { allow_admin: false };
+/// assert!(role.parse_with_context(r#""admin""#, &flags).is_err());
+/// ```
+pub struct ZSuperRefineCtx<T, C, F>
+where
+    T: VldSchema,
+    C: 'static,
+    F: Fn(&T::Output, &C, &mut VldError),
+{
+    pub(crate) inner: T,
+    pub(crate) check: F,
+    _ctx: PhantomData<fn() -> C>,
+}
+
+impl<T, C, F> ZSuperRefineCtx<T, C, F>
+where
+    T: VldSchema,
+    C: 'static,
+    F: Fn(&T::Output, &C, &mut VldError),
+{
+    pub fn new(inner: T, check: F) -> Self {
+        Self {
+            inner,
+            check,
+            _ctx: PhantomData,
+        }
+    }
+
+    /// Access the inner schema.
+    pub fn inner_schema(&self) -> &T {
+        &self.inner
+    }
+
+    pub(crate) fn check_output(&self, result: T::Output) -> Result<T::Output, VldError> {
+        with_current::<C, _>(|ctx| {
+            let ctx = ctx.ok_or_else(missing_context_error::<C>)?;
+            let mut errors = VldError::new();
+            (self.check)(&result, ctx, &mut errors);
+            if errors.is_empty() {
+                Ok(result)
+            } else {
+                Err(errors)
+            }
+        })
+    }
+}
+
+impl<T, C, F> VldSchema for ZSuperRefineCtx<T, C, F>
+where
+    T: VldSchema,
+    C: 'static,
+    F: Fn(&T::Output, &C, &mut VldError),
+{
+    type Output = T::Output;
+
+    fn parse_value(&self, value: &Value) -> Result<T::Output, VldError> {
+        let result = self.inner.parse_value(value)?;
+        self.check_output(result)
+    }
+
+    fn __vld_optional_key(&self) -> bool {
+        self.inner.__vld_optional_key()
+    }
+}
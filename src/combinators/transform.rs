@@ -21,7 +21,7 @@ where
     F: Fn(T::Output) -> U,
 {
     inner: T,
-    transform_fn: F,
+    pub(crate) transform_fn: F,
     _phantom: PhantomData<U>,
 }
 
@@ -55,4 +55,15 @@ where
         let result = self.inner.parse_value(value)?;
         Ok((self.transform_fn)(result))
     }
+
+    fn deserialize_with<'de, D: serde::Deserializer<'de>>(
+        &self,
+        de: D,
+    ) -> crate::de::DeResult<U, D::Error> {
+        Ok(self.inner.deserialize_with(de)?.map(&self.transform_fn))
+    }
+
+    fn __vld_optional_key(&self) -> bool {
+        self.inner.__vld_optional_key()
+    }
 }
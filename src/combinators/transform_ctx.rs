@@ -0,0 +1,80 @@
+use serde_json::Value;
+use std::marker::PhantomData;
+
+use crate::context::{missing_context_error, with_current};
+use crate::error::VldError;
+use crate::schema::VldSchema;
+
+/// Transforms the output of a schema using the validation context.
+///
+/// Created via [`VldSchema::transform_ctx()`].
+///
+/// # Example
+/// ```
+/// use vld::prelude::*;
+///
+/// #[derive(Clone)]
+/// struct Locale(&'static str);
+///
+/// let greeting = vld::string().transform_ctx(|name, l: &Locale| match l.0 {
+///     "de" => format!("Hallo, {}", name),
+///     _ => format!("Hello, {}", name),
+/// });
+/// let out = greeting.parse_with_context(r#""Ann""#, &Locale("de")).unwrap();
+/// assert_eq!(out, "Hallo, Ann");
+/// ```
+pub struct ZTransformCtx<T, C, F, U>
+where
+    T: VldSchema,
+    C: 'static,
+    F: Fn(T::Output, &C) -> U,
+{
+    inner: T,
+    pub(crate) transform_fn: F,
+    _phantom: PhantomData<fn() -> (C, U)>,
+}
+
+impl<T, C, F, U> ZTransformCtx<T, C, F, U>
+where
+    T: VldSchema,
+    C: 'static,
+    F: Fn(T::Output, &C) -> U,
+{
+    pub fn new(inner: T, transform_fn: F) -> Self {
+        Self {
+            inner,
+            transform_fn,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Access the inner schema.
+    pub fn inner_schema(&self) -> &T {
+        &self.inner
+    }
+
+    pub(crate) fn apply(&self, result: T::Output) -> Result<U, VldError> {
+        with_current::<C, _>(|ctx| match ctx {
+            Some(ctx) => Ok((self.transform_fn)(result, ctx)),
+            None => Err(missing_context_error::<C>()),
+        })
+    }
+}
+
+impl<T, C, F, U> VldSchema for ZTransformCtx<T, C, F, U>
+where
+    T: VldSchema,
+    C: 'static,
+    F: Fn(T::Output, &C) -> U,
+{
+    type Output = U;
+
+    fn parse_value(&self, value: &Value) -> Result<U, VldError> {
+        let result = self.inner.parse_value(value)?;
+        self.apply(result)
+    }
+
+    fn __vld_optional_key(&self) -> bool {
+        self.inner.__vld_optional_key()
+    }
+}
@@ -0,0 +1,145 @@
+//! Request-scoped validation context.
+//!
+//! A context value (current user, tenant limits, feature flags, locale, ...)
+//! is installed for the duration of one parse via
+//! [`VldSchema::parse_with_context()`](crate::schema::VldSchema::parse_with_context)
+//! and read back by [`refine_ctx`](crate::schema::VldSchema::refine_ctx),
+//! [`super_refine_ctx`](crate::schema::VldSchema::super_refine_ctx) and
+//! [`transform_ctx`](crate::schema::VldSchema::transform_ctx).
+//!
+//! Because the context is scoped to the current call, it reaches every schema
+//! nested inside objects, arrays, records, `nested()` structs and
+//! `schema!`-generated types without any of them having to forward it.
+//!
+//! The context lives in a thread-local, so async parses install it with
+//! [`VldSchemaAsync::parse_with_context_async()`](crate::async_schema::VldSchemaAsync::parse_with_context_async)
+//! or [`with_context_async`], which put it back on every poll. That way it
+//! survives `.await` points and moves with the future between worker threads.
+//!
+//! # Example
+//! ```
+//! use vld::prelude::*;
+//!
+//! #[derive(Clone)]
+//! struct Tenant {
+//!     max_tags: usize,
+//! }
+//!
+//! let tags = vld::array(vld::string())
+//!     .refine_ctx(|tags, t: &Tenant| tags.len() <= t.max_tags, "Too many tags");
+//!
+//! let free = Tenant { max_tags: 1 };
+//! let premium = Tenant { max_tags: 10 };
+//! assert!(tags.parse_with_context(r#"["a", "b"]"#, &free).is_err());
+//! assert!(tags.parse_with_context(r#"["a", "b"]"#, &premium).is_ok());
+//! ```
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::{IssueCode, VldError};
+
+thread_local! {
+    static CONTEXT_STACK: RefCell<Vec<Rc<dyn Any>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Pops the context pushed by [`with_context`], even if the parse panics.
+struct ContextGuard;
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        CONTEXT_STACK.with(|stack| {
+            stack.borrow_mut().pop();
+        });
+    }
+}
+
+/// Run `f` with `ctx` installed as the current validation context.
+///
+/// Contexts nest: an inner `with_context` shadows outer contexts of the same
+/// type while leaving contexts of other types visible.
+pub fn with_context<C: Clone + 'static, R>(ctx: &C, f: impl FnOnce() -> R) -> R {
+    CONTEXT_STACK.with(|stack| stack.borrow_mut().push(Rc::new(ctx.clone())));
+    let _guard = ContextGuard;
+    f()
+}
+
+/// Future returned by [`with_context_async`].
+///
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub struct WithContext<C, F: std::future::Future> {
+    ctx: C,
+    fut: std::pin::Pin<Box<F>>,
+}
+
+// The context is never pinned, only the boxed future.
+#[cfg(feature = "async")]
+impl<C, F: std::future::Future> Unpin for WithContext<C, F> {}
+
+#[cfg(feature = "async")]
+impl<C: Clone + 'static, F: std::future::Future> std::future::Future for WithContext<C, F> {
+    type Output = F::Output;
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<F::Output> {
+        let this = self.get_mut();
+        with_context(&this.ctx, || this.fut.as_mut().poll(cx))
+    }
+}
+
+/// Run `fut` with `ctx` installed as the current validation context.
+///
+/// The context is installed around every poll of `fut`, so it stays visible
+/// across `.await` points and on multi-threaded runtimes. Use it to wrap
+/// async parses that have no `*_with_context_async` method, e.g. the
+/// `parse_async()` of a [`schema!`](crate::schema!) struct.
+///
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub fn with_context_async<C: Clone + 'static, F: std::future::Future>(
+    ctx: &C,
+    fut: F,
+) -> WithContext<C, F> {
+    WithContext {
+        ctx: ctx.clone(),
+        fut: Box::pin(fut),
+    }
+}
+
+/// Call `f` with the innermost context of type `C`, or `None` if there is none.
+///
+/// Useful for custom [`VldSchema`](crate::schema::VldSchema) implementations that
+/// need request-scoped data.
+pub fn with_current<C: 'static, R>(f: impl FnOnce(Option<&C>) -> R) -> R {
+    let found = CONTEXT_STACK.with(|stack| {
+        stack
+            .borrow()
+            .iter()
+            .rev()
+            .find(|ctx| ctx.is::<C>())
+            .cloned()
+    });
+    f(found.as_deref().and_then(|ctx| ctx.downcast_ref::<C>()))
+}
+
+/// Returns `true` if a context of type `C` is currently installed.
+pub fn has_context<C: 'static>() -> bool {
+    with_current::<C, _>(|ctx| ctx.is_some())
+}
+
+/// Issue reported when a context-aware schema runs without its context.
+pub(crate) fn missing_context_error<C>() -> VldError {
+    VldError::single(
+        IssueCode::Custom {
+            code: "missing_context".to_string(),
+        },
+        format!(
+            "Validation context `{}` is not available, use parse_with_context()",
+            std::any::type_name::<C>()
+        ),
+    )
+}
@@ -0,0 +1,335 @@
+//! One-pass parsing driven by a [`serde::Deserializer`].
+//!
+//! [`VldSchema::parse()`](crate::schema::VldSchema::parse) first turns the input
+//! into a `serde_json::Value` tree and then walks it. The methods below skip that
+//! intermediate tree and validate while deserializing:
+//!
+//! - [`VldSchema::parse_json_str()`](crate::schema::VldSchema::parse_json_str) /
+//!   [`parse_json_slice()`](crate::schema::VldSchema::parse_json_slice) — JSON text,
+//! - [`VldSchema::parse_deserializer()`](crate::schema::VldSchema::parse_deserializer) —
+//!   any self-describing serde format,
+//! - `parse_json_str()` / `parse_deserializer()` on `schema!` structs.
+//!
+//! Arrays, `schema!` structs, [`vld::nested!`](crate::nested!) fields and the
+//! `optional` / `nullable` / `nullish` / `with_default` / `refine` /
+//! `super_refine` / `transform` / `describe` wrappers are validated in a single
+//! pass. Every other schema buffers only its own subtree into a `Value` and runs
+//! [`parse_value()`](crate::schema::VldSchema::parse_value) on it, so results and
+//! issues are the same on both paths.
+//!
+//! # Example
+//! ```
+//! use vld::prelude::*;
+//!
+//! let schema = vld::array(vld::number().int().positive());
+//!
+//! assert_eq!(schema.parse_json_str("[1, 2, 3]").unwrap(), vec![1, 2, 3]);
+//!
+//! let err = schema.parse_json_str("[1, -2, 3]").unwrap_err();
+//! assert_eq!(err.issues[0].path[0].to_string(), "[1]");
+//! ```
+//!
+//! # Custom schemas
+//!
+//! Override [`VldSchema::deserialize_with()`](crate::schema::VldSchema::deserialize_with)
+//! and use [`deserialize_any()`] with a [`DirectVisitor`] to stream the kinds of
+//! values your schema cares about.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{
+    self, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::error::{IssueCode, VldError};
+use crate::schema::{ParseVia, VldParse, VldSchema};
+
+/// Result of a one-pass parse.
+///
+/// The outer `Err` is a deserializer error (malformed input); the inner
+/// `Err` holds validation issues.
+pub type DeResult<T, E> = Result<Result<T, VldError>, E>;
+
+/// A [`DeserializeSeed`] that validates with a schema while deserializing.
+///
+/// Useful inside hand-written visitors, e.g. with
+/// [`SeqAccess::next_element_seed`].
+pub struct SchemaSeed<'a, S>(pub &'a S);
+
+impl<'de, S: VldSchema> DeserializeSeed<'de> for SchemaSeed<'_, S> {
+    type Value = Result<S::Output, VldError>;
+
+    fn deserialize<D: Deserializer<'de>>(self, de: D) -> Result<Self::Value, D::Error> {
+        self.0.deserialize_with(de)
+    }
+}
+
+/// Callbacks used by [`deserialize_any()`].
+///
+/// Implement [`visit_seq`](Self::visit_seq) and/or [`visit_map`](Self::visit_map)
+/// for the container kinds a schema streams. Everything else is handed to
+/// [`visit_value`](Self::visit_value) as a `serde_json::Value`.
+pub trait DirectVisitor<'de>: Sized {
+    type Output;
+
+    /// Validate a value this visitor does not stream (usually `schema.parse_value(&value)`).
+    fn visit_value(self, value: Value) -> Result<Self::Output, VldError>;
+
+    /// Validate an array. Buffers it into a `Value` by default.
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> DeResult<Self::Output, A::Error> {
+        let value = Value::deserialize(de::value::SeqAccessDeserializer::new(seq))?;
+        Ok(self.visit_value(value))
+    }
+
+    /// Validate an object. Buffers it into a `Value` by default.
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> DeResult<Self::Output, A::Error> {
+        let value = Value::deserialize(de::value::MapAccessDeserializer::new(map))?;
+        Ok(self.visit_value(value))
+    }
+}
+
+/// Drive `visitor` with whatever value `de` contains.
+///
+/// Requires a self-describing format (JSON, YAML, TOML, MessagePack, CBOR, ...).
+pub fn deserialize_any<'de, D, V>(de: D, visitor: V) -> DeResult<V::Output, D::Error>
+where
+    D: Deserializer<'de>,
+    V: DirectVisitor<'de>,
+{
+    de.deserialize_any(AnyVisitor(visitor))
+}
+
+struct AnyVisitor<V>(V);
+
+impl<'de, V: DirectVisitor<'de>> Visitor<'de> for AnyVisitor<V> {
+    type Value = Result<V::Output, VldError>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("any value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(self.0.visit_value(Value::Bool(v)))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(self.0.visit_value(Value::from(v)))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(self.0.visit_value(Value::from(v)))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(self.0.visit_value(Value::from(v)))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(self.0.visit_value(Value::String(v.to_owned())))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(self.0.visit_value(Value::String(v)))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(self.0.visit_value(Value::Null))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(self.0.visit_value(Value::Null))
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, de: D) -> Result<Self::Value, D::Error> {
+        deserialize_any(de, self.0)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        self.0.visit_seq(seq)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.0.visit_map(map)
+    }
+}
+
+/// Parse a [`VldParse`] type (e.g. a `schema!` struct) from a JSON string in one pass.
+pub fn parse_json_str<T: VldParse>(json: &str) -> Result<T, VldError> {
+    from_json(serde_json::Deserializer::from_str(json), |de| {
+        T::vld_deserialize(de)
+    })
+}
+
+/// Parse a [`VldParse`] type from JSON bytes in one pass.
+pub fn parse_json_slice<T: VldParse>(json: &[u8]) -> Result<T, VldError> {
+    from_json(serde_json::Deserializer::from_slice(json), |de| {
+        T::vld_deserialize(de)
+    })
+}
+
+/// Parse a [`VldParse`] type from any self-describing serde deserializer in one pass.
+pub fn parse_deserializer<'de, T: VldParse, D: Deserializer<'de>>(de: D) -> Result<T, VldError> {
+    T::vld_deserialize(de).unwrap_or_else(|e| Err(input_error(e)))
+}
+
+/// Deserialize a value that may be `null`: `null` (or a missing value in
+/// formats that have one) becomes `None`, anything else goes to `inner`.
+pub(crate) fn deserialize_nullable<'de, D, S>(
+    de: D,
+    inner: &S,
+) -> DeResult<Option<S::Output>, D::Error>
+where
+    D: Deserializer<'de>,
+    S: VldSchema,
+{
+    de.deserialize_option(NullableVisitor(inner))
+}
+
+struct NullableVisitor<'a, S>(&'a S);
+
+impl<'de, S: VldSchema> NullableVisitor<'_, S> {
+    fn forward<D: Deserializer<'de>>(
+        self,
+        de: D,
+    ) -> Result<Result<Option<S::Output>, VldError>, D::Error> {
+        Ok(self.0.deserialize_with(de)?.map(Some))
+    }
+}
+
+impl<'de, S: VldSchema> Visitor<'de> for NullableVisitor<'_, S> {
+    type Value = Result<Option<S::Output>, VldError>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("any value")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Ok(None))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Ok(None))
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, de: D) -> Result<Self::Value, D::Error> {
+        self.forward(de)
+    }
+
+    // Formats that do not distinguish options hand the value over directly.
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        self.forward(v.into_deserializer())
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        self.forward(v.into_deserializer())
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.forward(v.into_deserializer())
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        self.forward(v.into_deserializer())
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        self.forward(v.into_deserializer())
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        self.forward(v.into_deserializer())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        self.forward(de::value::SeqAccessDeserializer::new(seq))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.forward(de::value::MapAccessDeserializer::new(map))
+    }
+}
+
+/// Parse JSON text with `f`, rejecting trailing characters.
+pub(crate) fn from_json<'de, R, T>(
+    mut de: serde_json::Deserializer<R>,
+    f: impl FnOnce(&mut serde_json::Deserializer<R>) -> DeResult<T, serde_json::Error>,
+) -> Result<T, VldError>
+where
+    R: serde_json::de::Read<'de>,
+{
+    let parsed = f(&mut de).and_then(|r| de.end().map(|()| r));
+    parsed.unwrap_or_else(|e| Err(json_error(e)))
+}
+
+/// Issue for malformed JSON text, worded like [`VldInput`](crate::input::VldInput).
+pub(crate) fn json_error(e: serde_json::Error) -> VldError {
+    VldError::single(IssueCode::ParseError, format!("Invalid JSON: {}", e))
+}
+
+/// Issue for a deserializer error from an arbitrary format.
+pub(crate) fn input_error(e: impl fmt::Display) -> VldError {
+    VldError::single(IssueCode::ParseError, format!("Invalid input: {}", e))
+}
+
+// ---------------------------------------------------------------------------
+// Macro support
+// ---------------------------------------------------------------------------
+
+/// Type probe used by [`vld::nested!`](crate::nested!) to validate nested types
+/// that implement [`VldParse`] in one pass via [`ParseVia`].
+#[doc(hidden)]
+pub struct __VldNestedDe<T>(pub PhantomData<T>);
+
+#[doc(hidden)]
+pub trait __VldNestedDeViaParse<T> {
+    fn __vld_parse_fn(&self, f: fn(&Value) -> Result<T, VldError>) -> ParseVia<T>;
+}
+
+impl<T: VldParse> __VldNestedDeViaParse<T> for &__VldNestedDe<T> {
+    fn __vld_parse_fn(&self, f: fn(&Value) -> Result<T, VldError>) -> ParseVia<T> {
+        ParseVia::new(f)
+    }
+}
+
+#[doc(hidden)]
+pub trait __VldNestedDeFallback<T> {
+    fn __vld_parse_fn(
+        &self,
+        f: fn(&Value) -> Result<T, VldError>,
+    ) -> fn(&Value) -> Result<T, VldError>;
+}
+
+impl<T> __VldNestedDeFallback<T> for __VldNestedDe<T> {
+    fn __vld_parse_fn(
+        &self,
+        f: fn(&Value) -> Result<T, VldError>,
+    ) -> fn(&Value) -> Result<T, VldError> {
+        f
+    }
+}
+
+/// Streams a map into a [`schema!`](crate::schema!) struct. Generated per
+/// struct so the shared [`__VldStructVisitor`] works for generic structs too.
+#[doc(hidden)]
+pub trait __VldVisitMap: VldParse {
+    fn __vld_visit_map<'de, M: MapAccess<'de>>(map: M) -> DeResult<Self, M::Error>;
+}
+
+#[doc(hidden)]
+pub struct __VldStructVisitor<T>(pub PhantomData<T>);
+
+impl<'de, T: __VldVisitMap> DirectVisitor<'de> for __VldStructVisitor<T> {
+    type Output = T;
+
+    fn visit_value(self, value: Value) -> Result<T, VldError> {
+        T::vld_parse_value(&value)
+    }
+
+    fn visit_map<M: MapAccess<'de>>(self, map: M) -> DeResult<T, M::Error> {
+        T::__vld_visit_map(map)
+    }
+}
@@ -75,17 +75,42 @@ pub enum StringValidation {
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
 pub enum IssueCode {
-    InvalidType { expected: String, received: String },
-    TooSmall { minimum: f64, inclusive: bool },
-    TooBig { maximum: f64, inclusive: bool },
-    InvalidString { validation: StringValidation },
+    InvalidType {
+        expected: String,
+        received: String,
+    },
+    TooSmall {
+        minimum: f64,
+        inclusive: bool,
+    },
+    TooBig {
+        maximum: f64,
+        inclusive: bool,
+    },
+    InvalidString {
+        validation: StringValidation,
+    },
     NotInt,
     NotFinite,
     MissingField,
     UnrecognizedField,
     IoError,
     ParseError,
-    Custom { code: String },
+    /// A field required by a condition on `fields` is missing.
+    RequiredIf {
+        fields: Vec<String>,
+    },
+    /// A field required when `fields` are present is missing.
+    RequiredWith {
+        fields: Vec<String>,
+    },
+    /// A field is present together with `fields`, which exclude it.
+    ExcludedWith {
+        fields: Vec<String>,
+    },
+    Custom {
+        code: String,
+    },
 }
 
 /// A single validation issue with path, message and received value.
@@ -98,6 +123,103 @@ pub struct ValidationIssue {
     pub path: Vec<PathSegment>,
     /// The value that was received (if available).
     pub received: Option<serde_json::Value>,
+    /// Location of the offending value in the source text; read it with
+    /// [`span()`](Self::span).
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub(crate) span: Option<Span>,
+}
+
+impl ValidationIssue {
+    /// Create an issue at the root path, without a received value or span.
+    pub fn new(code: IssueCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            path: vec![],
+            received: None,
+            span: None,
+        }
+    }
+
+    /// Set the path of the offending value.
+    pub fn with_path(mut self, path: Vec<PathSegment>) -> Self {
+        self.path = path;
+        self
+    }
+
+    /// Attach the received value.
+    pub fn with_received(mut self, value: serde_json::Value) -> Self {
+        self.received = Some(value);
+        self
+    }
+
+    /// Attach the location of the offending value in the source text.
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /// Location of the offending value in the source text.
+    ///
+    /// Only set when parsing with span tracking
+    /// ([`VldSchema::parse_with_spans()`](crate::schema::VldSchema::parse_with_spans))
+    /// or after [`VldError::with_source()`].
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+}
+
+/// A region of the source text: byte offsets plus the 1-based line and
+/// column (in characters) of its start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct Span {
+    /// Byte offset of the first byte.
+    pub start: usize,
+    /// Byte offset one past the last byte.
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// A field of a validated struct, as a typed key.
+///
+/// Implemented by the field enums that `schema!` and `#[derive(Validate)]`
+/// generate on request; see [`VldError::for_field()`].
+pub trait FieldKey: Copy {
+    /// The JSON key the field is read from.
+    fn key(self) -> &'static str;
+
+    /// Other keys the field accepts (`#[serde(alias = "...")]`).
+    fn aliases(self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+/// Whether two field names are equal, usable in constants. Checks the
+/// `field => Variant` list of `#[field_errors(...)]` on a `schema!` struct.
+#[doc(hidden)]
+pub const fn __same_field(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
 }
 
 /// Collection of validation errors.
@@ -119,12 +241,7 @@ impl VldError {
     /// Create an error with a single issue (no received value).
     pub fn single(code: IssueCode, message: impl Into<String>) -> Self {
         Self {
-            issues: vec![ValidationIssue {
-                code,
-                message: message.into(),
-                path: vec![],
-                received: None,
-            }],
+            issues: vec![ValidationIssue::new(code, message)],
         }
     }
 
@@ -140,6 +257,7 @@ impl VldError {
                 message: message.into(),
                 path: vec![],
                 received: Some(truncate_value(received)),
+                span: None,
             }],
         }
     }
@@ -152,6 +270,23 @@ impl VldError {
         self
     }
 
+    /// Attach source spans to every issue that has none, resolving each
+    /// issue path against the JSON `source` it was parsed from.
+    ///
+    /// Issues whose path does not exist in `source` (e.g. a missing field)
+    /// point at the nearest enclosing value. Does nothing if `source` is not
+    /// valid JSON. See [`vld::source`](crate::source).
+    pub fn with_source(mut self, source: &str) -> Self {
+        if let Some(map) = crate::source::SourceMap::new(source) {
+            for issue in &mut self.issues {
+                if issue.span.is_none() {
+                    issue.span = map.span(&issue.path);
+                }
+            }
+        }
+        self
+    }
+
     /// Merge another error's issues into this one.
     pub fn merge(mut self, other: VldError) -> Self {
         self.issues.extend(other.issues);
@@ -163,14 +298,28 @@ impl VldError {
         self.issues.is_empty()
     }
 
+    /// Issues reported under a field, i.e. whose path starts at its key (or
+    /// one of its aliases).
+    ///
+    /// Takes the typed field enum generated by `#[field_errors(...)]` on a
+    /// [`schema!`](crate::schema) struct or `#[vld(field_errors)]` on a
+    /// derived one.
+    pub fn for_field<F: FieldKey>(&self, field: F) -> Vec<&ValidationIssue> {
+        let (key, aliases) = (field.key(), field.aliases());
+        self.issues
+            .iter()
+            .filter(|issue| {
+                matches!(
+                    issue.path.first(),
+                    Some(PathSegment::Field(name)) if name == key || aliases.contains(&name.as_str())
+                )
+            })
+            .collect()
+    }
+
     /// Push a single issue (no received value).
     pub fn push(&mut self, code: IssueCode, message: impl Into<String>) {
-        self.issues.push(ValidationIssue {
-            code,
-            message: message.into(),
-            path: vec![],
-            received: None,
-        });
+        self.issues.push(ValidationIssue::new(code, message));
     }
 
     /// Push a single issue with the received value.
@@ -185,6 +334,7 @@ impl VldError {
             message: message.into(),
             path: vec![],
             received: Some(truncate_value(received)),
+            span: None,
         });
     }
 }
@@ -251,6 +401,7 @@ impl<'a> IssueBuilder<'a> {
             message: msg,
             path: self.path,
             received: self.received,
+            span: None,
         });
     }
 }
@@ -324,6 +475,9 @@ impl IssueCode {
             IssueCode::UnrecognizedField => "unrecognized_field",
             IssueCode::IoError => "io_error",
             IssueCode::ParseError => "parse_error",
+            IssueCode::RequiredIf { .. } => "required_if",
+            IssueCode::RequiredWith { .. } => "required_with",
+            IssueCode::ExcludedWith { .. } => "excluded_with",
             IssueCode::Custom { code } => code,
         }
     }
@@ -354,6 +508,9 @@ impl IssueCode {
             IssueCode::InvalidString { validation } => {
                 vec![("validation", format!("{:?}", validation))]
             }
+            IssueCode::RequiredIf { fields }
+            | IssueCode::RequiredWith { fields }
+            | IssueCode::ExcludedWith { fields } => vec![("fields", fields.join(", "))],
             _ => vec![],
         }
     }
@@ -407,9 +564,44 @@ pub struct FieldResult {
     /// `Ok(json_value)` if the field is valid (output serialized to JSON),
     /// `Err(error)` if validation failed.
     pub result: Result<serde_json::Value, VldError>,
+    /// Results of the fields of a nested struct, or of the elements of an
+    /// array (named by index: `"0"`, `"1"`, ...). Empty for leaf fields.
+    ///
+    /// Filled by lenient parsing through [`vld::nested!`](crate::nested!),
+    /// arrays and optionals, so a form can keep every valid nested value and
+    /// highlight only the broken leaves.
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Vec::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub(crate) children: Vec<FieldResult>,
 }
 
 impl FieldResult {
+    /// Create a result without children.
+    pub fn new(
+        name: impl Into<String>,
+        input: serde_json::Value,
+        result: Result<serde_json::Value, VldError>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            input,
+            result,
+            children: Vec::new(),
+        }
+    }
+
+    /// Set the results of nested fields or array elements.
+    pub fn with_children(mut self, children: Vec<FieldResult>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Results of the fields of a nested struct, or of the elements of an
+    /// array (named by index). Empty for leaf fields.
+    pub fn children(&self) -> &[FieldResult] {
+        &self.children
+    }
+
     /// Whether this field passed validation.
     pub fn is_ok(&self) -> bool {
         self.result.is_ok()
@@ -419,6 +611,58 @@ impl FieldResult {
     pub fn is_err(&self) -> bool {
         self.result.is_err()
     }
+
+    /// Get a child result by field name or array index (`"0"`, `"1"`, ...).
+    pub fn child(&self, name: &str) -> Option<&FieldResult> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    /// Follow a path of child names, e.g. `&["items", "2", "sku"]`.
+    pub fn descendant(&self, path: &[&str]) -> Option<&FieldResult> {
+        path.iter().try_fold(self, |field, name| field.child(name))
+    }
+
+    /// The failing leaf results below this one (this result itself if it
+    /// failed and has no children).
+    pub fn error_leaves(&self) -> Vec<&FieldResult> {
+        let mut out = Vec::new();
+        self.collect_error_leaves(&mut out);
+        out
+    }
+
+    fn collect_error_leaves<'a>(&'a self, out: &mut Vec<&'a FieldResult>) {
+        if self.children.is_empty() {
+            if self.is_err() {
+                out.push(self);
+            }
+        } else {
+            for child in &self.children {
+                child.collect_error_leaves(out);
+            }
+        }
+    }
+}
+
+/// Replace the `Ok` values of `children` (recursively) with the matching
+/// parts of `output`, the serialized parent value. Used by the generated
+/// `parse_lenient()` so that array elements report their parsed output.
+#[doc(hidden)]
+pub fn __fill_child_outputs(children: &mut [FieldResult], output: &serde_json::Value) {
+    for child in children {
+        let part = match output {
+            serde_json::Value::Array(items) => {
+                child.name.parse::<usize>().ok().and_then(|i| items.get(i))
+            }
+            serde_json::Value::Object(map) => map.get(&child.name),
+            _ => None,
+        };
+        if let Some(part) = part {
+            if child.result.is_ok() {
+                child.result = Ok(part.clone());
+            }
+            __fill_child_outputs(&mut child.children, part);
+        }
+    }
 }
 
 impl std::fmt::Display for FieldResult {
@@ -450,7 +694,8 @@ impl std::fmt::Display for FieldResult {
 /// [`impl_validate_fields!`](crate::impl_validate_fields).
 ///
 /// The struct is always constructed — invalid fields fall back to
-/// `Default::default()`. You can inspect which fields passed/failed via
+/// `Default::default()`, while nested structs and arrays keep their valid
+/// parts (see [`FieldResult::children`]). You can inspect which fields passed/failed via
 /// [`fields()`](Self::fields), and save the result to a file at any time
 /// via `save_to_file()` (requires the `serialize` feature).
 #[derive(Debug)]
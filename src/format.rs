@@ -118,6 +118,9 @@ pub fn treeify_error(error: &VldError) -> ErrorTree {
 
 /// Format a `VldError` into a human-readable string.
 ///
+/// Issues that carry a source [`Span`](crate::error::Span) get an extra
+/// `--> line:column` line.
+///
 /// # Example output
 /// ```text
 /// ✖ String must be at least 2 characters
@@ -129,22 +132,106 @@ pub fn prettify_error(error: &VldError) -> String {
     let mut lines = Vec::new();
 
     for issue in &error.issues {
-        lines.push(format!("✖ {}", issue.message));
-        if !issue.path.is_empty() || issue.received.is_some() {
-            let mut parts = Vec::new();
-            if !issue.path.is_empty() {
-                let path_str: String = issue.path.iter().map(|p| p.to_string()).collect();
-                parts.push(format!("at {}", path_str));
-            }
-            if let Some(val) = &issue.received {
-                parts.push(format!(
-                    "received {}",
-                    crate::error::format_value_short(val)
-                ));
-            }
-            lines.push(format!("  → {}", parts.join(", ")));
+        push_issue_header(&mut lines, issue);
+        if let Some(span) = &issue.span {
+            lines.push(format!("  --> {}", span));
         }
     }
 
     lines.join("\n")
 }
+
+/// Like [`prettify_error`], but renders a code frame for every issue with a
+/// source [`Span`](crate::error::Span), pointing a caret at the offending value.
+///
+/// `source_name` is shown in the location line (usually the file name);
+/// `source` must be the text the error was produced from, e.g. by
+/// [`VldSchema::parse_with_spans()`](crate::schema::VldSchema::parse_with_spans).
+///
+/// # Example
+/// ```
+/// use vld::prelude::*;
+///
+/// let text = "{\n  \"name\": \"api\",\n  \"port\": 0\n}";
+/// let schema = vld::object()
+///     .field("name", vld::string())
+///     .field("port", vld::number().int().min(1));
+/// let err = schema.parse_with_spans(text).unwrap_err();
+///
+/// assert_eq!(
+///     prettify_error_with_source(&err, "config.json", text),
+///     "\
+/// ✖ Number must be at least 1
+///   → at .port, received 0
+///   --> config.json:3:11
+///   |
+/// 2 |   \"name\": \"api\",
+/// 3 |   \"port\": 0
+///   |           ^"
+/// );
+/// ```
+pub fn prettify_error_with_source(error: &VldError, source_name: &str, source: &str) -> String {
+    let mut lines = Vec::new();
+    let source_lines: Vec<&str> = source.lines().collect();
+
+    for issue in &error.issues {
+        push_issue_header(&mut lines, issue);
+        let Some(span) = &issue.span else { continue };
+        lines.push(format!("  --> {}:{}", source_name, span));
+
+        let Some(line) = source_lines.get(span.line - 1) else {
+            continue;
+        };
+        let first = if span.line > 1 {
+            span.line - 1
+        } else {
+            span.line
+        };
+        let width = span.line.to_string().len();
+        let gutter = " ".repeat(width);
+
+        lines.push(format!("{} |", gutter));
+        for n in first..=span.line {
+            lines.push(format!(
+                "{:>width$} | {}",
+                n,
+                source_lines[n - 1],
+                width = width
+            ));
+        }
+
+        // Keep tabs so the caret lines up with the source line.
+        let prefix: String = line
+            .chars()
+            .take(span.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let rest = line.chars().count().saturating_sub(span.column - 1);
+        let len = source
+            .get(span.start..span.end)
+            .map_or(1, |s| s.chars().count())
+            .min(rest)
+            .max(1);
+        lines.push(format!("{} | {}{}", gutter, prefix, "^".repeat(len)));
+    }
+
+    lines.join("\n")
+}
+
+fn push_issue_header(lines: &mut Vec<String>, issue: &crate::error::ValidationIssue) {
+    lines.push(format!("✖ {}", issue.message));
+    if !issue.path.is_empty() || issue.received.is_some() {
+        let mut parts = Vec::new();
+        if !issue.path.is_empty() {
+            let path_str: String = issue.path.iter().map(|p| p.to_string()).collect();
+            parts.push(format!("at {}", path_str));
+        }
+        if let Some(val) = &issue.received {
+            parts.push(format!(
+                "received {}",
+                crate::error::format_value_short(val)
+            ));
+        }
+        lines.push(format!("  → {}", parts.join(", ")));
+    }
+}
@@ -122,6 +122,7 @@ pub fn translate_issue(issue: &ValidationIssue, resolver: &dyn MessageResolver)
         message,
         path: issue.path.clone(),
         received: issue.received.clone(),
+        span: issue.span,
     }
 }
 
@@ -177,6 +178,18 @@ pub fn english() -> MapResolver {
     m.insert("not_finite".into(), "Number must be finite".into());
     m.insert("missing_field".into(), "Required field is missing".into());
     m.insert("unrecognized_field".into(), "Unrecognized field".into());
+    m.insert(
+        "required_if".into(),
+        "Required by the value of {fields}".into(),
+    );
+    m.insert(
+        "required_with".into(),
+        "Required when {fields} is present".into(),
+    );
+    m.insert(
+        "excluded_with".into(),
+        "Not allowed together with {fields}".into(),
+    );
     m.insert("parse_error".into(), "Failed to parse input".into());
     MapResolver::new(m)
 }
@@ -207,6 +220,18 @@ pub fn russian() -> MapResolver {
         "Обязательное поле отсутствует".into(),
     );
     m.insert("unrecognized_field".into(), "Неизвестное поле".into());
+    m.insert(
+        "required_if".into(),
+        "Обязательно при значении {fields}".into(),
+    );
+    m.insert(
+        "required_with".into(),
+        "Обязательно, если указано {fields}".into(),
+    );
+    m.insert(
+        "excluded_with".into(),
+        "Нельзя указывать вместе с {fields}".into(),
+    );
     m.insert("parse_error".into(), "Ошибка разбора входных данных".into());
     MapResolver::new(m)
 }
@@ -234,6 +259,18 @@ pub fn german() -> MapResolver {
     m.insert("not_finite".into(), "Zahl muss endlich sein".into());
     m.insert("missing_field".into(), "Pflichtfeld fehlt".into());
     m.insert("unrecognized_field".into(), "Unbekanntes Feld".into());
+    m.insert(
+        "required_if".into(),
+        "Pflichtfeld wegen des Werts von {fields}".into(),
+    );
+    m.insert(
+        "required_with".into(),
+        "Pflichtfeld, wenn {fields} angegeben ist".into(),
+    );
+    m.insert(
+        "excluded_with".into(),
+        "Nicht zusammen mit {fields} erlaubt".into(),
+    );
     m.insert(
         "parse_error".into(),
         "Eingabe konnte nicht verarbeitet werden".into(),
@@ -264,6 +301,18 @@ pub fn spanish() -> MapResolver {
     m.insert("not_finite".into(), "El número debe ser finito".into());
     m.insert("missing_field".into(), "Campo obligatorio faltante".into());
     m.insert("unrecognized_field".into(), "Campo no reconocido".into());
+    m.insert(
+        "required_if".into(),
+        "Obligatorio según el valor de {fields}".into(),
+    );
+    m.insert(
+        "required_with".into(),
+        "Obligatorio cuando {fields} está presente".into(),
+    );
+    m.insert(
+        "excluded_with".into(),
+        "No permitido junto con {fields}".into(),
+    );
     m.insert("parse_error".into(), "Error al procesar la entrada".into());
     MapResolver::new(m)
 }
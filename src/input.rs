@@ -7,15 +7,29 @@ use crate::error::{IssueCode, VldError};
 /// Implemented for JSON strings (`&str`, `String`), raw bytes (`&[u8]`),
 /// file paths (`Path`, `PathBuf` — requires the `std` feature), and
 /// `serde_json::Value`.
+///
+/// Other formats are available behind features as wrapper types:
+/// [`Yaml`] (`yaml`), [`Toml`] (`toml`), [`MsgPack`] (`msgpack`),
+/// [`Cbor`] (`cbor`) and [`UrlEncoded`] (`urlencoded`).
 pub trait VldInput {
     /// Convert this input into a `serde_json::Value`.
     fn to_json_value(&self) -> Result<Value, VldError>;
+
+    /// Borrow this input as a `serde_json::Value` if it already is one,
+    /// so parsing can skip the conversion.
+    fn as_json_value(&self) -> Option<&Value> {
+        None
+    }
 }
 
 impl VldInput for Value {
     fn to_json_value(&self) -> Result<Value, VldError> {
         Ok(self.clone())
     }
+
+    fn as_json_value(&self) -> Option<&Value> {
+        Some(self)
+    }
 }
 
 impl VldInput for str {
@@ -40,11 +54,25 @@ impl VldInput for [u8] {
 
 #[cfg(feature = "std")]
 impl VldInput for std::path::Path {
+    /// The decoder is picked from the file extension: `.yaml` / `.yml`,
+    /// `.toml`, `.msgpack` / `.mpk` and `.cbor` use the matching format when
+    /// its feature is enabled; anything else is read as JSON.
     fn to_json_value(&self) -> Result<Value, VldError> {
-        let content = std::fs::read_to_string(self).map_err(|e| {
-            VldError::single(IssueCode::IoError, format!("Failed to read file: {}", e))
-        })?;
-        content.as_str().to_json_value()
+        let ext = self
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_ascii_lowercase());
+        match ext.as_deref() {
+            #[cfg(feature = "yaml")]
+            Some("yaml" | "yml") => Yaml(&read_file_to_string(self)?).to_json_value(),
+            #[cfg(feature = "toml")]
+            Some("toml") => Toml(&read_file_to_string(self)?).to_json_value(),
+            #[cfg(feature = "msgpack")]
+            Some("msgpack" | "mpk") => MsgPack(&read_file(self)?).to_json_value(),
+            #[cfg(feature = "cbor")]
+            Some("cbor") => Cbor(&read_file(self)?).to_json_value(),
+            _ => read_file_to_string(self)?.as_str().to_json_value(),
+        }
     }
 }
 
@@ -54,3 +82,197 @@ impl VldInput for std::path::PathBuf {
         self.as_path().to_json_value()
     }
 }
+
+#[cfg(feature = "std")]
+fn read_file_to_string(path: &std::path::Path) -> Result<String, VldError> {
+    std::fs::read_to_string(path)
+        .map_err(|e| VldError::single(IssueCode::IoError, format!("Failed to read file: {}", e)))
+}
+
+#[cfg(all(feature = "std", any(feature = "msgpack", feature = "cbor")))]
+fn read_file(path: &std::path::Path) -> Result<Vec<u8>, VldError> {
+    std::fs::read(path)
+        .map_err(|e| VldError::single(IssueCode::IoError, format!("Failed to read file: {}", e)))
+}
+
+// ---------------------------------------------------------------------------
+// Feature-gated formats
+// ---------------------------------------------------------------------------
+
+/// YAML text input. Requires the `yaml` feature.
+///
+/// ```
+/// # #[cfg(feature = "yaml")] {
+/// use vld::prelude::*;
+///
+/// let tags = vld::array(vld::string()).parse(&vld::input::Yaml("- a\n- b\n")).unwrap();
+/// assert_eq!(tags, vec!["a", "b"]);
+/// # }
+/// ```
+#[cfg(feature = "yaml")]
+#[derive(Debug, Clone, Copy)]
+pub struct Yaml<'a>(pub &'a str);
+
+#[cfg(feature = "yaml")]
+impl VldInput for Yaml<'_> {
+    fn to_json_value(&self) -> Result<Value, VldError> {
+        // serde_yaml already appends "at line L column C" when it knows the position.
+        serde_yaml::from_str(self.0)
+            .map_err(|e| VldError::single(IssueCode::ParseError, format!("Invalid YAML: {}", e)))
+    }
+}
+
+/// TOML document input. Requires the `toml` feature.
+///
+/// Datetimes are converted to their RFC 3339 string form, so they can be
+/// validated with `vld::string()` or the date schemas.
+#[cfg(feature = "toml")]
+#[derive(Debug, Clone, Copy)]
+pub struct Toml<'a>(pub &'a str);
+
+#[cfg(feature = "toml")]
+impl VldInput for Toml<'_> {
+    fn to_json_value(&self) -> Result<Value, VldError> {
+        let table: toml::Table = self.0.parse().map_err(|e: toml::de::Error| {
+            let mut msg = format!("Invalid TOML: {}", e.message().trim().replace('\n', "; "));
+            if let Some(span) = e.span() {
+                let (line, column) = line_column(self.0, span.start);
+                msg.push_str(&format!(" at line {} column {}", line, column));
+            }
+            VldError::single(IssueCode::ParseError, msg)
+        })?;
+        Ok(toml_to_json(toml::Value::Table(table)))
+    }
+}
+
+#[cfg(feature = "toml")]
+fn toml_to_json(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(s) => Value::String(s),
+        toml::Value::Integer(i) => Value::from(i),
+        toml::Value::Float(f) => Value::from(f),
+        toml::Value::Boolean(b) => Value::Bool(b),
+        toml::Value::Datetime(dt) => Value::String(dt.to_string()),
+        toml::Value::Array(items) => Value::Array(items.into_iter().map(toml_to_json).collect()),
+        toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(k, v)| (k, toml_to_json(v)))
+                .collect(),
+        ),
+    }
+}
+
+/// 1-based line and column (in characters) of a byte offset in `src`.
+#[cfg(feature = "toml")]
+fn line_column(src: &str, offset: usize) -> (usize, usize) {
+    let before = &src[..offset.min(src.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+/// MessagePack input. Requires the `msgpack` feature.
+#[cfg(feature = "msgpack")]
+#[derive(Debug, Clone, Copy)]
+pub struct MsgPack<'a>(pub &'a [u8]);
+
+#[cfg(feature = "msgpack")]
+impl VldInput for MsgPack<'_> {
+    fn to_json_value(&self) -> Result<Value, VldError> {
+        rmp_serde::from_slice(self.0).map_err(|e| {
+            VldError::single(IssueCode::ParseError, format!("Invalid MessagePack: {}", e))
+        })
+    }
+}
+
+/// CBOR input. Requires the `cbor` feature.
+#[cfg(feature = "cbor")]
+#[derive(Debug, Clone, Copy)]
+pub struct Cbor<'a>(pub &'a [u8]);
+
+#[cfg(feature = "cbor")]
+impl VldInput for Cbor<'_> {
+    fn to_json_value(&self) -> Result<Value, VldError> {
+        use ciborium::de::Error;
+
+        ciborium::de::from_reader(self.0).map_err(|e: Error<std::io::Error>| {
+            let msg = match e {
+                Error::Io(e) => format!("Invalid CBOR: {}", e),
+                Error::Syntax(offset) => format!("Invalid CBOR: syntax error at byte {}", offset),
+                Error::Semantic(Some(offset), msg) => {
+                    format!("Invalid CBOR: {} at byte {}", msg, offset)
+                }
+                Error::Semantic(None, msg) => format!("Invalid CBOR: {}", msg),
+                Error::RecursionLimitExceeded => {
+                    "Invalid CBOR: recursion limit exceeded".to_string()
+                }
+            };
+            VldError::single(IssueCode::ParseError, msg)
+        })
+    }
+}
+
+/// `application/x-www-form-urlencoded` input. Requires the `urlencoded` feature.
+///
+/// Produces a flat object. Values are coerced the same way the HTTP
+/// integrations coerce query strings: `""` and `"null"` become `null`,
+/// `"true"` / `"false"` become booleans and numeric strings become numbers.
+/// A key that appears more than once becomes an array of its values.
+///
+/// ```
+/// # #[cfg(feature = "urlencoded")] {
+/// use vld::prelude::*;
+///
+/// let v = vld::input::UrlEncoded("name=Ann+Lee&age=30&tag=a&tag=b")
+///     .to_json_value()
+///     .unwrap();
+/// assert_eq!(v, serde_json::json!({"name": "Ann Lee", "age": 30, "tag": ["a", "b"]}));
+/// # }
+/// ```
+#[cfg(feature = "urlencoded")]
+#[derive(Debug, Clone, Copy)]
+pub struct UrlEncoded<'a>(pub &'a str);
+
+#[cfg(feature = "urlencoded")]
+impl VldInput for UrlEncoded<'_> {
+    fn to_json_value(&self) -> Result<Value, VldError> {
+        let mut map = serde_json::Map::new();
+        for (key, raw) in form_urlencoded::parse(self.0.trim_start_matches('?').as_bytes()) {
+            let value = coerce_form_value(&raw);
+            match map.get_mut(key.as_ref()) {
+                Some(Value::Array(items)) => items.push(value),
+                Some(existing) => {
+                    let first = existing.take();
+                    *existing = Value::Array(vec![first, value]);
+                }
+                None => {
+                    map.insert(key.into_owned(), value);
+                }
+            }
+        }
+        Ok(Value::Object(map))
+    }
+}
+
+#[cfg(feature = "urlencoded")]
+fn coerce_form_value(raw: &str) -> Value {
+    if raw.is_empty() || raw.eq_ignore_ascii_case("null") {
+        return Value::Null;
+    }
+    if raw.eq_ignore_ascii_case("true") {
+        return Value::Bool(true);
+    }
+    if raw.eq_ignore_ascii_case("false") {
+        return Value::Bool(false);
+    }
+    if let Ok(n) = raw.parse::<i64>() {
+        return Value::from(n);
+    }
+    if let Ok(f) = raw.parse::<f64>() {
+        if let Some(n) = serde_json::Number::from_f64(f) {
+            return Value::Number(n);
+        }
+    }
+    Value::String(raw.to_string())
+}
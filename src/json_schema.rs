@@ -51,6 +51,99 @@ pub trait OpenApiParameterIn {
     }
 }
 
+/// A named type with its own JSON Schema: the `$ref` target other schemas
+/// point at.
+///
+/// Implemented by [`schema!`](crate::schema) and `#[derive(Validate)]` types.
+/// Generic types append their arguments to the name, so `Paginated<User>`
+/// is registered as the component `Paginated_User`.
+pub trait VldJsonSchema {
+    /// Component name under `#/components/schemas`.
+    fn vld_schema_name() -> String;
+
+    /// Full JSON Schema of the type.
+    fn vld_json_schema() -> Value;
+}
+
+/// Name of a generic instantiation: `Paginated` with `["User"]` is
+/// `Paginated_User`.
+#[doc(hidden)]
+pub fn __generic_schema_name(name: &str, args: &[String]) -> String {
+    let mut out = String::from(name);
+    for arg in args {
+        out.push('_');
+        out.push_str(arg);
+    }
+    out
+}
+
+/// Copy the `properties` and `required` keys of an object schema into the
+/// schema being built. Used for `#[serde(flatten)]` fields.
+#[doc(hidden)]
+pub fn __flatten_into(
+    schema: &serde_json::Value,
+    properties: &mut serde_json::Map<String, serde_json::Value>,
+    required: &mut Vec<String>,
+) {
+    if let Some(props) = schema.get("properties").and_then(|p| p.as_object()) {
+        for (key, value) in props {
+            properties.insert(key.clone(), value.clone());
+        }
+    }
+    if let Some(keys) = schema.get("required").and_then(|r| r.as_array()) {
+        required.extend(keys.iter().filter_map(|k| k.as_str()).map(String::from));
+    }
+}
+
+/// Leak `name` once and hand out the same `&'static str` for it afterwards.
+///
+/// Nested schemas keep `'static` component names; names of generic
+/// instantiations are only known at runtime, and there are finitely many.
+pub(crate) fn intern_schema_name(name: String) -> &'static str {
+    use std::collections::HashSet;
+    use std::sync::{Mutex, OnceLock};
+
+    static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+    let mut names = NAMES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if let Some(existing) = names.get(name.as_str()) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(name.into_boxed_str());
+    names.insert(leaked);
+    leaked
+}
+
+/// Type probe used by [`vld::nested!`](crate::nested!) to name the `$ref` of
+/// a [`VldJsonSchema`] type after the type itself rather than its spelling
+/// in the macro call (`Paginated_User`, not `Paginated<User>`).
+#[doc(hidden)]
+pub struct __VldSchemaName<T>(pub std::marker::PhantomData<T>);
+
+#[doc(hidden)]
+pub trait __VldSchemaNameViaTrait {
+    fn __vld_schema_name_fn(&self) -> Option<fn() -> String>;
+}
+
+impl<T: VldJsonSchema> __VldSchemaNameViaTrait for &__VldSchemaName<T> {
+    fn __vld_schema_name_fn(&self) -> Option<fn() -> String> {
+        Some(T::vld_schema_name)
+    }
+}
+
+#[doc(hidden)]
+pub trait __VldSchemaNameFallback {
+    fn __vld_schema_name_fn(&self) -> Option<fn() -> String>;
+}
+
+impl<T> __VldSchemaNameFallback for __VldSchemaName<T> {
+    fn __vld_schema_name_fn(&self) -> Option<fn() -> String> {
+        None
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Primitives
 // ---------------------------------------------------------------------------
@@ -220,6 +313,16 @@ impl<S: crate::schema::VldSchema + JsonSchema> JsonSchema for crate::modifiers::
     }
 }
 
+impl<S: crate::schema::VldSchema + JsonSchema> JsonSchema for crate::modifiers::ZPatch<S> {
+    fn json_schema(&self) -> Value {
+        // Nullable; objects also leave the key out of `required`.
+        let inner = self.inner_schema().json_schema();
+        serde_json::json!({
+            "oneOf": [inner, {"type": "null"}]
+        })
+    }
+}
+
 impl<S: crate::schema::VldSchema + JsonSchema> JsonSchema for crate::modifiers::ZDefault<S>
 where
     S::Output: Clone,
@@ -251,6 +354,54 @@ where
     }
 }
 
+impl<S: crate::schema::VldSchema + JsonSchema, C: 'static, F> JsonSchema
+    for crate::combinators::ZRefineCtx<S, C, F>
+where
+    F: Fn(&S::Output, &C) -> bool,
+{
+    fn json_schema(&self) -> Value {
+        self.inner_schema().json_schema()
+    }
+}
+
+impl<S: crate::schema::VldSchema + JsonSchema, C: 'static, F> JsonSchema
+    for crate::combinators::ZSuperRefineCtx<S, C, F>
+where
+    F: Fn(&S::Output, &C, &mut crate::error::VldError),
+{
+    fn json_schema(&self) -> Value {
+        self.inner_schema().json_schema()
+    }
+}
+
+impl<S: crate::schema::VldSchema + JsonSchema, C: 'static, F, U> JsonSchema
+    for crate::combinators::ZTransformCtx<S, C, F, U>
+where
+    F: Fn(S::Output, &C) -> U,
+{
+    fn json_schema(&self) -> Value {
+        self.inner_schema().json_schema()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<S: crate::schema::VldSchema + JsonSchema, F> JsonSchema
+    for crate::combinators::ZRefineAsync<S, F>
+{
+    fn json_schema(&self) -> Value {
+        self.inner.json_schema()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<S: crate::schema::VldSchema + JsonSchema, F> JsonSchema
+    for crate::combinators::ZSuperRefineAsync<S, F>
+{
+    fn json_schema(&self) -> Value {
+        self.inner.json_schema()
+    }
+}
+
 impl<S: crate::schema::VldSchema + JsonSchema, F, U> JsonSchema
     for crate::combinators::ZTransform<S, F, U>
 where
@@ -320,10 +471,10 @@ where
 
 impl<T, F> JsonSchema for crate::schema::NestedSchema<T, F>
 where
-    F: Fn(&serde_json::Value) -> Result<T, crate::error::VldError>,
+    F: crate::schema::NestedParseFn<T>,
 {
     fn json_schema(&self) -> Value {
-        match self.name {
+        match self.name_fn.map(|f| f()).or(self.name.map(String::from)) {
             Some(name) => serde_json::json!({
                 "$ref": format!("#/components/schemas/{}", name)
             }),
@@ -337,7 +488,7 @@ where
 // Object (dynamic)
 // ---------------------------------------------------------------------------
 
-impl JsonSchema for crate::object::ZObject {
+impl<D: ?Sized + crate::object::DynStore> JsonSchema for crate::object::ZObject<D> {
     fn json_schema(&self) -> Value {
         self.to_json_schema()
     }
@@ -425,6 +576,14 @@ impl<S: crate::schema::VldSchema + CollectNestedSchemas> CollectNestedSchemas
     }
 }
 
+impl<S: crate::schema::VldSchema + CollectNestedSchemas> CollectNestedSchemas
+    for crate::modifiers::ZPatch<S>
+{
+    fn collect_nested_schemas(&self, out: &mut Vec<NestedSchemaEntry>) {
+        self.inner_schema().collect_nested_schemas(out);
+    }
+}
+
 impl<S: crate::schema::VldSchema + CollectNestedSchemas> CollectNestedSchemas
     for crate::modifiers::ZDefault<S>
 where
@@ -456,6 +615,54 @@ where
     }
 }
 
+impl<S: crate::schema::VldSchema + CollectNestedSchemas, C: 'static, F> CollectNestedSchemas
+    for crate::combinators::ZRefineCtx<S, C, F>
+where
+    F: Fn(&S::Output, &C) -> bool,
+{
+    fn collect_nested_schemas(&self, out: &mut Vec<NestedSchemaEntry>) {
+        self.inner_schema().collect_nested_schemas(out);
+    }
+}
+
+impl<S: crate::schema::VldSchema + CollectNestedSchemas, C: 'static, F> CollectNestedSchemas
+    for crate::combinators::ZSuperRefineCtx<S, C, F>
+where
+    F: Fn(&S::Output, &C, &mut crate::error::VldError),
+{
+    fn collect_nested_schemas(&self, out: &mut Vec<NestedSchemaEntry>) {
+        self.inner_schema().collect_nested_schemas(out);
+    }
+}
+
+impl<S: crate::schema::VldSchema + CollectNestedSchemas, C: 'static, F, U> CollectNestedSchemas
+    for crate::combinators::ZTransformCtx<S, C, F, U>
+where
+    F: Fn(S::Output, &C) -> U,
+{
+    fn collect_nested_schemas(&self, out: &mut Vec<NestedSchemaEntry>) {
+        self.inner_schema().collect_nested_schemas(out);
+    }
+}
+
+#[cfg(feature = "async")]
+impl<S: crate::schema::VldSchema + CollectNestedSchemas, F> CollectNestedSchemas
+    for crate::combinators::ZRefineAsync<S, F>
+{
+    fn collect_nested_schemas(&self, out: &mut Vec<NestedSchemaEntry>) {
+        self.inner.collect_nested_schemas(out);
+    }
+}
+
+#[cfg(feature = "async")]
+impl<S: crate::schema::VldSchema + CollectNestedSchemas, F> CollectNestedSchemas
+    for crate::combinators::ZSuperRefineAsync<S, F>
+{
+    fn collect_nested_schemas(&self, out: &mut Vec<NestedSchemaEntry>) {
+        self.inner.collect_nested_schemas(out);
+    }
+}
+
 impl<S: crate::schema::VldSchema + CollectNestedSchemas, F, U> CollectNestedSchemas
     for crate::combinators::ZTransform<S, F, U>
 where
@@ -512,17 +719,21 @@ where
 // NestedSchema — the key implementation.
 impl<T, F> CollectNestedSchemas for crate::schema::NestedSchema<T, F>
 where
-    F: Fn(&serde_json::Value) -> Result<T, crate::error::VldError>,
+    F: crate::schema::NestedParseFn<T>,
 {
     fn collect_nested_schemas(&self, out: &mut Vec<NestedSchemaEntry>) {
-        if let (Some(name), Some(f)) = (self.name, self.json_schema_fn) {
+        let name = match self.name_fn {
+            Some(name_fn) => Some(intern_schema_name(name_fn())),
+            None => self.name,
+        };
+        if let (Some(name), Some(f)) = (name, self.json_schema_fn) {
             out.push((name, f));
         }
     }
 }
 
 // ZObject — no nested schemas.
-impl CollectNestedSchemas for crate::object::ZObject {}
+impl<D: ?Sized> CollectNestedSchemas for crate::object::ZObject<D> {}
 
 // ---------------------------------------------------------------------------
 // Helpers
@@ -582,3 +793,92 @@ pub fn to_openapi_document_multi(schemas: &[(&str, Value)]) -> Value {
         }
     })
 }
+
+/// Build the JSON Schema of a `#[derive(Validate)]` enum.
+///
+/// `variants` holds each variant's serialized name and payload schema
+/// (`None` for unit variants). Tagged representations produce a `oneOf`
+/// with an OpenAPI `discriminator`; unit-only enums become a string `enum`.
+#[doc(hidden)]
+pub fn __enum_json_schema(
+    tag: Option<&str>,
+    content: Option<&str>,
+    untagged: bool,
+    variants: Vec<(&str, Option<Value>)>,
+) -> Value {
+    if untagged {
+        let one_of: Vec<Value> = variants
+            .into_iter()
+            .map(|(_, payload)| payload.unwrap_or_else(|| serde_json::json!({"type": "null"})))
+            .collect();
+        return serde_json::json!({ "oneOf": one_of });
+    }
+
+    let Some(tag) = tag else {
+        if variants.iter().all(|(_, payload)| payload.is_none()) {
+            let names: Vec<&str> = variants.iter().map(|(name, _)| *name).collect();
+            return serde_json::json!({"type": "string", "enum": names});
+        }
+        let one_of: Vec<Value> = variants
+            .into_iter()
+            .map(|(name, payload)| match payload {
+                None => serde_json::json!({"type": "string", "const": name}),
+                Some(payload) => serde_json::json!({
+                    "type": "object",
+                    "required": [name],
+                    "properties": { name: payload },
+                    "additionalProperties": false,
+                }),
+            })
+            .collect();
+        return serde_json::json!({ "oneOf": one_of });
+    };
+
+    let one_of: Vec<Value> = variants
+        .into_iter()
+        .map(|(name, payload)| {
+            let tag_schema = serde_json::json!({"type": "string", "const": name});
+            match (content, payload) {
+                (Some(content), Some(payload)) => serde_json::json!({
+                    "type": "object",
+                    "required": [tag, content],
+                    "properties": { tag: tag_schema, content: payload },
+                }),
+                (_, None) => serde_json::json!({
+                    "type": "object",
+                    "required": [tag],
+                    "properties": { tag: tag_schema },
+                }),
+                (None, Some(mut payload)) => {
+                    // Internally tagged: the tag lives next to the payload's fields.
+                    match payload.get_mut("properties").and_then(Value::as_object_mut) {
+                        Some(properties) => {
+                            properties.insert(tag.to_string(), tag_schema);
+                            let mut required = vec![Value::from(tag)];
+                            if let Some(Value::Array(rest)) = payload.get("required") {
+                                required.extend(rest.iter().cloned());
+                            }
+                            payload["required"] = Value::Array(required);
+                            payload
+                        }
+                        None => serde_json::json!({
+                            "allOf": [
+                                {
+                                    "type": "object",
+                                    "required": [tag],
+                                    "properties": { tag: tag_schema },
+                                },
+                                payload,
+                            ]
+                        }),
+                    }
+                }
+            }
+        })
+        .collect();
+
+    serde_json::json!({
+        "oneOf": one_of,
+        "discriminator": { "propertyName": tag },
+    })
+}
@@ -24,8 +24,14 @@
 //! assert_eq!(user.age, None);
 //! ```
 
+#[cfg(feature = "async")]
+pub mod async_schema;
+#[cfg(any(feature = "chrono", feature = "jiff", feature = "time"))]
+pub mod clock;
 pub mod collections;
 pub mod combinators;
+pub mod context;
+pub mod de;
 #[cfg(feature = "diff")]
 pub mod diff;
 pub mod error;
@@ -37,8 +43,15 @@ pub mod json_schema;
 mod macros;
 pub mod modifiers;
 pub mod object;
+pub mod options;
 pub mod primitives;
 pub mod schema;
+pub mod source;
+#[cfg(feature = "std")]
+pub mod stream;
+pub mod validated;
+
+pub use validated::{Validated, ValidatedTyped};
 
 // Re-export serde_json for use in macros
 #[doc(hidden)]
@@ -88,6 +101,47 @@ macro_rules! __vld_if_openapi {
     ($($tt:tt)*) => {};
 }
 
+/// Emit the given tokens only when the `async` feature is enabled on `vld`.
+#[cfg(feature = "async")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __vld_if_async {
+    ($($tt:tt)*) => { $($tt)* };
+}
+
+/// No-op: `async` feature is disabled.
+#[cfg(not(feature = "async"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __vld_if_async {
+    ($($tt:tt)*) => {};
+}
+
+/// Attach the async parse function of `$ty` to a nested schema.
+#[cfg(feature = "async")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __vld_nested_with_async {
+    ($schema:expr, $ty:ty) => {
+        $schema.with_async({
+            #[allow(unused_imports)]
+            use $crate::async_schema::{__VldNestedFallback as _, __VldNestedViaParse as _};
+            (&&$crate::async_schema::__VldNestedAsync::<$ty>(::std::marker::PhantomData))
+                .__vld_async_fn()
+        })
+    };
+}
+
+/// No-op: `async` feature is disabled.
+#[cfg(not(feature = "async"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __vld_nested_with_async {
+    ($schema:expr, $ty:ty) => {
+        $schema
+    };
+}
+
 #[cfg(feature = "openapi")]
 #[doc(hidden)]
 #[macro_export]
@@ -106,6 +160,27 @@ macro_rules! __vld_nested_schema_fn {
     };
 }
 
+#[cfg(feature = "openapi")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __vld_nested_name_fn {
+    ($ty:ty) => {{
+        #[allow(unused_imports)]
+        use $crate::json_schema::{__VldSchemaNameFallback as _, __VldSchemaNameViaTrait as _};
+        (&&$crate::json_schema::__VldSchemaName::<$ty>(::std::marker::PhantomData))
+            .__vld_schema_name_fn()
+    }};
+}
+
+#[cfg(not(feature = "openapi"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __vld_nested_name_fn {
+    ($ty:ty) => {
+        None
+    };
+}
+
 // Re-export regex_lite when the `regex` feature is enabled
 #[cfg(feature = "regex")]
 pub use regex_lite;
@@ -199,6 +274,35 @@ pub fn object() -> object::ZObject {
     object::ZObject::new()
 }
 
+/// Create a dynamic object schema for async validation.
+///
+/// Its field schemas must be `Send + Sync` and implement
+/// [`VldSchemaAsync`](async_schema::VldSchemaAsync); each one runs through
+/// its async implementation when the object is parsed with
+/// [`parse_async()`](async_schema::VldSchemaAsync::parse_async).
+///
+/// Requires the `async` feature.
+///
+/// # Example
+/// ```ignore
+/// use vld::prelude::*;
+///
+/// let signup = vld::object_async()
+///     .field("name", vld::string().min(2))
+///     .field("email", vld::string().email().refine_async(
+///         |email: &String| {
+///             let email = email.clone();
+///             async move { !db::email_taken(&email).await }
+///         },
+///         "Email is already taken",
+///     ));
+/// let value = signup.parse_async(r#"{"name": "Alex", "email": "a@b.com"}"#).await?;
+/// ```
+#[cfg(feature = "async")]
+pub fn object_async() -> object::ZObjectAsync {
+    object::ZObjectAsync::default()
+}
+
 /// Create a schema for nested/composed structs.
 pub fn nested<T, F>(f: F) -> schema::NestedSchema<T, F>
 where
@@ -218,6 +322,55 @@ where
     schema::NestedSchema::new_named(f, name, json_schema_fn)
 }
 
+/// Create a nested schema for a type parameter of a generic struct, such as
+/// the `T` of `Paginated<T>`.
+///
+/// [`nested!`] needs a concrete type; this goes through the
+/// [`VldTypeParam`](schema::VldTypeParam) bound instead, so the same field
+/// schema works for every instantiation and its `$ref` names the concrete
+/// type argument.
+///
+/// ```
+/// use vld::prelude::*;
+///
+/// vld::schema! {
+///     #[derive(Debug)]
+///     pub struct Tag {
+///         pub name: String => vld::string().min(1),
+///     }
+/// }
+///
+/// vld::schema! {
+///     #[derive(Debug)]
+///     pub struct Paginated<T> {
+///         pub items: Vec<T> => vld::array(vld::nested_param::<T>()),
+///         pub total: i64 => vld::number().int().non_negative(),
+///     }
+/// }
+///
+/// let page = Paginated::<Tag>::parse(r#"{"items": [{"name": "rust"}], "total": 1}"#).unwrap();
+/// assert_eq!(page.items[0].name, "rust");
+/// assert!(Paginated::<Tag>::parse(r#"{"items": [{"name": ""}], "total": 1}"#).is_err());
+/// ```
+pub fn nested_param<T: schema::VldTypeParam>() -> schema::NestedSchema<T, schema::ParseVia<T>> {
+    #[cfg(feature = "openapi")]
+    let nested = schema::NestedSchema::new_named(
+        schema::ParseVia::new(T::vld_parse_value),
+        std::any::type_name::<T>(),
+        Some(T::vld_json_schema as fn() -> serde_json::Value),
+    )
+    .__with_schema_name_fn(Some(T::vld_schema_name));
+    #[cfg(not(feature = "openapi"))]
+    let nested = schema::NestedSchema::new_named(
+        schema::ParseVia::new(T::vld_parse_value),
+        std::any::type_name::<T>(),
+        None,
+    );
+    #[cfg(feature = "async")]
+    let nested = nested.with_async(Some(T::vld_parse_value_async));
+    nested
+}
+
 /// Create a named nested schema with `$ref` generation and full JSON Schema.
 ///
 /// ```ignore
@@ -230,10 +383,35 @@ where
 #[macro_export]
 macro_rules! nested {
     ($ty:ty) => {
-        $crate::nested_named(
-            stringify!($ty),
-            <$ty>::parse_value,
-            $crate::__vld_nested_schema_fn!($ty),
+        $crate::__vld_nested_with_async!(
+            $crate::schema::NestedSchema::new_named(
+                {
+                    #[allow(unused_imports)]
+                    use $crate::de::{__VldNestedDeFallback as _, __VldNestedDeViaParse as _};
+                    (&&$crate::de::__VldNestedDe::<$ty>(::std::marker::PhantomData))
+                        .__vld_parse_fn(<$ty>::parse_value)
+                },
+                stringify!($ty),
+                $crate::__vld_nested_schema_fn!($ty),
+            )
+            .__with_schema_name_fn($crate::__vld_nested_name_fn!($ty))
+            .with_lenient({
+                #[allow(unused_imports)]
+                use $crate::schema::{
+                    __VldNestedLenientFallback as _, __VldNestedLenientViaTrait as _,
+                };
+                (&&$crate::schema::__VldNestedLenient::<$ty>(::std::marker::PhantomData))
+                    .__vld_lenient_fn()
+            })
+            .with_deep_partial({
+                #[allow(unused_imports)]
+                use $crate::schema::{
+                    __VldNestedDeepPartialFallback as _, __VldNestedDeepPartialViaTrait as _,
+                };
+                (&&$crate::schema::__VldNestedDeepPartial::<$ty>(::std::marker::PhantomData))
+                    .__vld_deep_partial_fn()
+            }),
+            $ty
         )
     };
 }
@@ -342,6 +520,33 @@ pub fn intersection<A: schema::VldSchema, B: schema::VldSchema>(
     combinators::ZIntersection::new(a, b)
 }
 
+/// Wrap a schema for partial (PATCH) updates.
+///
+/// The output is a [`Patch`](modifiers::Patch): `Absent` when the object key
+/// is missing, `Null` when it is `null`, and `Value` otherwise. Works as a
+/// field of `schema!`, `#[derive(Validate)]` and [`ZObject`](object::ZObject).
+///
+/// # Example
+/// ```
+/// use vld::prelude::*;
+///
+/// vld::schema! {
+///     struct UserPatch {
+///         nickname: Patch<String> => vld::patch(vld::string().min(2)),
+///     }
+/// }
+///
+/// assert_eq!(UserPatch::parse("{}").unwrap().nickname, Patch::Absent);
+/// assert_eq!(UserPatch::parse(r#"{"nickname": null}"#).unwrap().nickname, Patch::Null);
+/// assert_eq!(
+///     UserPatch::parse(r#"{"nickname": "al"}"#).unwrap().nickname,
+///     Patch::Value("al".to_string())
+/// );
+/// ```
+pub fn patch<T: schema::VldSchema>(schema: T) -> modifiers::ZPatch<T> {
+    modifiers::ZPatch::new(schema)
+}
+
 /// Create a discriminated union schema.
 ///
 /// Routes to the correct variant schema based on the value of a discriminator field.
@@ -349,6 +554,17 @@ pub fn discriminated_union(discriminator: impl Into<String>) -> combinators::ZDi
     combinators::ZDiscriminatedUnion::new(discriminator)
 }
 
+/// Create a discriminated union schema for async validation, like
+/// [`object_async()`] for objects.
+///
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub fn discriminated_union_async(
+    discriminator: impl Into<String>,
+) -> combinators::ZDiscriminatedUnionAsync {
+    combinators::ZDiscriminatedUnionAsync::with_discriminator(discriminator)
+}
+
 /// Create a lazy schema for recursive data structures.
 ///
 /// The factory function is called on each parse, enabling self-referencing schemas.
@@ -381,20 +597,30 @@ where
 
 /// Common imports for working with `vld`.
 pub mod prelude {
+    #[cfg(feature = "async")]
+    pub use crate::async_schema::VldSchemaAsync;
     pub use crate::collections::{ZArray, ZMap, ZRecord, ZSet};
     pub use crate::combinators::{
         Either, Either3, ZCatch, ZCustom, ZDescribe, ZDiscriminatedUnion, ZIntersection, ZLazy,
-        ZMessage, ZPipe, ZPreprocess, ZRefine, ZSuperRefine, ZTransform, ZUnion2, ZUnion3,
+        ZMessage, ZPipe, ZPreprocess, ZRefine, ZRefineCtx, ZSuperRefine, ZSuperRefineCtx,
+        ZTransform, ZTransformCtx, ZUnion2, ZUnion3,
     };
+    #[cfg(feature = "async")]
+    pub use crate::combinators::{ZDiscriminatedUnionAsync, ZRefineAsync, ZSuperRefineAsync};
     pub use crate::error::{
-        FieldResult, IssueBuilder, IssueCode, ParseResult, PathSegment, ValidationIssue, VldError,
+        FieldKey, FieldResult, IssueBuilder, IssueCode, ParseResult, PathSegment, ValidationIssue,
+        VldError,
+    };
+    pub use crate::format::{
+        flatten_error, prettify_error, prettify_error_with_source, treeify_error,
     };
-    pub use crate::format::{flatten_error, prettify_error, treeify_error};
     pub use crate::input::VldInput;
     #[cfg(feature = "openapi")]
     pub use crate::json_schema::JsonSchema;
-    pub use crate::modifiers::{ZDefault, ZNullable, ZNullish, ZOptional};
-    pub use crate::object::ZObject;
+    pub use crate::modifiers::{Patch, ZDefault, ZNullable, ZNullish, ZOptional, ZPatch};
+    #[cfg(feature = "async")]
+    pub use crate::object::ZObjectAsync;
+    pub use crate::object::{Condition, ZObject};
     #[cfg(feature = "decimal")]
     pub use crate::primitives::ZDecimal;
     #[cfg(feature = "net")]
@@ -409,5 +635,5 @@ pub mod prelude {
     pub use crate::primitives::{ZDate, ZDateTime};
     #[cfg(feature = "std")]
     pub use crate::primitives::{ZDuration, ZPath};
-    pub use crate::schema::{VldParse, VldSchema};
+    pub use crate::schema::{VldDeepPartial, VldParse, VldSchema};
 }
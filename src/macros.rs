@@ -12,14 +12,30 @@ macro_rules! __vld_resolve_key {
     };
 }
 
-/// Filter `#[into_params(...)]` off struct attributes, then emit attrs + items together.
+/// Helper macro for `schema!`: `true` if the struct has a `dependencies`
+/// block with at least one rule.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __vld_has_dependencies {
+    () => {
+        false
+    };
+    ($($dep_rule:ident)+) => {
+        true
+    };
+}
+
+/// Filter `#[into_params(...)]` off struct attributes, expand
+/// `#[field_errors(...)]` from the field list, then emit attrs + items together.
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __vld_struct_attrs {
     ( attrs: $(#[ $($attr:tt)* ])*
+      fields: { $($fields:tt)* }
       body: { $($body:tt)* }
     ) => {
         $crate::__vld_struct_attrs!(@filter [] $(#[ $($attr)* ])*
+            fields: { $($fields)* }
             body: { $($body)* }
         );
     };
@@ -27,26 +43,52 @@ macro_rules! __vld_struct_attrs {
         @filter [$($acc:tt)*]
         #[ into_params ( $($p:tt)* ) ]
         $(#[ $($rest:tt)* ])*
+        fields: { $($fields:tt)* }
         body: { $($body:tt)* }
     ) => {
         $crate::__vld_struct_attrs!(@filter [$($acc)*]
             $(#[ $($rest)* ])*
+            fields: { $($fields)* }
             body: { $($body)* }
         );
     };
+    (
+        @filter [$($acc:tt)*]
+        #[ field_errors (
+            $field_enum:ident, $error_enum:ident,
+            $( $map_field:ident => $variant:ident ),+ $(,)?
+        ) ]
+        $(#[ $($rest:tt)* ])*
+        fields: { $($fields:tt)* }
+        body: { $($body:tt)* }
+    ) => {
+        $crate::__vld_struct_attrs!(@filter [$($acc)*]
+            $(#[ $($rest)* ])*
+            fields: { $($fields)* }
+            body: {
+                $($body)*
+                $crate::__vld_field_errors! {
+                    $field_enum, $error_enum, [ $( $map_field => $variant ),+ ], $($fields)*
+                }
+            }
+        );
+    };
     (
         @filter [$($acc:tt)*]
         #[ $($attr:tt)* ]
         $(#[ $($rest:tt)* ])*
+        fields: { $($fields:tt)* }
         body: { $($body:tt)* }
     ) => {
         $crate::__vld_struct_attrs!(@filter [$($acc)* #[ $($attr)* ]]
             $(#[ $($rest)* ])*
+            fields: { $($fields)* }
             body: { $($body)* }
         );
     };
     (
         @filter [$($acc:tt)*]
+        fields: { $($fields:tt)* }
         body: { $($body:tt)* }
     ) => {
         $($acc)*
@@ -54,13 +96,113 @@ macro_rules! __vld_struct_attrs {
     };
 }
 
+/// Typed field and field-error enums for `#[field_errors(...)]` on a
+/// [`schema!`] struct. The `field => Variant` list is paired with the
+/// struct's fields, so it must name every field in declaration order.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __vld_field_errors {
+    (
+        $field_enum:ident, $error_enum:ident,
+        [ $( $map_field:ident => $variant:ident ),+ ],
+        $vis:vis $name:ident [ $( $field_name:ident $(as $rename:literal)? ),* ]
+    ) => {
+        $(
+            const _: () = ::std::assert!(
+                $crate::error::__same_field(stringify!($field_name), stringify!($map_field)),
+                concat!(
+                    "#[field_errors] on `", stringify!($name),
+                    "` must list every field in declaration order"
+                ),
+            );
+        )*
+
+        #[doc = concat!("The fields of [`", stringify!($name), "`], one variant per field.")]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $field_enum {
+            $( $variant, )*
+        }
+
+        impl $field_enum {
+            /// Every field, in declaration order.
+            pub const ALL: &'static [Self] = &[ $( Self::$variant ),* ];
+
+            /// The field read from this JSON key.
+            pub fn from_key(key: &str) -> ::std::option::Option<Self> {
+                $(
+                    if key == $crate::__vld_resolve_key!(stringify!($field_name) $(, $rename)?) {
+                        return ::std::option::Option::Some(Self::$variant);
+                    }
+                )*
+                ::std::option::Option::None
+            }
+        }
+
+        impl $crate::error::FieldKey for $field_enum {
+            fn key(self) -> &'static str {
+                match self {
+                    $( Self::$variant => $crate::__vld_resolve_key!(stringify!($field_name) $(, $rename)?), )*
+                }
+            }
+        }
+
+        #[doc = concat!("A validation issue of one of the fields of [`", stringify!($name), "`].")]
+        #[derive(Debug, Clone)]
+        $vis enum $error_enum {
+            $( $variant($crate::error::ValidationIssue), )*
+        }
+
+        impl $error_enum {
+            /// The field the issue is about.
+            pub fn field(&self) -> $field_enum {
+                match self {
+                    $( Self::$variant(_) => $field_enum::$variant, )*
+                }
+            }
+
+            /// The issue itself.
+            pub fn issue(&self) -> &$crate::error::ValidationIssue {
+                match self {
+                    $( Self::$variant(issue) => issue, )*
+                }
+            }
+
+            /// The issue as a field error, if its path starts at one of the fields.
+            pub fn from_issue(issue: &$crate::error::ValidationIssue) -> ::std::option::Option<Self> {
+                let key = match issue.path.first() {
+                    ::std::option::Option::Some($crate::error::PathSegment::Field(key)) => key,
+                    _ => return ::std::option::Option::None,
+                };
+                match $field_enum::from_key(key)? {
+                    $( $field_enum::$variant => ::std::option::Option::Some(Self::$variant(issue.clone())), )*
+                }
+            }
+
+            /// Every issue of `err` that belongs to a field. Struct-level
+            /// issues and unknown keys are left out.
+            pub fn from_error(err: &$crate::error::VldError) -> ::std::vec::Vec<Self> {
+                err.issues.iter().filter_map(Self::from_issue).collect()
+            }
+        }
+    };
+}
+
 /// Define a validated struct with field-level schemas.
 ///
 /// This macro generates:
 /// - A regular Rust struct with the specified fields and types
 /// - A `parse()` method that validates input and constructs the struct
 /// - A `parse_value()` method for direct `serde_json::Value` input
+/// - A `parse_with_context()` method that makes a validation context available to
+///   `refine_ctx` / `super_refine_ctx` / `transform_ctx` field schemas
+/// - A `parse_with_options()` method applying [`ParseOptions`](crate::options::ParseOptions) limits
+/// - `parse_json_str()` / `parse_deserializer()` methods that validate while deserializing,
+///   without an intermediate `serde_json::Value` (see [`vld::de`](crate::de))
 /// - An implementation of [`VldParse`](crate::schema::VldParse) for use with framework extractors
+/// - An implementation of [`VldDeepPartial`](crate::schema::VldDeepPartial), so that
+///   [`ZObject::deep_partial()`](crate::object::ZObject::deep_partial) recurses into the struct
+/// - With the `async` feature: `parse_async()` / `parse_value_async()`, which also run
+///   `refine_async` / `super_refine_async` checks on fields
 ///
 /// # Syntax
 ///
@@ -130,6 +272,113 @@ macro_rules! __vld_struct_attrs {
 /// assert_eq!(r.first_name, "John");
 /// ```
 ///
+/// # Struct-level rules
+///
+/// Checks that need several fields go in an optional `refine { ... }` block
+/// after the struct. Each rule is `"path" => check, "message";`, where
+/// `check` is a `Fn(&Self) -> bool` and `"path"` is the JSON key the issue is
+/// reported under (`_` reports it on the struct itself). Rules run only once
+/// every field is valid, and all failing rules are reported together:
+///
+/// ```
+/// use vld::prelude::*;
+///
+/// vld::schema! {
+///     #[derive(Debug)]
+///     pub struct Signup {
+///         pub password: String => vld::string().min(8),
+///         pub confirm: String => vld::string(),
+///     }
+///     refine {
+///         "confirm" => |s| s.password == s.confirm, "Passwords do not match";
+///     }
+/// }
+///
+/// let err = Signup::parse(r#"{"password": "hunter22", "confirm": "hunter2"}"#).unwrap_err();
+/// assert_eq!(err.to_string(), ".confirm: Passwords do not match");
+/// ```
+///
+/// # Dependency rules
+///
+/// An optional `dependencies { ... }` block after `refine` lists rules on
+/// which JSON keys must or must not appear together, each a
+/// [`Dependency`](crate::object::Dependency) constructor call:
+/// `required_if(key, condition)`, `required_with(key, [others])` and
+/// `excluded_with(key, [others])`. They run on the input object alongside
+/// the field schemas and appear in `json_schema()`:
+///
+/// ```
+/// use vld::prelude::*;
+///
+/// vld::schema! {
+///     #[derive(Debug)]
+///     pub struct Checkout {
+///         pub payment_method: String => vld::string(),
+///         pub billing_address: Option<String> => vld::string().optional(),
+///         pub coupon: Option<String> => vld::string().optional(),
+///         pub gift_card: Option<String> => vld::string().optional(),
+///     }
+///     dependencies {
+///         required_if("billing_address", Condition::field("payment_method").eq("card"));
+///         excluded_with("coupon", ["gift_card"]);
+///     }
+/// }
+///
+/// let err = Checkout::parse(r#"{"payment_method": "card"}"#).unwrap_err();
+/// assert_eq!(err.issues[0].code.key(), "required_if");
+/// ```
+///
+/// With dependency rules, `parse_json_str()` / `parse_deserializer()`
+/// buffer the input object instead of validating it in one pass.
+///
+/// # Typed field errors
+///
+/// `#[field_errors(FieldEnum, ErrorEnum, field => Variant, ...)]` on the
+/// struct also generates a `Copy` enum with one variant per field, for
+/// [`VldError::for_field()`](crate::error::VldError::for_field), and an enum
+/// pairing each variant with its [`ValidationIssue`](crate::error::ValidationIssue).
+/// Every field is given a variant name, in declaration order; a missing,
+/// misspelled or reordered field fails to compile:
+///
+/// ```
+/// use vld::prelude::*;
+///
+/// vld::schema! {
+///     #[derive(Debug)]
+///     #[field_errors(SignupField, SignupFieldError, email => Email, display_name => DisplayName)]
+///     pub struct Signup {
+///         pub email: String => vld::string().email(),
+///         pub display_name: String as "displayName" => vld::string().min(2),
+///     }
+/// }
+///
+/// let err = Signup::parse(r#"{"email": "nope", "displayName": "Al"}"#).unwrap_err();
+/// assert_eq!(err.for_field(SignupField::Email).len(), 1);
+/// assert!(err.for_field(SignupField::DisplayName).is_empty());
+///
+/// let errors = SignupFieldError::from_error(&err);
+/// assert!(matches!(errors[0], SignupFieldError::Email(_)));
+/// ```
+///
+/// # Generic Structs
+///
+/// Type parameters get a [`VldTypeParam`](crate::schema::VldTypeParam) bound;
+/// validate fields of a parameter type with
+/// [`nested_param()`](crate::nested_param). Each instantiation implements
+/// [`VldParse`](crate::schema::VldParse), and its JSON Schema component is
+/// named after the type arguments (`Paginated_User`):
+///
+/// ```ignore
+/// vld::schema! {
+///     pub struct Paginated<T> {
+///         pub items: Vec<T> => vld::array(vld::nested_param::<T>()),
+///         pub total: i64 => vld::number().int().non_negative(),
+///     }
+/// }
+///
+/// let page = Paginated::<User>::parse(r#"{"items": [], "total": 0}"#)?;
+/// ```
+///
 /// # Nested Structs
 ///
 /// Use [`nested()`](crate::nested) to compose schemas:
@@ -152,39 +401,99 @@ macro_rules! __vld_struct_attrs {
 macro_rules! schema {
     (
         $(#[ $($struct_attr:tt)* ])*
-        $vis:vis struct $name:ident {
+        $vis:vis struct $name:ident $(< $($gen:ident),+ $(,)? >)? {
             $(
                 $(#[$field_meta:meta])*
                 $field_vis:vis $field_name:ident : $field_type:ty $(as $rename:literal)? => $schema:expr
             ),* $(,)?
         }
+        $(
+            refine {
+                $( $rule_path:tt => $rule_check:expr, $rule_message:expr );* $(;)?
+            }
+        )?
+        $(
+            dependencies {
+                $( $dep_rule:ident ( $($dep_arg:tt)* ) );* $(;)?
+            }
+        )?
     ) => {
         $crate::__vld_struct_attrs! {
             attrs: $(#[ $($struct_attr)* ])*
+            fields: { $vis $name [ $( $field_name $(as $rename)? ),* ] }
             body: {
-        $vis struct $name {
+        $vis struct $name $(<$($gen),+>)? {
             $(
                 $(#[$field_meta])*
                 $field_vis $field_name: $field_type,
             )*
         }
 
-        impl $name {
+        impl $(<$($gen: $crate::schema::VldTypeParam),+>)? $name $(<$($gen),+>)? {
             /// Parse and validate input data into this struct.
             ///
             /// Accepts any type implementing [`VldInput`]: JSON strings, file paths,
             /// `serde_json::Value`, byte slices, etc.
             pub fn parse<__VldInputT: $crate::input::VldInput + ?Sized>(
                 input: &__VldInputT,
-            ) -> ::std::result::Result<$name, $crate::error::VldError> {
+            ) -> ::std::result::Result<Self, $crate::error::VldError> {
                 let __vld_json = <__VldInputT as $crate::input::VldInput>::to_json_value(input)?;
                 Self::parse_value(&__vld_json)
             }
 
+            /// Parse and validate input data with a request-scoped validation context.
+            ///
+            /// The context is visible to `refine_ctx` / `super_refine_ctx` /
+            /// `transform_ctx` on every field, including nested structs.
+            pub fn parse_with_context<
+                __VldInputT: $crate::input::VldInput + ?Sized,
+                __VldCtxT: ::std::clone::Clone + 'static,
+            >(
+                input: &__VldInputT,
+                ctx: &__VldCtxT,
+            ) -> ::std::result::Result<Self, $crate::error::VldError> {
+                let __vld_json = <__VldInputT as $crate::input::VldInput>::to_json_value(input)?;
+                $crate::context::with_context(ctx, || Self::parse_value(&__vld_json))
+            }
+
+            /// Parse and validate input data with
+            /// [`ParseOptions`]($crate::options::ParseOptions) limits.
+            pub fn parse_with_options<__VldInputT: $crate::input::VldInput + ?Sized>(
+                input: &__VldInputT,
+                options: &$crate::options::ParseOptions,
+            ) -> ::std::result::Result<Self, $crate::error::VldError> {
+                let __vld_json = <__VldInputT as $crate::input::VldInput>::to_json_value(input)?;
+                <Self as $crate::schema::VldParse>::vld_parse_value_with_options(&__vld_json, options)
+            }
+
+            /// Parse and validate JSON text, attaching a source span
+            /// (line/column) to every issue. See [`vld::source`]($crate::source).
+            pub fn parse_with_spans(
+                text: &str,
+            ) -> ::std::result::Result<Self, $crate::error::VldError> {
+                $crate::source::parse_with_spans(text)
+            }
+
+            /// Parse and validate a JSON string in one pass, without building
+            /// a `serde_json::Value` first. See [`vld::de`]($crate::de).
+            pub fn parse_json_str(
+                json: &str,
+            ) -> ::std::result::Result<Self, $crate::error::VldError> {
+                $crate::de::parse_json_str(json)
+            }
+
+            /// Parse and validate from any self-describing serde `Deserializer`
+            /// in one pass. See [`vld::de`]($crate::de).
+            pub fn parse_deserializer<'de, __VldDe: $crate::serde::Deserializer<'de>>(
+                de: __VldDe,
+            ) -> ::std::result::Result<Self, $crate::error::VldError> {
+                $crate::de::parse_deserializer(de)
+            }
+
             /// Parse and validate directly from a `serde_json::Value`.
             pub fn parse_value(
                 __vld_json: &$crate::serde_json::Value,
-            ) -> ::std::result::Result<$name, $crate::error::VldError> {
+            ) -> ::std::result::Result<Self, $crate::error::VldError> {
                 use $crate::schema::VldSchema as _;
 
                 let __vld_obj = __vld_json.as_object().ok_or_else(|| {
@@ -211,7 +520,7 @@ macro_rules! schema {
                         );
                         let __vld_field_value = __vld_obj
                             .get(__vld_key)
-                            .unwrap_or(&$crate::serde_json::Value::Null);
+                            .unwrap_or($crate::object::__absent());
                         match __vld_field_schema.parse_value(__vld_field_value) {
                             ::std::result::Result::Ok(v) => ::std::option::Option::Some(v),
                             ::std::result::Result::Err(e) => {
@@ -230,30 +539,301 @@ macro_rules! schema {
                     };
                 )*
 
+                $($(
+                    $crate::object::Dependency::$dep_rule($($dep_arg)*).__check(
+                        __vld_obj,
+                        __vld_json,
+                        &mut __vld_errors,
+                    );
+                )*)?
+
                 if !$crate::error::VldError::is_empty(&__vld_errors) {
                     return ::std::result::Result::Err(__vld_errors);
                 }
 
-                ::std::result::Result::Ok($name {
+                let __vld_value = Self {
                     $(
                         $field_name: $field_name.unwrap(),
                     )*
-                })
+                };
+                Self::__vld_check_rules(__vld_value)
             }
 
+            /// Run the struct-level `refine { ... }` rules on a value whose
+            /// fields are all valid.
+            #[doc(hidden)]
+            pub fn __vld_check_rules(
+                __vld_value: Self,
+            ) -> ::std::result::Result<Self, $crate::error::VldError> {
+                #[allow(unused_mut)]
+                let mut __vld_errors = $crate::error::VldError::new();
+                $($(
+                    $crate::schema::__refine_struct(
+                        &__vld_value,
+                        &mut __vld_errors,
+                        $crate::schema!(@rule_path $rule_path),
+                        $rule_message,
+                        $rule_check,
+                    );
+                )*)?
+                if !$crate::error::VldError::is_empty(&__vld_errors) {
+                    return ::std::result::Result::Err(__vld_errors);
+                }
+                ::std::result::Result::Ok(__vld_value)
+            }
         }
 
-        impl $crate::schema::VldParse for $name {
+        impl $(<$($gen: $crate::schema::VldTypeParam),+>)? $crate::schema::VldParse for $name $(<$($gen),+>)? {
             fn vld_parse_value(
                 value: &$crate::serde_json::Value,
             ) -> ::std::result::Result<Self, $crate::error::VldError> {
                 Self::parse_value(value)
             }
+
+            fn vld_deserialize<'de, __VldDe: $crate::serde::Deserializer<'de>>(
+                de: __VldDe,
+            ) -> $crate::de::DeResult<Self, __VldDe::Error> {
+                $crate::de::deserialize_any(
+                    de,
+                    $crate::de::__VldStructVisitor::<Self>(::std::marker::PhantomData),
+                )
+            }
+
+            $crate::__vld_if_async! {
+                fn vld_parse_value_async(
+                    value: &$crate::serde_json::Value,
+                ) -> $crate::async_schema::BoxFuture<
+                    '_,
+                    ::std::result::Result<Self, $crate::error::VldError>,
+                > {
+                    Self::parse_value_async(value)
+                }
+            }
+        }
+
+        impl $(<$($gen: $crate::schema::VldTypeParam),+>)? $crate::schema::VldDeepPartial for $name $(<$($gen),+>)? {
+            fn vld_deep_partial() -> $crate::object::ZObject {
+                #[allow(unused_imports)]
+                use $crate::object::{__VldPartialViaInput as _, __VldPartialViaSerialize as _};
+                let __vld_object = $crate::object::ZObject::new()
+                    $(
+                        .__field_boxed(
+                            $crate::__vld_resolve_key!(stringify!($field_name) $(, $rename)?),
+                            (&&$crate::object::__VldPartialField(::std::cell::Cell::new(
+                                ::std::option::Option::Some($schema),
+                            )))
+                            .__vld_boxed(),
+                        )
+                    )*
+                    $($( .$dep_rule($($dep_arg)*) )*)?;
+                __vld_object.deep_partial()
+            }
+        }
+
+        impl $(<$($gen: $crate::schema::VldTypeParam),+>)? $crate::de::__VldVisitMap for $name $(<$($gen),+>)? {
+            fn __vld_visit_map<'de, __VldMap: $crate::serde::de::MapAccess<'de>>(
+                mut __vld_map: __VldMap,
+            ) -> $crate::de::DeResult<Self, __VldMap::Error> {
+                use $crate::schema::VldSchema as _;
+
+                // Dependency rules look at the whole input object, so
+                // buffer it and validate it like `parse_value()`.
+                if $crate::__vld_has_dependencies!($($($dep_rule)*)?) {
+                    let mut __vld_obj = $crate::serde_json::Map::new();
+                    while let ::std::option::Option::Some((__vld_key, __vld_value)) =
+                        __vld_map.next_entry::<::std::string::String, $crate::serde_json::Value>()?
+                    {
+                        __vld_obj.insert(__vld_key, __vld_value);
+                    }
+                    return ::std::result::Result::Ok(Self::parse_value(
+                        &$crate::serde_json::Value::Object(__vld_obj),
+                    ));
+                }
+
+                $(
+                    #[allow(non_snake_case)]
+                    let mut $field_name: (
+                        _,
+                        ::std::option::Option<
+                            ::std::result::Result<$field_type, $crate::error::VldError>,
+                        >,
+                    ) = ($schema, ::std::option::Option::None);
+                )*
+
+                while let ::std::option::Option::Some(__vld_key) =
+                    __vld_map.next_key::<::std::string::String>()?
+                {
+                    $(
+                        if __vld_key == $crate::__vld_resolve_key!(
+                            stringify!($field_name) $(, $rename)?
+                        ) {
+                            $field_name.1 = ::std::option::Option::Some(
+                                __vld_map.next_value_seed(
+                                    $crate::de::SchemaSeed(&$field_name.0),
+                                )?,
+                            );
+                            continue;
+                        }
+                    )*
+                    __vld_map.next_value::<$crate::serde::de::IgnoredAny>()?;
+                }
+
+                let mut __vld_errors = $crate::error::VldError::new();
+
+                $(
+                    #[allow(non_snake_case)]
+                    let $field_name: ::std::option::Option<$field_type> = {
+                        let (__vld_field_schema, __vld_parsed) = $field_name;
+                        let __vld_key = $crate::__vld_resolve_key!(
+                            stringify!($field_name) $(, $rename)?
+                        );
+                        // Missing fields are validated as `null`, like `parse_value()`.
+                        let __vld_parsed = __vld_parsed.unwrap_or_else(|| {
+                            __vld_field_schema.parse_value($crate::object::__absent())
+                        });
+                        match __vld_parsed {
+                            ::std::result::Result::Ok(v) => ::std::option::Option::Some(v),
+                            ::std::result::Result::Err(e) => {
+                                __vld_errors = $crate::error::VldError::merge(
+                                    __vld_errors,
+                                    $crate::error::VldError::with_prefix(
+                                        e,
+                                        $crate::error::PathSegment::Field(
+                                            ::std::string::String::from(__vld_key),
+                                        ),
+                                    ),
+                                );
+                                ::std::option::Option::None
+                            }
+                        }
+                    };
+                )*
+
+                if !$crate::error::VldError::is_empty(&__vld_errors) {
+                    return ::std::result::Result::Ok(::std::result::Result::Err(__vld_errors));
+                }
+
+                ::std::result::Result::Ok(Self::__vld_check_rules(Self {
+                    $(
+                        $field_name: $field_name.unwrap(),
+                    )*
+                }))
+            }
+        }
+
+        $crate::__vld_if_async! {
+            impl $(<$($gen: $crate::schema::VldTypeParam),+>)? $name $(<$($gen),+>)? {
+                /// Parse and validate input data, running async refinements
+                /// (`refine_async`, `super_refine_async`) in the same pass.
+                ///
+                /// Requires the `async` feature.
+                pub fn parse_async<__VldInputT: $crate::input::VldInput + ?Sized>(
+                    input: &__VldInputT,
+                ) -> $crate::async_schema::BoxFuture<
+                    'static,
+                    ::std::result::Result<Self, $crate::error::VldError>,
+                > {
+                    let __vld_json = <__VldInputT as $crate::input::VldInput>::to_json_value(input);
+                    ::std::boxed::Box::pin(async move {
+                        let __vld_json = __vld_json?;
+                        Self::parse_value_async(&__vld_json).await
+                    })
+                }
+
+                /// Async counterpart of `parse_value()`.
+                ///
+                /// Fields whose schema implements
+                /// [`VldSchemaAsync`]($crate::async_schema::VldSchemaAsync) are parsed
+                /// asynchronously; all other fields use their synchronous parse.
+                ///
+                /// Requires the `async` feature.
+                pub fn parse_value_async(
+                    __vld_json: &$crate::serde_json::Value,
+                ) -> $crate::async_schema::BoxFuture<
+                    '_,
+                    ::std::result::Result<Self, $crate::error::VldError>,
+                > {
+                    ::std::boxed::Box::pin(async move {
+                        #[allow(unused_imports)]
+                        use $crate::async_schema::{__VldViaAsync as _, __VldViaSync as _};
+
+                        let __vld_obj = __vld_json.as_object().ok_or_else(|| {
+                            $crate::error::VldError::single(
+                                $crate::error::IssueCode::InvalidType {
+                                    expected: ::std::string::String::from("object"),
+                                    received: $crate::error::value_type_name(__vld_json),
+                                },
+                                ::std::format!(
+                                    "Expected object, received {}",
+                                    $crate::error::value_type_name(__vld_json)
+                                ),
+                            )
+                        })?;
+
+                        let mut __vld_errors = $crate::error::VldError::new();
+
+                        $(
+                            #[allow(non_snake_case)]
+                            let $field_name: ::std::option::Option<$field_type> = {
+                                let __vld_key = $crate::__vld_resolve_key!(
+                                    stringify!($field_name) $(, $rename)?
+                                );
+                                let __vld_field_value = __vld_obj
+                                    .get(__vld_key)
+                                    .unwrap_or($crate::object::__absent());
+                                // Built in its own block so that a schema
+                                // which is not `Send` is dropped before the
+                                // `.await`.
+                                let __vld_field_future = {
+                                    let __vld_field_schema = $schema;
+                                    (&&$crate::async_schema::__VldAsyncField::new(
+                                        __vld_field_schema,
+                                    ))
+                                    .__vld_parse_async(__vld_field_value)
+                                };
+                                match __vld_field_future.await {
+                                    ::std::result::Result::Ok(v) => ::std::option::Option::Some(v),
+                                    ::std::result::Result::Err(e) => {
+                                        __vld_errors = $crate::error::VldError::merge(
+                                            __vld_errors,
+                                            $crate::error::VldError::with_prefix(
+                                                e,
+                                                $crate::error::PathSegment::Field(
+                                                    ::std::string::String::from(__vld_key),
+                                                ),
+                                            ),
+                                        );
+                                        ::std::option::Option::None
+                                    }
+                                }
+                            };
+                        )*
+
+                        $($(
+                            $crate::object::Dependency::$dep_rule($($dep_arg)*).__check(
+                                __vld_obj,
+                                __vld_json,
+                                &mut __vld_errors,
+                            );
+                        )*)?
+
+                        if !$crate::error::VldError::is_empty(&__vld_errors) {
+                            return ::std::result::Result::Err(__vld_errors);
+                        }
+
+                        Self::__vld_check_rules(Self {
+                            $(
+                                $field_name: $field_name.unwrap(),
+                            )*
+                        })
+                    })
+                }
+            }
         }
 
 
         $crate::__vld_if_serialize! {
-            impl $name {
+            impl $(<$($gen: $crate::schema::VldTypeParam),+>)? $name $(<$($gen),+>)? {
                 /// Validate an existing Rust value that can be serialized to JSON.
                 ///
                 /// The value is serialized via `serde`, then validated against the
@@ -286,7 +866,7 @@ macro_rules! schema {
         }
 
         $crate::__vld_if_openapi! {
-            impl $name {
+            impl $(<$($gen: $crate::schema::VldTypeParam),+>)? $name $(<$($gen),+>)? {
                 /// Generate a JSON Schema / OpenAPI 3.1 representation of this struct.
                 ///
                 /// Requires the `openapi` feature.
@@ -306,24 +886,32 @@ macro_rules! schema {
                                 ::std::string::String::from(__vld_key),
                                 __vld_field_schema.json_schema(),
                             );
-                            __vld_required.push(
-                                ::std::string::String::from(__vld_key),
-                            );
+                            if !$crate::schema::VldSchema::__vld_optional_key(&__vld_field_schema) {
+                                __vld_required.push(::std::string::String::from(__vld_key));
+                            }
                         }
                     )*
 
-                    $crate::serde_json::json!({
+                    #[allow(unused_mut)]
+                    let mut __vld_schema = $crate::serde_json::json!({
                         "type": "object",
                         "required": __vld_required,
                         "properties": $crate::serde_json::Value::Object(__vld_properties),
-                    })
+                    });
+                    $($(
+                        $crate::object::Dependency::$dep_rule($($dep_arg)*)
+                            .__add_to_json_schema(&mut __vld_schema);
+                    )*)?
+                    __vld_schema
                 }
 
                 /// Wrap `json_schema()` in a minimal OpenAPI 3.1 document.
                 ///
                 /// Requires the `openapi` feature.
                 pub fn to_openapi_document() -> $crate::serde_json::Value {
-                    $crate::json_schema::to_openapi_document(stringify!($name), &Self::json_schema())
+                    $crate::json_schema::to_openapi_document(&<Self as $crate::json_schema::VldJsonSchema>::vld_schema_name(),
+                        &Self::json_schema(),
+                    )
                 }
 
                 /// Collect `(name, json_schema_fn)` pairs for all nested schemas
@@ -350,7 +938,20 @@ macro_rules! schema {
                 }
             }
 
-            impl $crate::json_schema::OpenApiParameterIn for $name {
+            impl $(<$($gen: $crate::schema::VldTypeParam),+>)? $crate::json_schema::VldJsonSchema for $name $(<$($gen),+>)? {
+                fn vld_schema_name() -> ::std::string::String {
+                    $crate::json_schema::__generic_schema_name(
+                        stringify!($name),
+                        &[$($(<$gen as $crate::json_schema::VldJsonSchema>::vld_schema_name()),+)?],
+                    )
+                }
+
+                fn vld_json_schema() -> $crate::serde_json::Value {
+                    Self::json_schema()
+                }
+            }
+
+            impl $(<$($gen: $crate::schema::VldTypeParam),+>)? $crate::json_schema::OpenApiParameterIn for $name $(<$($gen),+>)? {
                 fn parameter_in() -> Option<&'static str> {
                     $crate::schema!(@param_in $(#[ $($struct_attr)* ])*)
                 }
@@ -360,6 +961,14 @@ macro_rules! schema {
         }
     };
 
+    // Path of a `refine { ... }` rule: `_` targets the struct itself.
+    (@rule_path _) => {
+        ::std::option::Option::None
+    };
+    (@rule_path $path:literal) => {
+        ::std::option::Option::Some($path)
+    };
+
     // Parse `#[into_params(parameter_in = ...)]` for [`OpenApiParameterIn`].
     (@param_in) => {
         None::<&'static str>
@@ -407,6 +1016,12 @@ macro_rules! schema {
 /// - **`parse_lenient(input)`** — build the struct even if some fields fail
 ///   (uses `Default` for invalid fields), returns [`ParseResult<Self>`](crate::error::ParseResult)
 ///
+/// Both recurse into `vld::nested!` structs that have lenient parsing too,
+/// arrays and optionals: valid nested values are kept and
+/// [`FieldResult::children`](crate::error::FieldResult::children) reports
+/// each nested field / array element. See
+/// [`VldSchema::parse_value_lenient()`](crate::schema::VldSchema::parse_value_lenient).
+///
 /// The returned [`ParseResult`](crate::error::ParseResult) can be inspected,
 /// converted to JSON, or saved to a file at any time via `.save_to_file(path)`.
 ///
@@ -467,18 +1082,20 @@ macro_rules! impl_validate_fields {
                         );
                         let __vld_field_value = __vld_obj
                             .get(__vld_key)
-                            .unwrap_or(&$crate::serde_json::Value::Null);
+                            .unwrap_or($crate::object::__absent());
 
-                        let __vld_result = $crate::object::DynSchema::dyn_parse(
-                            &__vld_field_schema,
-                            __vld_field_value,
-                        );
+                        let (__vld_result, __vld_children) =
+                            $crate::object::DynSchema::dyn_parse_lenient(
+                                &__vld_field_schema,
+                                __vld_field_value,
+                            );
 
-                        __vld_results.push($crate::error::FieldResult {
-                            name: ::std::string::String::from(__vld_key),
-                            input: __vld_field_value.clone(),
-                            result: __vld_result,
-                        });
+                        __vld_results.push($crate::error::FieldResult::new(
+                            ::std::string::String::from(__vld_key),
+                            __vld_field_value.clone(),
+                            __vld_result,
+                        )
+                        .with_children(__vld_children));
                     }
                 )*
 
@@ -488,7 +1105,8 @@ macro_rules! impl_validate_fields {
             /// Parse leniently: build the struct even when some fields fail.
             ///
             /// - Valid fields get their parsed value.
-            /// - Invalid fields fall back to `Default::default()`.
+            /// - Nested structs and arrays keep their valid parts.
+            /// - Other invalid fields fall back to `Default::default()`.
             ///
             /// Returns a [`ParseResult`](crate::error::ParseResult) that wraps
             /// the struct and per-field diagnostics. You can inspect it, convert
@@ -537,28 +1155,31 @@ macro_rules! impl_validate_fields {
                         );
                         let __vld_field_value = __vld_obj
                             .get(__vld_key)
-                            .unwrap_or(&$crate::serde_json::Value::Null);
+                            .unwrap_or($crate::object::__absent());
 
-                        match __vld_field_schema.parse_value(__vld_field_value) {
-                            ::std::result::Result::Ok(v) => {
-                                let __json_repr = $crate::serde_json::to_value(&v)
-                                    .unwrap_or_else(|_| __vld_field_value.clone());
-                                __vld_results.push($crate::error::FieldResult {
-                                    name: ::std::string::String::from(__vld_key),
-                                    input: __vld_field_value.clone(),
-                                    result: ::std::result::Result::Ok(__json_repr),
-                                });
-                                v
-                            }
-                            ::std::result::Result::Err(e) => {
-                                __vld_results.push($crate::error::FieldResult {
-                                    name: ::std::string::String::from(__vld_key),
-                                    input: __vld_field_value.clone(),
-                                    result: ::std::result::Result::Err(e),
-                                });
-                                <$field_type as ::std::default::Default>::default()
-                            }
+                        let __vld_lenient = __vld_field_schema.parse_value_lenient(__vld_field_value);
+                        let mut __vld_children = __vld_lenient.children;
+                        let __json_repr = __vld_lenient
+                            .value
+                            .as_ref()
+                            .and_then(|v| $crate::serde_json::to_value(v).ok());
+                        if let ::std::option::Option::Some(j) = &__json_repr {
+                            $crate::error::__fill_child_outputs(&mut __vld_children, j);
                         }
+                        __vld_results.push($crate::error::FieldResult::new(
+                            ::std::string::String::from(__vld_key),
+                            __vld_field_value.clone(),
+                            match __vld_lenient.error {
+                                ::std::option::Option::Some(e) => ::std::result::Result::Err(e),
+                                ::std::option::Option::None => ::std::result::Result::Ok(
+                                    __json_repr.unwrap_or_else(|| __vld_field_value.clone()),
+                                ),
+                            },
+                        )
+                        .with_children(__vld_children));
+                        // Partially valid nested values are kept; only
+                        // unrecoverable fields fall back to `Default`.
+                        __vld_lenient.value.unwrap_or_default()
                     };
                 )*
 
@@ -571,6 +1192,15 @@ macro_rules! impl_validate_fields {
                 )
             }
         }
+
+        impl $crate::schema::VldParseLenient for $name {
+            fn vld_parse_lenient_value(
+                value: &$crate::serde_json::Value,
+            ) -> ::std::result::Result<$crate::error::ParseResult<Self>, $crate::error::VldError>
+            {
+                Self::parse_lenient_value(value)
+            }
+        }
     };
 }
 
@@ -608,6 +1238,16 @@ macro_rules! schema_validated {
                 $field_vis:vis $field_name:ident : $field_type:ty $(as $rename:literal)? => $schema:expr
             ),* $(,)?
         }
+        $(
+            refine {
+                $( $rule_path:tt => $rule_check:expr, $rule_message:expr );* $(;)?
+            }
+        )?
+        $(
+            dependencies {
+                $( $dep_rule:ident ( $($dep_arg:tt)* ) );* $(;)?
+            }
+        )?
     ) => {
         // 1. Generate the struct + parse/parse_value (same as schema!)
         $crate::schema! {
@@ -618,6 +1258,16 @@ macro_rules! schema_validated {
                     $field_vis $field_name : $field_type $(as $rename)? => $schema
                 ),*
             }
+            $(
+                refine {
+                    $( $rule_path => $rule_check, $rule_message; )*
+                }
+            )?
+            $(
+                dependencies {
+                    $( $dep_rule ( $($dep_arg)* ); )*
+                }
+            )?
         }
 
         // 2. Generate validate_fields + parse_lenient (same as impl_validate_fields!)
@@ -13,7 +13,7 @@ where
     T::Output: Clone,
 {
     inner: T,
-    default_value: T::Output,
+    pub(crate) default_value: T::Output,
 }
 
 impl<T: VldSchema> ZDefault<T>
@@ -45,4 +45,19 @@ where
         }
         self.inner.parse_value(value)
     }
+
+    fn deserialize_with<'de, D: serde::Deserializer<'de>>(
+        &self,
+        de: D,
+    ) -> crate::de::DeResult<T::Output, D::Error> {
+        let parsed = crate::de::deserialize_nullable(de, &self.inner)?;
+        Ok(parsed.map(|v| v.unwrap_or_else(|| self.default_value.clone())))
+    }
+
+    fn parse_value_lenient(&self, value: &Value) -> crate::schema::Lenient<T::Output> {
+        if value.is_null() {
+            return crate::schema::Lenient::from_result(Ok(self.default_value.clone()));
+        }
+        self.inner.parse_value_lenient(value)
+    }
 }
@@ -2,8 +2,10 @@ mod default_val;
 mod nullable;
 mod nullish;
 mod optional;
+mod patch;
 
 pub use default_val::ZDefault;
 pub use nullable::ZNullable;
 pub use nullish::ZNullish;
 pub use optional::ZOptional;
+pub use patch::{Patch, ZPatch};
@@ -27,6 +27,19 @@ impl<T: VldSchema> ZNullable<T> {
     pub fn inner_schema(&self) -> &T {
         &self.inner
     }
+
+    /// This schema around the inner schema that `partial` rebuilds from the
+    /// current one, or `None` when it has no partial form. Used by
+    /// [`ZObject::deep_partial()`](crate::object::ZObject::deep_partial).
+    pub(crate) fn deep_partial_with<D>(
+        &mut self,
+        partial: impl FnOnce(&mut T) -> Option<Box<D>>,
+    ) -> Option<ZNullable<crate::object::DynValue<D>>>
+    where
+        D: crate::object::DynSchema + ?Sized,
+    {
+        partial(&mut self.inner).map(|inner| ZNullable::new(crate::object::DynValue(inner)))
+    }
 }
 
 impl<T: VldSchema> VldSchema for ZNullable<T> {
@@ -38,4 +51,23 @@ impl<T: VldSchema> VldSchema for ZNullable<T> {
         }
         self.inner.parse_value(value).map(Some)
     }
+
+    fn deserialize_with<'de, D: serde::Deserializer<'de>>(
+        &self,
+        de: D,
+    ) -> crate::de::DeResult<Option<T::Output>, D::Error> {
+        crate::de::deserialize_nullable(de, &self.inner)
+    }
+
+    fn parse_value_lenient(&self, value: &Value) -> crate::schema::Lenient<Option<T::Output>> {
+        if value.is_null() {
+            return crate::schema::Lenient::from_result(Ok(None));
+        }
+        self.inner.parse_value_lenient(value).map(Some)
+    }
+
+    fn __vld_deep_partial(&mut self) -> Option<Box<dyn crate::object::DynSchema>> {
+        self.deep_partial_with(T::__vld_deep_partial)
+            .map(crate::object::erase)
+    }
 }
@@ -0,0 +1,180 @@
+use serde_json::Value;
+
+use crate::error::VldError;
+use crate::schema::VldSchema;
+
+/// A field of a partial update: left alone, cleared, or set.
+///
+/// Output of [`vld::patch()`](crate::patch). Unlike `Option`, it keeps
+/// "key missing" ([`Absent`](Patch::Absent)) apart from "key is `null`"
+/// ([`Null`](Patch::Null)), as JSON Merge Patch (RFC 7396) requires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Patch<T> {
+    /// The key was not in the input: leave the value unchanged.
+    #[default]
+    Absent,
+    /// The key was `null`: clear the value.
+    Null,
+    /// The key had a valid value: set it.
+    Value(T),
+}
+
+impl<T> Patch<T> {
+    /// `true` for [`Patch::Absent`].
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Patch::Absent)
+    }
+
+    /// `true` for [`Patch::Null`].
+    pub fn is_null(&self) -> bool {
+        matches!(self, Patch::Null)
+    }
+
+    /// `true` for [`Patch::Value`].
+    pub fn is_value(&self) -> bool {
+        matches!(self, Patch::Value(_))
+    }
+
+    /// The new value, if one was set.
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Patch::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Convert from `&Patch<T>` to `Patch<&T>`.
+    pub fn as_ref(&self) -> Patch<&T> {
+        match self {
+            Patch::Absent => Patch::Absent,
+            Patch::Null => Patch::Null,
+            Patch::Value(v) => Patch::Value(v),
+        }
+    }
+
+    /// Map the new value, keeping `Absent` and `Null`.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Patch<U> {
+        match self {
+            Patch::Absent => Patch::Absent,
+            Patch::Null => Patch::Null,
+            Patch::Value(v) => Patch::Value(f(v)),
+        }
+    }
+
+    /// `None` when absent, `Some(None)` when null, `Some(Some(v))` when set.
+    pub fn into_option(self) -> Option<Option<T>> {
+        match self {
+            Patch::Absent => None,
+            Patch::Null => Some(None),
+            Patch::Value(v) => Some(Some(v)),
+        }
+    }
+
+    /// Apply the update to a nullable column: `Absent` leaves `target`
+    /// unchanged, `Null` clears it and `Value` replaces it.
+    ///
+    /// ```
+    /// use vld::modifiers::Patch;
+    ///
+    /// let mut nickname = Some("al".to_string());
+    /// Patch::Absent.apply_to(&mut nickname);
+    /// assert_eq!(nickname.as_deref(), Some("al"));
+    /// Patch::Null.apply_to(&mut nickname);
+    /// assert_eq!(nickname, None);
+    /// ```
+    pub fn apply_to(self, target: &mut Option<T>) {
+        match self {
+            Patch::Absent => {}
+            Patch::Null => *target = None,
+            Patch::Value(v) => *target = Some(v),
+        }
+    }
+}
+
+/// Serializes `Absent` and `Null` as `null`. Leave absent fields out with
+/// `#[serde(skip_serializing_if = "Patch::is_absent")]`.
+impl<T: serde::Serialize> serde::Serialize for Patch<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Patch::Value(v) => serializer.serialize_some(v),
+            Patch::Absent | Patch::Null => serializer.serialize_none(),
+        }
+    }
+}
+
+/// Deserializes `null` as `Null` and anything else as `Value`. A missing
+/// field is `Absent` with `#[serde(default)]`.
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Patch<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            Some(v) => Patch::Value(v),
+            None => Patch::Null,
+        })
+    }
+}
+
+/// Wraps a schema for partial updates, with output [`Patch<T>`].
+///
+/// A missing object key gives [`Patch::Absent`], `null` gives
+/// [`Patch::Null`], and any other value is validated by the inner schema.
+/// In a [`ZObject`](crate::object::ZObject) an absent key is left out of
+/// the output, and in JSON Schema the field is nullable and not required.
+/// This also holds through `describe`, `refine`, `transform` and the other
+/// wrappers, except [`preprocess`](crate::combinators::ZPreprocess), which
+/// turns a missing key into a plain `null`.
+///
+/// Created with [`vld::patch()`](crate::patch).
+pub struct ZPatch<T: VldSchema> {
+    inner: T,
+}
+
+impl<T: VldSchema> ZPatch<T> {
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Access the inner schema (for JSON Schema generation).
+    pub fn inner_schema(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T: VldSchema> VldSchema for ZPatch<T> {
+    type Output = Patch<T::Output>;
+
+    fn parse_value(&self, value: &Value) -> Result<Patch<T::Output>, VldError> {
+        if crate::object::is_absent(value) {
+            return Ok(Patch::Absent);
+        }
+        if value.is_null() {
+            return Ok(Patch::Null);
+        }
+        self.inner.parse_value(value).map(Patch::Value)
+    }
+
+    fn deserialize_with<'de, D: serde::Deserializer<'de>>(
+        &self,
+        de: D,
+    ) -> crate::de::DeResult<Patch<T::Output>, D::Error> {
+        Ok(
+            crate::de::deserialize_nullable(de, &self.inner)?.map(|v| match v {
+                Some(v) => Patch::Value(v),
+                None => Patch::Null,
+            }),
+        )
+    }
+
+    fn parse_value_lenient(&self, value: &Value) -> crate::schema::Lenient<Patch<T::Output>> {
+        if crate::object::is_absent(value) {
+            return crate::schema::Lenient::from_result(Ok(Patch::Absent));
+        }
+        if value.is_null() {
+            return crate::schema::Lenient::from_result(Ok(Patch::Null));
+        }
+        self.inner.parse_value_lenient(value).map(Patch::Value)
+    }
+
+    fn __vld_optional_key(&self) -> bool {
+        true
+    }
+}
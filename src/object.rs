@@ -1,6 +1,6 @@
 use serde_json::{Map, Value};
 
-use crate::error::{value_type_name, IssueCode, PathSegment, VldError};
+use crate::error::{value_type_name, FieldResult, IssueCode, PathSegment, VldError};
 use crate::schema::VldSchema;
 
 /// Object-safe trait for type-erased schema validation.
@@ -8,6 +8,26 @@ use crate::schema::VldSchema;
 /// Used internally by [`ZObject`] to store heterogeneous field schemas.
 pub trait DynSchema {
     fn dyn_parse(&self, value: &Value) -> Result<Value, VldError>;
+
+    /// Lenient counterpart of [`dyn_parse`](Self::dyn_parse): the result plus
+    /// the per-field / per-element children of nested structs and arrays.
+    /// See [`VldSchema::parse_value_lenient()`].
+    fn dyn_parse_lenient(&self, value: &Value) -> (Result<Value, VldError>, Vec<FieldResult>) {
+        (self.dyn_parse(value), vec![])
+    }
+
+    /// Async counterpart of [`dyn_parse`](Self::dyn_parse). Defaults to the
+    /// synchronous parse, run before the future is returned.
+    ///
+    /// Only available with the `async` feature.
+    #[cfg(feature = "async")]
+    fn dyn_parse_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> crate::async_schema::BoxFuture<'a, Result<Value, VldError>> {
+        Box::pin(std::future::ready(self.dyn_parse(value)))
+    }
+
     /// Generate a JSON Schema for this field. Returns empty schema `{}` by default.
     ///
     /// Only available with the `openapi` feature.
@@ -15,6 +35,33 @@ pub trait DynSchema {
     fn dyn_json_schema(&self) -> Value {
         serde_json::json!({})
     }
+
+    /// See [`VldSchema::__vld_optional_key()`].
+    #[doc(hidden)]
+    fn __dyn_optional_key(&self) -> bool {
+        false
+    }
+
+    /// Whether [`ZObject::to_json_schema()`] lists a key with this schema as
+    /// `required`. Fields made optional with [`ZObject::field_optional()`] or
+    /// [`ZObject::partial()`] are not.
+    #[doc(hidden)]
+    fn __dyn_json_required(&self) -> bool {
+        !self.__dyn_optional_key()
+    }
+
+    /// See [`VldSchema::__vld_deep_partial()`].
+    #[doc(hidden)]
+    fn __dyn_deep_partial(&mut self) -> Option<Box<dyn DynSchema>> {
+        None
+    }
+
+    /// See [`VldSchemaAsync::__vld_deep_partial_async()`](crate::async_schema::VldSchemaAsync::__vld_deep_partial_async).
+    #[cfg(feature = "async")]
+    #[doc(hidden)]
+    fn __dyn_deep_partial_async(&mut self) -> Option<Box<dyn DynSchema + Send + Sync>> {
+        None
+    }
 }
 
 /// Blanket implementation: any `VldSchema` whose output is `Serialize`
@@ -26,15 +73,398 @@ where
 {
     fn dyn_parse(&self, value: &Value) -> Result<Value, VldError> {
         let result = self.parse_value(value)?;
-        serde_json::to_value(&result).map_err(|e| {
-            VldError::single(
-                IssueCode::Custom {
-                    code: "serialize".to_string(),
-                },
-                format!("Failed to serialize validated value: {}", e),
-            )
+        serialize_output(&result)
+    }
+
+    fn dyn_parse_lenient(&self, value: &Value) -> (Result<Value, VldError>, Vec<FieldResult>) {
+        let lenient = self.parse_value_lenient(value);
+        let mut children = lenient.children;
+        let output = lenient.value.as_ref().map(serialize_output);
+        if let Some(Ok(json)) = &output {
+            crate::error::__fill_child_outputs(&mut children, json);
+        }
+        let result = match (lenient.error, output) {
+            (Some(e), _) => Err(e),
+            (None, Some(output)) => output,
+            (None, None) => Ok(Value::Null),
+        };
+        (result, children)
+    }
+
+    fn __dyn_optional_key(&self) -> bool {
+        self.__vld_optional_key()
+    }
+
+    fn __dyn_deep_partial(&mut self) -> Option<Box<dyn DynSchema>> {
+        self.__vld_deep_partial()
+    }
+}
+
+/// JSON Schema bound of schemas that a [`ZObject`] or
+/// [`ZRecord`](crate::collections::ZRecord) stores type-erased, such as
+/// [`ZObject::pattern_field()`] schemas.
+///
+/// With the `openapi` feature enabled this is
+/// [`JsonSchema`](crate::json_schema::JsonSchema), so their JSON Schema is
+/// kept. Otherwise it is implemented for every type.
+#[cfg(feature = "openapi")]
+pub trait MaybeJsonSchema: crate::json_schema::JsonSchema {}
+#[cfg(feature = "openapi")]
+impl<T: crate::json_schema::JsonSchema + ?Sized> MaybeJsonSchema for T {}
+
+/// JSON Schema bound of schemas that a [`ZObject`] or
+/// [`ZRecord`](crate::collections::ZRecord) stores type-erased, such as
+/// [`ZObject::pattern_field()`] schemas.
+///
+/// With the `openapi` feature enabled this is
+/// [`JsonSchema`](crate::json_schema::JsonSchema), so their JSON Schema is
+/// kept. Otherwise it is implemented for every type.
+#[cfg(not(feature = "openapi"))]
+pub trait MaybeJsonSchema {}
+#[cfg(not(feature = "openapi"))]
+impl<T: ?Sized> MaybeJsonSchema for T {}
+
+/// Box a schema rebuilt by [`ZObject::deep_partial()`], keeping its JSON
+/// Schema.
+pub(crate) fn erase<S>(schema: S) -> Box<dyn DynSchema>
+where
+    S: VldSchema + MaybeJsonSchema + 'static,
+    S::Output: serde::Serialize,
+{
+    Box::new(Erased(schema))
+}
+
+struct Erased<S>(S);
+
+impl<S> DynSchema for Erased<S>
+where
+    S: VldSchema + MaybeJsonSchema,
+    S::Output: serde::Serialize,
+{
+    fn dyn_parse(&self, value: &Value) -> Result<Value, VldError> {
+        self.0.dyn_parse(value)
+    }
+
+    fn dyn_parse_lenient(&self, value: &Value) -> (Result<Value, VldError>, Vec<FieldResult>) {
+        self.0.dyn_parse_lenient(value)
+    }
+
+    #[cfg(feature = "openapi")]
+    fn dyn_json_schema(&self) -> Value {
+        self.0.json_schema()
+    }
+
+    fn __dyn_optional_key(&self) -> bool {
+        self.0.__vld_optional_key()
+    }
+
+    fn __dyn_deep_partial(&mut self) -> Option<Box<dyn DynSchema>> {
+        self.0.__vld_deep_partial()
+    }
+}
+
+/// Box a schema for an async object, keeping its async path and JSON
+/// Schema. Used for the schemas [`ZObject::deep_partial()`] rebuilds and
+/// for [`ZObjectAsync::pattern_field()`] schemas.
+#[cfg(feature = "async")]
+pub(crate) fn erase_async<S>(schema: S) -> Box<dyn DynSchema + Send + Sync>
+where
+    S: crate::async_schema::VldSchemaAsync + MaybeJsonSchema + 'static,
+    S::Output: serde::Serialize,
+{
+    Box::new(ErasedAsync(schema))
+}
+
+#[cfg(feature = "async")]
+struct ErasedAsync<S>(S);
+
+#[cfg(feature = "async")]
+impl<S> DynSchema for ErasedAsync<S>
+where
+    S: crate::async_schema::VldSchemaAsync + MaybeJsonSchema,
+    S::Output: serde::Serialize,
+{
+    fn dyn_parse(&self, value: &Value) -> Result<Value, VldError> {
+        self.0.dyn_parse(value)
+    }
+
+    fn dyn_parse_lenient(&self, value: &Value) -> (Result<Value, VldError>, Vec<FieldResult>) {
+        self.0.dyn_parse_lenient(value)
+    }
+
+    fn dyn_parse_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> crate::async_schema::BoxFuture<'a, Result<Value, VldError>> {
+        Box::pin(async move {
+            let result = self.0.parse_value_async(value).await?;
+            serialize_output(&result)
         })
     }
+
+    #[cfg(feature = "openapi")]
+    fn dyn_json_schema(&self) -> Value {
+        self.0.json_schema()
+    }
+
+    fn __dyn_optional_key(&self) -> bool {
+        self.0.__vld_optional_key()
+    }
+
+    fn __dyn_deep_partial(&mut self) -> Option<Box<dyn DynSchema>> {
+        self.0.__vld_deep_partial()
+    }
+
+    fn __dyn_deep_partial_async(&mut self) -> Option<Box<dyn DynSchema + Send + Sync>> {
+        self.0.__vld_deep_partial_async()
+    }
+}
+
+/// A type-erased schema as a [`VldSchema`] with `serde_json::Value` output,
+/// so that [`ZObject::deep_partial()`] can put a rebuilt element schema back
+/// into an array, record or optional schema.
+pub(crate) struct DynValue<D: ?Sized = dyn DynSchema>(pub(crate) Box<D>);
+
+impl<D: ?Sized + DynSchema> VldSchema for DynValue<D> {
+    type Output = Value;
+
+    fn parse_value(&self, value: &Value) -> Result<Value, VldError> {
+        self.0.dyn_parse(value)
+    }
+
+    fn parse_value_lenient(&self, value: &Value) -> crate::schema::Lenient<Value> {
+        let (result, children) = self.0.dyn_parse_lenient(value);
+        let mut lenient = crate::schema::Lenient::from_result(result);
+        lenient.children = children;
+        lenient
+    }
+
+    fn __vld_optional_key(&self) -> bool {
+        self.0.__dyn_optional_key()
+    }
+
+    fn __vld_deep_partial(&mut self) -> Option<Box<dyn DynSchema>> {
+        self.0.__dyn_deep_partial()
+    }
+}
+
+#[cfg(feature = "async")]
+impl crate::async_schema::VldSchemaAsync for DynValue<dyn DynSchema + Send + Sync> {
+    fn parse_value_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> crate::async_schema::BoxFuture<'a, Result<Value, VldError>> {
+        self.0.dyn_parse_async(value)
+    }
+
+    fn __vld_deep_partial_async(&mut self) -> Option<Box<dyn DynSchema + Send + Sync>> {
+        self.0.__dyn_deep_partial_async()
+    }
+}
+
+#[cfg(feature = "openapi")]
+impl<D: ?Sized + DynSchema> crate::json_schema::JsonSchema for DynValue<D> {
+    fn json_schema(&self) -> Value {
+        self.0.dyn_json_schema()
+    }
+}
+
+/// A field of a [`schema!`](crate::schema!) / `#[derive(Validate)]` struct
+/// in the [`ZObject`] of [`VldDeepPartial`](crate::schema::VldDeepPartial).
+///
+/// Fields whose output is `Serialize` are boxed as they are; the others
+/// are wrapped in a schema that returns the validated input as-is.
+#[doc(hidden)]
+pub struct __VldPartialField<S>(pub std::cell::Cell<Option<S>>);
+
+#[doc(hidden)]
+pub trait __VldPartialViaSerialize {
+    fn __vld_boxed(&self) -> Box<dyn DynSchema>;
+}
+
+impl<S> __VldPartialViaSerialize for &__VldPartialField<S>
+where
+    S: VldSchema + MaybeJsonSchema + 'static,
+    S::Output: serde::Serialize,
+{
+    fn __vld_boxed(&self) -> Box<dyn DynSchema> {
+        Box::new(json_field(
+            self.0.take().expect("field schema is boxed once"),
+        ))
+    }
+}
+
+#[doc(hidden)]
+pub trait __VldPartialViaInput {
+    fn __vld_boxed(&self) -> Box<dyn DynSchema>;
+}
+
+impl<S> __VldPartialViaInput for __VldPartialField<S>
+where
+    S: VldSchema + MaybeJsonSchema + 'static,
+{
+    fn __vld_boxed(&self) -> Box<dyn DynSchema> {
+        Box::new(json_field(PassInput(
+            self.0.take().expect("field schema is boxed once"),
+        )))
+    }
+}
+
+/// A schema as a [`DynSchema`], with its JSON Schema when the `openapi`
+/// feature is enabled. The result is `Send + Sync` when `S` is.
+#[cfg(feature = "openapi")]
+pub(crate) fn json_field<S>(schema: S) -> impl DynSchema
+where
+    S: VldSchema + crate::json_schema::JsonSchema + 'static,
+    S::Output: serde::Serialize,
+{
+    JsonSchemaField { inner: schema }
+}
+
+/// A schema as a [`DynSchema`], with its JSON Schema when the `openapi`
+/// feature is enabled. The result is `Send + Sync` when `S` is.
+#[cfg(not(feature = "openapi"))]
+pub(crate) fn json_field<S>(schema: S) -> impl DynSchema
+where
+    S: VldSchema + 'static,
+    S::Output: serde::Serialize,
+{
+    schema
+}
+
+/// Validates with the inner schema but outputs the input value, for field
+/// schemas whose output cannot be serialized back into JSON.
+struct PassInput<S>(S);
+
+impl<S: VldSchema> VldSchema for PassInput<S> {
+    type Output = Value;
+
+    fn parse_value(&self, value: &Value) -> Result<Value, VldError> {
+        self.0.parse_value(value).map(|_| value.clone())
+    }
+
+    fn __vld_optional_key(&self) -> bool {
+        self.0.__vld_optional_key()
+    }
+
+    fn __vld_deep_partial(&mut self) -> Option<Box<dyn DynSchema>> {
+        self.0.__vld_deep_partial()
+    }
+}
+
+#[cfg(feature = "openapi")]
+impl<S: crate::json_schema::JsonSchema> crate::json_schema::JsonSchema for PassInput<S> {
+    fn json_schema(&self) -> Value {
+        self.0.json_schema()
+    }
+}
+
+/// Serialize a validated output back into a JSON value for dynamic schemas.
+pub(crate) fn serialize_output<T: serde::Serialize>(output: &T) -> Result<Value, VldError> {
+    serde_json::to_value(output).map_err(|e| {
+        VldError::single(
+            IssueCode::Custom {
+                code: "serialize".to_string(),
+            },
+            format!("Failed to serialize validated value: {}", e),
+        )
+    })
+}
+
+/// Issue for a key that a strict object does not declare.
+fn unrecognized_field(key: &str) -> VldError {
+    VldError::single(
+        IssueCode::UnrecognizedField,
+        format!("Unrecognized field: \"{}\"", key),
+    )
+    .with_prefix(PathSegment::Field(key.to_string()))
+}
+
+/// The value a missing object key is parsed from: `null`, but told apart
+/// from an explicit `null` by address (see [`ZPatch`](crate::modifiers::ZPatch)).
+static ABSENT: Value = Value::Null;
+
+/// The `null` a missing object key is parsed from. Schemas other than
+/// [`vld::patch()`](crate::patch) treat it as any other `null`.
+#[doc(hidden)]
+pub fn __absent() -> &'static Value {
+    &ABSENT
+}
+
+/// Whether `value` stands for a missing object key.
+pub(crate) fn is_absent(value: &Value) -> bool {
+    std::ptr::eq(value, &ABSENT)
+}
+
+/// Look up a field of a `#[derive(Validate)]` struct by its JSON key or one
+/// of its `#[serde(alias)]` keys. Returns the key that matched (or `key`).
+#[doc(hidden)]
+pub fn __field_entry<'a>(
+    obj: &'a Map<String, Value>,
+    key: &'a str,
+    aliases: &[&'a str],
+) -> (&'a str, Option<&'a Value>) {
+    std::iter::once(key)
+        .chain(aliases.iter().copied())
+        .find_map(|k| obj.get(k).map(|v| (k, Some(v))))
+        .unwrap_or((key, None))
+}
+
+/// Reject every key of `obj` outside `known`, like
+/// [`ZObject::strict()`]. Used for `#[serde(deny_unknown_fields)]`.
+#[doc(hidden)]
+pub fn __deny_unknown_fields(obj: &Map<String, Value>, known: &[&str], errors: &mut VldError) {
+    for key in obj.keys() {
+        if !known.contains(&key.as_str()) {
+            errors.issues.extend(unrecognized_field(key).issues);
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+/// Wrapper that routes a field through [`VldSchemaAsync`](crate::async_schema::VldSchemaAsync)
+/// on the async path.
+///
+/// Used by [`ZObjectAsync::field()`] and the other builders of async objects.
+pub(crate) struct AsyncField<T> {
+    inner: T,
+}
+
+#[cfg(feature = "async")]
+impl<T> DynSchema for AsyncField<T>
+where
+    T: crate::async_schema::VldSchemaAsync,
+    T::Output: serde::Serialize,
+{
+    fn dyn_parse(&self, value: &Value) -> Result<Value, VldError> {
+        self.inner.dyn_parse(value)
+    }
+
+    fn dyn_parse_lenient(&self, value: &Value) -> (Result<Value, VldError>, Vec<FieldResult>) {
+        self.inner.dyn_parse_lenient(value)
+    }
+
+    fn dyn_parse_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> crate::async_schema::BoxFuture<'a, Result<Value, VldError>> {
+        Box::pin(async move {
+            let result = self.inner.parse_value_async(value).await?;
+            serialize_output(&result)
+        })
+    }
+
+    fn __dyn_optional_key(&self) -> bool {
+        self.inner.__vld_optional_key()
+    }
+
+    fn __dyn_deep_partial(&mut self) -> Option<Box<dyn DynSchema>> {
+        self.inner.__vld_deep_partial()
+    }
+
+    fn __dyn_deep_partial_async(&mut self) -> Option<Box<dyn DynSchema + Send + Sync>> {
+        self.inner.__vld_deep_partial_async()
+    }
 }
 
 #[cfg(feature = "openapi")]
@@ -53,24 +483,418 @@ where
 {
     fn dyn_parse(&self, value: &Value) -> Result<Value, VldError> {
         let result = self.inner.parse_value(value)?;
-        serde_json::to_value(&result).map_err(|e| {
-            VldError::single(
-                IssueCode::Custom {
-                    code: "serialize".to_string(),
-                },
-                format!("Failed to serialize validated value: {}", e),
-            )
-        })
+        serialize_output(&result)
     }
 
     fn dyn_json_schema(&self) -> Value {
         self.inner.json_schema()
     }
+
+    fn __dyn_optional_key(&self) -> bool {
+        self.inner.__vld_optional_key()
+    }
+
+    fn __dyn_deep_partial(&mut self) -> Option<Box<dyn DynSchema>> {
+        self.inner.__vld_deep_partial()
+    }
+}
+
+/// A predicate on the input object, deciding which branch of a
+/// [`ZObject::if_then()`] / [`ZObject::if_then_else()`] rule applies.
+///
+/// Conditions look at the raw input, before any field schema runs. Build
+/// them with [`Condition::field()`] or [`Condition::matches()`], and negate
+/// them with `!`.
+///
+/// # Example
+/// ```
+/// use vld::prelude::*;
+///
+/// let adults: Condition = Condition::field("age").gte(18);
+/// let staff: Condition = Condition::field("role").is_in(["admin", "owner"]);
+/// let anonymous: Condition = !Condition::field("user_id").exists();
+/// ```
+///
+/// For the objects of [`vld::object_async()`](crate::object_async) the
+/// condition is a `Condition<dyn DynSchema + Send + Sync>`, built with
+/// [`Condition::matches_async()`] instead of [`Condition::matches()`].
+pub struct Condition<D: ?Sized = dyn DynSchema> {
+    kind: ConditionKind<D>,
+}
+
+enum ConditionKind<D: ?Sized> {
+    Equals(String, Value),
+    In(String, Vec<Value>),
+    Exists(String),
+    Compare(String, Comparison, f64),
+    Not(Box<Condition<D>>),
+    Matches(Box<D>),
+}
+
+#[derive(Clone, Copy)]
+enum Comparison {
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+/// Predicates on one field of the input; see [`Condition::field()`].
+pub struct FieldCondition {
+    field: String,
+}
+
+impl FieldCondition {
+    fn compare<D: ?Sized>(self, op: Comparison, value: f64) -> Condition<D> {
+        Condition {
+            kind: ConditionKind::Compare(self.field, op, value),
+        }
+    }
+
+    /// The field equals `value`.
+    pub fn eq<D: ?Sized>(self, value: impl Into<Value>) -> Condition<D> {
+        Condition {
+            kind: ConditionKind::Equals(self.field, value.into()),
+        }
+    }
+
+    /// The field equals one of `values`.
+    pub fn is_in<D: ?Sized, V: Into<Value>>(
+        self,
+        values: impl IntoIterator<Item = V>,
+    ) -> Condition<D> {
+        Condition {
+            kind: ConditionKind::In(self.field, values.into_iter().map(Into::into).collect()),
+        }
+    }
+
+    /// The key is present in the input, even if its value is `null`.
+    pub fn exists<D: ?Sized>(self) -> Condition<D> {
+        Condition {
+            kind: ConditionKind::Exists(self.field),
+        }
+    }
+
+    /// The field is a number greater than `value`.
+    pub fn gt<D: ?Sized>(self, value: impl Into<f64>) -> Condition<D> {
+        self.compare(Comparison::Gt, value.into())
+    }
+
+    /// The field is a number greater than or equal to `value`.
+    pub fn gte<D: ?Sized>(self, value: impl Into<f64>) -> Condition<D> {
+        self.compare(Comparison::Gte, value.into())
+    }
+
+    /// The field is a number less than `value`.
+    pub fn lt<D: ?Sized>(self, value: impl Into<f64>) -> Condition<D> {
+        self.compare(Comparison::Lt, value.into())
+    }
+
+    /// The field is a number less than or equal to `value`.
+    pub fn lte<D: ?Sized>(self, value: impl Into<f64>) -> Condition<D> {
+        self.compare(Comparison::Lte, value.into())
+    }
 }
 
-struct ObjectField {
+impl Condition {
+    /// Start a condition on one field of the input.
+    pub fn field(name: impl Into<String>) -> FieldCondition {
+        FieldCondition { field: name.into() }
+    }
+
+    /// The whole input object passes `schema`.
+    ///
+    /// In JSON Schema output the condition is `schema`'s
+    /// [`dyn_json_schema()`](DynSchema::dyn_json_schema), so an object
+    /// schema needs [`field_schema()`](ZObject::field_schema) fields; use
+    /// [`matches_schema()`](Self::matches_schema) for other schemas.
+    pub fn matches<S: DynSchema + 'static>(schema: S) -> Self {
+        Self {
+            kind: ConditionKind::Matches(Box::new(schema)),
+        }
+    }
+
+    /// Same as [`matches()`](Self::matches), with `schema`'s own JSON Schema
+    /// in [`ZObject::to_json_schema()`].
+    ///
+    /// Requires the `openapi` feature.
+    #[cfg(feature = "openapi")]
+    pub fn matches_schema<S>(schema: S) -> Self
+    where
+        S: VldSchema + crate::json_schema::JsonSchema + 'static,
+        S::Output: serde::Serialize,
+    {
+        Self::matches(JsonSchemaField { inner: schema })
+    }
+}
+
+#[cfg(feature = "async")]
+impl Condition<dyn DynSchema + Send + Sync> {
+    /// [`matches()`](Condition::matches) for the objects of
+    /// [`vld::object_async()`](crate::object_async), with `schema`'s own
+    /// JSON Schema when the `openapi` feature is enabled. The schema runs
+    /// synchronously, like every condition.
+    ///
+    /// Requires the `async` feature.
+    pub fn matches_async<S>(schema: S) -> Self
+    where
+        S: VldSchema + MaybeJsonSchema + Send + Sync + 'static,
+        S::Output: serde::Serialize,
+    {
+        Self {
+            kind: ConditionKind::Matches(Box::new(json_field(schema))),
+        }
+    }
+}
+
+impl<D: ?Sized + DynSchema> Condition<D> {
+    /// The fields the condition reads, for issue params.
+    fn fields(&self) -> Vec<String> {
+        match &self.kind {
+            ConditionKind::Equals(field, _)
+            | ConditionKind::In(field, _)
+            | ConditionKind::Exists(field)
+            | ConditionKind::Compare(field, ..) => vec![field.clone()],
+            ConditionKind::Not(inner) => inner.fields(),
+            ConditionKind::Matches(_) => vec![],
+        }
+    }
+
+    /// Whether the input object satisfies the condition.
+    fn test(&self, obj: &Map<String, Value>, value: &Value) -> bool {
+        match &self.kind {
+            ConditionKind::Equals(field, expected) => obj.get(field) == Some(expected),
+            ConditionKind::In(field, values) => obj.get(field).is_some_and(|v| values.contains(v)),
+            ConditionKind::Exists(field) => obj.contains_key(field),
+            ConditionKind::Compare(field, op, bound) => obj
+                .get(field)
+                .and_then(Value::as_f64)
+                .is_some_and(|n| match op {
+                    Comparison::Gt => n > *bound,
+                    Comparison::Gte => n >= *bound,
+                    Comparison::Lt => n < *bound,
+                    Comparison::Lte => n <= *bound,
+                }),
+            ConditionKind::Not(inner) => !inner.test(obj, value),
+            ConditionKind::Matches(schema) => schema.dyn_parse(value).is_ok(),
+        }
+    }
+
+    /// The condition as the `if` of a JSON Schema `if` / `then` / `else`.
+    #[cfg(feature = "openapi")]
+    fn json_schema(&self) -> Value {
+        let on_field = |field: &str, schema: Value| {
+            serde_json::json!({
+                "properties": { field: schema },
+                "required": [field],
+            })
+        };
+        match &self.kind {
+            ConditionKind::Equals(field, value) => {
+                on_field(field, serde_json::json!({ "const": value }))
+            }
+            ConditionKind::In(field, values) => {
+                on_field(field, serde_json::json!({ "enum": values }))
+            }
+            ConditionKind::Exists(field) => serde_json::json!({ "required": [field] }),
+            ConditionKind::Compare(field, op, bound) => {
+                let keyword = match op {
+                    Comparison::Gt => "exclusiveMinimum",
+                    Comparison::Gte => "minimum",
+                    Comparison::Lt => "exclusiveMaximum",
+                    Comparison::Lte => "maximum",
+                };
+                on_field(
+                    field,
+                    serde_json::json!({ "type": "number", keyword: bound }),
+                )
+            }
+            ConditionKind::Not(inner) => serde_json::json!({ "not": inner.json_schema() }),
+            ConditionKind::Matches(schema) => schema.dyn_json_schema(),
+        }
+    }
+}
+
+impl<D: ?Sized> std::ops::Not for Condition<D> {
+    type Output = Condition<D>;
+
+    fn not(self) -> Condition<D> {
+        Condition {
+            kind: ConditionKind::Not(Box::new(self)),
+        }
+    }
+}
+
+/// A rule on which keys must or must not appear together.
+///
+/// Added to a [`ZObject`] with [`required_if()`](ZObject::required_if),
+/// [`required_with()`](ZObject::required_with) and
+/// [`excluded_with()`](ZObject::excluded_with), or listed in the
+/// `dependencies { ... }` block of [`schema!`](crate::schema!). A key counts
+/// as present when it is in the input and not `null`.
+pub struct Dependency<D: ?Sized = dyn DynSchema> {
+    field: String,
+    kind: DependencyKind<D>,
+}
+
+enum DependencyKind<D: ?Sized> {
+    RequiredIf(Condition<D>),
+    RequiredWith(Vec<String>),
+    ExcludedWith(Vec<String>),
+}
+
+/// Whether `key` is in `obj` with a non-`null` value.
+fn is_present(obj: &Map<String, Value>, key: &str) -> bool {
+    obj.get(key).is_some_and(|v| !v.is_null())
+}
+
+impl Dependency {
+    /// `field` must be present whenever `condition` holds.
+    pub fn required_if(field: impl Into<String>, condition: Condition) -> Self {
+        Self {
+            field: field.into(),
+            kind: DependencyKind::RequiredIf(condition),
+        }
+    }
+
+    /// `field` must be present whenever any of `others` is.
+    pub fn required_with<S: Into<String>>(
+        field: impl Into<String>,
+        others: impl IntoIterator<Item = S>,
+    ) -> Self {
+        Self {
+            field: field.into(),
+            kind: DependencyKind::RequiredWith(others.into_iter().map(Into::into).collect()),
+        }
+    }
+
+    /// `field` must be absent whenever any of `others` is present.
+    pub fn excluded_with<S: Into<String>>(
+        field: impl Into<String>,
+        others: impl IntoIterator<Item = S>,
+    ) -> Self {
+        Self {
+            field: field.into(),
+            kind: DependencyKind::ExcludedWith(others.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl<D: ?Sized + DynSchema> Dependency<D> {
+    /// Check the rule against the input object, reporting a violation under
+    /// the rule's field.
+    #[doc(hidden)]
+    pub fn __check(&self, obj: &Map<String, Value>, value: &Value, errors: &mut VldError) {
+        let present_others = |others: &[String]| -> Vec<String> {
+            others
+                .iter()
+                .filter(|other| is_present(obj, other))
+                .cloned()
+                .collect()
+        };
+        let (code, message) = match &self.kind {
+            DependencyKind::RequiredIf(condition) => {
+                if is_present(obj, &self.field) || !condition.test(obj, value) {
+                    return;
+                }
+                let fields = condition.fields();
+                let message = if fields.is_empty() {
+                    "Required field is missing".to_string()
+                } else {
+                    format!("Required by the value of {}", fields.join(", "))
+                };
+                (IssueCode::RequiredIf { fields }, message)
+            }
+            DependencyKind::RequiredWith(others) => {
+                let fields = present_others(others);
+                if is_present(obj, &self.field) || fields.is_empty() {
+                    return;
+                }
+                let message = format!("Required when {} is present", fields.join(", "));
+                (IssueCode::RequiredWith { fields }, message)
+            }
+            DependencyKind::ExcludedWith(others) => {
+                let fields = present_others(others);
+                if !is_present(obj, &self.field) || fields.is_empty() {
+                    return;
+                }
+                let message = format!("Not allowed together with {}", fields.join(", "));
+                (IssueCode::ExcludedWith { fields }, message)
+            }
+        };
+        errors.issues.extend(
+            VldError::single(code, message)
+                .with_prefix(PathSegment::Field(self.field.clone()))
+                .issues,
+        );
+    }
+
+    /// Add the rule to an object's JSON Schema: `dependentRequired` for
+    /// `required_with`, `dependentSchemas` with `not` for `excluded_with`,
+    /// and an `if` / `then` entry of `allOf` for `required_if`.
+    #[cfg(feature = "openapi")]
+    #[doc(hidden)]
+    pub fn __add_to_json_schema(&self, schema: &mut Value) {
+        let field = Value::String(self.field.clone());
+        match &self.kind {
+            DependencyKind::RequiredIf(condition) => {
+                let entry = serde_json::json!({
+                    "if": condition.json_schema(),
+                    "then": { "required": [field] },
+                });
+                match schema["allOf"].as_array_mut() {
+                    Some(rules) => rules.push(entry),
+                    None => schema["allOf"] = Value::Array(vec![entry]),
+                }
+            }
+            DependencyKind::RequiredWith(others) => {
+                for other in others {
+                    let required = &mut schema["dependentRequired"][other.as_str()];
+                    match required.as_array_mut() {
+                        Some(fields) if !fields.contains(&field) => fields.push(field.clone()),
+                        Some(_) => {}
+                        None => *required = Value::Array(vec![field.clone()]),
+                    }
+                }
+            }
+            DependencyKind::ExcludedWith(others) => {
+                for other in others {
+                    let excluded = serde_json::json!({ "not": { "required": [field] } });
+                    let entry = &mut schema["dependentSchemas"][other.as_str()];
+                    if entry.is_null() {
+                        *entry = excluded;
+                    } else if let Some(all) = entry["allOf"].as_array_mut() {
+                        all.push(excluded);
+                    } else {
+                        *entry = serde_json::json!({ "allOf": [entry.take(), excluded] });
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// An [`if_then()`](ZObject::if_then) / [`if_then_else()`](ZObject::if_then_else) rule.
+struct IfRule<D: ?Sized> {
+    condition: Condition<D>,
+    then: ZObject<D>,
+    otherwise: Option<ZObject<D>>,
+}
+
+impl<D: ?Sized + DynSchema> IfRule<D> {
+    /// The branch that applies to the input, if any.
+    fn branch(&self, obj: &Map<String, Value>, value: &Value) -> Option<&ZObject<D>> {
+        if self.condition.test(obj, value) {
+            Some(&self.then)
+        } else {
+            self.otherwise.as_ref()
+        }
+    }
+}
+
+struct ObjectField<D: ?Sized> {
     name: String,
-    schema: Box<dyn DynSchema>,
+    schema: Box<D>,
 }
 
 /// How to handle unknown fields not declared in the schema.
@@ -104,32 +928,109 @@ enum UnknownFieldMode {
 /// ```
 /// Conditional validation rule: when `condition_field` has `condition_value`,
 /// validate `target_field` with `schema`.
-struct ConditionalRule {
+struct ConditionalRule<D: ?Sized> {
     condition_field: String,
     condition_value: serde_json::Value,
     target_field: String,
-    schema: Box<dyn DynSchema>,
+    schema: Box<D>,
+}
+
+#[cfg(feature = "openapi")]
+impl<D: ?Sized + DynSchema> ConditionalRule<D> {
+    /// The rule as an `if` / `then` entry of `allOf`.
+    fn json_schema(&self) -> Value {
+        let mut then = serde_json::json!({
+            "properties": { self.target_field.as_str(): self.schema.dyn_json_schema() },
+        });
+        // A missing target is validated as `null`.
+        if self.schema.dyn_parse(&Value::Null).is_err() {
+            then["required"] = serde_json::json!([self.target_field]);
+        }
+        serde_json::json!({
+            "if": {
+                "properties": { self.condition_field.as_str(): { "const": self.condition_value } },
+                "required": [self.condition_field],
+            },
+            "then": then,
+        })
+    }
+}
+
+/// Schema for the undeclared keys matching a pattern, see
+/// [`ZObject::pattern_field()`].
+#[cfg(feature = "regex")]
+struct PatternField<D: ?Sized> {
+    pattern: regex_lite::Regex,
+    schema: Box<D>,
 }
 
-pub struct ZObject {
-    fields: Vec<ObjectField>,
+pub struct ZObject<D: ?Sized = dyn DynSchema> {
+    fields: Vec<ObjectField<D>>,
+    #[cfg(feature = "regex")]
+    pattern_fields: Vec<PatternField<D>>,
     unknown_mode: UnknownFieldMode,
-    catchall_schema: Option<Box<dyn DynSchema>>,
-    conditional_rules: Vec<ConditionalRule>,
+    catchall_schema: Option<Box<D>>,
+    conditional_rules: Vec<ConditionalRule<D>>,
+    if_rules: Vec<IfRule<D>>,
+    dependencies: Vec<Dependency<D>>,
     min_keys: Option<usize>,
     max_keys: Option<usize>,
 }
 
+/// An object schema whose field schemas are `Send + Sync` and run through
+/// their [`VldSchemaAsync`](crate::async_schema::VldSchemaAsync)
+/// implementation on the async path. Created via
+/// [`vld::object_async()`](crate::object_async).
+///
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub type ZObjectAsync = ZObject<dyn DynSchema + Send + Sync>;
+
+/// The boxed schema type of a [`ZObject`]: `dyn DynSchema`, or
+/// `dyn DynSchema + Send + Sync` for a [`ZObjectAsync`].
+pub trait DynStore: DynSchema + 'static {
+    #[doc(hidden)]
+    fn __optional(schema: Box<Self>) -> Box<Self>;
+
+    #[doc(hidden)]
+    fn __required(schema: Box<Self>) -> Box<Self>;
+
+    #[doc(hidden)]
+    fn __deep_partial(schema: &mut Self) -> Option<Box<Self>>;
+}
+
+impl DynStore for dyn DynSchema {
+    fn __optional(schema: Box<Self>) -> Box<Self> {
+        Box::new(OptionalDynSchema(schema))
+    }
+
+    fn __required(schema: Box<Self>) -> Box<Self> {
+        Box::new(RequiredDynSchema(schema))
+    }
+
+    fn __deep_partial(schema: &mut Self) -> Option<Box<Self>> {
+        schema.__dyn_deep_partial()
+    }
+}
+
+#[cfg(feature = "async")]
+impl DynStore for dyn DynSchema + Send + Sync {
+    fn __optional(schema: Box<Self>) -> Box<Self> {
+        Box::new(OptionalDynSchema(schema))
+    }
+
+    fn __required(schema: Box<Self>) -> Box<Self> {
+        Box::new(RequiredDynSchema(schema))
+    }
+
+    fn __deep_partial(schema: &mut Self) -> Option<Box<Self>> {
+        schema.__dyn_deep_partial_async()
+    }
+}
+
 impl ZObject {
     pub fn new() -> Self {
-        Self {
-            fields: vec![],
-            unknown_mode: UnknownFieldMode::Strip,
-            catchall_schema: None,
-            conditional_rules: vec![],
-            min_keys: None,
-            max_keys: None,
-        }
+        Self::default()
     }
 
     /// Add a field with its validation schema.
@@ -141,6 +1042,16 @@ impl ZObject {
         self
     }
 
+    /// Add a field with an already boxed schema.
+    #[doc(hidden)]
+    pub fn __field_boxed(mut self, name: impl Into<String>, schema: Box<dyn DynSchema>) -> Self {
+        self.fields.push(ObjectField {
+            name: name.into(),
+            schema,
+        });
+        self
+    }
+
     /// Add a field with its validation schema **and** JSON Schema support.
     ///
     /// Same as [`field()`](Self::field), but the field's schema will be
@@ -179,16 +1090,201 @@ impl ZObject {
     /// ```
     pub fn field_optional<S: DynSchema + 'static>(
         mut self,
-        name: impl Into<String>,
+        name: impl Into<String>,
+        schema: S,
+    ) -> Self {
+        self.fields.push(ObjectField {
+            name: name.into(),
+            schema: Box::new(OptionalDynSchema(Box::new(schema))),
+        });
+        self
+    }
+
+    /// Validate the undeclared keys matching `pattern` with `schema`.
+    ///
+    /// A key matching several patterns must pass all their schemas. Keys
+    /// that match no pattern go to the [`catchall()`](Self::catchall)
+    /// schema or follow the unknown field mode, so with
+    /// [`strict()`](Self::strict) everything else is rejected. Maps to
+    /// `patternProperties` in [`to_json_schema()`](Self::to_json_schema).
+    ///
+    /// Requires the `regex` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use vld::prelude::*;
+    /// use vld::regex_lite::Regex;
+    ///
+    /// let headers = vld::object()
+    ///     .pattern_field(Regex::new("^x-").unwrap(), vld::string())
+    ///     .pattern_field(Regex::new("^[0-9]+$").unwrap(), vld::number().int())
+    ///     .strict();
+    ///
+    /// assert!(headers.parse(r#"{"x-trace": "abc", "42": 7}"#).is_ok());
+    /// let err = headers.parse(r#"{"x-trace": 1, "other": true}"#).unwrap_err();
+    /// assert_eq!(err.issues[0].path[0].to_string(), ".x-trace");
+    /// assert_eq!(err.issues[1].path[0].to_string(), ".other");
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn pattern_field<S>(mut self, pattern: regex_lite::Regex, schema: S) -> Self
+    where
+        S: VldSchema + MaybeJsonSchema + 'static,
+        S::Output: serde::Serialize,
+    {
+        self.pattern_fields.push(PatternField {
+            pattern,
+            schema: Box::new(json_field(schema)),
+        });
+        self
+    }
+
+    /// Validate unknown fields using the given schema instead of stripping/rejecting them.
+    ///
+    /// When set, unknown fields are parsed through the catchall schema
+    /// regardless of the unknown field mode.
+    pub fn catchall<S: DynSchema + 'static>(mut self, schema: S) -> Self {
+        self.catchall_schema = Some(Box::new(schema));
+        self
+    }
+
+    /// Add a conditional validation rule.
+    ///
+    /// When `condition_field` has the given value, `target_field` is validated
+    /// with the provided schema **in addition** to any existing field schemas.
+    /// For other predicates, or rules on several fields, see
+    /// [`if_then()`](Self::if_then).
+    ///
+    /// # Example
+    /// ```
+    /// use vld::prelude::*;
+    ///
+    /// let schema = vld::object()
+    ///     .field("role", vld::string())
+    ///     .field_optional("admin_key", vld::string())
+    ///     .when("role", "admin", "admin_key", vld::string().min(10));
+    ///
+    /// // When role != "admin", admin_key is optional and any value is fine
+    /// let ok = schema.parse(r#"{"role": "user"}"#);
+    /// assert!(ok.is_ok());
+    ///
+    /// // When role == "admin", admin_key must pass the extra schema
+    /// let err = schema.parse(r#"{"role": "admin", "admin_key": "short"}"#);
+    /// assert!(err.is_err());
+    /// ```
+    pub fn when<S: DynSchema + 'static>(
+        mut self,
+        condition_field: impl Into<String>,
+        condition_value: impl Into<serde_json::Value>,
+        target_field: impl Into<String>,
+        schema: S,
+    ) -> Self {
+        self.conditional_rules.push(ConditionalRule {
+            condition_field: condition_field.into(),
+            condition_value: condition_value.into(),
+            target_field: target_field.into(),
+            schema: Box::new(schema),
+        });
+        self
+    }
+}
+
+#[cfg(feature = "async")]
+impl ZObjectAsync {
+    /// Add a field with its validation schema, parsed through its
+    /// [`VldSchemaAsync`](crate::async_schema::VldSchemaAsync) implementation
+    /// on the async path.
+    pub fn field<S>(mut self, name: impl Into<String>, schema: S) -> Self
+    where
+        S: crate::async_schema::VldSchemaAsync + 'static,
+        S::Output: serde::Serialize,
+    {
+        self.fields.push(ObjectField {
+            name: name.into(),
+            schema: Box::new(AsyncField { inner: schema }),
+        });
+        self
+    }
+
+    /// Async counterpart of [`ZObject::field_schema()`].
+    ///
+    /// Requires the `openapi` feature.
+    #[cfg(feature = "openapi")]
+    pub fn field_schema<S>(mut self, name: impl Into<String>, schema: S) -> Self
+    where
+        S: crate::async_schema::VldSchemaAsync + crate::json_schema::JsonSchema + 'static,
+        S::Output: serde::Serialize,
+    {
+        self.fields.push(ObjectField {
+            name: name.into(),
+            schema: erase_async(schema),
+        });
+        self
+    }
+
+    /// Async counterpart of [`ZObject::field_optional()`].
+    pub fn field_optional<S>(mut self, name: impl Into<String>, schema: S) -> Self
+    where
+        S: crate::async_schema::VldSchemaAsync + 'static,
+        S::Output: serde::Serialize,
+    {
+        self.fields.push(ObjectField {
+            name: name.into(),
+            schema: Box::new(OptionalDynSchema(
+                Box::new(AsyncField { inner: schema }) as Box<dyn DynSchema + Send + Sync>
+            )),
+        });
+        self
+    }
+
+    /// Async counterpart of [`ZObject::pattern_field()`].
+    ///
+    /// Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    pub fn pattern_field<S>(mut self, pattern: regex_lite::Regex, schema: S) -> Self
+    where
+        S: crate::async_schema::VldSchemaAsync + MaybeJsonSchema + 'static,
+        S::Output: serde::Serialize,
+    {
+        self.pattern_fields.push(PatternField {
+            pattern,
+            schema: erase_async(schema),
+        });
+        self
+    }
+
+    /// Async counterpart of [`ZObject::catchall()`].
+    pub fn catchall<S>(mut self, schema: S) -> Self
+    where
+        S: crate::async_schema::VldSchemaAsync + 'static,
+        S::Output: serde::Serialize,
+    {
+        self.catchall_schema = Some(Box::new(AsyncField { inner: schema }));
+        self
+    }
+
+    /// Async counterpart of [`ZObject::when()`].
+    pub fn when<S>(
+        mut self,
+        condition_field: impl Into<String>,
+        condition_value: impl Into<serde_json::Value>,
+        target_field: impl Into<String>,
         schema: S,
-    ) -> Self {
-        self.fields.push(ObjectField {
-            name: name.into(),
-            schema: Box::new(OptionalDynSchema(Box::new(schema))),
+    ) -> Self
+    where
+        S: crate::async_schema::VldSchemaAsync + 'static,
+        S::Output: serde::Serialize,
+    {
+        self.conditional_rules.push(ConditionalRule {
+            condition_field: condition_field.into(),
+            condition_value: condition_value.into(),
+            target_field: target_field.into(),
+            schema: Box::new(AsyncField { inner: schema }),
         });
         self
     }
+}
 
+impl<D: ?Sized + DynStore> ZObject<D> {
     /// Reject unknown fields not defined in the schema.
     pub fn strict(mut self) -> Self {
         self.unknown_mode = UnknownFieldMode::Strict;
@@ -224,7 +1320,7 @@ impl ZObject {
     /// Merge another object schema's fields into this one.
     ///
     /// If both schemas define the same field, the one from `other` wins.
-    pub fn extend(mut self, other: ZObject) -> Self {
+    pub fn extend(mut self, other: ZObject<D>) -> Self {
         for field in other.fields {
             self.fields.retain(|f| f.name != field.name);
             self.fields.push(field);
@@ -233,7 +1329,7 @@ impl ZObject {
     }
 
     /// Alias for [`extend`](Self::extend).
-    pub fn merge(self, other: ZObject) -> Self {
+    pub fn merge(self, other: ZObject<D>) -> Self {
         self.extend(other)
     }
 
@@ -247,7 +1343,7 @@ impl ZObject {
             .into_iter()
             .map(|f| ObjectField {
                 name: f.name,
-                schema: Box::new(OptionalDynSchema(f.schema)),
+                schema: D::__optional(f.schema),
             })
             .collect();
         self
@@ -261,55 +1357,123 @@ impl ZObject {
             .into_iter()
             .map(|f| ObjectField {
                 name: f.name,
-                schema: Box::new(RequiredDynSchema(f.schema)),
+                schema: D::__required(f.schema),
             })
             .collect();
         self
     }
 
-    /// Validate unknown fields using the given schema instead of stripping/rejecting them.
+    /// Validate the input with `then` whenever `condition` holds.
     ///
-    /// When set, unknown fields are parsed through the catchall schema
-    /// regardless of the unknown field mode.
-    pub fn catchall<S: DynSchema + 'static>(mut self, schema: S) -> Self {
-        self.catchall_schema = Some(Box::new(schema));
+    /// `then` lists the fields the rule applies to, with the schemas they
+    /// must pass **in addition** to their own; its required fields become
+    /// required. Its output is discarded. In
+    /// [`to_json_schema()`](Self::to_json_schema) the rule is an
+    /// `if` / `then` entry of `allOf`.
+    ///
+    /// # Example
+    /// ```
+    /// use vld::prelude::*;
+    ///
+    /// let schema = vld::object()
+    ///     .field("role", vld::string())
+    ///     .field_optional("admin_key", vld::string())
+    ///     .if_then(
+    ///         Condition::field("role").is_in(["admin", "owner"]),
+    ///         vld::object().field("admin_key", vld::string().min(10)),
+    ///     );
+    ///
+    /// assert!(schema.parse(r#"{"role": "user"}"#).is_ok());
+    /// assert!(schema.parse(r#"{"role": "owner"}"#).is_err());
+    /// ```
+    pub fn if_then(mut self, condition: Condition<D>, then: ZObject<D>) -> Self {
+        self.if_rules.push(IfRule {
+            condition,
+            then,
+            otherwise: None,
+        });
         self
     }
 
-    /// Add a conditional validation rule.
-    ///
-    /// When `condition_field` has the given value, `target_field` is validated
-    /// with the provided schema **in addition** to any existing field schemas.
+    /// Like [`if_then()`](Self::if_then), validating the input with
+    /// `otherwise` when `condition` does not hold.
+    pub fn if_then_else(
+        mut self,
+        condition: Condition<D>,
+        then: ZObject<D>,
+        otherwise: ZObject<D>,
+    ) -> Self {
+        self.if_rules.push(IfRule {
+            condition,
+            then,
+            otherwise: Some(otherwise),
+        });
+        self
+    }
+
+    /// Require `field` whenever `condition` holds, reported with
+    /// [`IssueCode::RequiredIf`].
     ///
     /// # Example
     /// ```
     /// use vld::prelude::*;
     ///
     /// let schema = vld::object()
-    ///     .field("role", vld::string())
-    ///     .field_optional("admin_key", vld::string())
-    ///     .when("role", "admin", "admin_key", vld::string().min(10));
+    ///     .field("payment_method", vld::string())
+    ///     .field_optional("billing_address", vld::string())
+    ///     .required_if("billing_address", Condition::field("payment_method").eq("card"));
     ///
-    /// // When role != "admin", admin_key is optional and any value is fine
-    /// let ok = schema.parse(r#"{"role": "user"}"#);
-    /// assert!(ok.is_ok());
+    /// assert!(schema.parse(r#"{"payment_method": "cash"}"#).is_ok());
+    /// let err = schema.parse(r#"{"payment_method": "card"}"#).unwrap_err();
+    /// assert_eq!(err.issues[0].code.key(), "required_if");
+    /// ```
+    pub fn required_if(mut self, field: impl Into<String>, condition: Condition<D>) -> Self {
+        self.dependencies.push(Dependency {
+            field: field.into(),
+            kind: DependencyKind::RequiredIf(condition),
+        });
+        self
+    }
+
+    /// Require `field` whenever any of `others` is present, reported with
+    /// [`IssueCode::RequiredWith`]. In JSON Schema: `dependentRequired`.
+    pub fn required_with<S: Into<String>>(
+        mut self,
+        field: impl Into<String>,
+        others: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.dependencies.push(Dependency {
+            field: field.into(),
+            kind: DependencyKind::RequiredWith(others.into_iter().map(Into::into).collect()),
+        });
+        self
+    }
+
+    /// Reject `field` whenever any of `others` is present, reported with
+    /// [`IssueCode::ExcludedWith`]. In JSON Schema: `dependentSchemas` with
+    /// `not`.
     ///
-    /// // When role == "admin", admin_key must pass the extra schema
-    /// let err = schema.parse(r#"{"role": "admin", "admin_key": "short"}"#);
-    /// assert!(err.is_err());
+    /// # Example
     /// ```
-    pub fn when<S: DynSchema + 'static>(
+    /// use vld::prelude::*;
+    ///
+    /// let schema = vld::object()
+    ///     .field_optional("coupon", vld::string())
+    ///     .field_optional("gift_card", vld::string())
+    ///     .excluded_with("coupon", ["gift_card"]);
+    ///
+    /// assert!(schema.parse(r#"{"coupon": "SAVE10"}"#).is_ok());
+    /// let err = schema.parse(r#"{"coupon": "SAVE10", "gift_card": "GC-1"}"#).unwrap_err();
+    /// assert_eq!(err.to_string(), ".coupon: Not allowed together with gift_card");
+    /// ```
+    pub fn excluded_with<S: Into<String>>(
         mut self,
-        condition_field: impl Into<String>,
-        condition_value: impl Into<serde_json::Value>,
-        target_field: impl Into<String>,
-        schema: S,
+        field: impl Into<String>,
+        others: impl IntoIterator<Item = S>,
     ) -> Self {
-        self.conditional_rules.push(ConditionalRule {
-            condition_field: condition_field.into(),
-            condition_value: condition_value.into(),
-            target_field: target_field.into(),
-            schema: Box::new(schema),
+        self.dependencies.push(Dependency {
+            field: field.into(),
+            kind: DependencyKind::ExcludedWith(others.into_iter().map(Into::into).collect()),
         });
         self
     }
@@ -328,9 +1492,45 @@ impl ZObject {
 
     /// Make all fields optional recursively.
     ///
-    /// Currently equivalent to [`partial()`](Self::partial) — nested objects
-    /// must apply `partial()` separately.
-    pub fn deep_partial(self) -> Self {
+    /// Like [`partial()`](Self::partial), and every nested object schema
+    /// becomes partial too: object fields, the elements of
+    /// [`vld::array()`](crate::array), the values of
+    /// [`vld::record()`](crate::record), the inner schema of `optional()` /
+    /// `nullable()` / `nullish()`, and [`schema!`](crate::schema!) /
+    /// `#[derive(Validate)]` structs nested with
+    /// [`vld::nested!`](crate::nested!). A nested struct is validated as a
+    /// partial object of its field schemas, so its output is JSON rather
+    /// than the struct, and its struct-level `refine` rules do not run.
+    ///
+    /// # Example
+    /// ```
+    /// use vld::prelude::*;
+    ///
+    /// let user = vld::object()
+    ///     .field("name", vld::string().min(1))
+    ///     .field(
+    ///         "address",
+    ///         vld::object()
+    ///             .field("city", vld::string())
+    ///             .field("zip", vld::string().len(5)),
+    ///     )
+    ///     .field(
+    ///         "phones",
+    ///         vld::array(vld::object().field("number", vld::string())),
+    ///     );
+    ///
+    /// let patch = user.deep_partial();
+    /// assert!(patch.parse(r#"{"address": {"city": "Berlin"}}"#).is_ok());
+    /// assert!(patch.parse(r#"{"phones": [{}]}"#).is_ok());
+    /// // Values that are present are still validated.
+    /// assert!(patch.parse(r#"{"address": {"zip": "1"}}"#).is_err());
+    /// ```
+    pub fn deep_partial(mut self) -> Self {
+        for field in &mut self.fields {
+            if let Some(partial) = D::__deep_partial(&mut field.schema) {
+                field.schema = partial;
+            }
+        }
         self.partial()
     }
 
@@ -348,7 +1548,12 @@ impl ZObject {
     /// Requires the `openapi` feature.
     #[cfg(feature = "openapi")]
     pub fn to_json_schema(&self) -> serde_json::Value {
-        let required: Vec<String> = self.fields.iter().map(|f| f.name.clone()).collect();
+        let required: Vec<String> = self
+            .fields
+            .iter()
+            .filter(|f| f.schema.__dyn_json_required())
+            .map(|f| f.name.clone())
+            .collect();
         let mut props = serde_json::Map::new();
         for f in &self.fields {
             props.insert(f.name.clone(), f.schema.dyn_json_schema());
@@ -359,6 +1564,14 @@ impl ZObject {
             "properties": Value::Object(props),
             "additionalProperties": self.unknown_mode != UnknownFieldMode::Strict,
         });
+        #[cfg(feature = "regex")]
+        if !self.pattern_fields.is_empty() {
+            let mut patterns = serde_json::Map::new();
+            for p in &self.pattern_fields {
+                patterns.insert(p.pattern.as_str().to_string(), p.schema.dyn_json_schema());
+            }
+            schema["patternProperties"] = Value::Object(patterns);
+        }
         if let Some(ref catchall) = self.catchall_schema {
             schema["additionalProperties"] = catchall.dyn_json_schema();
         }
@@ -368,21 +1581,51 @@ impl ZObject {
         if let Some(max) = self.max_keys {
             schema["maxProperties"] = serde_json::json!(max);
         }
+        let rules: Vec<Value> = self
+            .conditional_rules
+            .iter()
+            .map(ConditionalRule::json_schema)
+            .chain(self.if_rules.iter().map(|rule| {
+                let mut entry = serde_json::json!({
+                    "if": rule.condition.json_schema(),
+                    "then": rule.then.to_json_schema(),
+                });
+                if let Some(otherwise) = &rule.otherwise {
+                    entry["else"] = otherwise.to_json_schema();
+                }
+                entry
+            }))
+            .collect();
+        if !rules.is_empty() {
+            schema["allOf"] = Value::Array(rules);
+        }
+        for dependency in &self.dependencies {
+            dependency.__add_to_json_schema(&mut schema);
+        }
         schema
     }
 }
 
-impl Default for ZObject {
+impl<D: ?Sized> Default for ZObject<D> {
     fn default() -> Self {
-        Self::new()
+        Self {
+            fields: vec![],
+            #[cfg(feature = "regex")]
+            pattern_fields: vec![],
+            unknown_mode: UnknownFieldMode::Strip,
+            catchall_schema: None,
+            conditional_rules: vec![],
+            if_rules: vec![],
+            dependencies: vec![],
+            min_keys: None,
+            max_keys: None,
+        }
     }
 }
 
-impl VldSchema for ZObject {
-    type Output = Map<String, Value>;
-
-    fn parse_value(&self, value: &Value) -> Result<Map<String, Value>, VldError> {
-        let obj = value.as_object().ok_or_else(|| {
+impl<D: ?Sized + DynStore> ZObject<D> {
+    fn expect_object(value: &Value) -> Result<&Map<String, Value>, VldError> {
+        value.as_object().ok_or_else(|| {
             VldError::single(
                 IssueCode::InvalidType {
                     expected: "object".to_string(),
@@ -390,11 +1633,11 @@ impl VldSchema for ZObject {
                 },
                 format!("Expected object, received {}", value_type_name(value)),
             )
-        })?;
+        })
+    }
 
-        let mut result = Map::new();
+    fn check_keys(&self, obj: &Map<String, Value>) -> VldError {
         let mut errors = VldError::new();
-
         if let Some(min) = self.min_keys {
             if obj.len() < min {
                 errors.push(
@@ -417,11 +1660,72 @@ impl VldSchema for ZObject {
                 );
             }
         }
+        errors
+    }
+
+    fn unknown_keys<'v>(&self, obj: &'v Map<String, Value>) -> Vec<&'v String> {
+        let known_keys: Vec<&str> = self.fields.iter().map(|f| f.name.as_str()).collect();
+        obj.keys()
+            .filter(|k| !known_keys.contains(&k.as_str()))
+            .collect()
+    }
+
+    /// The pattern field schemas that apply to an undeclared key.
+    #[cfg(feature = "regex")]
+    fn pattern_schemas<'s>(&'s self, key: &str) -> Vec<&'s D> {
+        self.pattern_fields
+            .iter()
+            .filter(|p| p.pattern.is_match(key))
+            .map(|p| p.schema.as_ref())
+            .collect()
+    }
+
+    /// Strip / reject / pass through unknown keys when no catchall is set.
+    fn handle_unknown_keys(
+        &self,
+        obj: &Map<String, Value>,
+        unknown_keys: &[&String],
+        result: &mut Map<String, Value>,
+        errors: &mut VldError,
+    ) {
+        match self.unknown_mode {
+            UnknownFieldMode::Strip => {}
+            UnknownFieldMode::Strict => {
+                for key in unknown_keys {
+                    errors.issues.extend(unrecognized_field(key).issues);
+                }
+            }
+            UnknownFieldMode::Passthrough => {
+                for key in unknown_keys {
+                    result.insert((*key).clone(), obj[key.as_str()].clone());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl ZObjectAsync {
+    /// Async counterpart of [`parse_value`](VldSchema::parse_value): every field,
+    /// catchall and conditional schema runs through
+    /// [`DynSchema::dyn_parse_async`].
+    pub(crate) async fn parse_value_async_impl(
+        &self,
+        value: &Value,
+    ) -> Result<Map<String, Value>, VldError> {
+        let obj = Self::expect_object(value)?;
+
+        let mut result = Map::new();
+        let mut errors = self.check_keys(obj);
+        let limit = crate::options::issue_limit();
 
-        // Validate defined fields
         for field in &self.fields {
-            let field_value = obj.get(&field.name).unwrap_or(&Value::Null);
-            match field.schema.dyn_parse(field_value) {
+            if crate::options::limit_reached(&errors, limit) {
+                break;
+            }
+            let field_value = obj.get(&field.name).unwrap_or(&ABSENT);
+            match field.schema.dyn_parse_async(field_value).await {
+                Ok(_) if is_absent(field_value) && field.schema.__dyn_optional_key() => {}
                 Ok(v) => {
                     result.insert(field.name.clone(), v);
                 }
@@ -431,17 +1735,43 @@ impl VldSchema for ZObject {
             }
         }
 
-        // Handle unknown fields
-        let known_keys: Vec<&str> = self.fields.iter().map(|f| f.name.as_str()).collect();
-        let unknown_keys: Vec<&String> = obj
-            .keys()
-            .filter(|k| !known_keys.contains(&k.as_str()))
-            .collect();
-
+        #[allow(unused_mut)]
+        let mut unknown_keys = self.unknown_keys(obj);
+        #[cfg(feature = "regex")]
+        {
+            let mut rest = Vec::with_capacity(unknown_keys.len());
+            for key in unknown_keys {
+                let schemas = self.pattern_schemas(key);
+                if schemas.is_empty() {
+                    rest.push(key);
+                    continue;
+                }
+                let mut output = None;
+                for schema in schemas {
+                    if crate::options::limit_reached(&errors, limit) {
+                        break;
+                    }
+                    match schema.dyn_parse_async(&obj[key.as_str()]).await {
+                        Ok(v) => {
+                            output.get_or_insert(v);
+                        }
+                        Err(e) => {
+                            errors = errors.merge(e.with_prefix(PathSegment::Field(key.clone())));
+                        }
+                    }
+                }
+                if let Some(v) = output {
+                    result.insert(key.clone(), v);
+                }
+            }
+            unknown_keys = rest;
+        }
         if let Some(catchall) = &self.catchall_schema {
             for key in &unknown_keys {
-                let val = &obj[key.as_str()];
-                match catchall.dyn_parse(val) {
+                if crate::options::limit_reached(&errors, limit) {
+                    break;
+                }
+                match catchall.dyn_parse_async(&obj[key.as_str()]).await {
                     Ok(v) => {
                         result.insert((*key).clone(), v);
                     }
@@ -451,24 +1781,115 @@ impl VldSchema for ZObject {
                 }
             }
         } else {
-            match self.unknown_mode {
-                UnknownFieldMode::Strip => {}
-                UnknownFieldMode::Strict => {
-                    for key in &unknown_keys {
-                        let mut issue_err = VldError::single(
-                            IssueCode::UnrecognizedField,
-                            format!("Unrecognized field: \"{}\"", key),
-                        );
-                        issue_err = issue_err.with_prefix(PathSegment::Field((*key).clone()));
-                        errors = errors.merge(issue_err);
+            self.handle_unknown_keys(obj, &unknown_keys, &mut result, &mut errors);
+        }
+
+        for rule in &self.conditional_rules {
+            let cond_val = obj.get(&rule.condition_field).unwrap_or(&Value::Null);
+            if *cond_val == rule.condition_value {
+                let target_val = obj.get(&rule.target_field).unwrap_or(&Value::Null);
+                if let Err(e) = rule.schema.dyn_parse_async(target_val).await {
+                    errors =
+                        errors.merge(e.with_prefix(PathSegment::Field(rule.target_field.clone())));
+                }
+            }
+        }
+        for rule in &self.if_rules {
+            if let Some(branch) = rule.branch(obj, value) {
+                let result = crate::async_schema::VldSchemaAsync::parse_value_async(branch, value);
+                if let Err(e) = result.await {
+                    errors = errors.merge(e);
+                }
+            }
+        }
+        for dependency in &self.dependencies {
+            dependency.__check(obj, value, &mut errors);
+        }
+
+        if errors.is_empty() {
+            Ok(result)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl<D: ?Sized + DynStore> VldSchema for ZObject<D> {
+    type Output = Map<String, Value>;
+
+    fn parse_value(&self, value: &Value) -> Result<Map<String, Value>, VldError> {
+        let obj = Self::expect_object(value)?;
+
+        let mut result = Map::new();
+        let mut errors = self.check_keys(obj);
+        let limit = crate::options::issue_limit();
+
+        // Validate defined fields
+        for field in &self.fields {
+            if crate::options::limit_reached(&errors, limit) {
+                break;
+            }
+            let field_value = obj.get(&field.name).unwrap_or(&ABSENT);
+            match field.schema.dyn_parse(field_value) {
+                Ok(_) if is_absent(field_value) && field.schema.__dyn_optional_key() => {}
+                Ok(v) => {
+                    result.insert(field.name.clone(), v);
+                }
+                Err(e) => {
+                    errors = errors.merge(e.with_prefix(PathSegment::Field(field.name.clone())));
+                }
+            }
+        }
+
+        // Handle unknown fields
+        #[allow(unused_mut)]
+        let mut unknown_keys = self.unknown_keys(obj);
+        #[cfg(feature = "regex")]
+        {
+            let mut rest = Vec::with_capacity(unknown_keys.len());
+            for key in unknown_keys {
+                let schemas = self.pattern_schemas(key);
+                if schemas.is_empty() {
+                    rest.push(key);
+                    continue;
+                }
+                let mut output = None;
+                for schema in schemas {
+                    if crate::options::limit_reached(&errors, limit) {
+                        break;
+                    }
+                    match schema.dyn_parse(&obj[key.as_str()]) {
+                        Ok(v) => {
+                            output.get_or_insert(v);
+                        }
+                        Err(e) => {
+                            errors = errors.merge(e.with_prefix(PathSegment::Field(key.clone())));
+                        }
                     }
                 }
-                UnknownFieldMode::Passthrough => {
-                    for key in &unknown_keys {
-                        result.insert((*key).clone(), obj[key.as_str()].clone());
+                if let Some(v) = output {
+                    result.insert(key.clone(), v);
+                }
+            }
+            unknown_keys = rest;
+        }
+        if let Some(catchall) = &self.catchall_schema {
+            for key in &unknown_keys {
+                if crate::options::limit_reached(&errors, limit) {
+                    break;
+                }
+                let val = &obj[key.as_str()];
+                match catchall.dyn_parse(val) {
+                    Ok(v) => {
+                        result.insert((*key).clone(), v);
+                    }
+                    Err(e) => {
+                        errors = errors.merge(e.with_prefix(PathSegment::Field((*key).clone())));
                     }
                 }
             }
+        } else {
+            self.handle_unknown_keys(obj, &unknown_keys, &mut result, &mut errors);
         }
 
         // Evaluate conditional rules
@@ -482,6 +1903,14 @@ impl VldSchema for ZObject {
                 }
             }
         }
+        for rule in &self.if_rules {
+            if let Some(Err(e)) = rule.branch(obj, value).map(|b| b.parse_value(value)) {
+                errors = errors.merge(e);
+            }
+        }
+        for dependency in &self.dependencies {
+            dependency.__check(obj, value, &mut errors);
+        }
 
         if errors.is_empty() {
             Ok(result)
@@ -489,24 +1918,69 @@ impl VldSchema for ZObject {
             Err(errors)
         }
     }
+
+    fn __vld_deep_partial(&mut self) -> Option<Box<dyn DynSchema>> {
+        Some(erase(std::mem::take(self).deep_partial()))
+    }
 }
 
 /// Internal wrapper that makes a DynSchema nullable (null/missing → Value::Null).
-struct OptionalDynSchema(Box<dyn DynSchema>);
+struct OptionalDynSchema<D: ?Sized>(Box<D>);
 
-impl DynSchema for OptionalDynSchema {
+impl<D: ?Sized + DynSchema> DynSchema for OptionalDynSchema<D> {
     fn dyn_parse(&self, value: &Value) -> Result<Value, VldError> {
         if value.is_null() {
             return Ok(Value::Null);
         }
         self.0.dyn_parse(value)
     }
+
+    fn __dyn_optional_key(&self) -> bool {
+        self.0.__dyn_optional_key()
+    }
+
+    fn __dyn_json_required(&self) -> bool {
+        false
+    }
+
+    fn __dyn_deep_partial(&mut self) -> Option<Box<dyn DynSchema>> {
+        self.0
+            .__dyn_deep_partial()
+            .map(|partial| Box::new(OptionalDynSchema(partial)) as Box<dyn DynSchema>)
+    }
+
+    #[cfg(feature = "async")]
+    fn __dyn_deep_partial_async(&mut self) -> Option<Box<dyn DynSchema + Send + Sync>> {
+        self.0
+            .__dyn_deep_partial_async()
+            .map(|partial| Box::new(OptionalDynSchema(partial)) as Box<dyn DynSchema + Send + Sync>)
+    }
+
+    #[cfg(feature = "openapi")]
+    fn dyn_json_schema(&self) -> Value {
+        let inner = self.0.dyn_json_schema();
+        if inner.as_object().is_some_and(|o| o.is_empty()) {
+            return inner;
+        }
+        serde_json::json!({ "oneOf": [inner, {"type": "null"}] })
+    }
+
+    #[cfg(feature = "async")]
+    fn dyn_parse_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> crate::async_schema::BoxFuture<'a, Result<Value, VldError>> {
+        if value.is_null() {
+            return Box::pin(async { Ok(Value::Null) });
+        }
+        self.0.dyn_parse_async(value)
+    }
 }
 
 /// Internal wrapper that rejects null values.
-struct RequiredDynSchema(Box<dyn DynSchema>);
+struct RequiredDynSchema<D: ?Sized>(Box<D>);
 
-impl DynSchema for RequiredDynSchema {
+impl<D: ?Sized + DynSchema> DynSchema for RequiredDynSchema<D> {
     fn dyn_parse(&self, value: &Value) -> Result<Value, VldError> {
         if value.is_null() {
             return Err(VldError::single(
@@ -516,4 +1990,42 @@ impl DynSchema for RequiredDynSchema {
         }
         self.0.dyn_parse(value)
     }
+
+    fn __dyn_json_required(&self) -> bool {
+        true
+    }
+
+    fn __dyn_deep_partial(&mut self) -> Option<Box<dyn DynSchema>> {
+        self.0
+            .__dyn_deep_partial()
+            .map(|partial| Box::new(RequiredDynSchema(partial)) as Box<dyn DynSchema>)
+    }
+
+    #[cfg(feature = "async")]
+    fn __dyn_deep_partial_async(&mut self) -> Option<Box<dyn DynSchema + Send + Sync>> {
+        self.0
+            .__dyn_deep_partial_async()
+            .map(|partial| Box::new(RequiredDynSchema(partial)) as Box<dyn DynSchema + Send + Sync>)
+    }
+
+    #[cfg(feature = "openapi")]
+    fn dyn_json_schema(&self) -> Value {
+        self.0.dyn_json_schema()
+    }
+
+    #[cfg(feature = "async")]
+    fn dyn_parse_async<'a>(
+        &'a self,
+        value: &'a Value,
+    ) -> crate::async_schema::BoxFuture<'a, Result<Value, VldError>> {
+        if value.is_null() {
+            return Box::pin(async {
+                Err(VldError::single(
+                    IssueCode::MissingField,
+                    "Required field is missing or null",
+                ))
+            });
+        }
+        self.0.dyn_parse_async(value)
+    }
 }
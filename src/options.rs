@@ -0,0 +1,236 @@
+//! Parse-time limits for untrusted input.
+//!
+//! By default every issue is collected and any input shape is accepted. For
+//! public endpoints pass a [`ParseOptions`] to
+//! [`VldSchema::parse_with_options()`](crate::schema::VldSchema::parse_with_options)
+//! to stop early and to reject oversized or deeply nested payloads before any
+//! schema work is done.
+//!
+//! # Example
+//! ```
+//! use vld::options::ParseOptions;
+//! use vld::prelude::*;
+//!
+//! let schema = vld::array(vld::number().int());
+//! let input = serde_json::json!(["a", "b", "c", "d"]);
+//!
+//! let err = schema.parse_value(&input).unwrap_err();
+//! assert_eq!(err.issues.len(), 4);
+//!
+//! let opts = ParseOptions::new().max_issues(2);
+//! let err = schema.parse_value_with_options(&input, &opts).unwrap_err();
+//! assert_eq!(err.issues.len(), 2);
+//! ```
+
+use serde_json::Value;
+
+use crate::error::{IssueCode, PathSegment, VldError};
+
+/// Limits applied while parsing.
+///
+/// - `abort_early` — stop at the first issue.
+/// - `max_issues` — stop once this many issues have been collected.
+/// - `max_depth` — maximum nesting of arrays/objects in the input (root is depth 0).
+/// - `max_array_len` — maximum number of elements in any input array.
+/// - `max_string_len` — maximum length (in chars) of any input string or object key.
+///
+/// The structural limits (`max_depth`, `max_array_len`, `max_string_len`) are
+/// checked against the raw input before the schema runs. `abort_early` and
+/// `max_issues` are honoured by [`ZArray`](crate::collections::ZArray),
+/// [`ZObject`](crate::object::ZObject), [`ZRecord`](crate::collections::ZRecord),
+/// [`ZSet`](crate::collections::ZSet) and [`ZMap`](crate::collections::ZMap),
+/// which stop visiting elements once the limit is reached. With the `async`
+/// feature the same loops stop early under
+/// [`VldSchemaAsync::parse_value_async_with_options()`](crate::async_schema::VldSchemaAsync::parse_value_async_with_options)
+/// and [`VldParse::vld_parse_value_async_with_options()`](crate::schema::VldParse::vld_parse_value_async_with_options).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub abort_early: bool,
+    pub max_issues: Option<usize>,
+    pub max_depth: Option<usize>,
+    pub max_array_len: Option<usize>,
+    pub max_string_len: Option<usize>,
+}
+
+impl ParseOptions {
+    /// No limits — same behaviour as a plain `parse()`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Conservative limits for endpoints that accept untrusted input:
+    /// at most 100 issues, nesting depth 64, 10 000 array elements and
+    /// 1 MiB strings.
+    pub fn safe_defaults() -> Self {
+        Self {
+            abort_early: false,
+            max_issues: Some(100),
+            max_depth: Some(64),
+            max_array_len: Some(10_000),
+            max_string_len: Some(1024 * 1024),
+        }
+    }
+
+    /// Stop at the first issue.
+    pub fn abort_early(mut self, abort: bool) -> Self {
+        self.abort_early = abort;
+        self
+    }
+
+    /// Stop once `max` issues have been collected. `0` counts as `1`: a
+    /// failing parse always reports at least one issue.
+    pub fn max_issues(mut self, max: usize) -> Self {
+        self.max_issues = Some(max);
+        self
+    }
+
+    /// Maximum nesting depth of arrays/objects in the input.
+    pub fn max_depth(mut self, max: usize) -> Self {
+        self.max_depth = Some(max);
+        self
+    }
+
+    /// Maximum number of elements in any input array.
+    pub fn max_array_len(mut self, max: usize) -> Self {
+        self.max_array_len = Some(max);
+        self
+    }
+
+    /// Maximum length (in chars) of any input string or object key.
+    pub fn max_string_len(mut self, max: usize) -> Self {
+        self.max_string_len = Some(max);
+        self
+    }
+
+    /// Effective issue cap: `1` with `abort_early`, otherwise `max_issues`
+    /// (at least `1`).
+    pub fn issue_limit(&self) -> Option<usize> {
+        if self.abort_early {
+            Some(1)
+        } else {
+            self.max_issues.map(|max| max.max(1))
+        }
+    }
+
+    /// Check the structural limits against a raw input value.
+    ///
+    /// Returns the first violation found, with the path to the offending value.
+    pub fn check_input(&self, value: &Value) -> Result<(), VldError> {
+        if self.max_depth.is_none() && self.max_array_len.is_none() && self.max_string_len.is_none()
+        {
+            return Ok(());
+        }
+
+        let mut path = Vec::new();
+        match self.find_violation(value, 0, &mut path) {
+            Some(mut err) => {
+                for issue in &mut err.issues {
+                    issue.path = path.clone();
+                }
+                Err(err)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Depth-first search for the first limit violation; on success `path`
+    /// holds the location of the offending value.
+    fn find_violation(
+        &self,
+        value: &Value,
+        depth: usize,
+        path: &mut Vec<PathSegment>,
+    ) -> Option<VldError> {
+        match value {
+            Value::Array(_) | Value::Object(_) if self.max_depth.is_some_and(|m| depth > m) => {
+                Some(limit_error(
+                    "max_depth_exceeded",
+                    format!(
+                        "Input is nested deeper than {} levels",
+                        self.max_depth.unwrap_or_default()
+                    ),
+                ))
+            }
+            Value::Array(arr) => {
+                if let Some(max) = self.max_array_len.filter(|max| arr.len() > *max) {
+                    return Some(limit_error(
+                        "max_array_len_exceeded",
+                        format!("Array must have at most {} elements", max),
+                    ));
+                }
+                for (i, item) in arr.iter().enumerate() {
+                    path.push(PathSegment::Index(i));
+                    if let Some(err) = self.find_violation(item, depth + 1, path) {
+                        return Some(err);
+                    }
+                    path.pop();
+                }
+                None
+            }
+            Value::Object(obj) => {
+                for (key, item) in obj {
+                    path.push(PathSegment::Field(key.clone()));
+                    if self.exceeds_string_len(key) {
+                        return Some(self.string_len_error());
+                    }
+                    if let Some(err) = self.find_violation(item, depth + 1, path) {
+                        return Some(err);
+                    }
+                    path.pop();
+                }
+                None
+            }
+            Value::String(s) if self.exceeds_string_len(s) => Some(self.string_len_error()),
+            _ => None,
+        }
+    }
+
+    /// Trim an error to the issue cap (`max_issues` / `abort_early`).
+    pub fn truncate(&self, mut err: VldError) -> VldError {
+        if let Some(limit) = self.issue_limit() {
+            err.issues.truncate(limit);
+        }
+        err
+    }
+
+    fn exceeds_string_len(&self, s: &str) -> bool {
+        // Byte length is an upper bound of the char count, so most strings
+        // skip the char scan.
+        self.max_string_len
+            .is_some_and(|max| s.len() > max && s.chars().count() > max)
+    }
+
+    fn string_len_error(&self) -> VldError {
+        limit_error(
+            "max_string_len_exceeded",
+            format!(
+                "String must be at most {} characters",
+                self.max_string_len.unwrap_or_default()
+            ),
+        )
+    }
+}
+
+fn limit_error(code: &str, message: String) -> VldError {
+    VldError::single(
+        IssueCode::Custom {
+            code: code.to_string(),
+        },
+        message,
+    )
+}
+
+/// Run `f` with `options` active for the containers reached during the parse.
+pub(crate) fn with_options<R>(options: &ParseOptions, f: impl FnOnce() -> R) -> R {
+    crate::context::with_context(options, f)
+}
+
+/// Issue cap of the active [`ParseOptions`], if any.
+pub(crate) fn issue_limit() -> Option<usize> {
+    crate::context::with_current::<ParseOptions, _>(|opts| opts.and_then(|o| o.issue_limit()))
+}
+
+/// Whether `errors` has reached the active issue cap.
+pub(crate) fn limit_reached(errors: &VldError, limit: Option<usize>) -> bool {
+    limit.is_some_and(|max| errors.issues.len() >= max)
+}
@@ -1,8 +1,25 @@
 use serde_json::Value;
+use std::time::{Duration, SystemTime};
 
+use crate::clock::{describe_duration, Clock, SharedClock};
 use crate::error::{value_type_name, IssueCode, VldError};
 use crate::schema::VldSchema;
 
+/// `t` as a chrono UTC datetime, saturating outside chrono's range.
+fn system_datetime(t: SystemTime) -> chrono::DateTime<chrono::Utc> {
+    let epoch = chrono::DateTime::<chrono::Utc>::from(SystemTime::UNIX_EPOCH);
+    match t.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(after) => chrono::Duration::from_std(after)
+            .ok()
+            .and_then(|d| epoch.checked_add_signed(d))
+            .unwrap_or(chrono::DateTime::<chrono::Utc>::MAX_UTC),
+        Err(before) => chrono::Duration::from_std(before.duration())
+            .ok()
+            .and_then(|d| epoch.checked_sub_signed(d))
+            .unwrap_or(chrono::DateTime::<chrono::Utc>::MIN_UTC),
+    }
+}
+
 /// Schema for date validation. Parses ISO 8601 date strings (`YYYY-MM-DD`)
 /// into [`chrono::NaiveDate`].
 ///
@@ -22,6 +39,9 @@ pub struct ZDate {
     max: Option<(chrono::NaiveDate, String)>,
     past: Option<String>,
     future: Option<String>,
+    within_next: Option<(Duration, String)>,
+    not_older_than: Option<(Duration, String)>,
+    clock: Option<SharedClock>,
     custom_type_error: Option<String>,
 }
 
@@ -32,6 +52,9 @@ impl ZDate {
             max: None,
             past: None,
             future: None,
+            within_next: None,
+            not_older_than: None,
+            clock: None,
             custom_type_error: None,
         }
     }
@@ -92,6 +115,45 @@ impl ZDate {
         self
     }
 
+    /// Date must be between today and `duration` from now (inclusive).
+    pub fn within_next(self, duration: Duration) -> Self {
+        let msg = format!(
+            "Date must be within the next {}",
+            describe_duration(duration)
+        );
+        self.within_next_msg(duration, msg)
+    }
+
+    /// Same as [`within_next`](Self::within_next), with custom message.
+    pub fn within_next_msg(mut self, duration: Duration, msg: impl Into<String>) -> Self {
+        self.within_next = Some((duration, msg.into()));
+        self
+    }
+
+    /// Date must not be more than `duration` in the past.
+    pub fn not_older_than(self, duration: Duration) -> Self {
+        let msg = format!(
+            "Date must not be older than {}",
+            describe_duration(duration)
+        );
+        self.not_older_than_msg(duration, msg)
+    }
+
+    /// Same as [`not_older_than`](Self::not_older_than), with custom message.
+    pub fn not_older_than_msg(mut self, duration: Duration, msg: impl Into<String>) -> Self {
+        self.not_older_than = Some((duration, msg.into()));
+        self
+    }
+
+    /// Use `clock` instead of the system clock for `past()`, `future()`,
+    /// `within_next()` and `not_older_than()`.
+    ///
+    /// See [`vld::clock`](crate::clock) for the available clocks.
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Some(SharedClock::new(clock));
+        self
+    }
+
     /// Generate a JSON Schema representation.
     #[cfg(feature = "openapi")]
     pub fn to_json_schema(&self) -> serde_json::Value {
@@ -162,7 +224,8 @@ impl VldSchema for ZDate {
                 );
             }
         }
-        let today = chrono::Utc::now().date_naive();
+        let now = crate::clock::now(self.clock.as_ref());
+        let today = system_datetime(now).date_naive();
         if let Some(msg) = &self.past {
             if date >= today {
                 errors.push_with_value(
@@ -185,6 +248,34 @@ impl VldSchema for ZDate {
                 );
             }
         }
+        if let Some((window, msg)) = &self.within_next {
+            let until = now
+                .checked_add(*window)
+                .map(|t| system_datetime(t).date_naive());
+            if date < today || until.is_some_and(|until| date > until) {
+                errors.push_with_value(
+                    IssueCode::Custom {
+                        code: "not_within_next_date".to_string(),
+                    },
+                    msg.clone(),
+                    value,
+                );
+            }
+        }
+        if let Some((age, msg)) = &self.not_older_than {
+            let since = now
+                .checked_sub(*age)
+                .map(|t| system_datetime(t).date_naive());
+            if since.is_some_and(|since| date < since) {
+                errors.push_with_value(
+                    IssueCode::Custom {
+                        code: "too_old_date".to_string(),
+                    },
+                    msg.clone(),
+                    value,
+                );
+            }
+        }
 
         if errors.is_empty() {
             Ok(date)
@@ -217,6 +308,9 @@ pub struct ZDateTime {
     max: Option<(chrono::DateTime<chrono::Utc>, String)>,
     past: Option<String>,
     future: Option<String>,
+    within_next: Option<(Duration, String)>,
+    not_older_than: Option<(Duration, String)>,
+    clock: Option<SharedClock>,
     allow_naive: bool,
     naive_offset: chrono::FixedOffset,
     required_timezone_offset: Option<(chrono::FixedOffset, String)>,
@@ -230,6 +324,9 @@ impl ZDateTime {
             max: None,
             past: None,
             future: None,
+            within_next: None,
+            not_older_than: None,
+            clock: None,
             allow_naive: true,
             naive_offset: chrono::FixedOffset::east_opt(0).expect("0 offset must be valid"),
             required_timezone_offset: None,
@@ -295,6 +392,45 @@ impl ZDateTime {
         self
     }
 
+    /// Datetime must be between now and `duration` from now (inclusive).
+    pub fn within_next(self, duration: Duration) -> Self {
+        let msg = format!(
+            "Datetime must be within the next {}",
+            describe_duration(duration)
+        );
+        self.within_next_msg(duration, msg)
+    }
+
+    /// Same as [`within_next`](Self::within_next), with custom message.
+    pub fn within_next_msg(mut self, duration: Duration, msg: impl Into<String>) -> Self {
+        self.within_next = Some((duration, msg.into()));
+        self
+    }
+
+    /// Datetime must not be more than `duration` in the past.
+    pub fn not_older_than(self, duration: Duration) -> Self {
+        let msg = format!(
+            "Datetime must not be older than {}",
+            describe_duration(duration)
+        );
+        self.not_older_than_msg(duration, msg)
+    }
+
+    /// Same as [`not_older_than`](Self::not_older_than), with custom message.
+    pub fn not_older_than_msg(mut self, duration: Duration, msg: impl Into<String>) -> Self {
+        self.not_older_than = Some((duration, msg.into()));
+        self
+    }
+
+    /// Use `clock` instead of the system clock for `past()`, `future()`,
+    /// `within_next()` and `not_older_than()`.
+    ///
+    /// See [`vld::clock`](crate::clock) for the available clocks.
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Some(SharedClock::new(clock));
+        self
+    }
+
     /// Allow or disallow naive datetime input without timezone.
     ///
     /// Default is `true` for backward compatibility.
@@ -459,7 +595,8 @@ impl VldSchema for ZDateTime {
                 );
             }
         }
-        let now = chrono::Utc::now();
+        let now_sys = crate::clock::now(self.clock.as_ref());
+        let now = system_datetime(now_sys);
         if let Some(msg) = &self.past {
             if dt >= now {
                 errors.push_with_value(
@@ -482,6 +619,30 @@ impl VldSchema for ZDateTime {
                 );
             }
         }
+        if let Some((window, msg)) = &self.within_next {
+            let until = now_sys.checked_add(*window).map(system_datetime);
+            if dt < now || until.is_some_and(|until| dt > until) {
+                errors.push_with_value(
+                    IssueCode::Custom {
+                        code: "not_within_next_datetime".to_string(),
+                    },
+                    msg.clone(),
+                    value,
+                );
+            }
+        }
+        if let Some((age, msg)) = &self.not_older_than {
+            let since = now_sys.checked_sub(*age).map(system_datetime);
+            if since.is_some_and(|since| dt < since) {
+                errors.push_with_value(
+                    IssueCode::Custom {
+                        code: "too_old_datetime".to_string(),
+                    },
+                    msg.clone(),
+                    value,
+                );
+            }
+        }
 
         if errors.is_empty() {
             Ok(dt)
@@ -1,7 +1,9 @@
 use std::str::FromStr;
+use std::time::{Duration, SystemTime};
 
 use serde_json::Value;
 
+use crate::clock::{describe_duration, Clock, SharedClock};
 use crate::error::{value_type_name, IssueCode, VldError};
 use crate::schema::VldSchema;
 use jiff::civil::Date;
@@ -18,8 +20,14 @@ fn parse_timestamp_literal(dt: &str) -> Timestamp {
         .unwrap_or_else(|_| panic!("Invalid datetime literal: {}", dt))
 }
 
-fn utc_today() -> Date {
-    Timestamp::now().to_zoned(TimeZone::UTC).date()
+fn system_timestamp(t: SystemTime) -> Timestamp {
+    Timestamp::try_from(t).unwrap_or_else(|_| {
+        if t > SystemTime::UNIX_EPOCH {
+            Timestamp::MAX
+        } else {
+            Timestamp::MIN
+        }
+    })
 }
 
 fn fixed_timezone(offset_seconds: i32) -> TimeZone {
@@ -59,6 +67,9 @@ pub struct ZDate {
     max: Option<(Date, String)>,
     past: Option<String>,
     future: Option<String>,
+    within_next: Option<(Duration, String)>,
+    not_older_than: Option<(Duration, String)>,
+    clock: Option<SharedClock>,
     custom_type_error: Option<String>,
 }
 
@@ -69,6 +80,9 @@ impl ZDate {
             max: None,
             past: None,
             future: None,
+            within_next: None,
+            not_older_than: None,
+            clock: None,
             custom_type_error: None,
         }
     }
@@ -125,6 +139,45 @@ impl ZDate {
         self
     }
 
+    /// Date must be between today and `duration` from now (inclusive).
+    pub fn within_next(self, duration: Duration) -> Self {
+        let msg = format!(
+            "Date must be within the next {}",
+            describe_duration(duration)
+        );
+        self.within_next_msg(duration, msg)
+    }
+
+    /// Same as [`within_next`](Self::within_next), with custom message.
+    pub fn within_next_msg(mut self, duration: Duration, msg: impl Into<String>) -> Self {
+        self.within_next = Some((duration, msg.into()));
+        self
+    }
+
+    /// Date must not be more than `duration` in the past.
+    pub fn not_older_than(self, duration: Duration) -> Self {
+        let msg = format!(
+            "Date must not be older than {}",
+            describe_duration(duration)
+        );
+        self.not_older_than_msg(duration, msg)
+    }
+
+    /// Same as [`not_older_than`](Self::not_older_than), with custom message.
+    pub fn not_older_than_msg(mut self, duration: Duration, msg: impl Into<String>) -> Self {
+        self.not_older_than = Some((duration, msg.into()));
+        self
+    }
+
+    /// Use `clock` instead of the system clock for `past()`, `future()`,
+    /// `within_next()` and `not_older_than()`.
+    ///
+    /// See [`vld::clock`](crate::clock) for the available clocks.
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Some(SharedClock::new(clock));
+        self
+    }
+
     /// Generate a JSON Schema representation.
     #[cfg(feature = "openapi")]
     pub fn to_json_schema(&self) -> serde_json::Value {
@@ -196,7 +249,8 @@ impl VldSchema for ZDate {
                 );
             }
         }
-        let today = utc_today();
+        let now = crate::clock::now(self.clock.as_ref());
+        let today = system_timestamp(now).to_zoned(TimeZone::UTC).date();
         if let Some(msg) = &self.past {
             if date >= today {
                 errors.push_with_value(
@@ -219,6 +273,34 @@ impl VldSchema for ZDate {
                 );
             }
         }
+        if let Some((window, msg)) = &self.within_next {
+            let until = now
+                .checked_add(*window)
+                .map(|t| system_timestamp(t).to_zoned(TimeZone::UTC).date());
+            if date < today || until.is_some_and(|until| date > until) {
+                errors.push_with_value(
+                    IssueCode::Custom {
+                        code: "not_within_next_date".to_string(),
+                    },
+                    msg.clone(),
+                    value,
+                );
+            }
+        }
+        if let Some((age, msg)) = &self.not_older_than {
+            let since = now
+                .checked_sub(*age)
+                .map(|t| system_timestamp(t).to_zoned(TimeZone::UTC).date());
+            if since.is_some_and(|since| date < since) {
+                errors.push_with_value(
+                    IssueCode::Custom {
+                        code: "too_old_date".to_string(),
+                    },
+                    msg.clone(),
+                    value,
+                );
+            }
+        }
 
         if errors.is_empty() {
             Ok(date)
@@ -251,6 +333,9 @@ pub struct ZDateTime {
     max: Option<(Timestamp, String)>,
     past: Option<String>,
     future: Option<String>,
+    within_next: Option<(Duration, String)>,
+    not_older_than: Option<(Duration, String)>,
+    clock: Option<SharedClock>,
     allow_naive: bool,
     naive_offset: Offset,
     required_timezone_offset: Option<(Offset, String)>,
@@ -264,6 +349,9 @@ impl ZDateTime {
             max: None,
             past: None,
             future: None,
+            within_next: None,
+            not_older_than: None,
+            clock: None,
             allow_naive: true,
             naive_offset: Offset::UTC,
             required_timezone_offset: None,
@@ -323,6 +411,45 @@ impl ZDateTime {
         self
     }
 
+    /// Datetime must be between now and `duration` from now (inclusive).
+    pub fn within_next(self, duration: Duration) -> Self {
+        let msg = format!(
+            "Datetime must be within the next {}",
+            describe_duration(duration)
+        );
+        self.within_next_msg(duration, msg)
+    }
+
+    /// Same as [`within_next`](Self::within_next), with custom message.
+    pub fn within_next_msg(mut self, duration: Duration, msg: impl Into<String>) -> Self {
+        self.within_next = Some((duration, msg.into()));
+        self
+    }
+
+    /// Datetime must not be more than `duration` in the past.
+    pub fn not_older_than(self, duration: Duration) -> Self {
+        let msg = format!(
+            "Datetime must not be older than {}",
+            describe_duration(duration)
+        );
+        self.not_older_than_msg(duration, msg)
+    }
+
+    /// Same as [`not_older_than`](Self::not_older_than), with custom message.
+    pub fn not_older_than_msg(mut self, duration: Duration, msg: impl Into<String>) -> Self {
+        self.not_older_than = Some((duration, msg.into()));
+        self
+    }
+
+    /// Use `clock` instead of the system clock for `past()`, `future()`,
+    /// `within_next()` and `not_older_than()`.
+    ///
+    /// See [`vld::clock`](crate::clock) for the available clocks.
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Some(SharedClock::new(clock));
+        self
+    }
+
     /// Allow or disallow naive datetime input without timezone.
     ///
     /// Default is `true` for backward compatibility.
@@ -495,7 +622,8 @@ impl VldSchema for ZDateTime {
                 );
             }
         }
-        let now = Timestamp::now();
+        let now_sys = crate::clock::now(self.clock.as_ref());
+        let now = system_timestamp(now_sys);
         if let Some(msg) = &self.past {
             if dt >= now {
                 errors.push_with_value(
@@ -518,6 +646,30 @@ impl VldSchema for ZDateTime {
                 );
             }
         }
+        if let Some((window, msg)) = &self.within_next {
+            let until = now_sys.checked_add(*window).map(system_timestamp);
+            if dt < now || until.is_some_and(|until| dt > until) {
+                errors.push_with_value(
+                    IssueCode::Custom {
+                        code: "not_within_next_datetime".to_string(),
+                    },
+                    msg.clone(),
+                    value,
+                );
+            }
+        }
+        if let Some((age, msg)) = &self.not_older_than {
+            let since = now_sys.checked_sub(*age).map(system_timestamp);
+            if since.is_some_and(|since| dt < since) {
+                errors.push_with_value(
+                    IssueCode::Custom {
+                        code: "too_old_datetime".to_string(),
+                    },
+                    msg.clone(),
+                    value,
+                );
+            }
+        }
 
         if errors.is_empty() {
             Ok(dt)
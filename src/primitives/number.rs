@@ -625,9 +625,29 @@ impl VldSchema for ZInt {
             return Err(VldError::single_with_value(IssueCode::NotInt, msg, value));
         }
 
+        // `as i64` saturates, so anything beyond i64 is an issue rather than a
+        // silently clamped value.
+        const BOUND: f64 = 9_223_372_036_854_775_808.0; // 2^63
+        let exact = value.as_i64();
+        if exact.is_none() && !(-BOUND..BOUND).contains(&n) {
+            let msg = "Number is out of range for i64".to_string();
+            let code = if n < 0.0 {
+                IssueCode::TooSmall {
+                    minimum: i64::MIN as f64,
+                    inclusive: true,
+                }
+            } else {
+                IssueCode::TooBig {
+                    maximum: i64::MAX as f64,
+                    inclusive: true,
+                }
+            };
+            return Err(VldError::single_with_value(code, msg, value));
+        }
+
         // Run other number checks
         self.inner.validate_number(n, value)?;
 
-        Ok(n as i64)
+        Ok(exact.unwrap_or(n as i64))
     }
 }
@@ -2,9 +2,10 @@ use serde_json::Value;
 use std::marker::PhantomData;
 
 use crate::combinators::{
-    ZCatch, ZDescribe, ZIntersection, ZPipe, ZRefine, ZSuperRefine, ZTransform, ZUnion2,
+    ZCatch, ZDescribe, ZIntersection, ZPipe, ZRefine, ZRefineCtx, ZSuperRefine, ZSuperRefineCtx,
+    ZTransform, ZTransformCtx, ZUnion2,
 };
-use crate::error::VldError;
+use crate::error::{FieldResult, ParseResult, PathSegment, VldError};
 use crate::input::VldInput;
 use crate::modifiers::{ZDefault, ZNullable, ZNullish, ZOptional};
 
@@ -30,10 +31,177 @@ pub trait VldSchema: Sized {
 
     /// Parse from any supported input (JSON string, file path, `serde_json::Value`, etc.)
     fn parse<I: VldInput + ?Sized>(&self, input: &I) -> Result<Self::Output, VldError> {
+        if let Some(value) = input.as_json_value() {
+            return self.parse_value(value);
+        }
         let json = input.to_json_value()?;
         self.parse_value(&json)
     }
 
+    /// Validate while deserializing from `de`, without building a
+    /// `serde_json::Value` first.
+    ///
+    /// Returns `Err` for deserializer errors (malformed input) and `Ok(Err(_))`
+    /// for validation issues. The default implementation buffers the value into
+    /// a `serde_json::Value` and calls [`parse_value()`](Self::parse_value);
+    /// arrays, `schema!` structs and the common wrappers override it to validate
+    /// in one pass. See [`vld::de`](crate::de).
+    fn deserialize_with<'de, D: serde::Deserializer<'de>>(
+        &self,
+        de: D,
+    ) -> crate::de::DeResult<Self::Output, D::Error> {
+        let value = <Value as serde::Deserialize>::deserialize(de)?;
+        Ok(self.parse_value(&value))
+    }
+
+    /// Parse from any self-describing serde [`Deserializer`](serde::Deserializer)
+    /// in one pass. Deserializer errors are reported as a `parse_error` issue.
+    fn parse_deserializer<'de, D: serde::Deserializer<'de>>(
+        &self,
+        de: D,
+    ) -> Result<Self::Output, VldError> {
+        self.deserialize_with(de)
+            .unwrap_or_else(|e| Err(crate::de::input_error(e)))
+    }
+
+    /// Parse a JSON string in one pass, without an intermediate `serde_json::Value`.
+    ///
+    /// # Example
+    /// ```
+    /// use vld::prelude::*;
+    ///
+    /// let schema = vld::array(vld::string().min(2));
+    /// assert_eq!(schema.parse_json_str(r#"["ab", "cd"]"#).unwrap().len(), 2);
+    /// assert!(schema.parse_json_str(r#"["ab", "c"]"#).is_err());
+    /// ```
+    fn parse_json_str(&self, json: &str) -> Result<Self::Output, VldError> {
+        crate::de::from_json(serde_json::Deserializer::from_str(json), |de| {
+            self.deserialize_with(de)
+        })
+    }
+
+    /// Parse JSON bytes in one pass, without an intermediate `serde_json::Value`.
+    fn parse_json_slice(&self, json: &[u8]) -> Result<Self::Output, VldError> {
+        crate::de::from_json(serde_json::Deserializer::from_slice(json), |de| {
+            self.deserialize_with(de)
+        })
+    }
+
+    /// Parse leniently, keeping as much of the value as possible.
+    ///
+    /// Used by `parse_lenient()` (see [`impl_validate_fields!`](crate::impl_validate_fields)).
+    /// The default implementation is all-or-nothing. [`vld::nested!`](crate::nested!)
+    /// schemas of types with `parse_lenient()`, arrays and the `optional` /
+    /// `nullable` / `nullish` / `with_default` / `describe` wrappers recurse, so
+    /// a struct or array with a few broken leaves is still recovered (broken
+    /// struct fields fall back to `Default`) and reports per-leaf
+    /// [`children`](crate::error::FieldResult::children).
+    fn parse_value_lenient(&self, value: &Value) -> Lenient<Self::Output> {
+        Lenient::from_result(self.parse_value(value))
+    }
+
+    /// Whether an object key with this schema may be absent: it is then left
+    /// out of a [`ZObject`](crate::object::ZObject)'s output and of the
+    /// JSON Schema `required` list. Only [`ZPatch`](crate::modifiers::ZPatch)
+    /// opts in; transparent wrappers such as `describe` and `refine` forward it.
+    #[doc(hidden)]
+    fn __vld_optional_key(&self) -> bool {
+        false
+    }
+
+    /// The partial counterpart of this schema for
+    /// [`ZObject::deep_partial()`](crate::object::ZObject::deep_partial), or
+    /// `None` if it has no object fields to make optional. Object, array,
+    /// record, `optional` / `nullable` / `nullish` and nested struct schemas
+    /// opt in. May leave `self` emptied.
+    #[doc(hidden)]
+    fn __vld_deep_partial(&mut self) -> Option<Box<dyn crate::object::DynSchema>> {
+        None
+    }
+
+    /// Parse from any supported input with a request-scoped validation context.
+    ///
+    /// The context is visible to every [`refine_ctx()`](Self::refine_ctx),
+    /// [`super_refine_ctx()`](Self::super_refine_ctx) and
+    /// [`transform_ctx()`](Self::transform_ctx) reached during this parse,
+    /// including those nested in objects, arrays, records and `schema!` structs.
+    ///
+    /// # Example
+    /// ```
+    /// use vld::prelude::*;
+    ///
+    /// #[derive(Clone)]
+    /// struct Tenant {
+    ///     premium: bool,
+    /// }
+    ///
+    /// let tags = vld::array(vld::string())
+    ///     .refine_ctx(|t, tenant: &Tenant| tenant.premium || t.len() <= 2, "Upgrade for more tags");
+    /// let input = r#"["a", "b", "c"]"#;
+    /// assert!(tags.parse_with_context(input, &Tenant { premium: false }).is_err());
+    /// assert!(tags.parse_with_context(input, &Tenant { premium: true }).is_ok());
+    /// ```
+    fn parse_with_context<I: VldInput + ?Sized, C: Clone + 'static>(
+        &self,
+        input: &I,
+        ctx: &C,
+    ) -> Result<Self::Output, VldError> {
+        let json = input.to_json_value()?;
+        self.parse_value_with_context(&json, ctx)
+    }
+
+    /// Parse a `serde_json::Value` with a request-scoped validation context.
+    fn parse_value_with_context<C: Clone + 'static>(
+        &self,
+        value: &Value,
+        ctx: &C,
+    ) -> Result<Self::Output, VldError> {
+        crate::context::with_context(ctx, || self.parse_value(value))
+    }
+
+    /// Parse from any supported input with [`ParseOptions`](crate::options::ParseOptions)
+    /// limits (fail-fast, issue cap, depth/length caps).
+    ///
+    /// # Example
+    /// ```
+    /// use vld::options::ParseOptions;
+    /// use vld::prelude::*;
+    ///
+    /// let schema = vld::array(vld::string().min(3));
+    /// let opts = ParseOptions::new().abort_early(true);
+    /// let err = schema.parse_with_options(r#"["a", "b"]"#, &opts).unwrap_err();
+    /// assert_eq!(err.issues.len(), 1);
+    /// ```
+    fn parse_with_options<I: VldInput + ?Sized>(
+        &self,
+        input: &I,
+        options: &crate::options::ParseOptions,
+    ) -> Result<Self::Output, VldError> {
+        let json = input.to_json_value()?;
+        self.parse_value_with_options(&json, options)
+    }
+
+    /// Parse JSON text and attach a source [`Span`](crate::error::Span) to
+    /// every issue, so errors can be reported as `file:line:column` or
+    /// rendered with [`prettify_error_with_source()`](crate::format::prettify_error_with_source).
+    ///
+    /// Spans are only computed when validation fails. See [`vld::source`](crate::source).
+    fn parse_with_spans(&self, text: &str) -> Result<Self::Output, VldError> {
+        let value = crate::source::json_value_with_spans(text)?;
+        self.parse_value(&value).map_err(|e| e.with_source(text))
+    }
+
+    /// Parse a `serde_json::Value` with [`ParseOptions`](crate::options::ParseOptions) limits.
+    fn parse_value_with_options(
+        &self,
+        value: &Value,
+        options: &crate::options::ParseOptions,
+    ) -> Result<Self::Output, VldError> {
+        options.check_input(value)?;
+        crate::options::with_options(options, || self.parse_value(value))
+            .map_err(|e| options.truncate(e))
+    }
+
     /// Validate an existing Rust value against this schema.
     ///
     /// The value is serialized to JSON via `serde`, then validated.
@@ -151,6 +319,71 @@ pub trait VldSchema: Sized {
         ZSuperRefine::new(self, check)
     }
 
+    /// Add a refinement check that reads the validation context `C`.
+    ///
+    /// The context is supplied by [`parse_with_context()`](Self::parse_with_context);
+    /// a plain `parse()` reports a `missing_context` issue.
+    fn refine_ctx<C, F>(self, check: F, message: &str) -> ZRefineCtx<Self, C, F>
+    where
+        C: 'static,
+        F: Fn(&Self::Output, &C) -> bool,
+    {
+        ZRefineCtx::new(self, check, message)
+    }
+
+    /// Add a context-aware refinement that can produce multiple errors.
+    fn super_refine_ctx<C, F>(self, check: F) -> ZSuperRefineCtx<Self, C, F>
+    where
+        C: 'static,
+        F: Fn(&Self::Output, &C, &mut VldError),
+    {
+        ZSuperRefineCtx::new(self, check)
+    }
+
+    /// Transform the parsed value using the validation context `C`.
+    fn transform_ctx<C, F, U>(self, f: F) -> ZTransformCtx<Self, C, F, U>
+    where
+        C: 'static,
+        F: Fn(Self::Output, &C) -> U,
+    {
+        ZTransformCtx::new(self, f)
+    }
+
+    /// Add an asynchronous refinement check (e.g. a database lookup).
+    ///
+    /// The check runs only on the async path
+    /// ([`parse_async()`](crate::async_schema::VldSchemaAsync::parse_async));
+    /// synchronous parsing reports an `async_refinement` issue.
+    ///
+    /// Requires the `async` feature.
+    #[cfg(feature = "async")]
+    fn refine_async<F, Fut>(
+        self,
+        check: F,
+        message: &str,
+    ) -> crate::combinators::ZRefineAsync<Self, F>
+    where
+        F: Fn(&Self::Output) -> Fut,
+        Fut: std::future::Future<Output = bool>,
+    {
+        crate::combinators::ZRefineAsync::new(self, check, message)
+    }
+
+    /// Add an asynchronous refinement that can produce multiple errors.
+    ///
+    /// The closure's future resolves to the collected issues; an empty
+    /// `VldError` means the value is valid.
+    ///
+    /// Requires the `async` feature.
+    #[cfg(feature = "async")]
+    fn super_refine_async<F, Fut>(self, check: F) -> crate::combinators::ZSuperRefineAsync<Self, F>
+    where
+        F: Fn(&Self::Output) -> Fut,
+        Fut: std::future::Future<Output = VldError>,
+    {
+        crate::combinators::ZSuperRefineAsync::new(self, check)
+    }
+
     /// Create a union: this schema **or** another. Returns `Either<Self::Output, B::Output>`.
     fn or<B: VldSchema>(self, other: B) -> ZUnion2<Self, B> {
         ZUnion2::new(self, other)
@@ -191,32 +424,363 @@ pub trait VldSchema: Sized {
 pub trait VldParse: Sized {
     /// Parse and validate a `serde_json::Value` into this type.
     fn vld_parse_value(value: &serde_json::Value) -> Result<Self, crate::error::VldError>;
+
+    /// Parse and validate a `serde_json::Value` with a request-scoped validation context.
+    ///
+    /// See [`VldSchema::parse_with_context()`].
+    fn vld_parse_value_with_context<C: Clone + 'static>(
+        value: &serde_json::Value,
+        ctx: &C,
+    ) -> Result<Self, crate::error::VldError> {
+        crate::context::with_context(ctx, || Self::vld_parse_value(value))
+    }
+
+    /// Parse and validate a `serde_json::Value` with
+    /// [`ParseOptions`](crate::options::ParseOptions) limits.
+    ///
+    /// See [`VldSchema::parse_with_options()`].
+    fn vld_parse_value_with_options(
+        value: &serde_json::Value,
+        options: &crate::options::ParseOptions,
+    ) -> Result<Self, crate::error::VldError> {
+        options.check_input(value)?;
+        crate::options::with_options(options, || Self::vld_parse_value(value))
+            .map_err(|e| options.truncate(e))
+    }
+
+    /// Validate while deserializing from `de`. See [`VldSchema::deserialize_with()`].
+    ///
+    /// The default implementation buffers the value into a `serde_json::Value`.
+    /// Types generated by `schema!` override it to validate in one pass.
+    fn vld_deserialize<'de, D: serde::Deserializer<'de>>(
+        de: D,
+    ) -> crate::de::DeResult<Self, D::Error> {
+        let value = <serde_json::Value as serde::Deserialize>::deserialize(de)?;
+        Ok(Self::vld_parse_value(&value))
+    }
+
+    /// Parse and validate a `serde_json::Value`, running async refinements.
+    ///
+    /// The default implementation delegates to [`vld_parse_value`](Self::vld_parse_value).
+    /// Types generated by `schema!` / `#[derive(Validate)]` override it to run
+    /// async field checks in the same pass.
+    ///
+    /// Requires the `async` feature.
+    #[cfg(feature = "async")]
+    fn vld_parse_value_async(
+        value: &serde_json::Value,
+    ) -> crate::async_schema::BoxFuture<'_, Result<Self, crate::error::VldError>> {
+        Box::pin(async move { Self::vld_parse_value(value) })
+    }
+
+    /// Async counterpart of
+    /// [`vld_parse_value_with_options`](Self::vld_parse_value_with_options):
+    /// `abort_early` / `max_issues` stop the container loops instead of only
+    /// trimming the issues afterwards.
+    ///
+    /// Requires the `async` feature.
+    #[cfg(feature = "async")]
+    fn vld_parse_value_async_with_options<'a>(
+        value: &'a serde_json::Value,
+        options: &'a crate::options::ParseOptions,
+    ) -> crate::async_schema::BoxFuture<'a, Result<Self, crate::error::VldError>>
+    where
+        Self: 'a,
+    {
+        Box::pin(async move {
+            options.check_input(value)?;
+            crate::context::with_context_async(options, Self::vld_parse_value_async(value))
+                .await
+                .map_err(|e| options.truncate(e))
+        })
+    }
+
+    /// Async counterpart of
+    /// [`vld_parse_value_with_context`](Self::vld_parse_value_with_context):
+    /// the context stays installed across `.await` points.
+    ///
+    /// Requires the `async` feature.
+    #[cfg(feature = "async")]
+    fn vld_parse_value_with_context_async<'a, C: Clone + Send + 'static>(
+        value: &'a serde_json::Value,
+        ctx: &C,
+    ) -> crate::async_schema::BoxFuture<'a, Result<Self, crate::error::VldError>>
+    where
+        Self: 'a,
+    {
+        Box::pin(crate::context::with_context_async(
+            ctx,
+            Self::vld_parse_value_async(value),
+        ))
+    }
+}
+
+/// Bound that [`schema!`](crate::schema!) and `#[derive(Validate)]` put on
+/// the type parameters of generic structs such as `Paginated<T>`.
+///
+/// Implemented for every [`VldParse`] type that is `Send + Sync + 'static`,
+/// and with the `openapi` feature also implements
+/// [`VldJsonSchema`](crate::json_schema::VldJsonSchema).
+#[cfg(feature = "openapi")]
+pub trait VldTypeParam:
+    VldParse + crate::json_schema::VldJsonSchema + Send + Sync + 'static
+{
+}
+
+#[cfg(feature = "openapi")]
+impl<T: VldParse + crate::json_schema::VldJsonSchema + Send + Sync + 'static> VldTypeParam for T {}
+
+/// Bound that [`schema!`](crate::schema!) and `#[derive(Validate)]` put on
+/// the type parameters of generic structs such as `Paginated<T>`.
+///
+/// Implemented for every [`VldParse`] type that is `Send + Sync + 'static`.
+#[cfg(not(feature = "openapi"))]
+pub trait VldTypeParam: VldParse + Send + Sync + 'static {}
+
+#[cfg(not(feature = "openapi"))]
+impl<T: VldParse + Send + Sync + 'static> VldTypeParam for T {}
+
+/// Run one struct-level rule of a [`schema!`](crate::schema!) `refine { ... }`
+/// block or a `#[vld(refine = ...)]` struct attribute: if `check` fails, push
+/// a `custom` issue with `message` at the field `path` (or at the struct
+/// itself when `path` is `None`).
+#[doc(hidden)]
+pub fn __refine_struct<T>(
+    value: &T,
+    errors: &mut VldError,
+    path: Option<&str>,
+    message: &str,
+    check: impl Fn(&T) -> bool,
+) {
+    if check(value) {
+        return;
+    }
+    let mut issue = errors
+        .issue(crate::error::IssueCode::Custom {
+            code: "custom".to_string(),
+        })
+        .message(message);
+    if let Some(path) = path {
+        issue = issue.path_field(path);
+    }
+    issue.finish();
+}
+
+/// Outcome of [`VldSchema::parse_value_lenient()`].
+#[derive(Debug)]
+pub struct Lenient<T> {
+    /// The recovered value. Set when validation passed, and also when only
+    /// some fields of a nested struct or some elements of an array failed.
+    pub value: Option<T>,
+    /// All issues, if validation failed.
+    pub error: Option<VldError>,
+    /// Per-field results of a nested struct, or per-element results of an
+    /// array (named by index).
+    pub children: Vec<FieldResult>,
+}
+
+impl<T> Lenient<T> {
+    /// All-or-nothing outcome without children.
+    pub fn from_result(result: Result<T, VldError>) -> Self {
+        match result {
+            Ok(v) => Self {
+                value: Some(v),
+                error: None,
+                children: vec![],
+            },
+            Err(e) => Self {
+                value: None,
+                error: Some(e),
+                children: vec![],
+            },
+        }
+    }
+
+    /// Map the recovered value.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Lenient<U> {
+        Lenient {
+            value: self.value.map(f),
+            error: self.error,
+            children: self.children,
+        }
+    }
+}
+
+/// Types with recursive lenient parsing.
+///
+/// Implemented by [`impl_validate_fields!`](crate::impl_validate_fields),
+/// [`schema_validated!`](crate::schema_validated) and `#[derive(Validate)]`;
+/// [`vld::nested!`](crate::nested!) uses it so that `parse_lenient()` of the
+/// parent keeps the valid fields of the nested struct.
+pub trait VldParseLenient: Sized {
+    /// Parse leniently from a `serde_json::Value`.
+    fn vld_parse_lenient_value(value: &Value) -> Result<ParseResult<Self>, VldError>;
+}
+
+/// Lenient parse function of a [`NestedSchema`].
+pub type ParseLenientFn<T> = fn(&Value) -> Result<ParseResult<T>, VldError>;
+
+#[doc(hidden)]
+pub struct __VldNestedLenient<T>(pub PhantomData<T>);
+
+#[doc(hidden)]
+pub trait __VldNestedLenientViaTrait<T> {
+    fn __vld_lenient_fn(&self) -> Option<ParseLenientFn<T>>;
+}
+
+impl<T: VldParseLenient> __VldNestedLenientViaTrait<T> for &__VldNestedLenient<T> {
+    fn __vld_lenient_fn(&self) -> Option<ParseLenientFn<T>> {
+        Some(T::vld_parse_lenient_value)
+    }
+}
+
+#[doc(hidden)]
+pub trait __VldNestedLenientFallback<T> {
+    fn __vld_lenient_fn(&self) -> Option<ParseLenientFn<T>>;
+}
+
+impl<T> __VldNestedLenientFallback<T> for __VldNestedLenient<T> {
+    fn __vld_lenient_fn(&self) -> Option<ParseLenientFn<T>> {
+        None
+    }
+}
+
+/// Types that can be validated as a partial object.
+///
+/// Implemented by [`schema!`](crate::schema!) and `#[derive(Validate)]`;
+/// [`vld::nested!`](crate::nested!) uses it so that
+/// [`ZObject::deep_partial()`](crate::object::ZObject::deep_partial) recurses
+/// into the nested struct.
+pub trait VldDeepPartial {
+    /// The struct's field schemas as a [`ZObject`](crate::object::ZObject)
+    /// with [`deep_partial()`](crate::object::ZObject::deep_partial) applied.
+    fn vld_deep_partial() -> crate::object::ZObject;
+}
+
+/// Deep-partial function of a [`NestedSchema`].
+pub type DeepPartialFn = fn() -> crate::object::ZObject;
+
+#[doc(hidden)]
+pub struct __VldNestedDeepPartial<T>(pub PhantomData<T>);
+
+#[doc(hidden)]
+pub trait __VldNestedDeepPartialViaTrait {
+    fn __vld_deep_partial_fn(&self) -> Option<DeepPartialFn>;
+}
+
+impl<T: VldDeepPartial> __VldNestedDeepPartialViaTrait for &__VldNestedDeepPartial<T> {
+    fn __vld_deep_partial_fn(&self) -> Option<DeepPartialFn> {
+        Some(T::vld_deep_partial)
+    }
+}
+
+#[doc(hidden)]
+pub trait __VldNestedDeepPartialFallback {
+    fn __vld_deep_partial_fn(&self) -> Option<DeepPartialFn>;
+}
+
+impl<T> __VldNestedDeepPartialFallback for __VldNestedDeepPartial<T> {
+    fn __vld_deep_partial_fn(&self) -> Option<DeepPartialFn> {
+        None
+    }
+}
+
+/// Parse function of a [`NestedSchema`].
+///
+/// Implemented for every `Fn(&Value) -> Result<T, VldError>` and for
+/// [`ParseVia`], which [`vld::nested!`](crate::nested!) uses for types that
+/// implement [`VldParse`] so that they also validate in one pass on the
+/// [`deserialize_with()`](VldSchema::deserialize_with) path.
+pub trait NestedParseFn<T> {
+    /// Parse and validate a `serde_json::Value`.
+    fn parse_nested(&self, value: &Value) -> Result<T, VldError>;
+
+    /// Validate while deserializing. Buffers the value by default.
+    fn deserialize_nested<'de, D: serde::Deserializer<'de>>(
+        &self,
+        de: D,
+    ) -> crate::de::DeResult<T, D::Error> {
+        let value = <Value as serde::Deserialize>::deserialize(de)?;
+        Ok(self.parse_nested(&value))
+    }
+}
+
+impl<T, F> NestedParseFn<T> for F
+where
+    F: Fn(&Value) -> Result<T, VldError>,
+{
+    fn parse_nested(&self, value: &Value) -> Result<T, VldError> {
+        self(value)
+    }
+}
+
+/// Nested parse function backed by a [`VldParse`] type.
+///
+/// Parses values with the given function and deserializers with
+/// [`VldParse::vld_deserialize()`].
+pub struct ParseVia<T> {
+    parse_fn: fn(&Value) -> Result<T, VldError>,
+}
+
+impl<T: VldParse> ParseVia<T> {
+    pub fn new(parse_fn: fn(&Value) -> Result<T, VldError>) -> Self {
+        Self { parse_fn }
+    }
+}
+
+impl<T: VldParse> NestedParseFn<T> for ParseVia<T> {
+    fn parse_nested(&self, value: &Value) -> Result<T, VldError> {
+        (self.parse_fn)(value)
+    }
+
+    fn deserialize_nested<'de, D: serde::Deserializer<'de>>(
+        &self,
+        de: D,
+    ) -> crate::de::DeResult<T, D::Error> {
+        T::vld_deserialize(de)
+    }
 }
 
 /// Schema for parsing nested structures. Created via [`vld::nested()`](crate::nested)
 /// or the [`vld::nested!`](crate::nested!) macro.
 pub struct NestedSchema<T, F>
 where
-    F: Fn(&Value) -> Result<T, VldError>,
+    F: NestedParseFn<T>,
 {
     parse_fn: F,
     #[allow(dead_code)]
     pub(crate) name: Option<&'static str>,
+    /// Computes the component name instead of `name` (set by
+    /// [`vld::nested!`](crate::nested!) for named and generic types).
+    #[allow(dead_code)]
+    pub(crate) name_fn: Option<fn() -> String>,
     /// Returns the full JSON Schema of the nested type (for OpenAPI component registration).
     #[allow(dead_code)]
     pub(crate) json_schema_fn: Option<fn() -> serde_json::Value>,
+    /// Async parse function of the nested type (set by [`vld::nested!`](crate::nested!)).
+    #[cfg(feature = "async")]
+    pub(crate) parse_async_fn: Option<crate::async_schema::ParseAsyncFn<T>>,
+    /// Lenient parse function of the nested type (set by [`vld::nested!`](crate::nested!)).
+    lenient_fn: Option<ParseLenientFn<T>>,
+    /// Deep-partial function of the nested type (set by [`vld::nested!`](crate::nested!)).
+    deep_partial_fn: Option<DeepPartialFn>,
     _phantom: PhantomData<T>,
 }
 
 impl<T, F> NestedSchema<T, F>
 where
-    F: Fn(&Value) -> Result<T, VldError>,
+    F: NestedParseFn<T>,
 {
     pub fn new(f: F) -> Self {
         Self {
             parse_fn: f,
             name: None,
+            name_fn: None,
             json_schema_fn: None,
+            #[cfg(feature = "async")]
+            parse_async_fn: None,
+            lenient_fn: None,
+            deep_partial_fn: None,
             _phantom: PhantomData,
         }
     }
@@ -229,18 +793,94 @@ where
         Self {
             parse_fn: f,
             name: Some(name),
+            name_fn: None,
             json_schema_fn,
+            #[cfg(feature = "async")]
+            parse_async_fn: None,
+            lenient_fn: None,
+            deep_partial_fn: None,
             _phantom: PhantomData,
         }
     }
+
+    /// Compute the component name with `f` when generating JSON Schema,
+    /// instead of the name given to [`new_named()`](Self::new_named).
+    #[doc(hidden)]
+    pub fn __with_schema_name_fn(mut self, f: Option<fn() -> String>) -> Self {
+        if f.is_some() {
+            self.name_fn = f;
+        }
+        self
+    }
+
+    /// Use `f` for [`parse_value_lenient()`](VldSchema::parse_value_lenient),
+    /// so lenient parsing recurses into the nested type.
+    pub fn with_lenient(mut self, f: Option<ParseLenientFn<T>>) -> Self {
+        self.lenient_fn = f;
+        self
+    }
+
+    /// Use `f` for [`ZObject::deep_partial()`](crate::object::ZObject::deep_partial),
+    /// so a deep-partial object recurses into the nested type.
+    pub fn with_deep_partial(mut self, f: Option<DeepPartialFn>) -> Self {
+        self.deep_partial_fn = f;
+        self
+    }
+
+    /// Use `f` on the async path instead of the synchronous parse function.
+    ///
+    /// Requires the `async` feature.
+    #[cfg(feature = "async")]
+    pub fn with_async(mut self, f: Option<crate::async_schema::ParseAsyncFn<T>>) -> Self {
+        self.parse_async_fn = f;
+        self
+    }
 }
 
 impl<T, F> VldSchema for NestedSchema<T, F>
 where
-    F: Fn(&Value) -> Result<T, VldError>,
+    F: NestedParseFn<T>,
 {
     type Output = T;
     fn parse_value(&self, value: &Value) -> Result<T, VldError> {
-        (self.parse_fn)(value)
+        self.parse_fn.parse_nested(value)
+    }
+
+    fn deserialize_with<'de, D: serde::Deserializer<'de>>(
+        &self,
+        de: D,
+    ) -> crate::de::DeResult<T, D::Error> {
+        self.parse_fn.deserialize_nested(de)
+    }
+
+    fn parse_value_lenient(&self, value: &Value) -> Lenient<T> {
+        let Some(lenient) = self.lenient_fn else {
+            return Lenient::from_result(self.parse_value(value));
+        };
+        match lenient(value) {
+            Ok(result) => {
+                let (value, children) = result.into_parts();
+                let error = children
+                    .iter()
+                    .filter_map(|child| {
+                        let e = child.result.as_ref().err()?;
+                        Some(
+                            e.clone()
+                                .with_prefix(PathSegment::Field(child.name.clone())),
+                        )
+                    })
+                    .fold(VldError::new(), VldError::merge);
+                Lenient {
+                    value: Some(value),
+                    error: (!error.is_empty()).then_some(error),
+                    children,
+                }
+            }
+            Err(e) => Lenient::from_result(Err(e)),
+        }
+    }
+
+    fn __vld_deep_partial(&mut self) -> Option<Box<dyn crate::object::DynSchema>> {
+        self.deep_partial_fn.map(|f| crate::object::erase(f()))
     }
 }
@@ -0,0 +1,255 @@
+//! Source positions for validation issues.
+//!
+//! Parsing with span tracking records where every value sits in the original
+//! JSON text, so each [`ValidationIssue`](crate::error::ValidationIssue)
+//! can carry a [`Span`] (byte offsets plus line/column) next to its path.
+//! [`prettify_error_with_source()`](crate::format::prettify_error_with_source)
+//! renders those spans as code frames.
+//!
+//! The positions are only computed when validation fails, so the happy path
+//! costs the same as a plain [`parse()`](crate::schema::VldSchema::parse).
+//!
+//! # Example
+//! ```
+//! use vld::prelude::*;
+//!
+//! let schema = vld::object().field("port", vld::number().int().max(65535));
+//! let text = "{\n  \"port\": 70000\n}";
+//! let err = schema.parse_with_spans(text).unwrap_err();
+//!
+//! let span = err.issues[0].span().unwrap();
+//! assert_eq!((span.line, span.column), (2, 11));
+//! assert_eq!(&text[span.start..span.end], "70000");
+//! ```
+
+use serde_json::Value;
+
+pub use crate::error::Span;
+use crate::error::{IssueCode, PathSegment, VldError};
+use crate::schema::VldParse;
+
+/// Positions of every value in a JSON document, addressable by issue path.
+pub struct SourceMap<'a> {
+    text: &'a str,
+    line_starts: Vec<usize>,
+    root: Node,
+}
+
+struct Node {
+    start: usize,
+    end: usize,
+    kind: NodeKind,
+}
+
+enum NodeKind {
+    Scalar,
+    Array(Vec<Node>),
+    Object(Vec<(String, Node)>),
+}
+
+impl<'a> SourceMap<'a> {
+    /// Index the values of a JSON document. Returns `None` if `text` is not
+    /// well-formed enough to locate them.
+    pub fn new(text: &'a str) -> Option<Self> {
+        let mut scanner = Scanner {
+            text,
+            bytes: text.as_bytes(),
+            pos: 0,
+        };
+        let root = scanner.value()?;
+        Some(Self {
+            text,
+            line_starts: line_starts(text),
+            root,
+        })
+    }
+
+    /// Span of the value at `path`.
+    ///
+    /// If the path leaves the document (a missing field or index), the span
+    /// of the deepest value that does exist is returned.
+    pub fn span(&self, path: &[PathSegment]) -> Option<Span> {
+        let mut node = &self.root;
+        for segment in path {
+            let next = match (&node.kind, segment) {
+                (NodeKind::Object(entries), PathSegment::Field(name)) => entries
+                    .iter()
+                    .rev()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, child)| child),
+                (NodeKind::Array(items), PathSegment::Index(idx)) => items.get(*idx),
+                _ => None,
+            };
+            match next {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+        Some(self.span_at(node.start, node.end))
+    }
+
+    fn span_at(&self, start: usize, end: usize) -> Span {
+        make_span(self.text, &self.line_starts, start, end)
+    }
+}
+
+fn line_starts(text: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+        .collect()
+}
+
+fn make_span(text: &str, line_starts: &[usize], start: usize, end: usize) -> Span {
+    let line = match line_starts.binary_search(&start) {
+        Ok(i) => i,
+        Err(i) => i - 1,
+    };
+    Span {
+        start,
+        end,
+        line: line + 1,
+        column: text[line_starts[line]..start].chars().count() + 1,
+    }
+}
+
+/// Minimal JSON scanner that records value offsets. It only runs on text
+/// that `serde_json` has already accepted, so it does not re-validate.
+struct Scanner<'a> {
+    text: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Scanner<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, byte: u8) -> Option<()> {
+        self.skip_ws();
+        if self.peek()? == byte {
+            self.pos += 1;
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn value(&mut self) -> Option<Node> {
+        self.skip_ws();
+        let start = self.pos;
+        let kind = match self.peek()? {
+            b'{' => self.object()?,
+            b'[' => self.array()?,
+            b'"' => {
+                self.string()?;
+                NodeKind::Scalar
+            }
+            _ => {
+                while !matches!(
+                    self.peek(),
+                    None | Some(b',' | b']' | b'}' | b' ' | b'\t' | b'\n' | b'\r')
+                ) {
+                    self.pos += 1;
+                }
+                if self.pos == start {
+                    return None;
+                }
+                NodeKind::Scalar
+            }
+        };
+        Some(Node {
+            start,
+            end: self.pos,
+            kind,
+        })
+    }
+
+    /// Consume a string literal and return its raw text, quotes included.
+    fn string(&mut self) -> Option<&str> {
+        let start = self.pos;
+        self.pos += 1;
+        loop {
+            match self.peek()? {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    self.pos += 1;
+                    return self.text.get(start..self.pos);
+                }
+                _ => self.pos += 1,
+            }
+        }
+    }
+
+    fn array(&mut self) -> Option<NodeKind> {
+        self.pos += 1;
+        let mut items = Vec::new();
+        if self.eat(b']').is_some() {
+            return Some(NodeKind::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            if self.eat(b',').is_none() {
+                self.eat(b']')?;
+                return Some(NodeKind::Array(items));
+            }
+        }
+    }
+
+    fn object(&mut self) -> Option<NodeKind> {
+        self.pos += 1;
+        let mut entries = Vec::new();
+        if self.eat(b'}').is_some() {
+            return Some(NodeKind::Object(entries));
+        }
+        loop {
+            self.skip_ws();
+            if self.peek()? != b'"' {
+                return None;
+            }
+            let raw = self.string()?;
+            let key = if raw.contains('\\') {
+                serde_json::from_str(raw).ok()?
+            } else {
+                raw[1..raw.len() - 1].to_string()
+            };
+            self.eat(b':')?;
+            entries.push((key, self.value()?));
+            if self.eat(b',').is_none() {
+                self.eat(b'}')?;
+                return Some(NodeKind::Object(entries));
+            }
+        }
+    }
+}
+
+/// Parse JSON text into a `serde_json::Value`, giving a syntax error a
+/// span at the position `serde_json` reports.
+pub(crate) fn json_value_with_spans(text: &str) -> Result<Value, VldError> {
+    serde_json::from_str(text).map_err(|e| {
+        let mut err = VldError::single(IssueCode::ParseError, format!("Invalid JSON: {}", e));
+        if e.line() > 0 {
+            let starts = line_starts(text);
+            let line_start = starts[(e.line() - 1).min(starts.len() - 1)];
+            let mut offset = (line_start + e.column().saturating_sub(1)).min(text.len());
+            while !text.is_char_boundary(offset) {
+                offset -= 1;
+            }
+            err.issues[0].span = Some(make_span(text, &starts, offset, offset));
+        }
+        err
+    })
+}
+
+/// Parse a [`VldParse`] type (e.g. a `schema!` struct) from JSON text with
+/// span tracking. See [`VldSchema::parse_with_spans()`](crate::schema::VldSchema::parse_with_spans).
+pub fn parse_with_spans<T: VldParse>(text: &str) -> Result<T, VldError> {
+    let value = json_value_with_spans(text)?;
+    T::vld_parse_value(&value).map_err(|e| e.with_source(text))
+}
@@ -0,0 +1,338 @@
+//! Streaming validation of NDJSON / JSON Lines and large top-level arrays.
+//!
+//! [`validate_lines()`] and [`validate_array_stream()`] read one record at a
+//! time from a [`BufRead`] and validate it in one pass (see
+//! [`vld::de`](crate::de)), so memory use is bounded by the largest record,
+//! not by the size of the input.
+//!
+//! Both return an iterator of `Result<T, (record_index, VldError)>`. Issue
+//! paths are prefixed with [`PathSegment::Index`] of the record, and the
+//! iterator keeps a running [`StreamSummary`] of valid and invalid records.
+//!
+//! # Example
+//! ```
+//! use vld::prelude::*;
+//!
+//! vld::schema! {
+//!     #[derive(Debug)]
+//!     pub struct Event {
+//!         pub id: i64 => vld::number().int().positive(),
+//!         pub kind: String => vld::string().min(1),
+//!     }
+//! }
+//!
+//! let input = "{\"id\": 1, \"kind\": \"a\"}\n{\"id\": -2, \"kind\": \"b\"}\n";
+//! let schema = vld::nested!(Event);
+//! let mut stream = vld::stream::validate_lines(input.as_bytes(), &schema);
+//!
+//! for record in stream.by_ref() {
+//!     match record {
+//!         Ok(event) => assert_eq!(event.id, 1),
+//!         Err((index, err)) => {
+//!             assert_eq!(index, 1);
+//!             assert_eq!(err.to_string(), "[1].id: Number must be positive, received -2");
+//!         }
+//!     }
+//! }
+//! assert_eq!(stream.summary().valid, 1);
+//! assert_eq!(stream.summary().invalid, 1);
+//! ```
+
+use std::io::BufRead;
+
+use crate::error::{IssueCode, PathSegment, VldError};
+use crate::schema::VldSchema;
+
+/// Item produced by the streaming iterators.
+pub type StreamItem<T> = Result<T, (usize, VldError)>;
+
+/// Running counts of a stream validation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct StreamSummary {
+    /// Records that passed validation.
+    pub valid: usize,
+    /// Records that failed validation or could not be decoded.
+    pub invalid: usize,
+}
+
+impl StreamSummary {
+    /// Total number of records seen.
+    pub fn total(&self) -> usize {
+        self.valid + self.invalid
+    }
+
+    /// `true` if no record failed.
+    pub fn is_valid(&self) -> bool {
+        self.invalid == 0
+    }
+
+    fn record<T>(&mut self, item: &StreamItem<T>) {
+        match item {
+            Ok(_) => self.valid += 1,
+            Err(_) => self.invalid += 1,
+        }
+    }
+}
+
+fn io_error(e: std::io::Error) -> VldError {
+    VldError::single(IssueCode::IoError, format!("Failed to read input: {}", e))
+}
+
+fn validate_record<S: VldSchema>(schema: &S, index: usize, bytes: &[u8]) -> StreamItem<S::Output> {
+    schema
+        .parse_json_slice(bytes)
+        .map_err(|e| (index, e.with_prefix(PathSegment::Index(index))))
+}
+
+// ---------------------------------------------------------------------------
+// NDJSON / JSON Lines
+// ---------------------------------------------------------------------------
+
+/// Validate newline-delimited JSON, one record per line.
+///
+/// Blank lines are skipped and do not count as records. A line that is not
+/// valid JSON (or not valid UTF-8) is reported as a `parse_error` for that
+/// record and reading continues; an I/O error is reported once and ends the
+/// stream.
+pub fn validate_lines<R: BufRead, S: VldSchema>(reader: R, schema: &S) -> LineStream<'_, R, S> {
+    LineStream {
+        reader,
+        schema,
+        buf: Vec::new(),
+        index: 0,
+        done: false,
+        summary: StreamSummary::default(),
+    }
+}
+
+/// Iterator returned by [`validate_lines()`].
+pub struct LineStream<'s, R, S> {
+    reader: R,
+    schema: &'s S,
+    buf: Vec<u8>,
+    index: usize,
+    done: bool,
+    summary: StreamSummary,
+}
+
+impl<R, S> LineStream<'_, R, S> {
+    /// Counts of the records yielded so far.
+    pub fn summary(&self) -> StreamSummary {
+        self.summary
+    }
+}
+
+impl<R: BufRead, S: VldSchema> LineStream<'_, R, S> {
+    /// Validate the remaining records, discarding them, and return the final counts.
+    pub fn summarize(mut self) -> StreamSummary {
+        self.by_ref().for_each(drop);
+        self.summary
+    }
+}
+
+impl<R: BufRead, S: VldSchema> Iterator for LineStream<'_, R, S> {
+    type Item = StreamItem<S::Output>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.buf.clear();
+            let item = match self.reader.read_until(b'\n', &mut self.buf) {
+                Ok(0) => {
+                    self.done = true;
+                    return None;
+                }
+                Ok(_) if self.buf.iter().all(u8::is_ascii_whitespace) => continue,
+                Ok(_) => validate_record(self.schema, self.index, &self.buf),
+                Err(e) => {
+                    self.done = true;
+                    Err((self.index, io_error(e)))
+                }
+            };
+            self.index += 1;
+            self.summary.record(&item);
+            return Some(item);
+        }
+        None
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Top-level JSON array
+// ---------------------------------------------------------------------------
+
+/// Validate the elements of a top-level JSON array one at a time.
+///
+/// Each element is buffered on its own and validated, so a malformed element
+/// is reported as a `parse_error` for that record and the following elements
+/// are still checked. A document that is not an array, ends early, or fails
+/// to read ends the stream with a final error.
+pub fn validate_array_stream<R: BufRead, S: VldSchema>(
+    reader: R,
+    schema: &S,
+) -> ArrayStream<'_, R, S> {
+    ArrayStream {
+        reader,
+        schema,
+        buf: Vec::new(),
+        index: 0,
+        state: ArrayState::Start,
+        summary: StreamSummary::default(),
+    }
+}
+
+/// Iterator returned by [`validate_array_stream()`].
+pub struct ArrayStream<'s, R, S> {
+    reader: R,
+    schema: &'s S,
+    buf: Vec<u8>,
+    index: usize,
+    state: ArrayState,
+    summary: StreamSummary,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ArrayState {
+    /// Before the opening `[`.
+    Start,
+    /// After `[`, before the first element or `]`.
+    First,
+    /// After an element, before `,` or `]`.
+    Next,
+    Done,
+}
+
+impl<R, S> ArrayStream<'_, R, S> {
+    /// Counts of the records yielded so far.
+    pub fn summary(&self) -> StreamSummary {
+        self.summary
+    }
+}
+
+impl<R: BufRead, S: VldSchema> ArrayStream<'_, R, S> {
+    /// Validate the remaining records, discarding them, and return the final counts.
+    pub fn summarize(mut self) -> StreamSummary {
+        self.by_ref().for_each(drop);
+        self.summary
+    }
+
+    fn peek(&mut self) -> std::io::Result<Option<u8>> {
+        Ok(self.reader.fill_buf()?.first().copied())
+    }
+
+    /// Skip whitespace and return the next significant byte without consuming it.
+    fn peek_token(&mut self) -> std::io::Result<Option<u8>> {
+        loop {
+            match self.peek()? {
+                Some(b) if b.is_ascii_whitespace() => self.reader.consume(1),
+                other => return Ok(other),
+            }
+        }
+    }
+
+    /// Copy one element into `buf`, stopping before the `,` or `]` that ends it.
+    /// Returns `false` if the input ends first.
+    fn read_element(&mut self) -> std::io::Result<bool> {
+        self.buf.clear();
+        let mut depth = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+        loop {
+            let chunk = self.reader.fill_buf()?;
+            if chunk.is_empty() {
+                return Ok(false);
+            }
+            let mut end = None;
+            for (i, &b) in chunk.iter().enumerate() {
+                if in_string {
+                    if escaped {
+                        escaped = false;
+                    } else if b == b'\\' {
+                        escaped = true;
+                    } else if b == b'"' {
+                        in_string = false;
+                    }
+                    continue;
+                }
+                match b {
+                    b'"' => in_string = true,
+                    b'[' | b'{' => depth += 1,
+                    b']' | b'}' if depth > 0 => depth -= 1,
+                    b',' | b']' if depth == 0 => {
+                        end = Some(i);
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+            let len = end.unwrap_or(chunk.len());
+            self.buf.extend_from_slice(&chunk[..len]);
+            self.reader.consume(len);
+            if end.is_some() {
+                return Ok(true);
+            }
+        }
+    }
+
+    fn fail(&mut self, message: &str) -> Option<StreamItem<S::Output>> {
+        self.state = ArrayState::Done;
+        let err = VldError::single(IssueCode::ParseError, format!("Invalid JSON: {}", message));
+        Some(Err((self.index, err)))
+    }
+
+    /// Consume the closing `]`; only whitespace may follow it.
+    fn close(&mut self) -> std::io::Result<Option<StreamItem<S::Output>>> {
+        self.reader.consume(1);
+        if self.peek_token()?.is_some() {
+            return Ok(self.fail("trailing characters after the top-level array"));
+        }
+        self.state = ArrayState::Done;
+        Ok(None)
+    }
+
+    fn step(&mut self) -> std::io::Result<Option<StreamItem<S::Output>>> {
+        match self.state {
+            ArrayState::Done => return Ok(None),
+            ArrayState::Start => {
+                if self.peek_token()? != Some(b'[') {
+                    return Ok(self.fail("expected a top-level array"));
+                }
+                self.reader.consume(1);
+                self.state = ArrayState::First;
+                if self.peek_token()? == Some(b']') {
+                    return self.close();
+                }
+            }
+            ArrayState::First => {}
+            ArrayState::Next => match self.peek_token()? {
+                Some(b',') => self.reader.consume(1),
+                Some(b']') => return self.close(),
+                _ => return Ok(self.fail("expected `,` or `]` after array element")),
+            },
+        }
+
+        if !self.read_element()? {
+            return Ok(self.fail("unexpected end of input inside array"));
+        }
+        self.state = ArrayState::Next;
+        Ok(Some(validate_record(self.schema, self.index, &self.buf)))
+    }
+}
+
+impl<R: BufRead, S: VldSchema> Iterator for ArrayStream<'_, R, S> {
+    type Item = StreamItem<S::Output>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = match self.step() {
+            Ok(item) => item?,
+            Err(e) => {
+                self.state = ArrayState::Done;
+                Err((self.index, io_error(e)))
+            }
+        };
+        self.index += 1;
+        self.summary.record(&item);
+        Some(item)
+    }
+}
@@ -0,0 +1,164 @@
+//! [`Validated<T>`] — run vld rules as part of `serde` deserialization.
+//!
+//! Wrapping a [`VldParse`] type (a `schema!` struct or a
+//! `#[derive(Validate)]` type) in [`Validated`] gives it a
+//! [`serde::Deserialize`] impl for any self-describing format, so that
+//! `toml::from_str`, `serde_yaml::from_str`, `rmp_serde::from_slice`,
+//! `serde_json::from_reader`, ... enforce the same rules as the HTTP layer.
+//!
+//! Formats that need the target shape up front (`bincode`, `postcard`, ...)
+//! cannot drive a validating visitor. For those use [`ValidatedTyped`] (or
+//! [`deserialize_typed`] with `#[serde(deserialize_with = ...)]`): the type's
+//! own `Deserialize` impl reads the data, and the result is then validated.
+//!
+//! Validation issues become a custom serde error whose message lists every
+//! issue with its path. To get the issues as a [`VldError`](crate::error::VldError)
+//! instead, use [`vld::de::parse_deserializer()`](crate::de::parse_deserializer).
+//!
+//! # Example
+//! ```
+//! use vld::Validated;
+//!
+//! vld::schema! {
+//!     #[derive(Debug)]
+//!     pub struct Config {
+//!         pub port: i64 => vld::number().int().min(1).max(65535),
+//!         pub host: String => vld::string().min(1),
+//!     }
+//! }
+//!
+//! let cfg: Validated<Config> =
+//!     serde_json::from_str(r#"{"port": 8080, "host": "localhost"}"#).unwrap();
+//! assert_eq!(cfg.port, 8080);
+//!
+//! let err = serde_json::from_str::<Validated<Config>>(r#"{"port": 0, "host": ""}"#)
+//!     .unwrap_err()
+//!     .to_string();
+//! assert!(err.contains(".port:"));
+//! assert!(err.contains(".host:"));
+//! ```
+
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+use serde::de::{Deserialize, Deserializer, Error as _};
+use serde::Serialize;
+
+use crate::error::VldError;
+use crate::schema::VldParse;
+
+/// A value that passed its vld rules during deserialization.
+///
+/// Requires a self-describing format (JSON, YAML, TOML, MessagePack, CBOR, ...).
+/// For formats such as `bincode` that need the target shape up front, use
+/// [`ValidatedTyped`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Validated<T>(pub T);
+
+/// A value that passed its vld rules after being read by its own
+/// `Deserialize` impl.
+///
+/// Works with every serde format, including ones that are not
+/// self-describing (`bincode`, `postcard`, ...). The decoded value is
+/// serialized to JSON and parsed with its vld rules, so `T`'s serde
+/// representation must match its schema (field names, renames).
+///
+/// # Example
+/// ```
+/// use vld::ValidatedTyped;
+///
+/// vld::schema! {
+///     #[derive(Debug, serde::Serialize, serde::Deserialize)]
+///     pub struct Point {
+///         pub x: i64 => vld::number().int().min(0),
+///         pub y: i64 => vld::number().int().min(0),
+///     }
+/// }
+///
+/// // Sequence form, as written by non-self-describing formats.
+/// let p: ValidatedTyped<Point> = serde_json::from_str("[1, 2]").unwrap();
+/// assert_eq!(p.y, 2);
+/// assert!(serde_json::from_str::<ValidatedTyped<Point>>("[1, -2]").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ValidatedTyped<T>(pub T);
+
+macro_rules! impl_wrapper {
+    ($wrapper:ident) => {
+        impl<T> $wrapper<T> {
+            /// Unwrap the validated value.
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        impl<T> Deref for $wrapper<T> {
+            type Target = T;
+            fn deref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T> DerefMut for $wrapper<T> {
+            fn deref_mut(&mut self) -> &mut T {
+                &mut self.0
+            }
+        }
+
+        impl<T> AsRef<T> for $wrapper<T> {
+            fn as_ref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T: Serialize> Serialize for $wrapper<T> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+
+        impl<T: fmt::Display> fmt::Display for $wrapper<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+impl_wrapper!(Validated);
+impl_wrapper!(ValidatedTyped);
+
+impl<'de, T: VldParse> Deserialize<'de> for Validated<T> {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        match T::vld_deserialize(de)? {
+            Ok(value) => Ok(Validated(value)),
+            Err(err) => Err(validation_failed(err)),
+        }
+    }
+}
+
+impl<'de, T> Deserialize<'de> for ValidatedTyped<T>
+where
+    T: VldParse + Deserialize<'de> + Serialize,
+{
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        deserialize_typed(de).map(ValidatedTyped)
+    }
+}
+
+/// Read `T` with its own `Deserialize` impl, then validate it with its vld
+/// rules. For `#[serde(deserialize_with = "vld::validated::deserialize_typed")]`
+/// fields; see [`ValidatedTyped`].
+pub fn deserialize_typed<'de, D, T>(de: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: VldParse + Deserialize<'de> + Serialize,
+{
+    let decoded = T::deserialize(de)?;
+    let json = serde_json::to_value(&decoded).map_err(D::Error::custom)?;
+    T::vld_parse_value(&json).map_err(validation_failed)
+}
+
+fn validation_failed<E: serde::de::Error>(err: VldError) -> E {
+    E::custom(format_args!("Validation failed: {}", err))
+}
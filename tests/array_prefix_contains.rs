@@ -0,0 +1,148 @@
+//! `ZArray::prefix_items()` and `ZArray::contains_schema()`.
+
+use serde_json::json;
+#[allow(unused_imports)]
+use vld::prelude::*;
+
+fn path_of(issue: &ValidationIssue) -> String {
+    issue.path.iter().map(|p| p.to_string()).collect()
+}
+
+fn paths(err: &VldError) -> Vec<String> {
+    err.issues.iter().map(path_of).collect()
+}
+
+fn command() -> ZArray<vld::primitives::ZJsonValue> {
+    vld::array(vld::json_value()).prefix_items((vld::string().min(1), vld::number().int().min(0)))
+}
+
+#[test]
+fn prefix_items_validate_by_position() {
+    let schema = command();
+    let parsed = schema.parse(r#"["deploy", 2, "--force", 3]"#).unwrap();
+    assert_eq!(
+        parsed,
+        vec![json!("deploy"), json!(2), json!("--force"), json!(3)]
+    );
+
+    // Shorter arrays pass unless a length is required.
+    assert!(schema.parse(r#"["deploy"]"#).is_ok());
+    assert!(command().min_len(2).parse(r#"["deploy"]"#).is_err());
+
+    let err = schema.parse(r#"["", "high", {}]"#).unwrap_err();
+    assert_eq!(paths(&err), vec!["[0]", "[1]"]);
+}
+
+#[test]
+fn rest_elements_use_the_element_schema() {
+    let schema = vld::array(vld::string().min(2)).prefix_items((vld::string().len(1),));
+    assert_eq!(
+        schema.parse(r#"["a", "bc", "de"]"#).unwrap(),
+        vec!["a", "bc", "de"]
+    );
+    let err = schema.parse(r#"["ab", "c"]"#).unwrap_err();
+    assert_eq!(paths(&err), vec!["[0]", "[1]"]);
+}
+
+#[test]
+fn prefix_output_must_fit_the_element_type() {
+    let schema = vld::array(vld::string()).prefix_items((vld::number(),));
+    let err = schema.parse("[1]").unwrap_err();
+    assert_eq!(paths(&err), vec!["[0]"]);
+    assert_eq!(err.issues[0].code.key(), "invalid_prefix_item");
+}
+
+#[test]
+fn prefix_items_in_lenient_parse() {
+    let result = command().parse_value_lenient(&json!(["deploy", -1]));
+    assert!(result.value.is_none());
+    assert_eq!(paths(result.error.as_ref().unwrap()), vec!["[1]"]);
+    assert!(result.children[0].result.is_ok());
+    assert!(result.children[1].result.is_err());
+}
+
+#[test]
+fn contains_schema_counts_matches() {
+    let scores = vld::array(vld::number())
+        .contains_schema(vld::number().min(90.0))
+        .min_contains(2)
+        .max_contains(3);
+    assert!(scores.parse("[95, 40, 91]").is_ok());
+
+    let err = scores.parse("[40, 50]").unwrap_err();
+    let keys: Vec<&str> = err.issues.iter().map(|i| i.code.key()).collect();
+    assert_eq!(keys, vec!["missing_contains", "too_small"]);
+    assert_eq!(
+        err.issues[0].message,
+        "Array must contain a matching element"
+    );
+
+    let err = scores.parse("[95]").unwrap_err();
+    assert_eq!(
+        err.issues[0].message,
+        "Array must contain a matching element at least 2 time(s)"
+    );
+
+    let err = scores.parse("[91, 92, 93, 94]").unwrap_err();
+    assert_eq!(err.issues[0].code.key(), "too_big");
+}
+
+#[test]
+fn contains_schema_replaces_contains_value() {
+    let schema = vld::array(vld::string())
+        .contains("admin")
+        .contains_schema(vld::string().starts_with("role:"));
+    assert!(schema.parse(r#"["role:user"]"#).is_ok());
+    assert!(schema.parse(r#"["admin"]"#).is_err());
+}
+
+#[test]
+fn contains_schema_in_struct_fields() {
+    vld::schema! {
+        #[derive(Debug)]
+        pub struct Team {
+            pub roles: Vec<String> => vld::array(vld::string())
+                .contains_schema(vld::string().starts_with("owner"))
+                .max_contains(1),
+        }
+    }
+
+    assert!(Team::parse_json_str(r#"{"roles": ["owner", "dev"]}"#).is_ok());
+    let err = Team::parse_json_str(r#"{"roles": ["owner", "owner"]}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".roles"]);
+}
+
+#[cfg(feature = "openapi")]
+#[test]
+fn json_schema_output() {
+    let schema = command()
+        .contains_schema(vld::string().min(3))
+        .min_contains(2)
+        .json_schema();
+    assert_eq!(
+        schema["prefixItems"],
+        json!([
+            {"type": "string", "minLength": 1},
+            {"type": "integer", "minimum": 0.0}
+        ])
+    );
+    assert_eq!(schema["items"], json!({}));
+    assert_eq!(
+        schema["contains"],
+        json!({"type": "string", "minLength": 3})
+    );
+    assert_eq!(schema["minContains"], 2);
+
+    let literal = vld::array(vld::number()).contains(2).json_schema();
+    assert_eq!(literal["contains"], json!({"const": 2}));
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn async_parse_uses_prefix_and_contains() {
+    let schema = command().contains_schema(vld::string().min(5));
+    assert!(schema.parse_async(r#"["deploy", 1]"#).await.is_ok());
+
+    let err = schema.parse_async(r#"["", 1]"#).await.unwrap_err();
+    assert_eq!(paths(&err), vec!["", "[0]"]);
+}
@@ -0,0 +1,368 @@
+#![cfg(feature = "async")]
+
+use vld::async_schema::BoxFuture;
+use vld::prelude::*;
+
+async fn email_taken(email: &str) -> bool {
+    email == "taken@example.com"
+}
+
+async fn order_exists(id: i64) -> bool {
+    id < 100
+}
+
+#[allow(clippy::ptr_arg)]
+fn check_email(email: &String) -> BoxFuture<'static, bool> {
+    let email = email.clone();
+    Box::pin(async move { !email_taken(&email).await })
+}
+
+type EmailSchema = ZRefineAsync<ZString, fn(&String) -> BoxFuture<'static, bool>>;
+
+fn email_schema() -> EmailSchema {
+    vld::string().email().refine_async(
+        check_email as fn(&String) -> BoxFuture<'static, bool>,
+        "Email is already taken",
+    )
+}
+
+fn path_of(issue: &ValidationIssue) -> String {
+    issue.path.iter().map(|p| p.to_string()).collect()
+}
+
+// ---------------------------------------------------------------------------
+// refine_async / super_refine_async
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn refine_async_passes() {
+    let email = email_schema()
+        .parse_async(r#""new@example.com""#)
+        .await
+        .unwrap();
+    assert_eq!(email, "new@example.com");
+}
+
+#[tokio::test]
+async fn refine_async_fails() {
+    let err = email_schema()
+        .parse_async(r#""taken@example.com""#)
+        .await
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 1);
+    assert_eq!(err.issues[0].message, "Email is already taken");
+    assert!(matches!(
+        &err.issues[0].code,
+        IssueCode::Custom { code } if code == "custom"
+    ));
+}
+
+#[tokio::test]
+async fn refine_async_skipped_when_inner_fails() {
+    let err = email_schema().parse_async(r#""nope""#).await.unwrap_err();
+    assert_eq!(err.issues.len(), 1);
+    assert!(!err.issues[0].message.contains("taken"));
+}
+
+#[test]
+fn sync_parse_of_async_refinement_errors() {
+    let err = email_schema().parse(r#""new@example.com""#).unwrap_err();
+    assert!(matches!(
+        &err.issues[0].code,
+        IssueCode::Custom { code } if code == "async_refinement"
+    ));
+}
+
+#[tokio::test]
+async fn super_refine_async_collects_issues() {
+    let schema = vld::number().int().super_refine_async(|id: &i64| {
+        let id = *id;
+        async move {
+            let mut errors = VldError::new();
+            if !order_exists(id).await {
+                errors.push(
+                    IssueCode::Custom {
+                        code: "not_found".into(),
+                    },
+                    "Unknown order",
+                );
+            }
+            if id % 2 != 0 {
+                errors.push(
+                    IssueCode::Custom { code: "odd".into() },
+                    "Order ids are even",
+                );
+            }
+            errors
+        }
+    });
+
+    assert_eq!(schema.parse_async("42").await.unwrap(), 42);
+    let err = schema.parse_async("101").await.unwrap_err();
+    assert_eq!(err.issues.len(), 2);
+}
+
+#[tokio::test]
+async fn sync_schemas_work_on_async_path() {
+    let schema = vld::string().min(3);
+    assert_eq!(schema.parse_async(r#""hello""#).await.unwrap(), "hello");
+    assert!(schema.parse_async(r#""hi""#).await.is_err());
+}
+
+// ---------------------------------------------------------------------------
+// Composition
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn optional_async() {
+    let schema = email_schema().optional();
+    assert_eq!(schema.parse_async("null").await.unwrap(), None);
+    assert!(schema.parse_async(r#""taken@example.com""#).await.is_err());
+}
+
+#[tokio::test]
+async fn array_async_reports_index_paths() {
+    let schema = vld::array(email_schema());
+    let err = schema
+        .parse_async(r#"["a@example.com", "taken@example.com", "b@example.com"]"#)
+        .await
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 1);
+    assert_eq!(path_of(&err.issues[0]), "[1]");
+}
+
+#[tokio::test]
+async fn object_async_merges_sync_and_async_issues() {
+    let schema = vld::object_async()
+        .field("name", vld::string().min(2))
+        .field("email", email_schema());
+
+    let ok = schema
+        .parse_async(r#"{"name": "Alice", "email": "alice@example.com"}"#)
+        .await
+        .unwrap();
+    assert_eq!(ok["email"], "alice@example.com");
+
+    let err = schema
+        .parse_async(r#"{"name": "A", "email": "taken@example.com"}"#)
+        .await
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 2);
+    assert!(err.issues.iter().any(|i| path_of(i) == ".email"));
+}
+
+#[tokio::test]
+async fn object_async_field_runs_inline_refinements() {
+    let schema = vld::object_async().field(
+        "email",
+        vld::string().refine_async(
+            |email: &String| {
+                let email = email.clone();
+                async move { !email_taken(&email).await }
+            },
+            "Email is already taken",
+        ),
+    );
+    assert!(schema
+        .parse_async(r#"{"email": "new@example.com"}"#)
+        .await
+        .is_ok());
+    let err = schema
+        .parse_async(r#"{"email": "taken@example.com"}"#)
+        .await
+        .unwrap_err();
+    assert_eq!(path_of(&err.issues[0]), ".email");
+    assert_eq!(err.issues[0].message, "Email is already taken");
+}
+
+#[tokio::test]
+async fn discriminated_union_async_routes_to_async_variant() {
+    let schema = vld::discriminated_union_async("type")
+        .variant_str(
+            "email",
+            vld::object_async()
+                .field("type", vld::literal("email"))
+                .field("to", email_schema()),
+        )
+        .variant_str(
+            "sms",
+            vld::object_async()
+                .field("type", vld::literal("sms"))
+                .field("phone", vld::string()),
+        );
+    assert!(schema
+        .parse_async(r#"{"type": "sms", "phone": "123"}"#)
+        .await
+        .is_ok());
+    let err = schema
+        .parse_async(r#"{"type": "email", "to": "taken@example.com"}"#)
+        .await
+        .unwrap_err();
+    assert_eq!(path_of(&err.issues[0]), ".to");
+}
+
+// ---------------------------------------------------------------------------
+// sync-only (!Send) schemas keep compiling with the feature enabled
+// ---------------------------------------------------------------------------
+
+type Username = ZRefine<ZString, Box<dyn Fn(&String) -> bool>>;
+
+/// A refinement holding an `Rc`, so the schema is neither `Send` nor `Sync`.
+fn username() -> Username {
+    let reserved = std::rc::Rc::new(vec!["admin", "root"]);
+    vld::string().refine(
+        Box::new(move |s: &String| !reserved.contains(&s.as_str())),
+        "Reserved",
+    )
+}
+
+#[test]
+fn object_accepts_rc_capturing_refine() {
+    let schema = vld::object().field("user", username());
+    assert!(schema.parse(r#"{"user": "alice"}"#).is_ok());
+    let err = schema.parse(r#"{"user": "root"}"#).unwrap_err();
+    assert_eq!(path_of(&err.issues[0]), ".user");
+}
+
+vld::schema! {
+    #[derive(Debug)]
+    pub struct Member {
+        pub user: String => username(),
+        pub email: String => email_schema(),
+    }
+}
+
+#[tokio::test]
+async fn schema_macro_parse_async_with_rc_capturing_refine() {
+    let m = Member::parse_async(r#"{"user": "alice", "email": "a@example.com"}"#)
+        .await
+        .unwrap();
+    assert_eq!(m.user, "alice");
+
+    let err = Member::parse_async(r#"{"user": "root", "email": "taken@example.com"}"#)
+        .await
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 2);
+}
+
+vld::schema! {
+    #[derive(Debug)]
+    pub struct Signup {
+        pub name: String => vld::string().min(2),
+        pub email: String => email_schema(),
+        pub order_id: Option<i64> => vld::number().int().refine_async(
+            |id: &i64| {
+                let id = *id;
+                async move { order_exists(id).await }
+            },
+            "Order not found",
+        ).optional(),
+    }
+}
+
+#[tokio::test]
+async fn schema_macro_parse_async() {
+    let s = Signup::parse_async(r#"{"name": "Bob", "email": "bob@example.com", "order_id": 7}"#)
+        .await
+        .unwrap();
+    assert_eq!(s.name, "Bob");
+    assert_eq!(s.order_id, Some(7));
+}
+
+#[tokio::test]
+async fn schema_macro_collects_all_issues_in_one_pass() {
+    let err =
+        Signup::parse_async(r#"{"name": "B", "email": "taken@example.com", "order_id": 500}"#)
+            .await
+            .unwrap_err();
+    assert_eq!(err.issues.len(), 3);
+    let paths: Vec<String> = err
+        .issues
+        .iter()
+        .map(|i| match &i.path[0] {
+            PathSegment::Field(f) => f.clone(),
+            PathSegment::Index(i) => i.to_string(),
+        })
+        .collect();
+    assert!(paths.contains(&"name".to_string()));
+    assert!(paths.contains(&"email".to_string()));
+    assert!(paths.contains(&"order_id".to_string()));
+}
+
+#[tokio::test]
+async fn vld_parse_value_async_uses_async_fields() {
+    let value = serde_json::json!({"name": "Bob", "email": "taken@example.com"});
+    let err = <Signup as VldParse>::vld_parse_value_async(&value)
+        .await
+        .unwrap_err();
+    assert_eq!(err.issues[0].message, "Email is already taken");
+}
+
+vld::schema! {
+    #[derive(Debug)]
+    pub struct Order {
+        pub signup: Signup => vld::nested!(Signup),
+        pub note: String => vld::string(),
+    }
+}
+
+#[tokio::test]
+async fn nested_schema_runs_async_refinements() {
+    let err = Order::parse_async(
+        r#"{"signup": {"name": "Bob", "email": "taken@example.com"}, "note": ""}"#,
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(path_of(&err.issues[0]), ".signup.email");
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use super::*;
+
+    #[derive(Debug, vld::Validate)]
+    struct Account {
+        #[vld(vld::string().min(2))]
+        name: String,
+        #[vld(email_schema())]
+        email: String,
+    }
+
+    #[tokio::test]
+    async fn derive_parse_async() {
+        let ok = Account::vld_parse_async(r#"{"name": "Bob", "email": "bob@example.com"}"#)
+            .await
+            .unwrap();
+        assert_eq!(ok.name, "Bob");
+        assert_eq!(ok.email, "bob@example.com");
+
+        let err = Account::vld_parse_async(r#"{"name": "Bob", "email": "taken@example.com"}"#)
+            .await
+            .unwrap_err();
+        assert_eq!(path_of(&err.issues[0]), ".email");
+    }
+
+    #[derive(Debug, vld::Validate)]
+    struct Login {
+        #[vld(username())]
+        user: String,
+        #[vld(email_schema())]
+        email: String,
+    }
+
+    #[tokio::test]
+    async fn derive_parse_async_with_rc_capturing_refine() {
+        let ok = Login::vld_parse_async(r#"{"user": "alice", "email": "a@example.com"}"#)
+            .await
+            .unwrap();
+        assert_eq!(
+            (ok.user.as_str(), ok.email.as_str()),
+            ("alice", "a@example.com")
+        );
+
+        let err = Login::vld_parse_async(r#"{"user": "root", "email": "a@example.com"}"#)
+            .await
+            .unwrap_err();
+        assert_eq!(path_of(&err.issues[0]), ".user");
+    }
+}
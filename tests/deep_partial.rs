@@ -0,0 +1,251 @@
+//! `ZObject::deep_partial()` makes nested objects, arrays and records of
+//! objects, and nested `schema!` / `#[derive(Validate)]` structs partial.
+
+use serde_json::json;
+#[allow(unused_imports)]
+use vld::prelude::*;
+
+fn path_of(issue: &ValidationIssue) -> String {
+    issue.path.iter().map(|p| p.to_string()).collect()
+}
+
+fn paths(err: &VldError) -> Vec<String> {
+    err.issues.iter().map(path_of).collect()
+}
+
+fn address() -> ZObject {
+    vld::object()
+        .field("city", vld::string().min(1))
+        .field("zip", vld::string().len(5))
+}
+
+fn user() -> ZObject {
+    vld::object()
+        .field("name", vld::string().min(1))
+        .field("address", address())
+        .field(
+            "phones",
+            vld::array(vld::object().field("number", vld::string())),
+        )
+        .field(
+            "labels",
+            vld::record(vld::object().field("color", vld::string())),
+        )
+        .field("billing", address().nullable())
+}
+
+#[test]
+fn nested_objects_become_partial() {
+    let patch = user().deep_partial();
+    assert!(patch.parse("{}").is_ok());
+    assert!(patch.parse(r#"{"address": {}}"#).is_ok());
+    assert!(patch.parse(r#"{"address": {"city": "Berlin"}}"#).is_ok());
+
+    let err = patch
+        .parse(r#"{"name": "", "address": {"zip": "1"}}"#)
+        .unwrap_err();
+    assert_eq!(paths(&err), vec![".name", ".address.zip"]);
+
+    // partial() still requires the nested fields.
+    let err = user()
+        .partial()
+        .parse(r#"{"address": {"city": "Berlin"}}"#)
+        .unwrap_err();
+    assert_eq!(paths(&err), vec![".address.zip"]);
+}
+
+#[test]
+fn arrays_records_and_nullable_recurse() {
+    let patch = user().deep_partial();
+    assert!(patch
+        .parse(r#"{"phones": [{}, {"number": "1"}], "labels": {"a": {}}, "billing": {}}"#)
+        .is_ok());
+    assert!(patch.parse(r#"{"billing": null}"#).is_ok());
+
+    let err = patch
+        .parse(
+            r#"{"phones": [{"number": 1}], "labels": {"a": {"color": 2}}, "billing": {"zip": ""}}"#,
+        )
+        .unwrap_err();
+    assert_eq!(
+        paths(&err),
+        vec![".phones[0].number", ".labels.a.color", ".billing.zip"]
+    );
+}
+
+#[test]
+fn array_constraints_are_kept() {
+    let patch = vld::object()
+        .field(
+            "items",
+            vld::array(vld::object().field("id", vld::number().int())).min_len(1),
+        )
+        .deep_partial();
+    assert!(patch.parse(r#"{"items": [{}]}"#).is_ok());
+    let err = patch.parse(r#"{"items": []}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".items"]);
+}
+
+vld::schema! {
+    #[derive(Debug, serde::Serialize)]
+    pub struct Address {
+        pub city: String => vld::string().min(1),
+        pub zip: String => vld::string().len(5),
+    }
+}
+
+vld::schema! {
+    #[derive(Debug)]
+    pub struct Profile {
+        pub name: String => vld::string().min(1),
+        pub home: Address => vld::nested!(Address),
+        pub previous: Vec<Address> => vld::array(vld::nested!(Address)),
+        pub email: String as "emailAddress" => vld::string().email(),
+    }
+}
+
+#[test]
+fn nested_schema_macro_structs_become_partial() {
+    let patch = vld::object()
+        .field("home", vld::nested!(Address))
+        .deep_partial();
+    assert!(patch.parse(r#"{"home": {"city": "Berlin"}}"#).is_ok());
+    let err = patch.parse(r#"{"home": {"zip": "1"}}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".home.zip"]);
+
+    let output = patch.parse(r#"{"home": {"city": "Berlin"}}"#).unwrap();
+    assert_eq!(output["home"], json!({"city": "Berlin", "zip": null}));
+}
+
+#[test]
+fn schema_macro_deep_partial() {
+    let patch = Profile::vld_deep_partial();
+    assert!(patch.parse("{}").is_ok());
+    assert!(patch
+        .parse(r#"{"home": {"zip": "10115"}, "previous": [{"city": "Bonn"}]}"#)
+        .is_ok());
+
+    let err = patch
+        .parse(r#"{"home": {"zip": "1"}, "previous": [{"city": ""}], "emailAddress": "x"}"#)
+        .unwrap_err();
+    assert_eq!(
+        paths(&err),
+        vec![".home.zip", ".previous[0].city", ".emailAddress"]
+    );
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use super::*;
+    use vld::Validate;
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize, Validate)]
+    struct Audit {
+        #[vld(length(min = 1))]
+        created_by: String,
+    }
+
+    #[derive(Debug, Default, serde::Serialize, serde::Deserialize, Validate)]
+    struct Street {
+        #[vld(length(min = 1))]
+        name: String,
+        #[vld(range(min = 1))]
+        number: u32,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, serde::Deserialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    struct Order {
+        #[vld(length(min = 1))]
+        order_id: String,
+        #[vld(vld::nested!(Street))]
+        ship_to: Street,
+        #[serde(flatten)]
+        audit: Audit,
+    }
+
+    #[test]
+    fn derived_structs_become_partial() {
+        let patch = Order::vld_deep_partial();
+        assert!(patch.parse("{}").is_ok());
+        assert!(patch.parse(r#"{"shipTo": {"number": 3}}"#).is_ok());
+
+        let err = patch
+            .parse(r#"{"orderId": "", "shipTo": {"number": 0}, "created_by": ""}"#)
+            .unwrap_err();
+        assert_eq!(
+            paths(&err),
+            vec![".orderId", ".shipTo.number", ".created_by"]
+        );
+    }
+}
+
+#[cfg(feature = "openapi")]
+#[test]
+fn json_schema_reflects_deep_partial() {
+    let schema = user().deep_partial().to_json_schema();
+    assert_eq!(schema["required"], json!([]));
+
+    let schema = vld::object()
+        .field_schema("name", vld::string().min(1))
+        .field_schema("address", address_schema())
+        .field_schema(
+            "phones",
+            vld::array(vld::object().field_schema("number", vld::string())),
+        )
+        .deep_partial()
+        .to_json_schema();
+    assert_eq!(schema["required"], json!([]));
+    assert_eq!(
+        schema["properties"]["name"],
+        json!({"oneOf": [{"type": "string", "minLength": 1}, {"type": "null"}]})
+    );
+    let address = &schema["properties"]["address"]["oneOf"][0];
+    assert_eq!(address["required"], json!([]));
+    assert_eq!(address["properties"]["zip"]["oneOf"][0]["minLength"], 5);
+    let phone = &schema["properties"]["phones"]["oneOf"][0]["items"];
+    assert_eq!(phone["required"], json!([]));
+
+    let profile = Profile::vld_deep_partial().to_json_schema();
+    assert_eq!(profile["required"], json!([]));
+    let home = &profile["properties"]["home"]["oneOf"][0];
+    assert_eq!(home["required"], json!([]));
+    assert!(home["properties"]["zip"].is_object());
+    assert!(profile["properties"]["emailAddress"].is_object());
+}
+
+#[cfg(feature = "openapi")]
+fn address_schema() -> ZObject {
+    vld::object()
+        .field_schema("city", vld::string().min(1))
+        .field_schema("zip", vld::string().len(5))
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn async_parse_of_deep_partial() {
+    let address = || {
+        vld::object_async()
+            .field("city", vld::string().min(1))
+            .field("zip", vld::string().len(5))
+    };
+    let patch = vld::object_async()
+        .field("name", vld::string().min(1))
+        .field("address", address())
+        .field(
+            "phones",
+            vld::array(vld::object_async().field("number", vld::string())),
+        )
+        .field("billing", address().nullable())
+        .deep_partial();
+    assert!(patch
+        .parse_async(r#"{"address": {"city": "Berlin"}}"#)
+        .await
+        .is_ok());
+    let err = patch
+        .parse_async(r#"{"phones": [{"number": 1}]}"#)
+        .await
+        .unwrap_err();
+    assert_eq!(paths(&err), vec![".phones[0].number"]);
+}
@@ -0,0 +1,341 @@
+//! Tests for `#[derive(Validate)]` on enums.
+#![cfg(feature = "derive")]
+
+use serde_json::json;
+#[allow(unused_imports)]
+use vld::prelude::*;
+use vld::Validate;
+
+fn path_of(issue: &ValidationIssue) -> String {
+    issue.path.iter().map(|p| p.to_string()).collect()
+}
+
+fn paths(err: &VldError) -> Vec<String> {
+    err.issues.iter().map(path_of).collect()
+}
+
+// ---------------------------------------------------------------------------
+// Unit enums
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, Validate)]
+#[serde(rename_all = "snake_case")]
+enum Role {
+    #[default]
+    Admin,
+    PowerUser,
+    #[serde(rename = "guest")]
+    Anonymous,
+}
+
+#[test]
+fn unit_enum_honors_rename_all() {
+    assert_eq!(Role::vld_parse(r#""admin""#).unwrap(), Role::Admin);
+    assert_eq!(Role::vld_parse(r#""power_user""#).unwrap(), Role::PowerUser);
+    assert_eq!(Role::vld_parse(r#""guest""#).unwrap(), Role::Anonymous);
+}
+
+#[test]
+fn unit_enum_errors_match_enumeration() {
+    let err = Role::vld_parse(r#""PowerUser""#).unwrap_err();
+    let expected = vld::enumeration(&["admin", "power_user", "guest"])
+        .parse(r#""PowerUser""#)
+        .unwrap_err();
+    assert_eq!(err.issues[0].message, expected.issues[0].message);
+    assert!(Role::vld_parse("1").is_err());
+}
+
+#[derive(Debug, PartialEq, Validate)]
+enum Level {
+    Low,
+    High,
+}
+
+#[test]
+fn unit_enum_without_serde() {
+    assert_eq!(Level::parse_value(&json!("High")).unwrap(), Level::High);
+}
+
+// ---------------------------------------------------------------------------
+// Externally tagged (serde default)
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq, serde::Deserialize, Validate)]
+enum Command {
+    Stop,
+    Move {
+        #[vld(vld::number().int())]
+        x: i64,
+        #[vld(vld::number().int().min(0))]
+        y: i64,
+    },
+    Say(#[vld(vld::string().min(1))] String),
+    Point(
+        #[vld(vld::number().int())] i64,
+        #[vld(vld::number().int().positive())] i64,
+    ),
+}
+
+#[test]
+fn external_variants() {
+    assert_eq!(Command::vld_parse(r#""Stop""#).unwrap(), Command::Stop);
+    assert_eq!(
+        Command::vld_parse(r#"{"Move": {"x": -1, "y": 2}}"#).unwrap(),
+        Command::Move { x: -1, y: 2 }
+    );
+    assert_eq!(
+        Command::vld_parse(r#"{"Say": "hi"}"#).unwrap(),
+        Command::Say("hi".into())
+    );
+    assert_eq!(
+        Command::vld_parse(r#"{"Point": [1, 2]}"#).unwrap(),
+        Command::Point(1, 2)
+    );
+    // Same shapes serde accepts.
+    let via_serde: Command = serde_json::from_str(r#"{"Point": [1, 2]}"#).unwrap();
+    assert_eq!(via_serde, Command::Point(1, 2));
+}
+
+#[test]
+fn external_errors_are_prefixed_with_variant() {
+    let err = Command::vld_parse(r#"{"Move": {"x": "a", "y": -1}}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".Move.x", ".Move.y"]);
+
+    let err = Command::vld_parse(r#"{"Point": [1, 0]}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".Point[1]"]);
+
+    let err = Command::vld_parse(r#"{"Point": [1]}"#).unwrap_err();
+    assert!(err.issues[0].message.contains("tuple of 2"));
+}
+
+#[test]
+fn external_unknown_or_malformed() {
+    let err = Command::vld_parse(r#"{"Jump": {}}"#).unwrap_err();
+    assert!(err.issues[0]
+        .message
+        .contains("Invalid discriminator value \"Jump\""));
+    assert!(Command::vld_parse(r#"{"Stop": null, "Say": "x"}"#).is_err());
+    assert!(Command::vld_parse(r#"{"Stop": 1}"#).is_err());
+    assert!(Command::vld_parse("[]").is_err());
+}
+
+// ---------------------------------------------------------------------------
+// Internally tagged
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize, Validate)]
+struct Square {
+    #[vld(vld::number().positive())]
+    side: f64,
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize, Validate)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Shape {
+    Circle {
+        #[vld(vld::number().positive())]
+        radius: f64,
+    },
+    Square(#[vld(vld::nested!(Square))] Square),
+    Empty,
+}
+
+#[test]
+fn internal_tag_routes_by_discriminator() {
+    assert_eq!(
+        Shape::vld_parse(r#"{"type": "circle", "radius": 2.5}"#).unwrap(),
+        Shape::Circle { radius: 2.5 }
+    );
+    assert_eq!(
+        Shape::vld_parse(r#"{"type": "square", "side": 3}"#).unwrap(),
+        Shape::Square(Square { side: 3.0 })
+    );
+    assert_eq!(
+        Shape::vld_parse(r#"{"type": "empty"}"#).unwrap(),
+        Shape::Empty
+    );
+}
+
+#[test]
+fn internal_tag_errors() {
+    let err = Shape::vld_parse(r#"{"type": "square", "side": -1}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".side"]);
+
+    let err = Shape::vld_parse(r#"{"radius": 1}"#).unwrap_err();
+    assert!(matches!(err.issues[0].code, IssueCode::MissingField));
+
+    let err = Shape::vld_parse(r#"{"type": "hexagon"}"#).unwrap_err();
+    assert_eq!(
+        err.issues[0].message,
+        "Invalid discriminator value \"hexagon\". Expected one of: \"circle\", \"square\", \"empty\""
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Adjacently tagged
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq, serde::Deserialize, Validate)]
+#[serde(tag = "t", content = "c")]
+enum Event {
+    Ping,
+    Resize(
+        #[vld(vld::number().int().min(1))] i64,
+        #[vld(vld::number().int().min(1))] i64,
+    ),
+    Rename {
+        #[vld(vld::string().min(1))]
+        #[serde(rename = "newName")]
+        new_name: String,
+    },
+}
+
+#[test]
+fn adjacent_tag_and_content() {
+    assert_eq!(Event::vld_parse(r#"{"t": "Ping"}"#).unwrap(), Event::Ping);
+    assert_eq!(
+        Event::vld_parse(r#"{"t": "Resize", "c": [640, 480]}"#).unwrap(),
+        Event::Resize(640, 480)
+    );
+    assert_eq!(
+        Event::vld_parse(r#"{"t": "Rename", "c": {"newName": "x"}}"#).unwrap(),
+        Event::Rename {
+            new_name: "x".into()
+        }
+    );
+
+    let err = Event::vld_parse(r#"{"t": "Resize", "c": [640, 0]}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".c[1]"]);
+    let err = Event::vld_parse(r#"{"t": "Rename"}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".c"]);
+}
+
+// ---------------------------------------------------------------------------
+// Untagged
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq, serde::Deserialize, Validate)]
+#[serde(untagged)]
+enum Id {
+    Numeric(#[vld(vld::number().int().positive())] i64),
+    Slug(#[vld(vld::string().min(3))] String),
+    Named {
+        #[vld(vld::string())]
+        name: String,
+    },
+}
+
+#[test]
+fn untagged_tries_variants_in_order() {
+    assert_eq!(Id::vld_parse("7").unwrap(), Id::Numeric(7));
+    assert_eq!(Id::vld_parse(r#""abc""#).unwrap(), Id::Slug("abc".into()));
+    assert_eq!(
+        Id::vld_parse(r#"{"name": "n"}"#).unwrap(),
+        Id::Named { name: "n".into() }
+    );
+
+    let err = Id::vld_parse("-1").unwrap_err();
+    assert_eq!(
+        err.issues[0].message,
+        "Input did not match any variant of Id"
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Nesting and JSON Schema
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Validate)]
+struct Drawing {
+    #[vld(vld::array(vld::nested!(Shape)))]
+    shapes: Vec<Shape>,
+    #[vld(vld::nested!(Role))]
+    owner: Role,
+}
+
+#[test]
+fn enums_nest_inside_structs() {
+    let d = Drawing::vld_parse(
+        r#"{"shapes": [{"type": "circle", "radius": 1}, {"type": "empty"}], "owner": "admin"}"#,
+    )
+    .unwrap();
+    assert_eq!(d.shapes.len(), 2);
+    assert_eq!(d.owner, Role::Admin);
+
+    let err =
+        Drawing::vld_parse(r#"{"shapes": [{"type": "circle", "radius": 0}], "owner": "root"}"#)
+            .unwrap_err();
+    assert_eq!(paths(&err), vec![".shapes[0].radius", ".owner"]);
+}
+
+#[cfg(feature = "openapi")]
+#[test]
+fn json_schema_representations() {
+    assert_eq!(
+        Role::json_schema(),
+        json!({"type": "string", "enum": ["admin", "power_user", "guest"]})
+    );
+
+    let shape = Shape::json_schema();
+    assert_eq!(shape["discriminator"], json!({"propertyName": "type"}));
+    assert_eq!(
+        shape["oneOf"][0]["properties"]["type"],
+        json!({"type": "string", "const": "circle"})
+    );
+    assert_eq!(shape["oneOf"][0]["required"], json!(["type", "radius"]));
+    assert_eq!(
+        shape["oneOf"][1]["allOf"][1],
+        json!({"$ref": "#/components/schemas/Square"})
+    );
+    assert_eq!(shape["oneOf"][2]["required"], json!(["type"]));
+
+    let event = Event::json_schema();
+    assert_eq!(event["oneOf"][1]["required"], json!(["t", "c"]));
+    assert_eq!(
+        event["oneOf"][1]["properties"]["c"]["prefixItems"][1]["minimum"],
+        1.0
+    );
+
+    let command = Command::json_schema();
+    assert!(command.get("discriminator").is_none());
+    assert_eq!(
+        command["oneOf"][0],
+        json!({"type": "string", "const": "Stop"})
+    );
+    assert_eq!(command["oneOf"][2]["required"], json!(["Say"]));
+
+    assert_eq!(Id::json_schema()["oneOf"].as_array().unwrap().len(), 3);
+}
+
+#[cfg(feature = "async")]
+mod async_enum {
+    use super::*;
+
+    #[derive(Debug, PartialEq, serde::Deserialize, Validate)]
+    #[serde(tag = "kind")]
+    enum Lookup {
+        ById {
+            #[vld(vld::number().int().refine_async(
+                |id: &i64| {
+                    let id = *id;
+                    async move { id < 100 }
+                },
+                "Unknown id",
+            ))]
+            id: i64,
+        },
+    }
+
+    #[tokio::test]
+    async fn variant_fields_run_async_refinements() {
+        let ok = Lookup::vld_parse_async(r#"{"kind": "ById", "id": 5}"#)
+            .await
+            .unwrap();
+        assert_eq!(ok, Lookup::ById { id: 5 });
+        let err = Lookup::vld_parse_async(r#"{"kind": "ById", "id": 500}"#)
+            .await
+            .unwrap_err();
+        assert_eq!(err.issues[0].message, "Unknown id");
+        assert_eq!(paths(&err), vec![".id"]);
+    }
+}
@@ -0,0 +1,140 @@
+//! Struct types derived with `#[vld(partial = ...)]`, `#[vld(pick(...))]`
+//! and `#[vld(omit(...))]`.
+#![cfg(feature = "derive")]
+
+#[allow(unused_imports)]
+use vld::prelude::*;
+use vld::Validate;
+
+fn path_of(issue: &ValidationIssue) -> String {
+    issue.path.iter().map(|p| p.to_string()).collect()
+}
+
+fn paths(err: &VldError) -> Vec<String> {
+    err.issues.iter().map(path_of).collect()
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize, Validate)]
+struct Address {
+    #[vld(length(min = 1))]
+    city: String,
+}
+
+#[derive(Debug, serde::Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+#[vld(partial(name = "UserPatch", derive(Debug, serde::Deserialize)))]
+#[vld(pick(name = "UserSummary", fields = [display_name, email], derive(Debug)))]
+#[vld(omit(name = "NewUser", fields = [id]), omit(name = "UserUpdate", fields = [id], partial))]
+#[vld(refine = |u: &User| u.id > 0, message = "Invalid id", path = "id")]
+struct User {
+    #[vld(range(min = 0))]
+    id: i64,
+    #[vld(length(min = 2))]
+    display_name: String,
+    #[vld(email)]
+    email: String,
+    #[vld(vld::number().int().min(13).optional())]
+    age: Option<i64>,
+    #[vld(nested)]
+    #[serde(default)]
+    address: Address,
+}
+
+#[test]
+fn partial_makes_every_field_optional() {
+    let patch = UserPatch::vld_parse("{}").unwrap();
+    assert_eq!(patch.id, None);
+    assert_eq!(patch.display_name, None);
+    assert_eq!(patch.email, None);
+    assert_eq!(patch.age, None);
+    assert_eq!(patch.address.city, "");
+
+    let patch = UserPatch::vld_parse(r#"{"displayName": "Al", "age": 20}"#).unwrap();
+    assert_eq!(patch.display_name.as_deref(), Some("Al"));
+    assert_eq!(patch.age, Some(20));
+
+    // Present values keep the original rules and serde keys.
+    let err = UserPatch::vld_parse(r#"{"displayName": "A", "email": "x", "age": 3}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".displayName", ".email", ".age"]);
+
+    // The generated type keeps the requested derives and serde attributes.
+    let theirs: UserPatch = serde_json::from_str(r#"{"displayName": "Al"}"#).unwrap();
+    assert_eq!(theirs.display_name.as_deref(), Some("Al"));
+}
+
+#[test]
+fn pick_and_omit_select_fields() {
+    let summary = UserSummary::vld_parse(r#"{"displayName": "Al", "email": "a@b.co"}"#).unwrap();
+    assert_eq!(summary.display_name, "Al");
+    assert_eq!(summary.email, "a@b.co");
+    let err = UserSummary::vld_parse(r#"{"displayName": "Al"}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".email"]);
+
+    let new = NewUser::vld_parse(r#"{"displayName": "Al", "email": "a@b.co"}"#).unwrap();
+    assert_eq!(new.display_name, "Al");
+    assert_eq!(new.email, "a@b.co");
+    assert_eq!(new.age, None);
+    assert_eq!(new.address.city, "");
+
+    let update = UserUpdate::vld_parse(r#"{"email": "a@b.co"}"#).unwrap();
+    assert_eq!(update.email.as_deref(), Some("a@b.co"));
+    assert_eq!(update.display_name, None);
+    assert_eq!(update.age, None);
+    assert_eq!(update.address.city, "");
+}
+
+#[test]
+fn struct_rules_stay_on_the_original() {
+    let json = r#"{"id": 0, "displayName": "Al", "email": "a@b.co"}"#;
+    let err = User::vld_parse(json).unwrap_err();
+    assert_eq!(err.to_string(), ".id: Invalid id");
+
+    let json = r#"{"id": 1, "displayName": "Al", "email": "a@b.co", "age": 30}"#;
+    let user = User::vld_parse(json).unwrap();
+    assert_eq!(
+        (user.display_name.as_str(), user.email.as_str()),
+        ("Al", "a@b.co")
+    );
+    assert_eq!((user.age, user.address.city.as_str()), (Some(30), ""));
+
+    let patch = UserPatch::vld_parse(r#"{"id": 0}"#).unwrap();
+    assert_eq!(patch.id, Some(0));
+}
+
+#[test]
+fn derived_types_are_vld_parse() {
+    fn parse<T: VldParse>(json: &str) -> Result<T, VldError> {
+        T::vld_parse_value(&serde_json::from_str(json).unwrap())
+    }
+
+    let patch: UserPatch = parse(r#"{"email": "a@b.co"}"#).unwrap();
+    assert_eq!(patch.email.as_deref(), Some("a@b.co"));
+
+    let result = UserPatch::parse_lenient(r#"{"email": "nope"}"#).unwrap();
+    assert_eq!(result.value.email, None);
+    assert!(!result.is_valid());
+}
+
+#[cfg(feature = "openapi")]
+#[test]
+fn derived_types_have_their_own_json_schema() {
+    use vld::json_schema::VldJsonSchema;
+
+    assert_eq!(UserPatch::vld_schema_name(), "UserPatch");
+    let schema = UserPatch::json_schema();
+    assert!(schema["properties"]["displayName"]["oneOf"].is_array());
+    assert_eq!(
+        schema["required"],
+        serde_json::json!(["id", "displayName", "email", "age"])
+    );
+
+    let summary = UserSummary::json_schema();
+    let keys: Vec<&str> = summary["properties"]
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    assert_eq!(keys, vec!["displayName", "email"]);
+    assert_eq!(summary["properties"]["email"]["format"], "email");
+}
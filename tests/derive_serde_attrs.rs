@@ -0,0 +1,217 @@
+//! `#[derive(Validate)]` honors the serde attributes that change how a struct
+//! is read: `flatten`, `default`, `skip`, `alias` and `deny_unknown_fields`.
+#![cfg(feature = "derive")]
+
+#[allow(unused_imports)]
+use vld::prelude::*;
+use vld::Validate;
+
+fn path_of(issue: &ValidationIssue) -> String {
+    issue.path.iter().map(|p| p.to_string()).collect()
+}
+
+fn paths(err: &VldError) -> Vec<String> {
+    err.issues.iter().map(path_of).collect()
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize, Validate)]
+struct Audit {
+    #[vld(length(min = 1))]
+    created_by: String,
+    #[vld(range(min = 1))]
+    version: u32,
+}
+
+fn default_role() -> String {
+    "member".into()
+}
+
+#[derive(Debug, serde::Deserialize, Validate)]
+struct Member {
+    #[vld(length(min = 2))]
+    #[serde(alias = "username", alias = "login")]
+    name: String,
+    #[vld(length(min = 1))]
+    #[serde(default = "default_role")]
+    role: String,
+    #[vld(range(max = 10))]
+    #[serde(default)]
+    level: u8,
+    #[serde(skip)]
+    session: Option<String>,
+    #[serde(flatten)]
+    audit: Audit,
+}
+
+#[test]
+fn flatten_reads_nested_fields_from_the_same_object() {
+    let m = Member::vld_parse(r#"{"name": "Al", "created_by": "root", "version": 2}"#).unwrap();
+    assert_eq!(m.name, "Al");
+    assert_eq!((m.audit.created_by.as_str(), m.audit.version), ("root", 2));
+
+    let err = Member::vld_parse(r#"{"name": "Al", "created_by": "", "version": 0}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".created_by", ".version"]);
+}
+
+#[test]
+fn default_fills_missing_fields_only() {
+    let m = Member::vld_parse(r#"{"name": "Al", "created_by": "r", "version": 1}"#).unwrap();
+    assert_eq!((m.role.as_str(), m.level), ("member", 0));
+
+    // A present value is still validated.
+    let json = r#"{"name": "Al", "role": "", "level": 11, "created_by": "r", "version": 1}"#;
+    let err = Member::vld_parse(json).unwrap_err();
+    assert_eq!(paths(&err), vec![".role", ".level"]);
+}
+
+#[test]
+fn skip_ignores_the_input() {
+    let json = r#"{"name": "Al", "session": 42, "created_by": "r", "version": 1}"#;
+    let m = Member::vld_parse(json).unwrap();
+    assert_eq!(m.session, None);
+}
+
+#[test]
+fn alias_accepts_alternate_keys() {
+    let m = Member::vld_parse(r#"{"login": "Bob", "created_by": "r", "version": 1}"#).unwrap();
+    assert_eq!(m.name, "Bob");
+
+    // Issues are reported under the key that was used.
+    let err =
+        Member::vld_parse(r#"{"username": "B", "created_by": "r", "version": 1}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".username"]);
+
+    let err = Member::vld_parse(r#"{"created_by": "r", "version": 1}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".name"]);
+}
+
+#[test]
+fn derive_agrees_with_serde() {
+    let json = r#"{"login": "Bob", "session": "s", "created_by": "r", "version": 3}"#;
+    let ours = Member::vld_parse(json).unwrap();
+    let theirs: Member = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        (ours.name, theirs.name),
+        ("Bob".to_string(), "Bob".to_string())
+    );
+    assert_eq!(
+        (ours.role, theirs.role),
+        ("member".to_string(), "member".to_string())
+    );
+    assert_eq!((ours.level, theirs.level), (0, 0));
+    assert_eq!((ours.session, theirs.session), (None, None));
+    assert_eq!((ours.audit.version, theirs.audit.version), (3, 3));
+    assert_eq!(ours.audit.created_by, theirs.audit.created_by);
+}
+
+#[derive(Debug, Default, serde::Serialize, Validate)]
+struct Settings {
+    #[vld(length(max = 5))]
+    theme: String,
+    #[vld(range(min = 1))]
+    #[serde(default)]
+    page_size: u32,
+    #[serde(flatten)]
+    audit: Audit,
+}
+
+#[test]
+fn lenient_and_per_field_results_follow_serde_attrs() {
+    let json = r#"{"theme": "dark", "created_by": "", "version": 1}"#;
+    let result = Settings::parse_lenient(json).unwrap();
+    assert_eq!(result.value.theme, "dark");
+    assert_eq!(result.value.page_size, 0);
+    assert_eq!(result.value.audit.version, 1);
+    assert!(!result.is_valid());
+
+    let fields = Settings::validate_fields(json).unwrap();
+    let names: Vec<&str> = fields.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, vec!["theme", "page_size", "created_by", "version"]);
+    assert_eq!(fields[1].result.as_ref().unwrap(), &serde_json::json!(0));
+    assert!(fields[2].result.is_err());
+}
+
+#[derive(Debug, Default, serde::Deserialize, Validate)]
+#[serde(default)]
+struct Paging {
+    #[vld(range(min = 1))]
+    page: u32,
+    #[vld(range(min = 1, max = 100))]
+    per_page: u32,
+}
+
+#[derive(Debug, serde::Deserialize, Validate)]
+#[serde(default = "DefaultPaging::first_page")]
+struct DefaultPaging {
+    #[vld(range(min = 1))]
+    page: u32,
+    #[vld(range(min = 1, max = 100))]
+    per_page: u32,
+}
+
+impl DefaultPaging {
+    fn first_page() -> Self {
+        Self {
+            page: 1,
+            per_page: 20,
+        }
+    }
+}
+
+#[test]
+fn struct_level_default_fills_missing_fields() {
+    let p = DefaultPaging::vld_parse(r#"{"page": 3}"#).unwrap();
+    assert_eq!((p.page, p.per_page), (3, 20));
+
+    // Defaults are not validated; present values are.
+    let p = Paging::vld_parse("{}").unwrap();
+    assert_eq!((p.page, p.per_page), (0, 0));
+    let err = Paging::vld_parse(r#"{"per_page": 500}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".per_page"]);
+}
+
+#[derive(Debug, serde::Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+struct Login {
+    #[vld(email)]
+    #[serde(alias = "mail")]
+    email: String,
+    #[vld(length(min = 8))]
+    password: String,
+}
+
+#[test]
+fn deny_unknown_fields_rejects_extra_keys() {
+    let l = Login::vld_parse(r#"{"mail": "a@b.co", "password": "12345678"}"#).unwrap();
+    assert_eq!((l.email.as_str(), l.password.len()), ("a@b.co", 8));
+
+    let json = r#"{"email": "a@b.co", "password": "1234", "admin": true}"#;
+    let err = Login::vld_parse(json).unwrap_err();
+    assert_eq!(paths(&err), vec![".password", ".admin"]);
+    assert!(matches!(err.issues[1].code, IssueCode::UnrecognizedField));
+
+    // Same issue as a strict object schema.
+    let strict = vld::object()
+        .field("email", vld::string())
+        .strict()
+        .parse(r#"{"email": "a", "admin": true}"#)
+        .unwrap_err();
+    assert_eq!(err.issues[1].message, strict.issues[0].message);
+}
+
+#[cfg(feature = "openapi")]
+#[test]
+fn json_schema_follows_serde_attrs() {
+    let schema = Member::json_schema();
+    let props = schema["properties"].as_object().unwrap();
+    let keys: Vec<&str> = props.keys().map(String::as_str).collect();
+    assert_eq!(keys, vec!["created_by", "level", "name", "role", "version"]);
+    assert_eq!(
+        schema["required"],
+        serde_json::json!(["name", "created_by", "version"])
+    );
+    assert!(schema.get("additionalProperties").is_none());
+
+    let schema = Login::json_schema();
+    assert_eq!(schema["additionalProperties"], false);
+}
@@ -0,0 +1,235 @@
+//! Tests for the shorthand `#[vld(email, length(...), ...)]` field attributes.
+#![cfg(feature = "derive")]
+
+#[allow(unused_imports)]
+use vld::prelude::*;
+use vld::Validate;
+
+fn path_of(issue: &ValidationIssue) -> String {
+    issue.path.iter().map(|p| p.to_string()).collect()
+}
+
+fn paths(err: &VldError) -> Vec<String> {
+    err.issues.iter().map(path_of).collect()
+}
+
+#[derive(Debug, Default, serde::Serialize, Validate)]
+struct Address {
+    #[vld(length(min = 1))]
+    city: String,
+}
+
+// `custom` functions receive the field's own type.
+#[allow(clippy::ptr_arg)]
+fn not_admin(name: &String) -> bool {
+    name != "admin"
+}
+
+#[derive(Debug, Validate)]
+struct Account {
+    #[vld(length(min = 2, max = 20), custom = not_admin)]
+    name: String,
+    #[vld(email)]
+    email: String,
+    #[vld(url(message = "Homepage must be a URL"))]
+    homepage: Option<String>,
+    #[vld(range(min = 18, max = 150))]
+    age: u8,
+    #[vld(range(min = 0, exclusive_max = 1))]
+    ratio: f64,
+    #[vld(contains = "@", length(equal = 5, message = "Handle must be 5 chars"))]
+    handle: String,
+    #[vld(nested)]
+    address: Address,
+    #[vld(nested, length(max = 2))]
+    previous: Vec<Address>,
+    #[vld(length(min = 1), contains = "rust")]
+    tags: Vec<String>,
+    #[vld(required)]
+    nickname: Option<String>,
+    #[vld(custom(function = "not_admin", message = "Reserved name"))]
+    alias: Option<String>,
+}
+
+fn valid() -> serde_json::Value {
+    serde_json::json!({
+        "name": "Alex",
+        "email": "alex@example.com",
+        "age": 30,
+        "ratio": 0.5,
+        "handle": "@alex",
+        "address": {"city": "Paris"},
+        "previous": [],
+        "tags": ["rust"],
+        "nickname": "al",
+    })
+}
+
+#[test]
+fn shorthand_accepts_valid_input() {
+    let a = Account::parse_value(&valid()).unwrap();
+    assert_eq!(a.age, 30u8);
+    assert_eq!(a.homepage, None);
+    assert_eq!(a.nickname.as_deref(), Some("al"));
+    assert_eq!(a.address.city, "Paris");
+    assert_eq!(
+        (a.name.as_str(), a.email.as_str()),
+        ("Alex", "alex@example.com")
+    );
+    assert_eq!((a.ratio, a.handle.as_str()), (0.5, "@alex"));
+    assert!(a.previous.is_empty());
+    assert_eq!(a.tags, vec!["rust"]);
+    assert_eq!(a.alias, None);
+}
+
+#[test]
+fn shorthand_reports_each_validator() {
+    let mut input = valid();
+    input["name"] = "admin".into();
+    input["email"] = "nope".into();
+    input["homepage"] = "ftp:/x".into();
+    input["age"] = 12.into();
+    input["ratio"] = 1.into();
+    input["handle"] = "alex".into();
+    input["address"] = serde_json::json!({"city": ""});
+    input["tags"] = serde_json::json!(["go"]);
+    input["alias"] = "admin".into();
+    let err = Account::parse_value(&input).unwrap_err();
+    assert_eq!(
+        paths(&err),
+        vec![
+            ".name",
+            ".email",
+            ".homepage",
+            ".age",
+            ".ratio",
+            ".handle",
+            ".handle",
+            ".address.city",
+            ".tags",
+            ".alias"
+        ]
+    );
+    let messages: Vec<&str> = err.issues.iter().map(|i| i.message.as_str()).collect();
+    assert_eq!(messages[0], "Invalid value");
+    assert_eq!(messages[2], "Homepage must be a URL");
+    assert_eq!(messages[6], "Handle must be 5 chars");
+    assert_eq!(messages[9], "Reserved name");
+}
+
+#[test]
+fn shorthand_matches_builder_chain() {
+    let mut input = valid();
+    input["email"] = "nope".into();
+    let err = Account::parse_value(&input).unwrap_err();
+    let expected = vld::string().email().parse("\"nope\"").unwrap_err();
+    assert_eq!(err.issues[0].message, expected.issues[0].message);
+}
+
+#[derive(Debug, Validate)]
+struct Counter {
+    #[vld(range(min = 1))]
+    count: u8,
+    #[vld(range(min = 0))]
+    weight: f32,
+}
+
+#[test]
+fn shorthand_integer_widths_are_checked() {
+    let c = Counter::vld_parse(r#"{"count": 255, "weight": 1.5}"#).unwrap();
+    assert_eq!(c.count, 255u8);
+    assert_eq!(c.weight, 1.5f32);
+
+    let err = Counter::vld_parse(r#"{"count": 256, "weight": 0}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".count"]);
+    assert_eq!(err.issues[0].message, "Number is out of range for u8");
+}
+
+#[derive(Debug, Validate)]
+struct Big {
+    #[vld(range(min = 0))]
+    big: u64,
+}
+
+#[test]
+fn shorthand_u64_beyond_i64_is_rejected() {
+    let b = Big::vld_parse(r#"{"big": 9223372036854775807}"#).unwrap();
+    assert_eq!(b.big, i64::MAX as u64);
+
+    let err = Big::vld_parse(&format!(r#"{{"big": {}}}"#, u64::MAX)).unwrap_err();
+    assert_eq!(paths(&err), vec![".big"]);
+    assert_eq!(err.issues[0].message, "Number is out of range for i64");
+}
+
+#[test]
+fn shorthand_required_and_array_length() {
+    let mut input = valid();
+    input.as_object_mut().unwrap().remove("nickname");
+    input["previous"] = serde_json::json!([{"city": "a"}, {"city": "b"}, {"city": "c"}]);
+    let err = Account::parse_value(&input).unwrap_err();
+    assert_eq!(paths(&err), vec![".previous", ".nickname"]);
+}
+
+#[derive(Debug, Validate)]
+struct Mixed {
+    #[vld(length(max = 3))]
+    short: String,
+    // The full expression form still works next to shorthand fields.
+    #[vld(vld::string().min(1).trim())]
+    trimmed: String,
+}
+
+#[test]
+fn shorthand_and_expressions_mix() {
+    let m = Mixed::vld_parse(r#"{"short": "abc", "trimmed": "  x "}"#).unwrap();
+    assert_eq!(m.short, "abc");
+    assert_eq!(m.trimmed, "x");
+    assert!(Mixed::vld_parse(r#"{"short": "abcd", "trimmed": "x"}"#).is_err());
+}
+
+#[derive(Debug, Validate)]
+struct Slug(#[vld(length(min = 3))] String);
+
+#[test]
+fn shorthand_on_newtypes() {
+    assert_eq!(Slug::vld_parse(r#""abc""#).unwrap().0, "abc");
+    assert!(Slug::vld_parse(r#""ab""#).is_err());
+}
+
+#[cfg(feature = "regex")]
+mod with_regex {
+    use super::*;
+
+    #[derive(Debug, Validate)]
+    struct Code {
+        #[vld(regex = r"^[A-Z]{3}$")]
+        code: String,
+        #[vld(pattern(pattern = r"^\d+$", message = "Digits only"))]
+        pin: String,
+    }
+
+    #[test]
+    fn shorthand_regex() {
+        let code = Code::vld_parse(r#"{"code": "ABC", "pin": "123"}"#).unwrap();
+        assert_eq!((code.code.as_str(), code.pin.as_str()), ("ABC", "123"));
+        let err = Code::vld_parse(r#"{"code": "abc", "pin": "12a"}"#).unwrap_err();
+        assert_eq!(paths(&err), vec![".code", ".pin"]);
+        assert_eq!(err.issues[1].message, "Digits only");
+    }
+}
+
+#[cfg(feature = "openapi")]
+#[test]
+fn shorthand_json_schema() {
+    let schema = Account::json_schema();
+    let props = &schema["properties"];
+    assert_eq!(props["name"]["minLength"], 2);
+    assert_eq!(props["name"]["maxLength"], 20);
+    assert_eq!(props["email"]["format"], "email");
+    assert_eq!(props["age"]["minimum"], 18.0);
+    assert_eq!(props["previous"]["maxItems"], 2);
+    assert_eq!(
+        props["address"],
+        serde_json::json!({"$ref": "#/components/schemas/Address"})
+    );
+}
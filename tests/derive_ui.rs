@@ -0,0 +1,9 @@
+//! Compile-fail tests pinning the diagnostics of `#[derive(Validate)]` and
+//! `schema!`.
+#![cfg(feature = "derive")]
+
+#[test]
+fn derive_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
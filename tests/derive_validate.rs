@@ -156,3 +156,100 @@ fn derive_array_input() {
     let err = SimpleUser::parse_value(&serde_json::json!([1, 2, 3])).unwrap_err();
     assert!(err.issues[0].message.contains("object"));
 }
+
+// ---------------------------------------------------------------------------
+// Tuple structs and newtypes
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Default, PartialEq, serde::Serialize, Validate)]
+struct Email(#[vld(vld::string().email())] String);
+
+#[derive(Debug, PartialEq, Validate)]
+struct Percent(#[vld(vld::number().min(0.0).max(100.0))] f64);
+
+#[derive(Debug, PartialEq, serde::Serialize, Validate)]
+struct Range(
+    #[vld(vld::number().int().min(0))] i64,
+    #[vld(vld::number().int().max(10))] i64,
+);
+
+#[test]
+fn derive_newtype_is_transparent() {
+    assert_eq!(
+        Email::vld_parse(r#""a@b.com""#).unwrap(),
+        Email("a@b.com".into())
+    );
+    assert_eq!(
+        Percent::parse_value(&serde_json::json!(42)).unwrap().0,
+        42.0
+    );
+
+    let err = Percent::parse_value(&serde_json::json!(120)).unwrap_err();
+    assert!(err.issues[0].path.is_empty());
+    assert!(Email::vld_parse(r#"{"email": "a@b.com"}"#).is_err());
+}
+
+#[test]
+fn derive_tuple_struct_from_array() {
+    assert_eq!(Range::vld_parse("[1, 10]").unwrap(), Range(1, 10));
+
+    let err = Range::vld_parse("[-1, 11]").unwrap_err();
+    let paths: Vec<String> = err
+        .issues
+        .iter()
+        .map(|i| i.path.iter().map(|p| p.to_string()).collect())
+        .collect();
+    assert_eq!(paths, vec!["[0]", "[1]"]);
+
+    let err = Range::vld_parse("[1]").unwrap_err();
+    assert_eq!(
+        err.issues[0].message,
+        "Expected tuple of 2 elements, received 1"
+    );
+    assert!(Range::vld_parse(r#"{"0": 1, "1": 2}"#).is_err());
+}
+
+#[test]
+fn derive_tuple_struct_lenient_and_fields() {
+    let fields = Range::validate_fields("[5, 50]").unwrap();
+    assert_eq!(fields[0].name, "0");
+    assert!(fields[0].is_ok());
+    assert!(fields[1].is_err());
+
+    let result = Email::parse_lenient(r#""nope""#).unwrap();
+    assert_eq!(result.value, Email::default());
+    assert!(result.fields()[0].is_err());
+}
+
+#[derive(Debug, Validate)]
+struct Contact {
+    #[vld(vld::nested!(Email))]
+    email: Email,
+    #[vld(vld::nested!(Range).optional())]
+    hours: Option<Range>,
+}
+
+#[test]
+fn derive_newtype_nested_in_struct() {
+    let c = Contact::vld_parse(r#"{"email": "x@y.io", "hours": [9, 10]}"#).unwrap();
+    assert_eq!(c.email.0, "x@y.io");
+    assert_eq!(c.hours, Some(Range(9, 10)));
+
+    let err = Contact::vld_parse(r#"{"email": "x", "hours": [9, 17]}"#).unwrap_err();
+    let paths: Vec<String> = err
+        .issues
+        .iter()
+        .map(|i| i.path.iter().map(|p| p.to_string()).collect())
+        .collect();
+    assert_eq!(paths, vec![".email", ".hours[1]"]);
+}
+
+#[cfg(feature = "openapi")]
+#[test]
+fn derive_tuple_struct_json_schema() {
+    assert_eq!(Email::json_schema(), vld::string().email().json_schema());
+    let range = Range::json_schema();
+    assert_eq!(range["type"], "array");
+    assert_eq!(range["minItems"], 2);
+    assert_eq!(range["prefixItems"][0]["minimum"], 0.0);
+}
@@ -0,0 +1,309 @@
+use serde_json::json;
+use vld::prelude::*;
+
+/// Both parsing paths must produce the same output and the same issues.
+macro_rules! assert_same {
+    ($schema:expr, $json:expr) => {{
+        let schema = $schema;
+        let json: &str = $json;
+        let via_value = schema.parse(json);
+        let via_de = schema.parse_json_str(json);
+        assert_eq!(
+            format!("{:?}", via_value),
+            format!("{:?}", via_de),
+            "paths differ for {}",
+            json
+        );
+        via_de
+    }};
+}
+
+fn path_of(issue: &vld::error::ValidationIssue) -> String {
+    issue.path.iter().map(|p| p.to_string()).collect()
+}
+
+// ---------------------------------------------------------------------------
+// Primitives and wrappers
+// ---------------------------------------------------------------------------
+
+#[test]
+fn primitives_match_value_path() {
+    assert_same!(vld::string().min(2), r#""hello""#).unwrap();
+    assert_same!(vld::string().min(2), r#""h""#).unwrap_err();
+    assert_same!(vld::string().email(), r#""nope""#).unwrap_err();
+    assert_same!(vld::string(), "42").unwrap_err();
+    assert_same!(vld::number().int().min(0), "7").unwrap();
+    assert_same!(vld::number().int().min(0), "-7").unwrap_err();
+    assert_same!(vld::number(), "1.5").unwrap();
+    assert_same!(vld::number(), r#""1.5""#).unwrap_err();
+    assert_same!(vld::boolean(), "true").unwrap();
+    assert_same!(vld::literal("admin"), r#""user""#).unwrap_err();
+    assert_same!(vld::enumeration(&["a", "b"]), r#""b""#).unwrap();
+    assert_same!(vld::any(), r#"{"x": [1, null]}"#).unwrap();
+}
+
+#[test]
+fn modifiers_match_value_path() {
+    assert_eq!(
+        assert_same!(vld::string().optional(), "null").unwrap(),
+        None
+    );
+    assert_eq!(
+        assert_same!(vld::string().optional(), r#""x""#).unwrap(),
+        Some("x".to_string())
+    );
+    assert_same!(vld::string().min(3).nullable(), r#""x""#).unwrap_err();
+    assert_same!(vld::number().int().nullish(), "null").unwrap();
+    assert_eq!(
+        assert_same!(vld::number().int().with_default(5), "null").unwrap(),
+        5
+    );
+    assert_eq!(
+        assert_same!(vld::number().int().with_default(5), "9").unwrap(),
+        9
+    );
+    assert_same!(vld::array(vld::number()).optional(), r#"[1, "x"]"#).unwrap_err();
+}
+
+#[test]
+fn combinators_match_value_path() {
+    let even = || vld::number().int().refine(|n| n % 2 == 0, "Must be even");
+    assert_same!(even(), "4").unwrap();
+    assert_same!(even(), "3").unwrap_err();
+
+    let handle = || {
+        vld::string().super_refine(|s, errors| {
+            if s.contains(' ') {
+                errors.push(
+                    IssueCode::Custom {
+                        code: "space".into(),
+                    },
+                    "No spaces",
+                );
+            }
+        })
+    };
+    assert_same!(handle(), r#""a b""#).unwrap_err();
+
+    let upper = || vld::string().transform(|s| s.to_uppercase());
+    assert_eq!(assert_same!(upper(), r#""abc""#).unwrap(), "ABC");
+
+    assert_same!(vld::string().min(2).describe("Name"), r#""a""#).unwrap_err();
+}
+
+// ---------------------------------------------------------------------------
+// Collections
+// ---------------------------------------------------------------------------
+
+#[test]
+fn arrays_match_value_path() {
+    let ints = || vld::array(vld::number().int().positive()).max_len(3);
+    assert_eq!(assert_same!(ints(), "[1, 2, 3]").unwrap(), vec![1, 2, 3]);
+    let err = assert_same!(ints(), r#"[1, -2, "x", 4]"#).unwrap_err();
+    assert_eq!(err.issues.len(), 3);
+    assert_eq!(path_of(&err.issues[1]), "[1]");
+    assert_eq!(path_of(&err.issues[2]), "[2]");
+
+    assert_same!(ints(), r#"{"a": 1}"#).unwrap_err();
+    assert_same!(vld::array(vld::string()).min_len(1), "[]").unwrap_err();
+    assert_same!(vld::array(vld::array(vld::boolean())), "[[true], [1]]").unwrap_err();
+}
+
+#[test]
+fn raw_value_array_checks_match_value_path() {
+    assert_same!(vld::array(vld::number()).unique(), "[1, 2, 1]").unwrap_err();
+    assert_same!(vld::array(vld::string()).contains("x"), r#"["a"]"#).unwrap_err();
+}
+
+#[test]
+fn buffered_schemas_match_value_path() {
+    let obj = || {
+        vld::object()
+            .field("a", vld::string().min(2))
+            .field("b", vld::number().int())
+            .strict()
+    };
+    assert_same!(obj(), r#"{"a": "xy", "b": 1}"#).unwrap();
+    assert_same!(obj(), r#"{"a": "x", "c": 1}"#).unwrap_err();
+
+    let rec = || vld::record(vld::number().int());
+    assert_same!(rec(), r#"{"a": 1, "b": "x"}"#).unwrap_err();
+
+    let set = vld::set(vld::string());
+    let json = r#"["a", "b", "a"]"#;
+    assert_eq!(set.parse_json_str(json).unwrap(), set.parse(json).unwrap());
+}
+
+// ---------------------------------------------------------------------------
+// schema! structs
+// ---------------------------------------------------------------------------
+
+vld::schema! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Address {
+        pub city: String => vld::string().min(1),
+        pub zip: String => vld::string().len(5),
+    }
+}
+
+vld::schema! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Customer {
+        pub name: String => vld::string().min(2),
+        pub email: String as "emailAddress" => vld::string().email(),
+        pub age: Option<i64> => vld::number().int().min(0).optional(),
+        pub role: String => vld::string().with_default("user".to_string()),
+        pub address: Address => vld::nested!(Address),
+        pub previous: Vec<Address> => vld::array(vld::nested!(Address)),
+    }
+}
+
+const GOOD: &str = r#"{
+    "name": "Alice",
+    "emailAddress": "alice@example.com",
+    "address": {"city": "Berlin", "zip": "10115"},
+    "previous": [{"city": "Paris", "zip": "75001"}],
+    "ignored": {"deep": [1, 2, 3]}
+}"#;
+
+const BAD: &str = r#"{
+    "name": "A",
+    "emailAddress": "nope",
+    "age": -1,
+    "address": {"city": "", "zip": "1"},
+    "previous": [{"city": "Paris", "zip": "75001"}, {"zip": 5}]
+}"#;
+
+#[test]
+fn schema_struct_parse_json_str() {
+    let c = Customer::parse_json_str(GOOD).unwrap();
+    assert_eq!(c, Customer::parse(GOOD).unwrap());
+    assert_eq!(c.role, "user");
+    assert_eq!(c.age, None);
+    assert_eq!(c.previous[0].city, "Paris");
+}
+
+#[test]
+fn schema_struct_errors_match_value_path() {
+    let via_value = Customer::parse(BAD).unwrap_err();
+    let via_de = Customer::parse_json_str(BAD).unwrap_err();
+    assert_eq!(format!("{:?}", via_value), format!("{:?}", via_de));
+
+    let paths: Vec<String> = via_de.issues.iter().map(path_of).collect();
+    assert!(paths.contains(&".emailAddress".to_string()));
+    assert!(paths.contains(&".address.zip".to_string()));
+    assert!(paths.contains(&".previous[1].city".to_string()));
+    assert!(paths.contains(&".previous[1].zip".to_string()));
+}
+
+#[test]
+fn schema_struct_missing_fields_and_wrong_type() {
+    let via_de = Customer::parse_json_str("{}").unwrap_err();
+    assert_eq!(
+        format!("{:?}", Customer::parse("{}").unwrap_err()),
+        format!("{:?}", via_de)
+    );
+
+    let err = Customer::parse_json_str("[1, 2]").unwrap_err();
+    assert!(matches!(err.issues[0].code, IssueCode::InvalidType { .. }));
+}
+
+#[test]
+fn duplicate_keys_keep_last_value() {
+    let json = r#"{"city": "", "city": "Rome", "zip": "00100"}"#;
+    assert_eq!(Address::parse_json_str(json).unwrap().city, "Rome");
+    assert_eq!(Address::parse(json).unwrap().city, "Rome");
+}
+
+#[test]
+fn nested_schema_in_plain_array() {
+    let schema = vld::array(vld::nested!(Address));
+    let json = r#"[{"city": "A", "zip": "12345"}, {"city": "B"}]"#;
+    let err = assert_same!(schema, json).unwrap_err();
+    assert_eq!(path_of(&err.issues[0]), "[1].zip");
+}
+
+// ---------------------------------------------------------------------------
+// Entry points and errors
+// ---------------------------------------------------------------------------
+
+#[test]
+fn malformed_json_is_a_parse_error() {
+    for input in [
+        r#"{"city": "A""#,
+        r#"{"city": "A", "zip": "12345"} trailing"#,
+    ] {
+        let err = Address::parse_json_str(input).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert!(matches!(err.issues[0].code, IssueCode::ParseError));
+        assert!(err.issues[0].message.starts_with("Invalid JSON"));
+    }
+    let err = vld::array(vld::string()).parse_json_str("[").unwrap_err();
+    assert!(matches!(err.issues[0].code, IssueCode::ParseError));
+}
+
+#[test]
+fn parse_json_slice() {
+    let bytes = br#"{"city": "Oslo", "zip": "01500"}"#;
+    assert_eq!(
+        vld::de::parse_json_slice::<Address>(bytes).unwrap().city,
+        "Oslo"
+    );
+    assert_eq!(
+        vld::array(vld::number().int())
+            .parse_json_slice(b"[1, 2]")
+            .unwrap(),
+        vec![1, 2]
+    );
+}
+
+#[test]
+fn parse_deserializer_accepts_any_self_describing_format() {
+    // `serde_json::Value` is itself a serde `Deserializer`.
+    let value = json!({"city": "Lima", "zip": "15001"});
+    assert_eq!(Address::parse_deserializer(value).unwrap().city, "Lima");
+
+    let value = json!([1, "x"]);
+    let err = vld::array(vld::number())
+        .parse_deserializer(value)
+        .unwrap_err();
+    assert_eq!(path_of(&err.issues[0]), "[1]");
+
+    let mut de = serde_json::Deserializer::from_str(r#"{"city": "", "zip": "1"}"#);
+    let err = Address::parse_deserializer(&mut de).unwrap_err();
+    assert_eq!(err.issues.len(), 2);
+}
+
+#[test]
+fn context_reaches_deserializer_path() {
+    #[derive(Clone)]
+    struct MaxLen(usize);
+
+    let schema = vld::array(vld::string().refine_ctx(|s, m: &MaxLen| s.len() <= m.0, "Too long"));
+    let ok = vld::context::with_context(&MaxLen(3), || schema.parse_json_str(r#"["abc"]"#));
+    assert!(ok.is_ok());
+    let err = vld::context::with_context(&MaxLen(2), || schema.parse_json_str(r#"["abc"]"#));
+    assert_eq!(err.unwrap_err().issues[0].message, "Too long");
+}
+
+#[test]
+fn value_input_is_parsed_without_conversion() {
+    let value = json!({"city": "Kyiv", "zip": "01001"});
+    assert_eq!(vld::nested!(Address).parse(&value).unwrap().zip, "01001");
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    #[derive(Debug, vld::Validate)]
+    struct Tag {
+        #[vld(vld::string().min(2))]
+        name: String,
+    }
+
+    #[test]
+    fn derive_types_use_buffered_fallback() {
+        let tag: Tag = vld::de::parse_json_str(r#"{"name": "rust"}"#).unwrap();
+        assert_eq!(tag.name, "rust");
+        assert!(vld::de::parse_json_str::<Tag>(r#"{"name": "r"}"#).is_err());
+    }
+}
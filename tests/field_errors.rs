@@ -0,0 +1,149 @@
+//! Typed field enums from `#[field_errors(...)]` on `schema!` structs and
+//! `#[vld(field_errors)]` on derived ones.
+
+#[allow(unused_imports)]
+use vld::prelude::*;
+
+vld::schema! {
+    #[derive(Debug)]
+    #[field_errors(
+        SignupField,
+        SignupFieldError,
+        email => Email,
+        display_name => DisplayName,
+        age => Age,
+    )]
+    pub struct Signup {
+        pub email: String => vld::string().email(),
+        pub display_name: String as "displayName" => vld::string().min(2),
+        pub age: Option<i64> => vld::number().int().min(13).optional(),
+    }
+    refine {
+        _ => |s| s.display_name != s.email, "Name must differ from email";
+    }
+}
+
+#[test]
+fn schema_for_field_filters_by_typed_key() {
+    let err = Signup::parse(r#"{"email": "nope", "displayName": "A"}"#).unwrap_err();
+
+    let email = err.for_field(SignupField::Email);
+    assert_eq!(email.len(), 1);
+    assert_eq!(email[0].code.key(), "invalid_string");
+    assert_eq!(err.for_field(SignupField::DisplayName).len(), 1);
+    assert!(err.for_field(SignupField::Age).is_empty());
+}
+
+#[test]
+fn schema_field_enum_uses_json_keys() {
+    assert_eq!(SignupField::DisplayName.key(), "displayName");
+    assert_eq!(
+        SignupField::from_key("displayName"),
+        Some(SignupField::DisplayName)
+    );
+    assert_eq!(SignupField::from_key("display_name"), None);
+    assert_eq!(
+        SignupField::ALL,
+        &[
+            SignupField::Email,
+            SignupField::DisplayName,
+            SignupField::Age
+        ]
+    );
+}
+
+#[test]
+fn schema_field_errors_skip_struct_level_issues() {
+    let err =
+        Signup::parse(r#"{"email": "a@b.co", "displayName": "a@b.co", "age": 1}"#).unwrap_err();
+    let fields: Vec<SignupField> = SignupFieldError::from_error(&err)
+        .iter()
+        .map(SignupFieldError::field)
+        .collect();
+    assert_eq!(fields, vec![SignupField::Age]);
+
+    let err = Signup::parse(r#"{"email": "a@b.co", "displayName": "a@b.co"}"#).unwrap_err();
+    assert_eq!(err.issues.len(), 1);
+    assert!(SignupFieldError::from_error(&err).is_empty());
+}
+
+#[test]
+fn schema_field_error_matches_on_variant() {
+    let err = Signup::parse(r#"{"email": "al@", "displayName": "Al"}"#).unwrap_err();
+    let suggestion = SignupFieldError::from_error(&err)
+        .into_iter()
+        .find_map(|e| match e {
+            SignupFieldError::Email(issue) => {
+                Some(format!("{}: did you mean .com?", issue.message))
+            }
+            _ => None,
+        });
+    assert_eq!(
+        suggestion.as_deref(),
+        Some("Invalid email address: did you mean .com?")
+    );
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use super::*;
+    use vld::Validate;
+
+    #[derive(Debug, serde::Deserialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    #[vld(field_errors)]
+    #[vld(refine = |a: &Account| a.user_name != "root", message = "Reserved", path = "userName")]
+    struct Account {
+        #[vld(length(min = 3))]
+        user_name: String,
+        #[vld(email)]
+        #[serde(alias = "mail")]
+        email_address: String,
+        #[serde(skip)]
+        login_count: u32,
+    }
+
+    #[test]
+    fn derive_generates_pascal_case_variants() {
+        assert_eq!(
+            AccountField::ALL,
+            &[AccountField::UserName, AccountField::EmailAddress]
+        );
+        assert_eq!(AccountField::UserName.key(), "userName");
+        assert_eq!(AccountField::EmailAddress.aliases(), &["mail"]);
+        assert_eq!(
+            AccountField::from_key("mail"),
+            Some(AccountField::EmailAddress)
+        );
+    }
+
+    #[test]
+    fn derive_for_field_follows_aliases() {
+        let err = Account::vld_parse(r#"{"userName": "al", "mail": "x"}"#).unwrap_err();
+        assert_eq!(err.for_field(AccountField::UserName).len(), 1);
+        let email = err.for_field(AccountField::EmailAddress);
+        assert_eq!(email.len(), 1);
+        assert_eq!(email[0].path[0].to_string(), ".mail");
+
+        let errors = AccountFieldError::from_error(&err);
+        assert!(matches!(errors[0], AccountFieldError::UserName(_)));
+        assert_eq!(errors[1].field(), AccountField::EmailAddress);
+        assert_eq!(errors[1].issue().code.key(), "invalid_string");
+    }
+
+    #[test]
+    fn derive_struct_rules_report_under_their_field() {
+        let err =
+            Account::vld_parse(r#"{"userName": "root", "emailAddress": "r@b.co"}"#).unwrap_err();
+        let rule = err.for_field(AccountField::UserName);
+        assert_eq!(rule[0].message, "Reserved");
+
+        let account =
+            Account::vld_parse(r#"{"userName": "alex", "emailAddress": "a@b.co"}"#).unwrap();
+        assert_eq!(
+            (account.user_name.as_str(), account.email_address.as_str()),
+            ("alex", "a@b.co")
+        );
+        assert_eq!(account.login_count, 0);
+    }
+}
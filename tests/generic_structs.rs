@@ -0,0 +1,200 @@
+//! Generic structs in `schema!` and `#[derive(Validate)]`: `Paginated<T>`
+//! works for every `T` that is itself a vld type.
+
+#[allow(unused_imports)]
+use vld::prelude::*;
+
+fn path_of(issue: &ValidationIssue) -> String {
+    issue.path.iter().map(|p| p.to_string()).collect()
+}
+
+vld::schema! {
+    #[derive(Debug)]
+    pub struct Tag {
+        pub name: String => vld::string().min(1),
+    }
+}
+
+vld::schema! {
+    #[derive(Debug)]
+    pub struct Paginated<T> {
+        pub items: Vec<T> => vld::array(vld::nested_param::<T>()),
+        pub total: i64 => vld::number().int().non_negative(),
+    }
+    refine {
+        "total" => |p| p.total >= p.items.len() as i64, "Total is less than the page size";
+    }
+}
+
+vld::schema! {
+    #[derive(Debug)]
+    pub struct Pair<A, B> {
+        pub left: A => vld::nested_param::<A>(),
+        pub right: B => vld::nested_param::<B>(),
+    }
+}
+
+#[test]
+fn schema_generic_struct_parses() {
+    let page = Paginated::<Tag>::parse(r#"{"items": [{"name": "rust"}], "total": 1}"#).unwrap();
+    assert_eq!(page.items[0].name, "rust");
+    assert_eq!(page.total, 1);
+
+    let err = Paginated::<Tag>::parse(r#"{"items": [{"name": ""}], "total": -1}"#).unwrap_err();
+    let paths: Vec<String> = err.issues.iter().map(path_of).collect();
+    assert_eq!(paths, vec![".items[0].name", ".total"]);
+
+    let err = Paginated::<Tag>::parse(r#"{"items": [{"name": "a"}], "total": 0}"#).unwrap_err();
+    assert_eq!(err.to_string(), ".total: Total is less than the page size");
+}
+
+#[test]
+fn schema_generic_struct_one_pass_matches_value_parse() {
+    let json = r#"{"items": [{"name": "a"}, {"name": ""}], "total": 2}"#;
+    let direct = Paginated::<Tag>::parse_json_str(json).unwrap_err();
+    let buffered = Paginated::<Tag>::parse(json).unwrap_err();
+    assert_eq!(direct.issues.len(), buffered.issues.len());
+    assert_eq!(path_of(&direct.issues[0]), ".items[1].name");
+}
+
+#[test]
+fn schema_generic_struct_is_vld_parse() {
+    fn parse<T: VldParse>(json: &str) -> Result<T, VldError> {
+        T::vld_parse_value(&serde_json::from_str(json).unwrap())
+    }
+
+    let page: Paginated<Tag> = parse(r#"{"items": [], "total": 0}"#).unwrap();
+    assert!(page.items.is_empty());
+
+    let pair: Pair<Tag, Paginated<Tag>> =
+        parse(r#"{"left": {"name": "x"}, "right": {"items": [], "total": 0}}"#).unwrap();
+    assert_eq!(pair.left.name, "x");
+    assert_eq!(pair.right.total, 0);
+}
+
+#[cfg(feature = "openapi")]
+mod openapi {
+    use super::*;
+    use vld::json_schema::VldJsonSchema;
+
+    #[test]
+    fn schema_names_follow_type_arguments() {
+        assert_eq!(Tag::vld_schema_name(), "Tag");
+        assert_eq!(Paginated::<Tag>::vld_schema_name(), "Paginated_Tag");
+        assert_eq!(
+            Pair::<Tag, Paginated<Tag>>::vld_schema_name(),
+            "Pair_Tag_Paginated_Tag"
+        );
+    }
+
+    #[test]
+    fn nested_refs_use_concrete_names() {
+        let schema = Pair::<Tag, Paginated<Tag>>::json_schema();
+        assert_eq!(
+            schema["properties"]["left"]["$ref"],
+            "#/components/schemas/Tag"
+        );
+        assert_eq!(
+            schema["properties"]["right"]["$ref"],
+            "#/components/schemas/Paginated_Tag"
+        );
+
+        let nested: Vec<&str> = Pair::<Tag, Paginated<Tag>>::__vld_nested_schemas()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(nested, vec!["Tag", "Paginated_Tag"]);
+
+        let doc = Paginated::<Tag>::to_openapi_document();
+        assert!(doc["components"]["schemas"]["Paginated_Tag"].is_object());
+    }
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use super::*;
+    use vld::Validate;
+
+    #[derive(Debug, Default, serde::Serialize, Validate)]
+    struct User {
+        #[vld(length(min = 2))]
+        name: String,
+    }
+
+    #[derive(Debug, Validate)]
+    struct Envelope<T> {
+        #[vld(nested)]
+        data: T,
+        #[vld(vld::string().min(1))]
+        request_id: String,
+    }
+
+    #[derive(Debug, Validate)]
+    struct Page<T> {
+        #[vld(nested, length(max = 2))]
+        items: Vec<T>,
+        #[vld(range(min = 0))]
+        total: u64,
+    }
+
+    #[derive(Debug, Validate)]
+    struct Wrapper<T>(#[vld(nested)] T);
+
+    #[test]
+    fn derive_generic_struct_parses() {
+        let env =
+            Envelope::<User>::vld_parse(r#"{"data": {"name": "Al"}, "request_id": "r1"}"#).unwrap();
+        assert_eq!(env.data.name, "Al");
+        assert_eq!(env.request_id, "r1");
+
+        let err = Envelope::<User>::vld_parse(r#"{"data": {"name": "A"}, "request_id": ""}"#)
+            .unwrap_err();
+        let paths: Vec<String> = err.issues.iter().map(path_of).collect();
+        assert_eq!(paths, vec![".data.name", ".request_id"]);
+    }
+
+    #[test]
+    fn derive_generic_struct_nests_generic_structs() {
+        let json = r#"{"data": {"items": [{"name": "Al"}], "total": 1}, "request_id": "r"}"#;
+        let env = Envelope::<Page<User>>::vld_parse(json).unwrap();
+        assert_eq!(env.data.items[0].name, "Al");
+        assert_eq!(env.data.total, 1);
+
+        let json = r#"{"data": {"items": [{}, {}, {}], "total": 3}, "request_id": "r"}"#;
+        let err = Envelope::<Page<User>>::vld_parse(json).unwrap_err();
+        assert_eq!(path_of(&err.issues[0]), ".data.items");
+    }
+
+    #[test]
+    fn derive_generic_newtype_and_lenient() {
+        let w = Wrapper::<User>::vld_parse(r#"{"name": "Bob"}"#).unwrap();
+        assert_eq!(w.0.name, "Bob");
+
+        let result =
+            Page::<User>::parse_lenient(r#"{"items": [{"name": "Al"}], "total": -1}"#).unwrap();
+        assert_eq!(result.value.items[0].name, "Al");
+        assert_eq!(result.value.total, 0);
+        assert!(!result.is_valid());
+    }
+
+    #[cfg(feature = "openapi")]
+    #[test]
+    fn derive_generic_schema_names() {
+        use vld::json_schema::VldJsonSchema;
+
+        assert_eq!(
+            Envelope::<Page<User>>::vld_schema_name(),
+            "Envelope_Page_User"
+        );
+        assert_eq!(Wrapper::<User>::vld_schema_name(), "Wrapper_User");
+        let schema = Envelope::<Page<User>>::json_schema();
+        assert_eq!(
+            schema["properties"]["data"]["$ref"],
+            "#/components/schemas/Page_User"
+        );
+        assert_eq!(
+            Page::<User>::json_schema()["properties"]["items"]["items"]["$ref"],
+            "#/components/schemas/User"
+        );
+    }
+}
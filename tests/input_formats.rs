@@ -0,0 +1,195 @@
+#![cfg(any(
+    feature = "yaml",
+    feature = "toml",
+    feature = "msgpack",
+    feature = "cbor",
+    feature = "urlencoded"
+))]
+
+use vld::prelude::*;
+
+vld::schema! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Service {
+        pub name: String => vld::string().min(2),
+        pub port: i64 => vld::number().int().min(1).max(65535),
+        pub tags: Vec<String> => vld::array(vld::string()).with_default(vec![]),
+    }
+}
+
+fn assert_parse_error(err: &VldError, prefix: &str) -> String {
+    assert_eq!(err.issues.len(), 1);
+    assert!(matches!(err.issues[0].code, IssueCode::ParseError));
+    let msg = err.issues[0].message.clone();
+    assert!(msg.starts_with(prefix), "{}", msg);
+    msg
+}
+
+#[cfg(feature = "yaml")]
+mod yaml {
+    use super::*;
+    use vld::input::Yaml;
+
+    #[test]
+    fn valid_and_invalid_documents() {
+        let svc = Service::parse(&Yaml("name: api\nport: 8080\ntags: [a, b]\n")).unwrap();
+        assert_eq!(svc.port, 8080);
+        assert_eq!(svc.tags, vec!["a", "b"]);
+
+        let err = Service::parse(&Yaml("name: x\nport: 0\n")).unwrap_err();
+        assert_eq!(err.issues.len(), 2);
+    }
+
+    #[test]
+    fn syntax_error_has_position() {
+        let err = Service::parse(&Yaml("name: api\nport: [1, 2\n")).unwrap_err();
+        let msg = assert_parse_error(&err, "Invalid YAML: ");
+        assert!(msg.contains("line 3"), "{}", msg);
+    }
+}
+
+#[cfg(feature = "toml")]
+mod toml_input {
+    use super::*;
+    use vld::input::Toml;
+
+    #[test]
+    fn valid_and_invalid_documents() {
+        let svc = Service::parse(&Toml("name = \"api\"\nport = 443\n")).unwrap();
+        assert_eq!(svc.name, "api");
+        assert!(svc.tags.is_empty());
+
+        let err = Service::parse(&Toml("name = \"api\"\nport = 70000\n")).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+    }
+
+    #[test]
+    fn datetimes_become_strings() {
+        let value = Toml("at = 1979-05-27T07:32:00Z\nday = 1979-05-27\n")
+            .to_json_value()
+            .unwrap();
+        assert_eq!(value["at"], "1979-05-27T07:32:00Z");
+        assert_eq!(value["day"], "1979-05-27");
+    }
+
+    #[test]
+    fn syntax_error_has_line_and_column() {
+        let err = Service::parse(&Toml("name = \"api\"\nport = = 1\n")).unwrap_err();
+        let msg = assert_parse_error(&err, "Invalid TOML: ");
+        assert!(msg.ends_with("at line 2 column 8"), "{}", msg);
+        assert!(!msg.contains('\n'), "{}", msg);
+    }
+}
+
+#[cfg(feature = "msgpack")]
+mod msgpack {
+    use super::*;
+    use serde_json::json;
+    use vld::input::MsgPack;
+
+    #[test]
+    fn valid_and_invalid_payloads() {
+        let bytes = rmp_serde::to_vec_named(&json!({"name": "db", "port": 5432})).unwrap();
+        assert_eq!(Service::parse(&MsgPack(&bytes)).unwrap().port, 5432);
+
+        let bytes = rmp_serde::to_vec_named(&json!({"name": "db", "port": -1})).unwrap();
+        assert!(Service::parse(&MsgPack(&bytes)).is_err());
+    }
+
+    #[test]
+    fn truncated_payload_is_parse_error() {
+        let bytes = rmp_serde::to_vec_named(&json!({"name": "db", "port": 5432})).unwrap();
+        let err = Service::parse(&MsgPack(&bytes[..bytes.len() - 3])).unwrap_err();
+        assert_parse_error(&err, "Invalid MessagePack: ");
+    }
+}
+
+#[cfg(feature = "cbor")]
+mod cbor {
+    use super::*;
+    use serde_json::json;
+    use vld::input::Cbor;
+
+    fn encode(value: &serde_json::Value) -> Vec<u8> {
+        let mut out = Vec::new();
+        ciborium::ser::into_writer(value, &mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn valid_and_invalid_payloads() {
+        let bytes = encode(&json!({"name": "cache", "port": 6379, "tags": ["kv"]}));
+        let svc = Service::parse(&Cbor(&bytes)).unwrap();
+        assert_eq!(svc.tags, vec!["kv"]);
+
+        let bytes = encode(&json!({"name": "c", "port": 6379}));
+        assert!(Service::parse(&Cbor(&bytes)).is_err());
+    }
+
+    #[test]
+    fn malformed_payload_is_parse_error() {
+        let err = Service::parse(&Cbor(&[0x1c])).unwrap_err();
+        let msg = assert_parse_error(&err, "Invalid CBOR: ");
+        assert!(msg.contains("at byte 0"), "{}", msg);
+    }
+}
+
+#[cfg(feature = "urlencoded")]
+mod urlencoded {
+    use super::*;
+    use serde_json::json;
+    use vld::input::UrlEncoded;
+
+    #[test]
+    fn values_are_coerced() {
+        let value = UrlEncoded("?name=my%20app&port=80&debug=true&ratio=0.5&empty=")
+            .to_json_value()
+            .unwrap();
+        assert_eq!(
+            value,
+            json!({"name": "my app", "port": 80, "debug": true, "ratio": 0.5, "empty": null})
+        );
+    }
+
+    #[test]
+    fn repeated_keys_become_arrays() {
+        let svc = Service::parse(&UrlEncoded("name=web&port=80&tags=a&tags=b&tags=c")).unwrap();
+        assert_eq!(svc.tags, vec!["a", "b", "c"]);
+
+        let err = Service::parse(&UrlEncoded("name=web&port=0")).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+    }
+}
+
+#[cfg(all(feature = "yaml", feature = "toml"))]
+mod file_extension {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn write_temp(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("vld_input_{}_{}", std::process::id(), name));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn decoder_is_picked_from_extension() {
+        let yaml = write_temp("svc.yaml", "name: api\nport: 1\n");
+        let yml = write_temp("svc.YML", "name: api\nport: 2\n");
+        let toml = write_temp("svc.toml", "name = \"api\"\nport = 3\n");
+        let json = write_temp("svc.json", r#"{"name": "api", "port": 4}"#);
+
+        assert_eq!(Service::parse(yaml.as_path()).unwrap().port, 1);
+        assert_eq!(Service::parse(&yml).unwrap().port, 2);
+        assert_eq!(Service::parse(&toml).unwrap().port, 3);
+        assert_eq!(Service::parse(&json).unwrap().port, 4);
+
+        let bad = write_temp("bad.toml", "port = [");
+        let err = Service::parse(&bad).unwrap_err();
+        assert_parse_error(&err, "Invalid TOML: ");
+
+        for path in [yaml, yml, toml, json, bad] {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
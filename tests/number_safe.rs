@@ -20,3 +20,12 @@ fn int_safe() {
     assert!(schema.parse("42").is_ok());
     assert!(schema.parse("9007199254740992").is_err());
 }
+
+#[test]
+fn int_rejects_values_outside_i64() {
+    let schema = vld::number().int();
+    assert_eq!(schema.parse("9223372036854775807").unwrap(), i64::MAX);
+    assert_eq!(schema.parse("-9223372036854775808").unwrap(), i64::MIN);
+    assert!(schema.parse("18446744073709551615").is_err());
+    assert!(schema.parse("-1e19").is_err());
+}
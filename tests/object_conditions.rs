@@ -0,0 +1,176 @@
+//! Predicate-based `if_then()` / `if_then_else()` rules on `ZObject`.
+
+use vld::prelude::*;
+
+fn path_of(issue: &ValidationIssue) -> String {
+    issue.path.iter().map(|p| p.to_string()).collect()
+}
+
+fn paths(err: &VldError) -> Vec<String> {
+    err.issues.iter().map(path_of).collect()
+}
+
+fn shipping() -> ZObject {
+    vld::object()
+        .field("country", vld::string())
+        .field_optional("zip", vld::string())
+        .field_optional("postcode", vld::string())
+        .if_then_else(
+            Condition::field("country").eq("US"),
+            vld::object().field("zip", vld::string().len(5)),
+            vld::object().field("postcode", vld::string().min(3)),
+        )
+}
+
+#[test]
+fn then_and_else_branches() {
+    let schema = shipping();
+    assert!(schema.parse(r#"{"country": "US", "zip": "12345"}"#).is_ok());
+    assert!(schema
+        .parse(r#"{"country": "FR", "postcode": "75001"}"#)
+        .is_ok());
+
+    let err = schema.parse(r#"{"country": "US"}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".zip"]);
+    let err = schema
+        .parse(r#"{"country": "FR", "zip": "12345"}"#)
+        .unwrap_err();
+    assert_eq!(paths(&err), vec![".postcode"]);
+}
+
+#[test]
+fn field_predicates() {
+    let schema = |condition: Condition| {
+        vld::object()
+            .field_optional("note", vld::string())
+            .if_then(condition, vld::object().field("note", vld::string()))
+    };
+
+    let is_in = schema(Condition::field("role").is_in(["admin", "owner"]));
+    assert!(is_in.parse(r#"{"role": "user"}"#).is_ok());
+    assert!(is_in.parse(r#"{"role": "owner"}"#).is_err());
+
+    let exists = schema(Condition::field("flag").exists());
+    assert!(exists.parse("{}").is_ok());
+    assert!(exists.parse(r#"{"flag": null}"#).is_err());
+
+    let not = schema(!Condition::field("flag").exists());
+    assert!(not.parse("{}").is_err());
+    assert!(not.parse(r#"{"flag": 1}"#).is_ok());
+
+    let gt = schema(Condition::field("total").gt(100.0));
+    assert!(gt.parse(r#"{"total": 100}"#).is_ok());
+    assert!(gt.parse(r#"{"total": "1000"}"#).is_ok());
+    assert!(gt.parse(r#"{"total": 100.5}"#).is_err());
+
+    let lte = schema(Condition::field("age").lte(12.0));
+    assert!(lte.parse(r#"{"age": 12}"#).is_err());
+    assert!(lte.parse(r#"{"age": 13}"#).is_ok());
+}
+
+#[test]
+fn sub_schema_condition_and_several_fields() {
+    let schema = vld::object()
+        .field("kind", vld::string())
+        .field_optional("width", vld::number())
+        .field_optional("height", vld::number())
+        .if_then(
+            Condition::matches(vld::object().field("kind", vld::literal("rect"))),
+            vld::object()
+                .field("width", vld::number().positive())
+                .field("height", vld::number().positive()),
+        );
+
+    assert!(schema.parse(r#"{"kind": "circle"}"#).is_ok());
+    let err = schema
+        .parse(r#"{"kind": "rect", "width": -1}"#)
+        .unwrap_err();
+    assert_eq!(paths(&err), vec![".width", ".height"]);
+}
+
+#[cfg(feature = "openapi")]
+#[test]
+fn rules_appear_as_if_then_else() {
+    use serde_json::json;
+
+    let schema = shipping()
+        .when("country", "DE", "postcode", vld::string())
+        .if_then(
+            !Condition::field("express").exists(),
+            vld::object().field_schema("weight", vld::number().max(30.0)),
+        )
+        .if_then(
+            Condition::field("weight").gte(10.0),
+            vld::object().field_schema("carrier", vld::string()),
+        )
+        .to_json_schema();
+    let rules = schema["allOf"].as_array().unwrap();
+    assert_eq!(rules.len(), 4);
+
+    // when() rules come first.
+    assert_eq!(
+        rules[0]["if"],
+        json!({"properties": {"country": {"const": "DE"}}, "required": ["country"]})
+    );
+    assert_eq!(rules[0]["then"]["required"], json!(["postcode"]));
+
+    assert_eq!(
+        rules[1]["if"],
+        json!({"properties": {"country": {"const": "US"}}, "required": ["country"]})
+    );
+    assert_eq!(rules[1]["then"]["required"], json!(["zip"]));
+    assert_eq!(rules[1]["else"]["required"], json!(["postcode"]));
+
+    assert_eq!(rules[2]["if"], json!({"not": {"required": ["express"]}}));
+    assert_eq!(
+        rules[2]["then"]["properties"]["weight"],
+        json!({"type": "number", "maximum": 30.0})
+    );
+    assert_eq!(
+        rules[3]["if"]["properties"]["weight"],
+        json!({"type": "number", "minimum": 10.0})
+    );
+
+    let plain = vld::object().field("a", vld::string()).to_json_schema();
+    assert!(plain.get("allOf").is_none());
+}
+
+#[cfg(feature = "openapi")]
+#[test]
+fn matches_schema_keeps_the_sub_schema() {
+    let schema = vld::object()
+        .if_then(
+            Condition::matches_schema(
+                vld::object().field_schema("kind", vld::enumeration(&["rect"])),
+            ),
+            vld::object().field_schema("width", vld::number()),
+        )
+        .to_json_schema();
+    assert_eq!(
+        schema["allOf"][0]["if"]["properties"]["kind"]["enum"],
+        serde_json::json!(["rect"])
+    );
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn async_parse_applies_rules() {
+    let schema = vld::object_async()
+        .field("country", vld::string())
+        .field_optional("zip", vld::string())
+        .field_optional("postcode", vld::string())
+        .if_then_else(
+            Condition::field("country").eq("US"),
+            vld::object_async().field("zip", vld::string().len(5)),
+            vld::object_async().field("postcode", vld::string().min(3)),
+        );
+    let err = schema
+        .parse_async(r#"{"country": "US"}"#)
+        .await
+        .unwrap_err();
+    assert_eq!(paths(&err), vec![".zip"]);
+    assert!(schema
+        .parse_async(r#"{"country": "US", "zip": "12345"}"#)
+        .await
+        .is_ok());
+}
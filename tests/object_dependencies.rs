@@ -0,0 +1,191 @@
+//! `required_if()` / `required_with()` / `excluded_with()` dependency rules.
+
+use vld::prelude::*;
+
+fn path_of(issue: &ValidationIssue) -> String {
+    issue.path.iter().map(|p| p.to_string()).collect()
+}
+
+fn paths(err: &VldError) -> Vec<String> {
+    err.issues.iter().map(path_of).collect()
+}
+
+fn checkout() -> ZObject {
+    vld::object()
+        .field("payment_method", vld::string())
+        .field_optional("billing_address", vld::string())
+        .field_optional("city", vld::string())
+        .field_optional("zip", vld::string())
+        .field_optional("coupon", vld::string())
+        .field_optional("gift_card", vld::string())
+        .required_if(
+            "billing_address",
+            Condition::field("payment_method").eq("card"),
+        )
+        .required_with("zip", ["city"])
+        .excluded_with("coupon", ["gift_card"])
+}
+
+#[test]
+fn required_if_follows_the_condition() {
+    let schema = checkout();
+    assert!(schema.parse(r#"{"payment_method": "cash"}"#).is_ok());
+    assert!(schema
+        .parse(r#"{"payment_method": "card", "billing_address": "Main St 1"}"#)
+        .is_ok());
+
+    let err = schema.parse(r#"{"payment_method": "card"}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".billing_address"]);
+    assert_eq!(err.issues[0].code.key(), "required_if");
+    assert_eq!(
+        err.issues[0].code.params(),
+        vec![("fields", "payment_method".to_string())]
+    );
+
+    // `null` counts as missing.
+    let err = schema
+        .parse(r#"{"payment_method": "card", "billing_address": null}"#)
+        .unwrap_err();
+    assert_eq!(paths(&err), vec![".billing_address"]);
+}
+
+#[test]
+fn required_with_needs_the_other_field() {
+    let schema = checkout();
+    assert!(schema
+        .parse(r#"{"payment_method": "cash", "city": "Berlin", "zip": "10115"}"#)
+        .is_ok());
+    assert!(schema
+        .parse(r#"{"payment_method": "cash", "zip": "10115"}"#)
+        .is_ok());
+
+    let err = schema
+        .parse(r#"{"payment_method": "cash", "city": "Berlin"}"#)
+        .unwrap_err();
+    assert_eq!(paths(&err), vec![".zip"]);
+    assert_eq!(err.issues[0].code.key(), "required_with");
+    assert_eq!(err.issues[0].message, "Required when city is present");
+}
+
+#[test]
+fn excluded_with_rejects_both_fields() {
+    let schema = checkout();
+    assert!(schema
+        .parse(r#"{"payment_method": "cash", "coupon": "SALE"}"#)
+        .is_ok());
+    assert!(schema
+        .parse(r#"{"payment_method": "cash", "coupon": "SALE", "gift_card": null}"#)
+        .is_ok());
+
+    let err = schema
+        .parse(r#"{"payment_method": "cash", "coupon": "SALE", "gift_card": "G-1"}"#)
+        .unwrap_err();
+    assert_eq!(paths(&err), vec![".coupon"]);
+    assert_eq!(err.issues[0].code.key(), "excluded_with");
+    assert_eq!(err.issues[0].message, "Not allowed together with gift_card");
+}
+
+#[test]
+fn dependency_issues_join_field_errors() {
+    let err = checkout()
+        .parse(r#"{"payment_method": "card", "city": 1, "coupon": "A", "gift_card": "B"}"#)
+        .unwrap_err();
+    assert_eq!(
+        paths(&err),
+        vec![".city", ".billing_address", ".zip", ".coupon"]
+    );
+}
+
+vld::schema! {
+    #[derive(Debug)]
+    pub struct Checkout {
+        pub payment_method: String => vld::string(),
+        pub billing_address: Option<String> => vld::string().optional(),
+        pub city: Option<String> => vld::string().optional(),
+        pub zip: Option<String> => vld::string().optional(),
+        pub coupon: Option<String> => vld::string().optional(),
+        pub gift_card: Option<String> => vld::string().optional(),
+    }
+    dependencies {
+        required_if("billing_address", Condition::field("payment_method").eq("card"));
+        required_with("zip", ["city"]);
+        excluded_with("coupon", ["gift_card"]);
+    }
+}
+
+#[test]
+fn schema_macro_dependencies() {
+    let ok =
+        Checkout::parse(r#"{"payment_method": "card", "billing_address": "Main St 1"}"#).unwrap();
+    assert_eq!(ok.billing_address.as_deref(), Some("Main St 1"));
+
+    let err = Checkout::parse(r#"{"payment_method": "card", "city": "Berlin"}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".billing_address", ".zip"]);
+
+    let err = Checkout::parse_value(&serde_json::json!({
+        "payment_method": "cash",
+        "coupon": "SALE",
+        "gift_card": "G-1",
+    }))
+    .unwrap_err();
+    assert_eq!(paths(&err), vec![".coupon"]);
+}
+
+#[test]
+fn schema_macro_dependencies_from_json_str() {
+    let err = Checkout::parse_json_str(r#"{"payment_method": "card"}"#).unwrap_err();
+    assert_eq!(paths(&err), vec![".billing_address"]);
+
+    let ok = Checkout::parse_json_str(r#"{"payment_method": "cash", "coupon": "SALE"}"#).unwrap();
+    assert_eq!(ok.coupon.as_deref(), Some("SALE"));
+}
+
+#[cfg(feature = "openapi")]
+#[test]
+fn dependencies_appear_in_json_schema() {
+    use serde_json::json;
+
+    let schema = checkout().to_json_schema();
+    assert_eq!(schema["dependentRequired"], json!({"city": ["zip"]}));
+    assert_eq!(
+        schema["dependentSchemas"],
+        json!({"gift_card": {"not": {"required": ["coupon"]}}})
+    );
+    assert_eq!(
+        schema["allOf"],
+        json!([{
+            "if": {
+                "properties": {"payment_method": {"const": "card"}},
+                "required": ["payment_method"]
+            },
+            "then": {"required": ["billing_address"]}
+        }])
+    );
+
+    let derived = Checkout::json_schema();
+    for key in ["dependentRequired", "dependentSchemas", "allOf"] {
+        assert_eq!(derived[key], schema[key], "{key}");
+    }
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn async_parse_applies_dependencies() {
+    let err = vld::object_async()
+        .field("payment_method", vld::string())
+        .field_optional("billing_address", vld::string())
+        .required_if(
+            "billing_address",
+            Condition::field("payment_method").eq("card"),
+        )
+        .parse_async(r#"{"payment_method": "card"}"#)
+        .await
+        .unwrap_err();
+    assert_eq!(paths(&err), vec![".billing_address"]);
+
+    let err =
+        Checkout::parse_async(r#"{"payment_method": "cash", "coupon": "A", "gift_card": "B"}"#)
+            .await
+            .unwrap_err();
+    assert_eq!(paths(&err), vec![".coupon"]);
+}
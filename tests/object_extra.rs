@@ -37,7 +37,7 @@ fn catchall_overrides_unknown_mode() {
 }
 
 #[test]
-fn deep_partial_same_as_partial() {
+fn deep_partial_accepts_null_fields() {
     let schema = vld::object()
         .field("name", vld::string().min(1))
         .deep_partial();
@@ -330,3 +330,229 @@ fn parse_missing_file() {
     let err = TestUser::parse(path).unwrap_err();
     assert!(!err.issues.is_empty());
 }
+
+// ---- recursive lenient parsing ----
+
+vld::schema_validated! {
+    #[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+    pub struct Address {
+        pub city: String => vld::string().min(1),
+        pub zip: String => vld::string().len(5),
+    }
+}
+
+vld::schema_validated! {
+    #[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+    pub struct Item {
+        pub sku: String => vld::string().min(3),
+        pub qty: i64 => vld::number().int().positive(),
+    }
+}
+
+vld::schema_validated! {
+    #[derive(Debug, Default, serde::Serialize)]
+    pub struct Order {
+        pub name: String => vld::string().min(2),
+        pub shipping: Address => vld::nested!(Address),
+        pub billing: Option<Address> => vld::nested!(Address).optional(),
+        pub items: Vec<Item> => vld::array(vld::nested!(Item)).min_len(1),
+        pub tags: Vec<String> => vld::array(vld::string().min(2)),
+    }
+}
+
+fn order_input() -> serde_json::Value {
+    json!({
+        "name": "Ann",
+        "shipping": {"city": "Berlin", "zip": "123"},
+        "billing": {"city": "", "zip": "10115"},
+        "items": [
+            {"sku": "abc", "qty": 2},
+            {"sku": "x", "qty": 1},
+            {"sku": "def", "qty": 0}
+        ],
+        "tags": ["ok", "x"]
+    })
+}
+
+#[test]
+fn lenient_keeps_valid_nested_values() {
+    let result = Order::parse_lenient(&order_input()).unwrap();
+    let order = &result.value;
+
+    assert_eq!(order.shipping.city, "Berlin");
+    assert_eq!(order.shipping.zip, "");
+    assert_eq!(order.billing.as_ref().unwrap().zip, "10115");
+    assert_eq!(order.items.len(), 3);
+    assert_eq!(
+        order.items[0],
+        Item {
+            sku: "abc".into(),
+            qty: 2
+        }
+    );
+    assert_eq!(order.items[1].qty, 1);
+    assert_eq!(order.items[1].sku, "");
+    assert_eq!(order.items[2].sku, "def");
+    // Elements that cannot be recovered drop the whole array to `Default`.
+    assert!(order.tags.is_empty());
+
+    assert_eq!(result.error_count(), 4);
+    assert!(result.field("name").unwrap().children().is_empty());
+}
+
+#[test]
+fn lenient_children_report_broken_leaves() {
+    let result = Order::parse_lenient(&order_input()).unwrap();
+
+    let shipping = result.field("shipping").unwrap();
+    assert!(shipping.is_err());
+    assert!(shipping.child("city").unwrap().is_ok());
+    assert_eq!(
+        shipping.child("city").unwrap().result.as_ref().unwrap(),
+        &json!("Berlin")
+    );
+    assert!(shipping.child("zip").unwrap().is_err());
+
+    let items = result.field("items").unwrap();
+    assert_eq!(items.children().len(), 3);
+    assert!(items.child("0").unwrap().is_ok());
+    assert_eq!(
+        items.child("0").unwrap().result.as_ref().unwrap(),
+        &json!({"sku": "abc", "qty": 2})
+    );
+    assert!(items.descendant(&["1", "sku"]).unwrap().is_err());
+    assert!(items.descendant(&["1", "qty"]).unwrap().is_ok());
+    assert!(items.descendant(&["2", "qty"]).unwrap().is_err());
+
+    let leaves: Vec<&str> = items
+        .error_leaves()
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect();
+    assert_eq!(leaves, vec!["sku", "qty"]);
+
+    let tags = result.field("tags").unwrap();
+    assert!(tags.child("0").unwrap().is_ok());
+    assert!(tags.child("1").unwrap().is_err());
+}
+
+#[test]
+fn lenient_nested_errors_match_strict_parse() {
+    let result = Order::parse_lenient(&order_input()).unwrap();
+    let strict = Order::parse(&order_input()).unwrap_err();
+
+    let lenient_paths: Vec<String> = result
+        .error_fields()
+        .iter()
+        .flat_map(|f| {
+            let e = f.result.as_ref().unwrap_err();
+            e.issues
+                .iter()
+                .map(|i| {
+                    let tail: String = i.path.iter().map(|p| p.to_string()).collect();
+                    format!(".{}{}", f.name, tail)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    let strict_paths: Vec<String> = strict
+        .issues
+        .iter()
+        .map(|i| i.path.iter().map(|p| p.to_string()).collect())
+        .collect();
+    assert_eq!(lenient_paths, strict_paths);
+}
+
+#[test]
+fn lenient_optional_and_non_object_nested() {
+    let input = json!({
+        "name": "Bob",
+        "shipping": "nowhere",
+        "billing": null,
+        "items": [{"sku": "abc", "qty": 1}],
+        "tags": []
+    });
+    let result = Order::parse_lenient(&input).unwrap();
+    assert_eq!(result.value.shipping, Address::default());
+    assert!(result.field("shipping").unwrap().children().is_empty());
+    assert_eq!(result.value.billing, None);
+    assert!(result.field("billing").unwrap().is_ok());
+    assert_eq!(result.error_count(), 1);
+}
+
+#[test]
+fn validate_fields_also_recurses() {
+    let fields = Order::validate_fields(&order_input()).unwrap();
+    let items = fields.iter().find(|f| f.name == "items").unwrap();
+    assert_eq!(items.children().len(), 3);
+    assert_eq!(
+        items.child("0").unwrap().result.as_ref().unwrap(),
+        &json!({"sku": "abc", "qty": 2})
+    );
+    assert!(items.descendant(&["1", "sku"]).unwrap().is_err());
+}
+
+#[test]
+fn plain_nested_fn_is_not_recursive() {
+    let input = json!({
+        "name": "Alex",
+        "email": "alex@example.com",
+        "inner": { "city": "" }
+    });
+    let result = TestUser::parse_lenient(&input).unwrap();
+    assert!(result.field("inner").unwrap().children().is_empty());
+    assert_eq!(result.value.inner, Inner::default());
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use super::Address;
+    use serde_json::json;
+
+    #[derive(Debug, Default, serde::Serialize, vld::Validate)]
+    struct Profile {
+        #[vld(vld::string().min(2))]
+        name: String,
+        #[vld(vld::array(vld::nested!(Address)))]
+        addresses: Vec<Address>,
+    }
+
+    #[derive(Debug, Default, serde::Serialize, vld::Validate)]
+    struct Account {
+        #[vld(vld::nested!(Profile))]
+        profile: Profile,
+    }
+
+    #[test]
+    fn derive_lenient_recurses() {
+        let input = json!({
+            "profile": {
+                "name": "Al",
+                "addresses": [{"city": "Rome", "zip": "00100"}, {"city": "", "zip": "00200"}]
+            }
+        });
+        let result = Account::parse_lenient(&input).unwrap();
+        let profile = &result.value.profile;
+        assert_eq!(profile.name, "Al");
+        assert_eq!(profile.addresses[0].city, "Rome");
+        assert_eq!(profile.addresses[1].zip, "00200");
+
+        let field = result.field("profile").unwrap();
+        assert!(field
+            .descendant(&["addresses", "1", "city"])
+            .unwrap()
+            .is_err());
+        assert!(field.descendant(&["addresses", "0"]).unwrap().is_ok());
+    }
+}
+
+#[test]
+fn field_result_builders() {
+    let leaf = vld::error::FieldResult::new("0", json!("a"), Ok(json!("a")));
+    assert!(leaf.children().is_empty());
+
+    let parent = vld::error::FieldResult::new("tags", json!(["a"]), Ok(json!(["a"])))
+        .with_children(vec![leaf]);
+    assert_eq!(parent.children().len(), 1);
+    assert_eq!(parent.child("0").unwrap().input, json!("a"));
+}
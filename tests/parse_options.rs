@@ -0,0 +1,421 @@
+use serde_json::json;
+use vld::options::ParseOptions;
+use vld::prelude::*;
+
+fn path_of(issue: &vld::error::ValidationIssue) -> String {
+    issue.path.iter().map(|p| p.to_string()).collect()
+}
+
+fn code_of(issue: &vld::error::ValidationIssue) -> &str {
+    match &issue.code {
+        IssueCode::Custom { code } => code,
+        other => other.key(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// abort_early / max_issues
+// ---------------------------------------------------------------------------
+
+#[test]
+fn default_options_collect_everything() {
+    let schema = vld::array(vld::number().int());
+    let input = json!(["a", "b", "c"]);
+    let err = schema
+        .parse_value_with_options(&input, &ParseOptions::new())
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 3);
+}
+
+#[test]
+fn abort_early_stops_at_first_issue() {
+    let schema = vld::array(vld::number().int());
+    let opts = ParseOptions::new().abort_early(true);
+    let err = schema
+        .parse_value_with_options(&json!([1, "a", "b"]), &opts)
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 1);
+    assert_eq!(path_of(&err.issues[0]), "[1]");
+}
+
+#[test]
+fn abort_early_wins_over_max_issues() {
+    let opts = ParseOptions::new().max_issues(10).abort_early(true);
+    assert_eq!(opts.issue_limit(), Some(1));
+}
+
+#[test]
+fn max_issues_zero_still_fails() {
+    let opts = ParseOptions::new().max_issues(0);
+    assert_eq!(opts.issue_limit(), Some(1));
+
+    let array = vld::array(vld::number().int());
+    let err = array
+        .parse_value_with_options(&json!(["a", "b"]), &opts)
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 1);
+    assert_eq!(path_of(&err.issues[0]), "[0]");
+
+    let object = vld::object()
+        .field("a", vld::string())
+        .field("b", vld::string());
+    let err = object
+        .parse_value_with_options(&json!({}), &opts)
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 1);
+    assert_eq!(path_of(&err.issues[0]), ".a");
+
+    let record = vld::record(vld::number());
+    assert!(record
+        .parse_value_with_options(&json!({"a": "x"}), &opts)
+        .is_err());
+}
+
+#[test]
+fn max_issues_in_object() {
+    let schema = vld::object()
+        .field("a", vld::string())
+        .field("b", vld::string())
+        .field("c", vld::string())
+        .field("d", vld::string());
+    let opts = ParseOptions::new().max_issues(2);
+    let err = schema
+        .parse_value_with_options(&json!({}), &opts)
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 2);
+    assert_eq!(path_of(&err.issues[0]), ".a");
+    assert_eq!(path_of(&err.issues[1]), ".b");
+}
+
+#[test]
+fn max_issues_in_record_set_and_map() {
+    let opts = ParseOptions::new().max_issues(2);
+
+    let record = vld::record(vld::number());
+    let err = record
+        .parse_value_with_options(&json!({"a": "x", "b": "x", "c": "x"}), &opts)
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 2);
+
+    let set = vld::set(vld::number().int());
+    let err = set
+        .parse_value_with_options(&json!(["x", "y", "z"]), &opts)
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 2);
+
+    let map = vld::map(vld::string(), vld::number());
+    let err = map
+        .parse_value_with_options(&json!([["a", "x"], ["b", "x"], ["c", "x"]]), &opts)
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 2);
+}
+
+#[test]
+fn max_issues_applies_across_nesting() {
+    let schema = vld::array(vld::array(vld::number()));
+    let opts = ParseOptions::new().max_issues(3);
+    let err = schema
+        .parse_value_with_options(&json!([["a", "b"], ["c", "d"], ["e"]]), &opts)
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 3);
+}
+
+#[test]
+fn options_do_not_leak_into_later_parses() {
+    let schema = vld::array(vld::number());
+    let input = json!(["a", "b", "c"]);
+    let opts = ParseOptions::new().abort_early(true);
+    assert_eq!(
+        schema
+            .parse_value_with_options(&input, &opts)
+            .unwrap_err()
+            .issues
+            .len(),
+        1
+    );
+    assert_eq!(schema.parse_value(&input).unwrap_err().issues.len(), 3);
+}
+
+// ---------------------------------------------------------------------------
+// Structural limits
+// ---------------------------------------------------------------------------
+
+#[test]
+fn max_depth_rejects_deep_input() {
+    let schema = vld::any();
+    let opts = ParseOptions::new().max_depth(2);
+    assert!(schema
+        .parse_value_with_options(&json!({"a": {"b": 1}}), &opts)
+        .is_ok());
+
+    let err = schema
+        .parse_value_with_options(&json!({"a": {"b": {"c": [1]}}}), &opts)
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 1);
+    assert_eq!(code_of(&err.issues[0]), "max_depth_exceeded");
+    assert_eq!(path_of(&err.issues[0]), ".a.b.c");
+}
+
+#[test]
+fn max_array_len_rejects_long_arrays() {
+    let schema = vld::object().field("items", vld::array(vld::number()));
+    let opts = ParseOptions::new().max_array_len(3);
+    assert!(schema
+        .parse_value_with_options(&json!({"items": [1, 2, 3]}), &opts)
+        .is_ok());
+
+    let err = schema
+        .parse_value_with_options(&json!({"items": [1, 2, 3, 4]}), &opts)
+        .unwrap_err();
+    assert_eq!(code_of(&err.issues[0]), "max_array_len_exceeded");
+    assert_eq!(path_of(&err.issues[0]), ".items");
+}
+
+#[test]
+fn max_string_len_checks_values_and_keys() {
+    let schema = vld::any();
+    let opts = ParseOptions::new().max_string_len(4);
+    assert!(schema
+        .parse_value_with_options(&json!({"key": "äöüß"}), &opts)
+        .is_ok());
+
+    let err = schema
+        .parse_value_with_options(&json!({"list": ["ok", "too long"]}), &opts)
+        .unwrap_err();
+    assert_eq!(code_of(&err.issues[0]), "max_string_len_exceeded");
+    assert_eq!(path_of(&err.issues[0]), ".list[1]");
+
+    let err = schema
+        .parse_value_with_options(&json!({"long_key": 1}), &opts)
+        .unwrap_err();
+    assert_eq!(path_of(&err.issues[0]), ".long_key");
+}
+
+#[test]
+fn structural_limits_run_before_schema() {
+    let schema = vld::array(vld::string().min(100));
+    let opts = ParseOptions::new().max_array_len(1);
+    let err = schema
+        .parse_value_with_options(&json!(["a", "b"]), &opts)
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 1);
+    assert_eq!(code_of(&err.issues[0]), "max_array_len_exceeded");
+}
+
+#[test]
+fn parse_with_options_from_str() {
+    let schema = vld::array(vld::number());
+    let opts = ParseOptions::new().max_issues(1);
+    let err = schema
+        .parse_with_options(r#"["a", "b"]"#, &opts)
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 1);
+}
+
+#[test]
+fn safe_defaults_are_bounded() {
+    let opts = ParseOptions::safe_defaults();
+    assert!(!opts.abort_early);
+    assert!(opts.max_issues.is_some());
+    assert!(opts.max_depth.is_some());
+    assert!(opts.max_array_len.is_some());
+    assert!(opts.max_string_len.is_some());
+
+    let mut deep = json!(1);
+    for _ in 0..100 {
+        deep = json!([deep]);
+    }
+    assert!(opts.check_input(&deep).is_err());
+}
+
+// ---------------------------------------------------------------------------
+// schema! / VldParse
+// ---------------------------------------------------------------------------
+
+vld::schema! {
+    #[derive(Debug)]
+    pub struct Signup {
+        pub name: String => vld::string().min(2),
+        pub email: String => vld::string().email(),
+        pub tags: Vec<String> => vld::array(vld::string().min(2)),
+    }
+}
+
+#[test]
+fn schema_macro_parse_with_options() {
+    let input = r#"{"name": "A", "email": "bad", "tags": ["x", "y"]}"#;
+    assert_eq!(Signup::parse(input).unwrap_err().issues.len(), 4);
+
+    let opts = ParseOptions::new().abort_early(true);
+    let err = Signup::parse_with_options(input, &opts).unwrap_err();
+    assert_eq!(err.issues.len(), 1);
+    assert_eq!(path_of(&err.issues[0]), ".name");
+}
+
+#[test]
+fn vld_parse_value_with_options() {
+    let value = json!({"name": "Al", "email": "a@b.com", "tags": ["x", "y", "z"]});
+    let opts = ParseOptions::new().max_issues(2);
+    let err = <Signup as VldParse>::vld_parse_value_with_options(&value, &opts).unwrap_err();
+    assert_eq!(err.issues.len(), 2);
+
+    let opts = ParseOptions::new().max_array_len(2);
+    let err = <Signup as VldParse>::vld_parse_value_with_options(&value, &opts).unwrap_err();
+    assert_eq!(path_of(&err.issues[0]), ".tags");
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use super::*;
+
+    #[derive(Debug, vld::Validate)]
+    struct Comment {
+        #[vld(vld::string().min(3))]
+        author: String,
+        #[vld(vld::string().min(3))]
+        body: String,
+    }
+
+    #[test]
+    fn derive_parse_with_options() {
+        let input = r#"{"author": "a", "body": "b"}"#;
+        assert_eq!(Comment::vld_parse(input).unwrap_err().issues.len(), 2);
+        let opts = ParseOptions::new().abort_early(true);
+        let err = Comment::vld_parse_with_options(input, &opts).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+
+        let ok = r#"{"author": "ann", "body": "hello"}"#;
+        let comment = Comment::vld_parse_with_options(ok, &opts).unwrap();
+        assert_eq!(
+            (comment.author.as_str(), comment.body.as_str()),
+            ("ann", "hello")
+        );
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_options {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// A number schema whose async check always fails and counts its calls.
+    fn counted(visited: &Arc<AtomicUsize>) -> impl VldSchemaAsync<Output = f64> {
+        let visited = Arc::clone(visited);
+        vld::number().refine_async(
+            move |_: &f64| {
+                visited.fetch_add(1, Ordering::SeqCst);
+                async { false }
+            },
+            "rejected",
+        )
+    }
+
+    fn numbers(n: usize) -> serde_json::Value {
+        serde_json::Value::Array((0..n).map(|i| json!(i)).collect())
+    }
+
+    #[tokio::test]
+    async fn abort_early_stops_the_async_array_loop() {
+        let visited = Arc::new(AtomicUsize::new(0));
+        let schema = vld::array(counted(&visited));
+        let input = numbers(100_000);
+
+        let opts = ParseOptions::new().abort_early(true);
+        let err = schema
+            .parse_value_async_with_options(&input, &opts)
+            .await
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(visited.load(Ordering::SeqCst), 1);
+
+        visited.store(0, Ordering::SeqCst);
+        let opts = ParseOptions::new().max_issues(5);
+        let err = schema
+            .parse_async_with_options("[1, 2, 3, 4, 5, 6, 7, 8, 9]", &opts)
+            .await
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 5);
+        assert_eq!(visited.load(Ordering::SeqCst), 5);
+    }
+
+    #[tokio::test]
+    async fn max_issues_zero_still_fails_async() {
+        let visited = Arc::new(AtomicUsize::new(0));
+        let schema = vld::array(counted(&visited));
+        let opts = ParseOptions::new().max_issues(0);
+        let err = schema
+            .parse_async_with_options("[1, 2, 3]", &opts)
+            .await
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(visited.load(Ordering::SeqCst), 1);
+
+        let object = vld::object_async().field("a", counted(&visited));
+        assert!(object
+            .parse_async_with_options(r#"{"a": 1}"#, &opts)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn max_issues_stops_async_records_and_objects() {
+        let visited = Arc::new(AtomicUsize::new(0));
+        let record = vld::record(counted(&visited));
+        let input: serde_json::Map<String, serde_json::Value> =
+            (0..50).map(|i| (format!("k{i}"), json!(i))).collect();
+        let opts = ParseOptions::new().max_issues(3);
+        let err = record
+            .parse_value_async_with_options(&serde_json::Value::Object(input), &opts)
+            .await
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 3);
+        assert_eq!(visited.load(Ordering::SeqCst), 3);
+
+        visited.store(0, Ordering::SeqCst);
+        let object = vld::object_async()
+            .field("a", counted(&visited))
+            .field("b", counted(&visited))
+            .field("c", counted(&visited));
+        let opts = ParseOptions::new().abort_early(true);
+        let err = object
+            .parse_value_async_with_options(&json!({"a": 1, "b": 2, "c": 3}), &opts)
+            .await
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(visited.load(Ordering::SeqCst), 1);
+    }
+
+    static BATCH_VISITED: AtomicUsize = AtomicUsize::new(0);
+
+    vld::schema! {
+        #[derive(Debug)]
+        pub struct Batch {
+            pub ids: Vec<f64> => vld::array(vld::number().refine_async(
+                |_: &f64| {
+                    BATCH_VISITED.fetch_add(1, Ordering::SeqCst);
+                    async { false }
+                },
+                "rejected",
+            )),
+        }
+    }
+
+    #[tokio::test]
+    async fn vld_parse_value_async_with_options_stops_early() {
+        let value = json!({ "ids": numbers(10_000) });
+        let opts = ParseOptions::new().max_issues(2);
+        let err = <Batch as VldParse>::vld_parse_value_async_with_options(&value, &opts)
+            .await
+            .unwrap_err();
+        assert_eq!(err.issues.len(), 2);
+        assert_eq!(BATCH_VISITED.load(Ordering::SeqCst), 2);
+
+        let opts = ParseOptions::new().max_array_len(5);
+        let err = <Batch as VldParse>::vld_parse_value_async_with_options(&value, &opts)
+            .await
+            .unwrap_err();
+        assert_eq!(code_of(&err.issues[0]), "max_array_len_exceeded");
+        assert_eq!(BATCH_VISITED.load(Ordering::SeqCst), 2);
+    }
+}
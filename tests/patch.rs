@@ -0,0 +1,237 @@
+//! `vld::patch()`: tri-state fields that tell a missing key from `null`.
+
+use vld::prelude::*;
+
+vld::schema! {
+    #[derive(Debug)]
+    pub struct UserPatch {
+        pub name: Patch<String> => vld::patch(vld::string().min(2)),
+        pub age: Patch<i64> => vld::patch(vld::number().int().min(0)),
+        pub email: Option<String> => vld::string().email().optional(),
+    }
+}
+
+vld::schema! {
+    #[derive(Debug)]
+    pub struct WrappedPatch {
+        pub name: Patch<String> => vld::patch(vld::string()).describe("Display name"),
+        pub age: Patch<i64> => vld::patch(vld::number().int())
+            .refine(|p| p.value().map_or(true, |v| *v >= 0), "Age must not be negative")
+            .describe("Age in years"),
+    }
+}
+
+#[test]
+fn patch_schema_on_its_own() {
+    let schema = vld::patch(vld::string().min(2));
+    assert_eq!(schema.parse("null").unwrap(), Patch::Null);
+    assert_eq!(
+        schema.parse(r#""ab""#).unwrap(),
+        Patch::Value("ab".to_string())
+    );
+    assert!(schema.parse(r#""a""#).is_err());
+}
+
+#[test]
+fn schema_macro_tells_absent_from_null() {
+    let p = UserPatch::parse("{}").unwrap();
+    assert_eq!(
+        (p.name, p.age, p.email),
+        (Patch::Absent, Patch::Absent, None)
+    );
+
+    let p = UserPatch::parse(r#"{"name": null, "age": 30}"#).unwrap();
+    assert_eq!((p.name, p.age), (Patch::Null, Patch::Value(30)));
+
+    let err = UserPatch::parse(r#"{"name": "a", "age": -1}"#).unwrap_err();
+    let paths: Vec<String> = err
+        .issues
+        .iter()
+        .map(|i| i.path.iter().map(|p| p.to_string()).collect())
+        .collect();
+    assert_eq!(paths, vec![".name", ".age"]);
+}
+
+#[test]
+fn one_pass_parse_agrees() {
+    let p = UserPatch::parse_json_str(r#"{"age": null}"#).unwrap();
+    assert_eq!((p.name, p.age), (Patch::Absent, Patch::Null));
+    let p = UserPatch::parse_json_str(r#"{"name": "Al"}"#).unwrap();
+    assert_eq!(
+        (p.name, p.age),
+        (Patch::Value("Al".to_string()), Patch::Absent)
+    );
+}
+
+#[test]
+fn object_leaves_absent_keys_out() {
+    let schema = vld::object()
+        .field("name", vld::patch(vld::string()))
+        .field("bio", vld::patch(vld::string()))
+        .field("nickname", vld::string().optional());
+
+    let out = schema.parse(r#"{"bio": null}"#).unwrap();
+    assert!(!out.contains_key("name"));
+    assert_eq!(out["bio"], serde_json::Value::Null);
+    // Plain optionals still report missing keys as null.
+    assert_eq!(out["nickname"], serde_json::Value::Null);
+
+    let out = schema.parse(r#"{"name": "Al"}"#).unwrap();
+    assert_eq!(out["name"], "Al");
+    assert!(!out.contains_key("bio"));
+}
+
+#[test]
+fn patch_applies_to_a_column() {
+    let mut nickname = Some("al".to_string());
+    Patch::Value("bo".to_string()).apply_to(&mut nickname);
+    assert_eq!(nickname.as_deref(), Some("bo"));
+    Patch::Absent.apply_to(&mut nickname);
+    assert_eq!(nickname.as_deref(), Some("bo"));
+    Patch::Null.apply_to(&mut nickname);
+    assert_eq!(nickname, None);
+
+    assert_eq!(Patch::<i32>::Absent.into_option(), None);
+    assert_eq!(Patch::<i32>::Null.into_option(), Some(None));
+    assert_eq!(Patch::Value(1).map(|v| v + 1).value(), Some(&2));
+}
+
+#[test]
+fn patch_serde_round_trip() {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Row {
+        #[serde(default, skip_serializing_if = "Patch::is_absent")]
+        name: Patch<String>,
+        #[serde(default)]
+        age: Patch<i64>,
+    }
+
+    let row: Row = serde_json::from_str(r#"{"age": null}"#).unwrap();
+    assert!(row.name.is_absent());
+    assert!(row.age.is_null());
+    assert_eq!(serde_json::to_string(&row).unwrap(), r#"{"age":null}"#);
+}
+
+#[cfg(feature = "openapi")]
+#[test]
+fn json_schema_marks_patch_fields_optional_and_nullable() {
+    use vld::json_schema::JsonSchema;
+
+    let schema = UserPatch::json_schema();
+    assert_eq!(schema["required"], serde_json::json!(["email"]));
+    assert_eq!(schema["properties"]["name"]["oneOf"][1]["type"], "null");
+
+    let object = vld::object()
+        .field_schema("name", vld::patch(vld::string()))
+        .field_schema("email", vld::string())
+        .to_json_schema();
+    assert_eq!(object["required"], serde_json::json!(["email"]));
+
+    let field = vld::patch(vld::number()).json_schema();
+    assert_eq!(field["oneOf"][0]["type"], "number");
+}
+
+#[test]
+fn wrapped_patch_fields_stay_optional() {
+    let p = WrappedPatch::parse("{}").unwrap();
+    assert_eq!((p.name, p.age), (Patch::Absent, Patch::Absent));
+    let p = WrappedPatch::parse(r#"{"name": null, "age": 3}"#).unwrap();
+    assert_eq!((p.name, p.age), (Patch::Null, Patch::Value(3)));
+    assert!(WrappedPatch::parse(r#"{"age": -1}"#).is_err());
+
+    let schema = vld::object()
+        .field("name", vld::patch(vld::string()).message("Invalid name"))
+        .field(
+            "age",
+            vld::patch(vld::number().int()).refine(|p| !p.is_null(), "Age must be set"),
+        )
+        .field(
+            "slug",
+            vld::patch(vld::string()).transform(|p| p.map(|s| s.to_lowercase())),
+        );
+    let out = schema.parse(r#"{"slug": "AB"}"#).unwrap();
+    assert!(!out.contains_key("name"));
+    assert!(!out.contains_key("age"));
+    assert_eq!(out["slug"], "ab");
+}
+
+#[test]
+fn preprocess_loses_the_absent_state() {
+    // The preprocessor returns a new `null`, which `patch` cannot tell from
+    // an explicit one.
+    let schema = vld::preprocess(|v| v.clone(), vld::patch(vld::string()));
+    assert_eq!(
+        schema.parse_value(vld::object::__absent()).unwrap(),
+        Patch::Null
+    );
+    assert_eq!(
+        vld::patch(vld::string())
+            .parse_value(vld::object::__absent())
+            .unwrap(),
+        Patch::Absent
+    );
+}
+
+#[cfg(feature = "openapi")]
+#[test]
+fn json_schema_leaves_wrapped_patch_fields_out_of_required() {
+    let schema = WrappedPatch::json_schema();
+    assert_eq!(schema["required"], serde_json::json!([]));
+
+    let object = vld::object()
+        .field_schema("name", vld::patch(vld::string()).describe("Display name"))
+        .field_schema("email", vld::string())
+        .to_json_schema();
+    assert_eq!(object["required"], serde_json::json!(["email"]));
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn async_parse_tells_absent_from_null() {
+    let schema = vld::object_async().field("name", vld::patch(vld::string()));
+    let out = schema.parse_async("{}").await.unwrap();
+    assert!(!out.contains_key("name"));
+
+    let p = UserPatch::parse_async(r#"{"age": 1}"#).await.unwrap();
+    assert_eq!((p.name, p.age), (Patch::Absent, Patch::Value(1)));
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use super::*;
+    use vld::Validate;
+
+    #[derive(Debug, Validate)]
+    struct ProfilePatch {
+        #[vld(vld::patch(vld::string().min(2)))]
+        display_name: Patch<String>,
+        #[vld(vld::patch(vld::string().url()))]
+        website: Patch<String>,
+    }
+
+    #[test]
+    fn derive_tells_absent_from_null() {
+        let p = ProfilePatch::vld_parse(r#"{"website": null}"#).unwrap();
+        assert_eq!((p.display_name, p.website), (Patch::Absent, Patch::Null));
+
+        let err = ProfilePatch::vld_parse(r#"{"display_name": "A"}"#).unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+    }
+
+    #[test]
+    fn lenient_parse_keeps_the_state() {
+        let result =
+            ProfilePatch::parse_lenient(r#"{"website": null, "display_name": "x"}"#).unwrap();
+        assert_eq!(result.value.website, Patch::Null);
+        assert_eq!(result.value.display_name, Patch::Absent);
+        assert!(!result.is_valid());
+    }
+
+    #[cfg(feature = "openapi")]
+    #[test]
+    fn derive_json_schema_leaves_patch_fields_out_of_required() {
+        let schema = ProfilePatch::json_schema();
+        assert_eq!(schema["required"], serde_json::json!([]));
+        assert!(schema["properties"]["website"]["oneOf"].is_array());
+    }
+}
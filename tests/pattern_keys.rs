@@ -0,0 +1,151 @@
+//! `ZObject::pattern_field()` and `ZRecord::key_schema()`.
+
+#[allow(unused_imports)]
+use vld::prelude::*;
+
+fn path_of(issue: &ValidationIssue) -> String {
+    issue.path.iter().map(|p| p.to_string()).collect()
+}
+
+fn paths(err: &VldError) -> Vec<String> {
+    err.issues.iter().map(path_of).collect()
+}
+
+#[test]
+fn record_key_schema_checks_every_key() {
+    let schema = vld::record(vld::number().int()).key_schema(vld::string().min(2).max(5));
+    let out = schema.parse(r#"{"ab": 1, "abcd": 2}"#).unwrap();
+    assert_eq!(out.len(), 2);
+
+    let err = schema
+        .parse(r#"{"a": 1, "ab": "x", "abcdef": 3}"#)
+        .unwrap_err();
+    let mut p = paths(&err);
+    p.sort();
+    assert_eq!(p, vec![".a", ".ab", ".abcdef"]);
+    let key_issue = err.issues.iter().find(|i| path_of(i) == ".a").unwrap();
+    assert_eq!(key_issue.code.key(), "too_small");
+}
+
+#[test]
+fn record_key_schema_with_key_limits() {
+    let schema = vld::record(vld::string())
+        .key_schema(vld::string().starts_with("x-"))
+        .max_keys(1);
+    let err = schema.parse(r#"{"x-a": "1", "b": "2"}"#).unwrap_err();
+    let mut p = paths(&err);
+    p.sort();
+    assert_eq!(p, vec!["", ".b"]);
+}
+
+#[cfg(feature = "regex")]
+mod patterns {
+    use super::*;
+    use vld::regex_lite::Regex;
+
+    fn headers() -> ZObject {
+        vld::object()
+            .field("id", vld::string())
+            .pattern_field(Regex::new("^x-").unwrap(), vld::string().min(1))
+            .pattern_field(Regex::new("^[0-9]+$").unwrap(), vld::number().int())
+    }
+
+    #[test]
+    fn matching_keys_use_the_pattern_schema() {
+        let out = headers()
+            .strict()
+            .parse(r#"{"id": "a", "x-trace": "abc", "42": 7}"#)
+            .unwrap();
+        assert_eq!(out["x-trace"], "abc");
+        assert_eq!(out["42"], 7);
+
+        let err = headers()
+            .strict()
+            .parse(r#"{"id": "a", "x-trace": "", "42": 1.5, "other": true}"#)
+            .unwrap_err();
+        assert_eq!(paths(&err), vec![".42", ".x-trace", ".other"]);
+        assert_eq!(err.issues[2].code.key(), "unrecognized_field");
+    }
+
+    #[test]
+    fn other_keys_follow_unknown_mode_and_catchall() {
+        let out = headers()
+            .parse(r#"{"id": "a", "x-a": "1", "other": true}"#)
+            .unwrap();
+        assert!(out.get("other").is_none());
+        assert_eq!(out["x-a"], "1");
+
+        let err = headers()
+            .catchall(vld::boolean())
+            .parse(r#"{"id": "a", "x-a": "1", "other": 1}"#)
+            .unwrap_err();
+        assert_eq!(paths(&err), vec![".other"]);
+    }
+
+    #[test]
+    fn declared_fields_are_not_matched() {
+        let schema = vld::object()
+            .field("x-id", vld::number())
+            .pattern_field(Regex::new("^x-").unwrap(), vld::string())
+            .strict();
+        assert!(schema.parse(r#"{"x-id": 1}"#).is_ok());
+    }
+
+    #[test]
+    fn all_matching_patterns_apply() {
+        let schema = vld::object()
+            .pattern_field(Regex::new("^x-").unwrap(), vld::string())
+            .pattern_field(Regex::new("-id$").unwrap(), vld::string().uuid());
+        assert!(schema.parse(r#"{"x-name": "a"}"#).is_ok());
+        let err = schema.parse(r#"{"x-id": "nope"}"#).unwrap_err();
+        assert_eq!(paths(&err), vec![".x-id"]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_parse_applies_patterns() {
+        let err = vld::object_async()
+            .field("id", vld::string())
+            .pattern_field(Regex::new("^x-").unwrap(), vld::string().min(1))
+            .pattern_field(Regex::new("^[0-9]+$").unwrap(), vld::number().int())
+            .strict()
+            .parse_async(r#"{"id": "a", "x-trace": "", "other": 1}"#)
+            .await
+            .unwrap_err();
+        assert_eq!(paths(&err), vec![".x-trace", ".other"]);
+    }
+
+    #[cfg(feature = "openapi")]
+    #[test]
+    fn patterns_appear_in_json_schema() {
+        use serde_json::json;
+
+        let schema = headers().strict().to_json_schema();
+        assert_eq!(
+            schema["patternProperties"],
+            json!({
+                "^x-": {"type": "string", "minLength": 1},
+                "^[0-9]+$": {"type": "integer"}
+            })
+        );
+        assert_eq!(schema["additionalProperties"], json!(false));
+    }
+}
+
+#[cfg(feature = "openapi")]
+#[test]
+fn key_schema_appears_as_property_names() {
+    use serde_json::json;
+
+    let schema = vld::record(vld::number())
+        .key_schema(vld::string().max(8))
+        .json_schema();
+    assert_eq!(
+        schema["propertyNames"],
+        json!({"type": "string", "maxLength": 8})
+    );
+    assert!(vld::record(vld::number())
+        .json_schema()
+        .get("propertyNames")
+        .is_none());
+}
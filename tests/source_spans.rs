@@ -0,0 +1,189 @@
+use vld::prelude::*;
+use vld::source::{SourceMap, Span};
+
+vld::schema! {
+    #[derive(Debug)]
+    pub struct Service {
+        pub name: String => vld::string().min(2),
+        pub port: i64 => vld::number().int().min(1).max(65535),
+    }
+}
+
+vld::schema! {
+    #[derive(Debug)]
+    pub struct Config {
+        pub version: i64 => vld::number().int(),
+        pub services: Vec<Service> => vld::array(vld::nested!(Service)),
+    }
+}
+
+const CONFIG: &str = r#"{
+  "version": 1,
+  "services": [
+    {"name": "api", "port": 80},
+    {
+      "name": "w",
+      "port": 70000
+    }
+  ]
+}"#;
+
+fn text_of<'a>(text: &'a str, span: &Span) -> &'a str {
+    &text[span.start..span.end]
+}
+
+#[test]
+fn issues_carry_line_and_column() {
+    let err = Config::parse_with_spans(CONFIG).unwrap_err();
+    assert_eq!(err.issues.len(), 2);
+
+    let name = err.issues[0].span().unwrap();
+    assert_eq!((name.line, name.column), (6, 15));
+    assert_eq!(text_of(CONFIG, &name), r#""w""#);
+
+    let port = err.issues[1].span().unwrap();
+    assert_eq!((port.line, port.column), (7, 15));
+    assert_eq!(text_of(CONFIG, &port), "70000");
+    assert_eq!(port.to_string(), "7:15");
+}
+
+#[test]
+fn plain_parse_has_no_spans() {
+    let err = Config::parse(CONFIG).unwrap_err();
+    assert!(err.issues.iter().all(|i| i.span().is_none()));
+}
+
+#[test]
+fn missing_field_points_at_enclosing_object() {
+    let text = "{\"services\": [{\"name\": \"db\"}]}";
+    let err = Config::parse_with_spans(text).unwrap_err();
+    let spans: Vec<&str> = err
+        .issues
+        .iter()
+        .map(|i| text_of(text, &i.span().unwrap()))
+        .collect();
+    assert_eq!(spans, vec![text, r#"{"name": "db"}"#]);
+}
+
+#[test]
+fn syntax_error_span() {
+    let text = "{\n  \"version\": 1,\n  \"services\": [,]\n}";
+    let err = Config::parse_with_spans(text).unwrap_err();
+    assert!(matches!(err.issues[0].code, IssueCode::ParseError));
+    let span = err.issues[0].span().unwrap();
+    assert_eq!((span.line, span.column), (3, 16));
+    assert_eq!(&text[span.start..span.start + 1], ",");
+}
+
+#[test]
+fn schema_method_and_unicode_columns() {
+    let text = r#"{"ключ": "значение", "n": "x"}"#;
+    let schema = vld::object().field("n", vld::number());
+    let err = schema.parse_with_spans(text).unwrap_err();
+    let span = err.issues[0].span().unwrap();
+    assert_eq!(span.column, 27);
+    assert_eq!(text_of(text, &span), r#""x""#);
+}
+
+#[test]
+fn source_map_resolves_escaped_and_duplicate_keys() {
+    let text = r#"{"a\"b": [1, {"c": true}], "d": 1, "d": 2}"#;
+    let map = SourceMap::new(text).unwrap();
+    let path = [
+        PathSegment::Field("a\"b".into()),
+        PathSegment::Index(1),
+        PathSegment::Field("c".into()),
+    ];
+    assert_eq!(text_of(text, &map.span(&path).unwrap()), "true");
+    let last = map.span(&[PathSegment::Field("d".into())]).unwrap();
+    assert_eq!(text_of(text, &last), "2");
+    assert!(SourceMap::new("{\"a\": ").is_none());
+}
+
+#[test]
+fn with_source_attaches_spans_afterwards() {
+    let text = r#"{"name": "x", "port": 1}"#;
+    let err = Service::parse(text).unwrap_err().with_source(text);
+    assert_eq!(err.issues[0].span().unwrap().column, 10);
+}
+
+#[test]
+fn prettify_renders_code_frame() {
+    let err = Config::parse_with_spans(CONFIG).unwrap_err();
+    let out = prettify_error_with_source(&err, "config.json", CONFIG);
+    let expected = "\
+✖ String must be at least 2 characters
+  → at .services[1].name, received \"w\"
+  --> config.json:6:15
+  |
+5 |     {
+6 |       \"name\": \"w\",
+  |               ^^^
+✖ Number must be at most 65535
+  → at .services[1].port, received 70000
+  --> config.json:7:15
+  |
+6 |       \"name\": \"w\",
+7 |       \"port\": 70000
+  |               ^^^^^";
+    assert_eq!(out, expected);
+
+    let plain = prettify_error(&err);
+    assert!(plain.contains("  --> 7:15"), "{}", plain);
+}
+
+#[test]
+fn multiline_value_caret_stops_at_line_end() {
+    let text = "{\"services\": {\n}}";
+    let err = Config::parse_with_spans(text).unwrap_err();
+    let out = prettify_error_with_source(&err, "c.json", text);
+    assert!(
+        out.ends_with("1 | {\"services\": {\n  |              ^"),
+        "{}",
+        out
+    );
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    #[derive(Debug, vld::Validate)]
+    struct Login {
+        #[vld(vld::string().email())]
+        email: String,
+    }
+
+    #[test]
+    fn derive_parse_with_spans() {
+        let text = "{\n  \"email\": \"nope\"\n}";
+        let err = Login::vld_parse_with_spans(text).unwrap_err();
+        let span = err.issues[0].span().unwrap();
+        assert_eq!((span.line, span.column), (2, 12));
+        assert!(Login::vld_parse_with_spans(r#"{"email": "a@b.co"}"#)
+            .unwrap()
+            .email
+            .contains('@'));
+    }
+}
+
+#[test]
+fn issue_builders() {
+    let span = Span {
+        start: 4,
+        end: 9,
+        line: 1,
+        column: 5,
+    };
+    let issue = ValidationIssue::new(IssueCode::ParseError, "bad")
+        .with_path(vec![PathSegment::Field("port".into())])
+        .with_received(serde_json::json!(70000))
+        .with_span(span);
+    assert_eq!(issue.message, "bad");
+    assert_eq!(issue.path[0].to_string(), ".port");
+    assert_eq!(issue.received, Some(serde_json::json!(70000)));
+    assert_eq!(issue.span(), Some(span));
+
+    assert_eq!(
+        VldError::single(IssueCode::ParseError, "x").issues[0].span(),
+        None
+    );
+}
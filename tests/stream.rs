@@ -0,0 +1,225 @@
+#![cfg(feature = "std")]
+
+use std::io::{BufRead, BufReader, Read};
+
+use vld::prelude::*;
+use vld::stream::{validate_array_stream, validate_lines, StreamSummary};
+
+vld::schema! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Row {
+        pub id: i64 => vld::number().int().positive(),
+        pub name: String => vld::string().min(1),
+    }
+}
+
+fn path_of(issue: &ValidationIssue) -> String {
+    issue.path.iter().map(|p| p.to_string()).collect()
+}
+
+fn failed<T>(items: &[Result<T, (usize, VldError)>]) -> Vec<(usize, Vec<String>)> {
+    items
+        .iter()
+        .filter_map(|r| r.as_ref().err())
+        .map(|(i, e)| (*i, e.issues.iter().map(path_of).collect()))
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// NDJSON
+// ---------------------------------------------------------------------------
+
+const NDJSON: &str = r#"{"id": 1, "name": "a"}
+{"id": -2, "name": ""}
+
+{"id": 3, "name": "c"}
+{"id": 4, "name":
+{"id": 5, "name": "e"}
+"#;
+
+#[test]
+fn lines_yield_records_and_prefixed_errors() {
+    let schema = vld::nested!(Row);
+    let items: Vec<_> = validate_lines(NDJSON.as_bytes(), &schema).collect();
+    assert_eq!(items.len(), 5);
+
+    let ok: Vec<i64> = items
+        .iter()
+        .filter_map(|r| r.as_ref().ok())
+        .map(|r| r.id)
+        .collect();
+    assert_eq!(ok, vec![1, 3, 5]);
+
+    let errs = failed(&items);
+    assert_eq!(
+        errs,
+        vec![
+            (1, vec!["[1].id".to_string(), "[1].name".to_string()]),
+            (3, vec!["[3]".to_string()]),
+        ]
+    );
+    let parse_err = &items[3].as_ref().unwrap_err().1.issues[0];
+    assert!(matches!(parse_err.code, IssueCode::ParseError));
+}
+
+#[test]
+fn lines_summary() {
+    let schema = vld::nested!(Row);
+    let mut stream = validate_lines(NDJSON.as_bytes(), &schema);
+    assert_eq!(stream.summary(), StreamSummary::default());
+    stream.next();
+    stream.next();
+    assert_eq!(stream.summary().total(), 2);
+
+    let summary = stream.summarize();
+    assert_eq!(summary.valid, 3);
+    assert_eq!(summary.invalid, 2);
+    assert!(!summary.is_valid());
+}
+
+#[test]
+fn lines_handle_crlf_and_missing_final_newline() {
+    let schema = vld::array(vld::number().int());
+    let input = "[1, 2]\r\n[3]\r\n  \r\n[4, \"x\"]";
+    let items: Vec<_> = validate_lines(input.as_bytes(), &schema).collect();
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[1].as_ref().unwrap(), &vec![3]);
+    assert_eq!(failed(&items), vec![(2, vec!["[2][1]".to_string()])]);
+}
+
+#[test]
+fn lines_invalid_utf8_is_a_record_error() {
+    let schema = vld::string();
+    let mut input = b"\"ok\"\n\"".to_vec();
+    input.extend_from_slice(&[0xff, b'"', b'\n', b'"', b'z', b'"']);
+    let items: Vec<_> = validate_lines(input.as_slice(), &schema).collect();
+    assert_eq!(items.len(), 3);
+    assert!(items[1].is_err());
+    assert_eq!(items[2].as_ref().unwrap(), "z");
+}
+
+struct FailingReader;
+
+impl Read for FailingReader {
+    fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::new(std::io::ErrorKind::Other, "disk gone"))
+    }
+}
+
+#[test]
+fn io_error_ends_the_stream() {
+    let schema = vld::nested!(Row);
+    let reader: Box<dyn BufRead> = Box::new(BufReader::new(FailingReader));
+    let items: Vec<_> = validate_lines(reader, &schema).collect();
+    assert_eq!(items.len(), 1);
+    let (index, err) = items[0].as_ref().unwrap_err();
+    assert_eq!(*index, 0);
+    assert!(matches!(err.issues[0].code, IssueCode::IoError));
+
+    let items: Vec<_> = validate_array_stream(BufReader::new(FailingReader), &schema).collect();
+    assert_eq!(items.len(), 1);
+}
+
+// ---------------------------------------------------------------------------
+// Top-level array
+// ---------------------------------------------------------------------------
+
+#[test]
+fn array_stream_validates_each_element() {
+    let schema = vld::nested!(Row);
+    let input = r#" [
+        {"id": 1, "name": "a, [b]"},
+        {"id": 0, "name": "\"}"},
+        {"id": 3, "name": "c"}
+    ] "#;
+    let items: Vec<_> = validate_array_stream(input.as_bytes(), &schema).collect();
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0].as_ref().unwrap().name, "a, [b]");
+    assert_eq!(failed(&items), vec![(1, vec!["[1].id".to_string()])]);
+    assert_eq!(items[2].as_ref().unwrap().id, 3);
+}
+
+#[test]
+fn array_stream_small_buffer() {
+    // A 1-byte buffer forces every element to span many reads.
+    let schema = vld::array(vld::string().min(2));
+    let input = r#"[["ab", "cd"], ["x"], [], ["\\\"]"]]"#;
+    let reader = BufReader::with_capacity(1, input.as_bytes());
+    let items: Vec<_> = validate_array_stream(reader, &schema).collect();
+    assert_eq!(items.len(), 4);
+    assert_eq!(items[0].as_ref().unwrap(), &vec!["ab", "cd"]);
+    assert_eq!(failed(&items), vec![(1, vec!["[1][0]".to_string()])]);
+    assert_eq!(items[3].as_ref().unwrap(), &vec!["\\\"]"]);
+}
+
+#[test]
+fn array_stream_malformed_element_continues() {
+    let schema = vld::number().int();
+    let input = "[1, {\"a\": }, 3, tru]";
+    let items: Vec<_> = validate_array_stream(input.as_bytes(), &schema).collect();
+    assert_eq!(items.len(), 4);
+    assert!(matches!(
+        items[1].as_ref().unwrap_err().1.issues[0].code,
+        IssueCode::ParseError
+    ));
+    assert_eq!(items[2].as_ref().unwrap(), &3);
+    assert!(items[3].is_err());
+}
+
+#[test]
+fn array_stream_structure_errors() {
+    let schema = vld::number();
+
+    let empty: Vec<_> = validate_array_stream(" [ ] ".as_bytes(), &schema).collect();
+    assert!(empty.is_empty());
+
+    let not_array: Vec<_> = validate_array_stream(r#"{"a": 1}"#.as_bytes(), &schema).collect();
+    assert_eq!(not_array.len(), 1);
+    let (index, err) = not_array[0].as_ref().unwrap_err();
+    assert_eq!(*index, 0);
+    assert!(err.issues[0].message.contains("top-level array"));
+
+    let truncated: Vec<_> = validate_array_stream("[1, 2".as_bytes(), &schema).collect();
+    assert_eq!(truncated.len(), 2);
+    assert!(truncated[0].is_ok());
+    assert!(truncated[1].is_err());
+
+    let summary = validate_array_stream("[1, 2, \"x\"]".as_bytes(), &schema).summarize();
+    assert_eq!(
+        summary,
+        StreamSummary {
+            valid: 2,
+            invalid: 1
+        }
+    );
+}
+
+#[test]
+fn array_stream_rejects_trailing_data() {
+    let schema = vld::number();
+
+    let garbage: Vec<_> = validate_array_stream("[1,2]garbage".as_bytes(), &schema).collect();
+    assert_eq!(garbage.len(), 3);
+    assert!(garbage[0].is_ok() && garbage[1].is_ok());
+    let (index, err) = garbage[2].as_ref().unwrap_err();
+    assert_eq!(*index, 2);
+    assert!(matches!(err.issues[0].code, IssueCode::ParseError));
+    assert!(err.issues[0].message.contains("trailing characters"));
+
+    let two_arrays: Vec<_> = validate_array_stream("[1][2]".as_bytes(), &schema).collect();
+    assert_eq!(two_arrays.len(), 2);
+    assert!(two_arrays[1].is_err());
+
+    let empty_then_data: Vec<_> = validate_array_stream("[] 1".as_bytes(), &schema).collect();
+    assert_eq!(empty_then_data.len(), 1);
+    assert!(empty_then_data[0].is_err());
+
+    let summary = validate_array_stream("[1]\n \t\r\n".as_bytes(), &schema).summarize();
+    assert_eq!(
+        summary,
+        StreamSummary {
+            valid: 1,
+            invalid: 0
+        }
+    );
+}
@@ -0,0 +1,169 @@
+//! Struct-level cross-field rules: `refine { ... }` in `schema!` and
+//! `#[vld(refine = ...)]` on derived structs.
+
+#[allow(unused_imports)]
+use vld::prelude::*;
+
+fn path_of(issue: &ValidationIssue) -> String {
+    issue.path.iter().map(|p| p.to_string()).collect()
+}
+
+vld::schema! {
+    #[derive(Debug)]
+    struct Signup {
+        password: String => vld::string().min(8),
+        confirm: String => vld::string(),
+    }
+    refine {
+        "confirm" => |s| s.password == s.confirm, "Passwords do not match";
+    }
+}
+
+vld::schema! {
+    #[derive(Debug)]
+    struct Booking {
+        start: i64 => vld::number().int(),
+        end: i64 as "endDate" => vld::number().int(),
+        nights: i64 => vld::number().int().min(1),
+    }
+    refine {
+        "endDate" => |b| b.start <= b.end, "End date must not be before start date";
+        _ => |b| b.end - b.start == b.nights, "Nights do not match the date range";
+    }
+}
+
+#[test]
+fn schema_rule_passes() {
+    let s = Signup::parse(r#"{"password": "hunter22!", "confirm": "hunter22!"}"#).unwrap();
+    assert_eq!(s.confirm, "hunter22!");
+}
+
+#[test]
+fn schema_rule_attaches_to_field_path() {
+    let err = Signup::parse(r#"{"password": "hunter22!", "confirm": "hunter23!"}"#).unwrap_err();
+    assert_eq!(err.issues.len(), 1);
+    assert_eq!(path_of(&err.issues[0]), ".confirm");
+    assert_eq!(err.issues[0].message, "Passwords do not match");
+
+    let flat = vld::format::flatten_error(&err);
+    assert_eq!(flat.field_errors["confirm"], vec!["Passwords do not match"]);
+    assert!(flat.form_errors.is_empty());
+}
+
+#[test]
+fn schema_rules_skip_when_fields_fail() {
+    let err = Signup::parse(r#"{"password": "short", "confirm": "other"}"#).unwrap_err();
+    assert_eq!(err.issues.len(), 1);
+    assert_eq!(path_of(&err.issues[0]), ".password");
+}
+
+#[test]
+fn schema_rules_report_together_and_at_root() {
+    let err = Booking::parse(r#"{"start": 10, "endDate": 5, "nights": 2}"#).unwrap_err();
+    let paths: Vec<String> = err.issues.iter().map(path_of).collect();
+    assert_eq!(paths, vec![".endDate", ""]);
+
+    let flat = vld::format::flatten_error(&err);
+    assert_eq!(
+        flat.form_errors,
+        vec!["Nights do not match the date range".to_string()]
+    );
+    assert!(flat.field_errors.contains_key("endDate"));
+
+    assert!(Booking::parse(r#"{"start": 1, "endDate": 3, "nights": 2}"#).is_ok());
+}
+
+#[test]
+fn schema_rules_run_in_one_pass_deserialize() {
+    assert!(Signup::parse_json_str(r#"{"password": "hunter22!", "confirm": "hunter22!"}"#).is_ok());
+    let err =
+        Signup::parse_json_str(r#"{"password": "hunter22!", "confirm": "nope"}"#).unwrap_err();
+    assert_eq!(path_of(&err.issues[0]), ".confirm");
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use super::*;
+    use vld::Validate;
+
+    fn dates_in_order(r: &Reservation) -> bool {
+        r.check_in <= r.check_out
+    }
+
+    #[derive(Debug, serde::Deserialize, Validate)]
+    #[vld(refine = dates_in_order, message = "Check-out must be after check-in", path = "checkOut")]
+    #[vld(refine = |r: &Reservation| r.guests <= 4 || r.suite, message = "Large groups need a suite")]
+    struct Reservation {
+        #[vld(vld::number().int())]
+        check_in: i64,
+        #[vld(vld::number().int())]
+        #[serde(rename = "checkOut")]
+        check_out: i64,
+        #[vld(vld::number().int().min(1))]
+        guests: i64,
+        #[vld(vld::boolean())]
+        suite: bool,
+    }
+
+    #[test]
+    fn derive_rules_pass() {
+        let r =
+            Reservation::vld_parse(r#"{"check_in": 1, "checkOut": 3, "guests": 6, "suite": true}"#)
+                .unwrap();
+        assert_eq!(r.guests, 6);
+    }
+
+    #[test]
+    fn derive_rules_report_paths() {
+        let err = Reservation::vld_parse(
+            r#"{"check_in": 5, "checkOut": 3, "guests": 6, "suite": false}"#,
+        )
+        .unwrap_err();
+        let paths: Vec<String> = err.issues.iter().map(path_of).collect();
+        assert_eq!(paths, vec![".checkOut", ""]);
+
+        let flat = vld::format::flatten_error(&err);
+        assert_eq!(
+            flat.field_errors["checkOut"],
+            vec!["Check-out must be after check-in"]
+        );
+        assert_eq!(flat.form_errors, vec!["Large groups need a suite"]);
+    }
+
+    #[test]
+    fn derive_rules_skip_when_fields_fail() {
+        let err = Reservation::vld_parse(
+            r#"{"check_in": 5, "checkOut": 3, "guests": 0, "suite": false}"#,
+        )
+        .unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(path_of(&err.issues[0]), ".guests");
+    }
+
+    #[derive(Debug, Validate)]
+    #[vld(refine = |r: &Span| r.0 < r.1, message = "Span must be increasing")]
+    struct Span(
+        #[vld(vld::number().int())] i64,
+        #[vld(vld::number().int())] i64,
+    );
+
+    #[test]
+    fn derive_rules_on_tuple_structs() {
+        assert!(Span::vld_parse("[1, 2]").is_ok());
+        let err = Span::vld_parse("[2, 1]").unwrap_err();
+        assert_eq!(err.issues[0].message, "Span must be increasing");
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_rules {
+    use super::*;
+
+    #[tokio::test]
+    async fn schema_rules_run_after_async_parse() {
+        let err = Signup::parse_async(r#"{"password": "hunter22!", "confirm": "x"}"#)
+            .await
+            .unwrap_err();
+        assert_eq!(path_of(&err.issues[0]), ".confirm");
+    }
+}
@@ -245,3 +245,154 @@ fn datetime_json_schema() {
     assert_eq!(js["type"], "string");
     assert_eq!(js["format"], "date-time");
 }
+
+// ---------------------------------------------------------------------------
+// Clock
+// ---------------------------------------------------------------------------
+
+mod clock {
+    use std::time::Duration;
+    use vld::clock::{FixedClock, OffsetClock, SharedClock};
+    use vld::prelude::*;
+
+    const DAY: u64 = 86_400;
+
+    // 2024-06-15T12:00:00Z
+    fn noon() -> FixedClock {
+        FixedClock::from_unix_secs(1_718_452_800)
+    }
+
+    fn code_of(err: &VldError) -> String {
+        match &err.issues[0].code {
+            IssueCode::Custom { code } => code.clone(),
+            other => panic!("unexpected code {:?}", other),
+        }
+    }
+
+    #[test]
+    fn date_past_future_with_fixed_clock() {
+        let past = vld::date().past().clock(noon());
+        assert!(past.parse(r#""2024-06-14""#).is_ok());
+        assert!(past.parse(r#""2024-06-15""#).is_err());
+
+        let future = vld::date().future().clock(noon());
+        assert!(future.parse(r#""2024-06-16""#).is_ok());
+        assert_eq!(
+            code_of(&future.parse(r#""2024-06-15""#).unwrap_err()),
+            "not_future_date"
+        );
+    }
+
+    #[test]
+    fn datetime_past_future_with_fixed_clock() {
+        let future = vld::datetime().future().clock(noon());
+        assert!(future.parse(r#""2024-06-15T12:00:01Z""#).is_ok());
+        assert!(future.parse(r#""2024-06-15T11:59:59Z""#).is_err());
+
+        let past = vld::datetime().past().clock(noon());
+        assert!(past.parse(r#""2024-06-15T11:59:59Z""#).is_ok());
+    }
+
+    #[test]
+    fn per_parse_clock_via_context() {
+        let schema = vld::datetime().future();
+        let ctx = SharedClock::new(noon());
+        assert!(schema
+            .parse_with_context(r#""2024-06-15T13:00:00Z""#, &ctx)
+            .is_ok());
+        assert!(schema
+            .parse_with_context(r#""2024-06-15T11:00:00Z""#, &ctx)
+            .is_err());
+    }
+
+    #[test]
+    fn schema_clock_wins_over_context() {
+        let schema = vld::date().future().clock(noon());
+        let later = SharedClock::new(FixedClock::from_unix_secs(1_718_452_800 + 10 * DAY as i64));
+        assert!(schema.parse_with_context(r#""2024-06-16""#, &later).is_ok());
+    }
+
+    #[test]
+    fn offset_clock() {
+        let clock = OffsetClock::ahead_of(noon(), Duration::from_secs(2 * DAY));
+        let schema = vld::date().future().clock(clock);
+        assert!(schema.parse(r#""2024-06-16""#).is_err());
+        assert!(schema.parse(r#""2024-06-18""#).is_ok());
+
+        let behind = OffsetClock::behind_of(noon(), Duration::from_secs(3600));
+        let past = vld::datetime().past().clock(behind);
+        assert!(past.parse(r#""2024-06-15T11:30:00Z""#).is_err());
+    }
+
+    #[test]
+    fn datetime_within_next() {
+        let schema = vld::datetime()
+            .within_next(Duration::from_secs(3600))
+            .clock(noon());
+        assert!(schema.parse(r#""2024-06-15T12:30:00Z""#).is_ok());
+        let err = schema.parse(r#""2024-06-15T14:00:00Z""#).unwrap_err();
+        assert_eq!(code_of(&err), "not_within_next_datetime");
+        assert_eq!(
+            err.issues[0].message,
+            "Datetime must be within the next 1 hour"
+        );
+        assert!(schema.parse(r#""2024-06-15T11:00:00Z""#).is_err());
+    }
+
+    #[test]
+    fn datetime_not_older_than() {
+        let schema = vld::datetime()
+            .not_older_than(Duration::from_secs(30 * 60))
+            .clock(noon());
+        assert!(schema.parse(r#""2024-06-15T11:45:00Z""#).is_ok());
+        let err = schema.parse(r#""2024-06-15T11:00:00Z""#).unwrap_err();
+        assert_eq!(code_of(&err), "too_old_datetime");
+        assert_eq!(
+            err.issues[0].message,
+            "Datetime must not be older than 30 minutes"
+        );
+    }
+
+    #[test]
+    fn date_within_next_and_not_older_than() {
+        let next_week = vld::date()
+            .within_next(Duration::from_secs(7 * DAY))
+            .clock(noon());
+        assert!(next_week.parse(r#""2024-06-15""#).is_ok());
+        assert!(next_week.parse(r#""2024-06-22""#).is_ok());
+        assert_eq!(
+            code_of(&next_week.parse(r#""2024-06-23""#).unwrap_err()),
+            "not_within_next_date"
+        );
+        assert!(next_week.parse(r#""2024-06-14""#).is_err());
+
+        let recent = vld::date()
+            .not_older_than_msg(Duration::from_secs(2 * DAY), "Too old")
+            .clock(noon());
+        assert!(recent.parse(r#""2024-06-13""#).is_ok());
+        let err = recent.parse(r#""2024-06-12""#).unwrap_err();
+        assert_eq!(code_of(&err), "too_old_date");
+        assert_eq!(err.issues[0].message, "Too old");
+    }
+
+    #[test]
+    fn huge_windows_do_not_panic() {
+        let huge = Duration::from_secs(10_000_000_000_000);
+
+        // System clock.
+        let date = vld::date().within_next(huge).not_older_than(huge);
+        assert!(date.parse(r#""9999-12-31""#).is_ok());
+        assert!(date.parse(r#""1900-01-01""#).is_err());
+
+        let date = vld::date().within_next(huge).clock(noon());
+        assert!(date.parse(r#""2024-06-15""#).is_ok());
+        let date = vld::date().not_older_than(huge).clock(noon());
+        assert!(date.parse(r#""0001-01-01""#).is_ok());
+
+        let datetime = vld::datetime().within_next(huge).clock(noon());
+        assert!(datetime.parse(r#""9999-12-31T00:00:00Z""#).is_ok());
+        assert!(datetime.parse(r#""2024-06-15T11:00:00Z""#).is_err());
+        let datetime = vld::datetime().not_older_than(huge);
+        assert!(datetime.parse(r#""0001-01-01T00:00:00Z""#).is_ok());
+    }
+}
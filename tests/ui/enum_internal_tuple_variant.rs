@@ -0,0 +1,9 @@
+use vld::Validate;
+
+#[derive(serde::Deserialize, Validate)]
+#[serde(tag = "type")]
+enum Shape {
+    Point(#[vld(vld::number())] f64, #[vld(vld::number())] f64),
+}
+
+fn main() {}
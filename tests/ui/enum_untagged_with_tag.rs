@@ -0,0 +1,13 @@
+use vld::Validate;
+
+#[derive(serde::Deserialize, Validate)]
+#[serde(tag = "type")]
+#[serde(untagged)]
+enum Shape {
+    Circle {
+        #[vld(vld::number())]
+        radius: f64,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use vld::Validate;
+
+#[derive(Validate)]
+#[vld(field_errors)]
+enum Status {
+    Active,
+    Inactive,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use vld::Validate;
+
+#[derive(serde::Deserialize, Validate)]
+struct Audit {
+    #[vld(vld::string())]
+    created_by: String,
+}
+
+#[derive(serde::Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+struct User {
+    #[vld(vld::string())]
+    name: String,
+    #[serde(flatten)]
+    audit: Audit,
+}
+
+fn main() {}
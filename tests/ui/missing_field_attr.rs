@@ -0,0 +1,10 @@
+use vld::Validate;
+
+#[derive(Validate)]
+struct User {
+    #[vld(vld::string().min(2))]
+    name: String,
+    email: String,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use vld::Validate;
+
+#[derive(Validate)]
+#[vld(partial = "EmailPatch")]
+struct Email(#[vld(vld::string().email())] String);
+
+fn main() {}
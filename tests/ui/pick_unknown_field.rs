@@ -0,0 +1,10 @@
+use vld::Validate;
+
+#[derive(Validate)]
+#[vld(pick(name = "UserSummary", fields = [name, nickname]))]
+struct User {
+    #[vld(vld::string())]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+vld::schema! {
+    #[field_errors(SignupField, SignupFieldError, email => Email)]
+    pub struct Signup {
+        pub email: String => vld::string().email(),
+        pub display_name: String => vld::string(),
+    }
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+vld::schema! {
+    #[field_errors(SignupField, SignupFieldError, display_name => DisplayName, email => Email)]
+    pub struct Signup {
+        pub email: String => vld::string().email(),
+        pub display_name: String => vld::string(),
+    }
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use vld::Validate;
+
+#[derive(Validate)]
+struct Ticket {
+    #[vld(regex = r"^[A-Z{3}$")]
+    code: String,
+}
+
+fn main() {}
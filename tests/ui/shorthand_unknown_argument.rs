@@ -0,0 +1,9 @@
+use vld::Validate;
+
+#[derive(Validate)]
+struct User {
+    #[vld(length(minimum = 2))]
+    name: String,
+}
+
+fn main() {}
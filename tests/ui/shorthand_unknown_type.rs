@@ -0,0 +1,11 @@
+use vld::Validate;
+
+struct Address;
+
+#[derive(Validate)]
+struct User {
+    #[vld(required)]
+    address: Address,
+}
+
+fn main() {}
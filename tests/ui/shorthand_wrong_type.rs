@@ -0,0 +1,9 @@
+use vld::Validate;
+
+#[derive(Validate)]
+struct User {
+    #[vld(email)]
+    age: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use vld::Validate;
+
+#[derive(Validate)]
+#[vld(refine = |s: &Signup| !s.password.is_empty(), message = "Required", field = "password")]
+struct Signup {
+    #[vld(vld::string())]
+    password: String,
+}
+
+fn main() {}
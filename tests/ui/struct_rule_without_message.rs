@@ -0,0 +1,12 @@
+use vld::Validate;
+
+#[derive(Validate)]
+#[vld(refine = |s: &Signup| s.password == s.confirm)]
+struct Signup {
+    #[vld(vld::string())]
+    password: String,
+    #[vld(vld::string())]
+    confirm: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use vld::Validate;
+
+#[derive(Validate)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
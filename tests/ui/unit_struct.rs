@@ -0,0 +1,6 @@
+use vld::Validate;
+
+#[derive(Validate)]
+struct Marker;
+
+fn main() {}
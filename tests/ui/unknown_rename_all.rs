@@ -0,0 +1,10 @@
+use vld::Validate;
+
+#[derive(serde::Deserialize, Validate)]
+#[serde(rename_all = "camelcase")]
+struct User {
+    #[vld(vld::string())]
+    first_name: String,
+}
+
+fn main() {}
@@ -0,0 +1,259 @@
+use serde_json::json;
+#[allow(unused_imports)]
+use vld::prelude::*;
+use vld::Validated;
+
+vld::schema! {
+    #[derive(Debug, Clone, PartialEq, serde::Serialize)]
+    pub struct Server {
+        pub host: String => vld::string().min(1),
+        pub port: i64 => vld::number().int().min(1).max(65535),
+    }
+}
+
+vld::schema! {
+    #[derive(Debug, Clone, PartialEq, serde::Serialize)]
+    pub struct Config {
+        pub name: String => vld::string().min(3),
+        pub workers: Option<i64> => vld::number().int().positive().optional(),
+        pub server: Server => vld::nested!(Server),
+        pub tags: Vec<String> => vld::array(vld::string().min(1)).max_len(3),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// JSON
+// ---------------------------------------------------------------------------
+
+#[test]
+fn json_valid() {
+    let cfg: Validated<Config> = serde_json::from_str(
+        r#"{"name": "api", "server": {"host": "0.0.0.0", "port": 80}, "tags": ["a"]}"#,
+    )
+    .unwrap();
+    assert_eq!(cfg.name, "api");
+    assert_eq!(cfg.server.port, 80);
+    assert_eq!(cfg.workers, None);
+}
+
+#[test]
+fn json_invalid_reports_all_issues() {
+    let err = serde_json::from_str::<Validated<Config>>(
+        r#"{"name": "x", "workers": 0, "server": {"host": "", "port": 0}, "tags": []}"#,
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.starts_with("Validation failed: "), "{}", err);
+    for path in [".name:", ".workers:", ".server.host:", ".server.port:"] {
+        assert!(err.contains(path), "missing {} in {}", path, err);
+    }
+}
+
+#[test]
+fn json_syntax_error_is_not_a_validation_error() {
+    let err = serde_json::from_str::<Validated<Server>>(r#"{"host": "a", "port": "#).unwrap_err();
+    assert!(err.is_eof());
+    assert!(!err.to_string().contains("Validation failed"));
+}
+
+#[test]
+fn from_value() {
+    let cfg: Validated<Server> =
+        serde_json::from_value(json!({"host": "localhost", "port": 8080})).unwrap();
+    assert_eq!(cfg.into_inner().port, 8080);
+
+    assert!(serde_json::from_value::<Validated<Server>>(json!({"host": "h"})).is_err());
+}
+
+#[test]
+fn as_field_of_serde_struct() {
+    #[derive(serde::Deserialize)]
+    struct Envelope {
+        version: u32,
+        server: Validated<Server>,
+    }
+
+    let env: Envelope =
+        serde_json::from_str(r#"{"version": 2, "server": {"host": "h", "port": 1}}"#).unwrap();
+    assert_eq!(env.version, 2);
+    assert_eq!(env.server.host, "h");
+
+    let err = serde_json::from_str::<Envelope>(r#"{"version": 2, "server": {"host": "h"}}"#)
+        .err()
+        .unwrap()
+        .to_string();
+    assert!(err.contains(".port:"), "{}", err);
+}
+
+#[test]
+fn serialize_is_transparent() {
+    let server = Validated(Server {
+        host: "h".into(),
+        port: 1,
+    });
+    assert_eq!(
+        serde_json::to_value(&server).unwrap(),
+        json!({"host": "h", "port": 1})
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Other formats
+// ---------------------------------------------------------------------------
+
+#[test]
+fn toml_format() {
+    let src = r#"
+        name = "ingest"
+        workers = 4
+        tags = ["etl"]
+
+        [server]
+        host = "127.0.0.1"
+        port = 9000
+    "#;
+    let cfg: Validated<Config> = toml::from_str(src).unwrap();
+    assert_eq!(cfg.workers, Some(4));
+    assert_eq!(cfg.server.port, 9000);
+
+    let bad = r#"
+        name = "in"
+        tags = ["a", "b", "c", "d"]
+
+        [server]
+        host = "127.0.0.1"
+        port = 70000
+    "#;
+    let err = toml::from_str::<Validated<Config>>(bad)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains(".name:"), "{}", err);
+    assert!(err.contains(".server.port:"), "{}", err);
+    assert!(err.contains(".tags:"), "{}", err);
+}
+
+#[test]
+fn msgpack_format() {
+    let good = rmp_serde::to_vec_named(&json!({
+        "name": "worker",
+        "workers": 2,
+        "server": {"host": "db", "port": 5432},
+        "tags": []
+    }))
+    .unwrap();
+    let cfg: Validated<Config> = rmp_serde::from_slice(&good).unwrap();
+    assert_eq!(cfg.server.host, "db");
+
+    let bad = rmp_serde::to_vec_named(&json!({
+        "name": "worker",
+        "workers": -2,
+        "server": {"host": "db", "port": 5432},
+        "tags": []
+    }))
+    .unwrap();
+    let err = rmp_serde::from_slice::<Validated<Config>>(&bad)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains(".workers:"), "{}", err);
+}
+
+// ---------------------------------------------------------------------------
+// Non-self-describing formats
+// ---------------------------------------------------------------------------
+
+vld::schema! {
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    pub struct Reading {
+        pub sensor: String => vld::string().min(1),
+        pub celsius: f64 => vld::number().min(-90.0).max(60.0),
+        pub tags: Vec<String> => vld::array(vld::string().min(1)).max_len(2),
+    }
+}
+
+#[test]
+fn postcard_format() {
+    let good = Reading {
+        sensor: "roof".into(),
+        celsius: 21.5,
+        tags: vec!["outdoor".into()],
+    };
+    let bytes = postcard::to_allocvec(&good).unwrap();
+    let reading: vld::ValidatedTyped<Reading> = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(reading.into_inner(), good);
+
+    let bad = Reading {
+        sensor: String::new(),
+        celsius: 300.0,
+        tags: vec!["a".into(), "b".into(), "".into()],
+    };
+    let bytes = postcard::to_allocvec(&bad).unwrap();
+    let err = postcard::from_bytes::<vld::ValidatedTyped<Reading>>(&bytes).unwrap_err();
+    assert!(matches!(err, postcard::Error::SerdeDeCustom));
+
+    // The visitor-driven wrapper needs a self-describing format.
+    assert!(postcard::from_bytes::<Validated<Reading>>(&bytes).is_err());
+}
+
+#[test]
+fn deserialize_typed_on_fields() {
+    #[derive(serde::Deserialize)]
+    struct Batch {
+        id: u32,
+        #[serde(deserialize_with = "vld::validated::deserialize_typed")]
+        reading: Reading,
+    }
+
+    #[derive(serde::Serialize)]
+    struct RawBatch<'a> {
+        id: u32,
+        reading: &'a Reading,
+    }
+
+    let reading = Reading {
+        sensor: "cellar".into(),
+        celsius: 12.0,
+        tags: vec![],
+    };
+    let bytes = postcard::to_allocvec(&RawBatch {
+        id: 7,
+        reading: &reading,
+    })
+    .unwrap();
+    let batch: Batch = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!((batch.id, batch.reading), (7, reading));
+
+    let err = serde_json::from_value::<vld::ValidatedTyped<Reading>>(
+        json!({"sensor": "", "celsius": 0.0, "tags": []}),
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.starts_with("Validation failed: "), "{}", err);
+    assert!(err.contains(".sensor:"), "{}", err);
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use vld::Validated;
+
+    #[derive(Debug, vld::Validate)]
+    struct User {
+        #[vld(vld::string().email())]
+        email: String,
+        #[vld(vld::number().int().min(18))]
+        age: i64,
+    }
+
+    #[test]
+    fn derive_types_deserialize_through_validated() {
+        let user: Validated<User> =
+            serde_json::from_str(r#"{"email": "a@b.com", "age": 30}"#).unwrap();
+        assert_eq!(user.email, "a@b.com");
+        assert_eq!(user.age, 30);
+
+        let err = toml::from_str::<Validated<User>>("email = \"nope\"\nage = 3")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(".email:"), "{}", err);
+        assert!(err.contains(".age:"), "{}", err);
+    }
+}
@@ -0,0 +1,305 @@
+use vld::prelude::*;
+
+#[derive(Clone)]
+struct Tenant {
+    premium: bool,
+    max_tags: usize,
+}
+
+const FREE: Tenant = Tenant {
+    premium: false,
+    max_tags: 2,
+};
+
+const PREMIUM: Tenant = Tenant {
+    premium: true,
+    max_tags: 10,
+};
+
+#[derive(Clone)]
+struct Locale(&'static str);
+
+macro_rules! tags_schema {
+    () => {
+        vld::array(vld::string()).refine_ctx(
+            |tags: &Vec<String>, t: &Tenant| tags.len() <= t.max_tags,
+            "Too many tags for your plan",
+        )
+    };
+}
+
+// ---------------------------------------------------------------------------
+// refine_ctx / super_refine_ctx / transform_ctx
+// ---------------------------------------------------------------------------
+
+#[test]
+fn refine_ctx_uses_context() {
+    let input = r#"["a", "b", "c"]"#;
+    let err = tags_schema!().parse_with_context(input, &FREE).unwrap_err();
+    assert_eq!(err.issues[0].message, "Too many tags for your plan");
+    assert_eq!(
+        tags_schema!()
+            .parse_with_context(input, &PREMIUM)
+            .unwrap()
+            .len(),
+        3
+    );
+}
+
+#[test]
+fn missing_context_is_reported() {
+    let err = tags_schema!().parse(r#"["a"]"#).unwrap_err();
+    assert!(matches!(
+        &err.issues[0].code,
+        IssueCode::Custom { code } if code == "missing_context"
+    ));
+    assert!(err.issues[0].message.contains("Tenant"));
+}
+
+#[test]
+fn context_of_other_type_is_not_used() {
+    let err = tags_schema!()
+        .parse_with_context(r#"["a"]"#, &Locale("en"))
+        .unwrap_err();
+    assert!(matches!(
+        &err.issues[0].code,
+        IssueCode::Custom { code } if code == "missing_context"
+    ));
+}
+
+#[test]
+fn super_refine_ctx_collects_issues() {
+    let schema = vld::string().super_refine_ctx(|s, t: &Tenant, errors| {
+        if !t.premium && s.starts_with("pro_") {
+            errors.push(
+                IssueCode::Custom {
+                    code: "premium_only".into(),
+                },
+                "Premium prefix",
+            );
+        }
+        if !t.premium && s.len() > 8 {
+            errors.push(
+                IssueCode::Custom {
+                    code: "too_long".into(),
+                },
+                "Too long",
+            );
+        }
+    });
+    let err = schema
+        .parse_with_context(r#""pro_handle""#, &FREE)
+        .unwrap_err();
+    assert_eq!(err.issues.len(), 2);
+    assert!(schema
+        .parse_with_context(r#""pro_handle""#, &PREMIUM)
+        .is_ok());
+}
+
+#[test]
+fn transform_ctx_uses_context() {
+    let schema = vld::string().transform_ctx(|s, l: &Locale| match l.0 {
+        "de" => format!("Hallo {}", s),
+        _ => format!("Hello {}", s),
+    });
+    assert_eq!(
+        schema
+            .parse_with_context(r#""Ann""#, &Locale("de"))
+            .unwrap(),
+        "Hallo Ann"
+    );
+    assert_eq!(
+        schema
+            .parse_with_context(r#""Ann""#, &Locale("en"))
+            .unwrap(),
+        "Hello Ann"
+    );
+}
+
+#[test]
+fn inner_failure_skips_context_check() {
+    let schema = vld::string()
+        .min(5)
+        .refine_ctx(|_, _: &Tenant| false, "never");
+    let err = schema.parse_with_context(r#""ab""#, &PREMIUM).unwrap_err();
+    assert_eq!(err.issues.len(), 1);
+    assert_ne!(err.issues[0].message, "never");
+}
+
+// ---------------------------------------------------------------------------
+// Threading through containers
+// ---------------------------------------------------------------------------
+
+#[test]
+fn context_reaches_object_array_and_record() {
+    let schema = vld::object()
+        .field("tags", tags_schema!())
+        .field("groups", vld::array(tags_schema!()))
+        .field("by_lang", vld::record(tags_schema!()));
+
+    let input = r#"{
+        "tags": ["a"],
+        "groups": [["a"], ["a", "b", "c"]],
+        "by_lang": {"en": ["a", "b", "c"]}
+    }"#;
+    let err = schema.parse_with_context(input, &FREE).unwrap_err();
+    assert_eq!(err.issues.len(), 2);
+    let paths: Vec<String> = err
+        .issues
+        .iter()
+        .map(|i| i.path.iter().map(|p| p.to_string()).collect())
+        .collect();
+    assert!(paths.contains(&".groups[1]".to_string()));
+    assert!(paths.contains(&".by_lang.en".to_string()));
+
+    assert!(schema.parse_with_context(input, &PREMIUM).is_ok());
+}
+
+#[test]
+fn nested_contexts_shadow_by_type() {
+    let schema = vld::string()
+        .refine_ctx(|_, t: &Tenant| t.premium, "Premium required")
+        .transform_ctx(|s, l: &Locale| format!("{}:{}", l.0, s));
+
+    let out = vld::context::with_context(&Locale("fr"), || {
+        schema.parse_with_context(r#""x""#, &PREMIUM)
+    })
+    .unwrap();
+    assert_eq!(out, "fr:x");
+
+    let premium_only = vld::string().refine_ctx(|_, t: &Tenant| t.premium, "Premium required");
+    let inner = vld::context::with_context(&FREE, || {
+        premium_only.parse_with_context(r#""x""#, &PREMIUM).is_ok()
+    });
+    assert!(inner);
+    assert!(!vld::context::has_context::<Tenant>());
+}
+
+vld::schema! {
+    #[derive(Debug)]
+    pub struct Profile {
+        pub handle: String => vld::string().refine_ctx(
+            |h, t: &Tenant| t.premium || h.len() <= 5,
+            "Short handles only on the free plan",
+        ),
+        pub tags: Vec<String> => tags_schema!(),
+    }
+}
+
+vld::schema! {
+    #[derive(Debug)]
+    pub struct Account {
+        pub profile: Profile => vld::nested!(Profile),
+        pub email: String => vld::string().email(),
+    }
+}
+
+#[test]
+fn schema_macro_parse_with_context() {
+    let input =
+        r#"{"profile": {"handle": "longhandle", "tags": ["a", "b", "c"]}, "email": "a@b.com"}"#;
+    let err = Account::parse_with_context(input, &FREE).unwrap_err();
+    assert_eq!(err.issues.len(), 2);
+    assert!(err.issues.iter().all(|i| matches!(
+        &i.path[0],
+        PathSegment::Field(f) if f == "profile"
+    )));
+
+    let account = Account::parse_with_context(input, &PREMIUM).unwrap();
+    assert_eq!(account.profile.handle, "longhandle");
+}
+
+#[test]
+fn vld_parse_value_with_context() {
+    let value = serde_json::json!({"handle": "abc", "tags": ["a", "b", "c"]});
+    assert!(<Profile as VldParse>::vld_parse_value_with_context(&value, &FREE).is_err());
+    assert!(<Profile as VldParse>::vld_parse_value_with_context(&value, &PREMIUM).is_ok());
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use super::*;
+
+    #[derive(Debug, vld::Validate)]
+    struct Post {
+        #[vld(vld::string().min(1))]
+        title: String,
+        #[vld(tags_schema!())]
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn derive_parse_with_context() {
+        let input = r#"{"title": "Hi", "tags": ["a", "b", "c"]}"#;
+        assert!(Post::vld_parse_with_context(input, &FREE).is_err());
+        let post = Post::vld_parse_with_context(input, &PREMIUM).unwrap();
+        assert_eq!(post.title, "Hi");
+        assert_eq!(post.tags.len(), 3);
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_context {
+    use super::*;
+
+    fn slow_tags() -> impl VldSchemaAsync<Output = Vec<String>> {
+        vld::array(vld::string().refine_async(
+            |_: &String| async {
+                tokio::task::yield_now().await;
+                true
+            },
+            "unreachable",
+        ))
+        .refine_ctx(
+            |tags: &Vec<String>, t: &Tenant| tags.len() <= t.max_tags,
+            "Too many tags for your plan",
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn context_survives_await_points() {
+        let input = r#"["a", "b", "c"]"#;
+        let err = slow_tags()
+            .parse_with_context_async(input, &FREE)
+            .await
+            .unwrap_err();
+        assert_eq!(err.issues[0].message, "Too many tags for your plan");
+
+        let tags =
+            tokio::spawn(
+                async move { slow_tags().parse_with_context_async(input, &PREMIUM).await },
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(tags.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn async_parse_without_context_reports_it() {
+        let err = slow_tags().parse_async(r#"["a"]"#).await.unwrap_err();
+        assert_eq!(err.issues[0].code.key(), "missing_context");
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn struct_parse_async_with_context() {
+        let input = r#"{"profile": {"handle": "longhandle", "tags": ["a"]}, "email": "a@b.com"}"#;
+        let err = vld::context::with_context_async(&FREE, Account::parse_async(input))
+            .await
+            .unwrap_err();
+        assert_eq!(err.issues[0].message, "Short handles only on the free plan");
+
+        let value = serde_json::from_str(input).unwrap();
+        let account = Account::vld_parse_value_with_context_async(&value, &PREMIUM)
+            .await
+            .unwrap();
+        assert_eq!(account.profile.handle, "longhandle");
+
+        let transformed = vld::string()
+            .transform_ctx(|s, l: &Locale| format!("{}:{}", l.0, s))
+            .parse_with_context_async(r#""hi""#, &Locale("de"))
+            .await
+            .unwrap();
+        assert_eq!(transformed, "de:hi");
+    }
+}